@@ -2,7 +2,7 @@
  * FROST Backend Factory
  *
  * Provides a unified interface for FROST threshold signatures across
- * different curve implementations (Ed25519, RedPallas).
+ * different curve implementations (Ed25519, RedPallas, RedJubjub).
  */
 
 export type { BackendId, FrostBackend, KeyGenResult, Round1Result, SigningPackageResult } from './types';
@@ -53,6 +53,13 @@ export async function getBackend(backendId: BackendId): Promise<FrostBackend> {
       return backend;
     }
 
+    case 'sapling-redjubjub': {
+      const { getSaplingBackend } = await import('./sapling');
+      const backend = await getSaplingBackend();
+      backends[backendId] = backend;
+      return backend;
+    }
+
     default:
       throw new Error(`Unknown backend: ${backendId}`);
   }
@@ -69,7 +76,7 @@ export function isBackendLoaded(backendId: BackendId): boolean {
  * Get all supported backend IDs.
  */
 export function getSupportedBackends(): BackendId[] {
-  return ['ed25519', 'orchard-redpallas'];
+  return ['ed25519', 'orchard-redpallas', 'sapling-redjubjub'];
 }
 
 /**
@@ -81,6 +88,8 @@ export function getBackendName(backendId: BackendId): string {
       return 'Ed25519 (Standard FROST)';
     case 'orchard-redpallas':
       return 'Zcash Orchard (RedPallas)';
+    case 'sapling-redjubjub':
+      return 'Zcash Sapling (RedJubjub)';
     default:
       return backendId;
   }
@@ -95,6 +104,8 @@ export function getBackendDescription(backendId: BackendId): string {
       return 'Standard FROST threshold signatures using the Ed25519 curve. Compatible with Ed25519 ecosystem.';
     case 'orchard-redpallas':
       return 'Rerandomized FROST for Zcash Orchard using the RedPallas curve. Provides transaction unlinkability.';
+    case 'sapling-redjubjub':
+      return 'Rerandomized FROST for Zcash Sapling using the RedJubjub curve. Spends funds held in Sapling.';
     default:
       return '';
   }
@@ -2,13 +2,13 @@
  * Backend-agnostic FROST interface types.
  *
  * This module defines a unified interface for FROST threshold signatures
- * that works across different curve implementations (Ed25519, RedPallas).
+ * that works across different curve implementations (Ed25519, RedPallas, RedJubjub).
  */
 
 /**
  * Supported FROST backend identifiers.
  */
-export type BackendId = 'ed25519' | 'orchard-redpallas';
+export type BackendId = 'ed25519' | 'orchard-redpallas' | 'sapling-redjubjub';
 
 /**
  * Result of key generation.
@@ -56,7 +56,7 @@ export interface SigningPackageResult {
 /**
  * Backend-agnostic FROST interface.
  *
- * All FROST backends (Ed25519, RedPallas) implement this interface.
+ * All FROST backends (Ed25519, RedPallas, RedJubjub) implement this interface.
  * The interface handles differences in rerandomization transparently.
  */
 export interface FrostBackend {
@@ -147,10 +147,10 @@ export interface FrostBackend {
 }
 
 /**
- * Check if a backend supports rerandomization (Orchard).
+ * Check if a backend supports rerandomization (Orchard, Sapling).
  */
 export function supportsRerandomization(backend: FrostBackend): boolean {
-  return backend.backendId === 'orchard-redpallas';
+  return backend.backendId === 'orchard-redpallas' || backend.backendId === 'sapling-redjubjub';
 }
 
 /**
@@ -0,0 +1,337 @@
+//! The web UI's FROST backends, mapped onto the JSON API of the crate
+//! implementing each one
+//!
+//! Key generation and both signing rounds use the same JSON types on every
+//! backend. Signing packages and signatures don't: the rerandomized backends
+//! carry the randomizer alongside, so those are handled as file contents.
+//!
+//! The Taproot backend can sign for a tweaked output key: round 2, aggregation
+//! and verification take the script tree merkle root, which is rejected on
+//! the other backends.
+
+use clap::ValueEnum;
+use frost_common::{
+    AggregateError, AggregateResult, AggregateSignature, FrostError, KeyGenResult, Round1Result,
+    SignatureShare, SigningPackage, SigningPackageResult, VerifyResult,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// FROST backend, named as in the web UI's `BackendId` where it has one
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Backend {
+    /// Ed25519 (frost-wasm)
+    Ed25519,
+    /// Rerandomized RedPallas for Zcash Orchard (frost-zcash-wasm)
+    OrchardRedpallas,
+    /// Rerandomized RedJubjub for Zcash Sapling (frost-zcash-wasm)
+    SaplingRedjubjub,
+    /// BIP-340 Schnorr on secp256k1 for Bitcoin Taproot (frost-secp256k1-tr-wasm)
+    #[value(name = "secp256k1-tr")]
+    Secp256k1Tr,
+}
+
+impl Backend {
+    /// Trusted dealer key generation
+    pub fn generate_key_shares(self, threshold: u16, total: u16) -> Result<KeyGenResult, String> {
+        match self {
+            Backend::Ed25519 => frost_wasm::json::generate_key_shares(threshold, total),
+            Backend::OrchardRedpallas => {
+                frost_zcash_wasm::json::generate_key_shares(threshold, total)
+            }
+            Backend::SaplingRedjubjub => {
+                frost_zcash_wasm::json::sapling_generate_key_shares(threshold, total)
+            }
+            Backend::Secp256k1Tr => {
+                frost_secp256k1_tr_wasm::json::generate_key_shares(threshold, total)
+            }
+        }
+        .map_err(frost_error)
+    }
+
+    /// Round 1 nonces and commitment
+    pub fn generate_round1_commitment(
+        self,
+        key_package_json: &str,
+    ) -> Result<Round1Result, String> {
+        match self {
+            Backend::Ed25519 => frost_wasm::json::generate_round1_commitment(key_package_json),
+            Backend::OrchardRedpallas => {
+                frost_zcash_wasm::json::generate_round1_commitment(key_package_json)
+            }
+            Backend::SaplingRedjubjub => {
+                frost_zcash_wasm::json::sapling_generate_round1_commitment(key_package_json)
+            }
+            Backend::Secp256k1Tr => {
+                frost_secp256k1_tr_wasm::json::generate_round1_commitment(key_package_json)
+            }
+        }
+        .map_err(frost_error)
+    }
+
+    /// Signing package file contents: a SigningPackage, or a
+    /// SigningPackageResult (with the randomizer) on the rerandomized backends
+    pub fn create_signing_package(
+        self,
+        commitments_json: &str,
+        message_hex: &str,
+        public_key_package_json: &str,
+    ) -> Result<String, String> {
+        match self {
+            Backend::Ed25519 => to_pretty_json(
+                &frost_wasm::json::create_signing_package(commitments_json, message_hex)
+                    .map_err(frost_error)?,
+            ),
+            Backend::OrchardRedpallas => to_pretty_json(
+                &frost_zcash_wasm::json::create_signing_package(
+                    commitments_json,
+                    message_hex,
+                    public_key_package_json,
+                )
+                .map_err(frost_error)?,
+            ),
+            Backend::SaplingRedjubjub => to_pretty_json(
+                &frost_zcash_wasm::json::sapling_create_signing_package(
+                    commitments_json,
+                    message_hex,
+                    public_key_package_json,
+                )
+                .map_err(frost_error)?,
+            ),
+            Backend::Secp256k1Tr => to_pretty_json(
+                &frost_secp256k1_tr_wasm::json::create_signing_package(
+                    commitments_json,
+                    message_hex,
+                )
+                .map_err(frost_error)?,
+            ),
+        }
+    }
+
+    /// Round 2 signature share over the coordinator's signing package file,
+    /// for the Taproot output key if a merkle root is given
+    pub fn generate_round2_signature(
+        self,
+        key_package_json: &str,
+        nonces_json: &str,
+        signing_package_file: &str,
+        merkle_root_hex: Option<&str>,
+    ) -> Result<SignatureShare, String> {
+        self.check_merkle_root(merkle_root_hex)?;
+        match self {
+            Backend::Ed25519 => {
+                let package: SigningPackage = from_json(signing_package_file, "signing package")?;
+                frost_wasm::json::generate_round2_signature_with_package(
+                    key_package_json,
+                    nonces_json,
+                    &package.signing_package,
+                )
+            }
+            Backend::OrchardRedpallas => {
+                let package: SigningPackageResult =
+                    from_json(signing_package_file, "signing package")?;
+                frost_zcash_wasm::json::generate_round2_signature(
+                    key_package_json,
+                    nonces_json,
+                    &package.signing_package,
+                    &package.randomizer,
+                )
+            }
+            Backend::SaplingRedjubjub => {
+                let package: SigningPackageResult =
+                    from_json(signing_package_file, "signing package")?;
+                frost_zcash_wasm::json::sapling_generate_round2_signature(
+                    key_package_json,
+                    nonces_json,
+                    &package.signing_package,
+                    &package.randomizer,
+                )
+            }
+            Backend::Secp256k1Tr => {
+                let package: SigningPackage = from_json(signing_package_file, "signing package")?;
+                match merkle_root_hex {
+                    Some(merkle_root_hex) => {
+                        frost_secp256k1_tr_wasm::json::generate_round2_signature_with_package_and_tweak(
+                            key_package_json,
+                            nonces_json,
+                            &package.signing_package,
+                            merkle_root_hex,
+                        )
+                    }
+                    None => frost_secp256k1_tr_wasm::json::generate_round2_signature_with_package(
+                        key_package_json,
+                        nonces_json,
+                        &package.signing_package,
+                    ),
+                }
+            }
+        }
+        .map_err(frost_error)
+    }
+
+    /// Signature file contents: an AggregateSignature, or an AggregateResult
+    /// (with the randomizer) on the rerandomized backends. With a merkle root
+    /// the signature verifies under the Taproot output key
+    pub fn aggregate_signature(
+        self,
+        shares_json: &str,
+        signing_package_file: &str,
+        public_key_package_json: &str,
+        merkle_root_hex: Option<&str>,
+    ) -> Result<String, String> {
+        self.check_merkle_root(merkle_root_hex)?;
+        match self {
+            Backend::Ed25519 => {
+                let package: SigningPackage = from_json(signing_package_file, "signing package")?;
+                to_pretty_json(
+                    &frost_wasm::json::aggregate_signature_with_package(
+                        shares_json,
+                        &package.signing_package,
+                        public_key_package_json,
+                    )
+                    .map_err(aggregate_error)?,
+                )
+            }
+            Backend::OrchardRedpallas => {
+                let package: SigningPackageResult =
+                    from_json(signing_package_file, "signing package")?;
+                to_pretty_json(
+                    &frost_zcash_wasm::json::aggregate_signature(
+                        shares_json,
+                        &package.signing_package,
+                        public_key_package_json,
+                        &package.randomizer,
+                    )
+                    .map_err(aggregate_error)?,
+                )
+            }
+            Backend::SaplingRedjubjub => {
+                let package: SigningPackageResult =
+                    from_json(signing_package_file, "signing package")?;
+                to_pretty_json(
+                    &frost_zcash_wasm::json::sapling_aggregate_signature(
+                        shares_json,
+                        &package.signing_package,
+                        public_key_package_json,
+                        &package.randomizer,
+                    )
+                    .map_err(aggregate_error)?,
+                )
+            }
+            Backend::Secp256k1Tr => {
+                let package: SigningPackage = from_json(signing_package_file, "signing package")?;
+                let signature = match merkle_root_hex {
+                    Some(merkle_root_hex) => {
+                        frost_secp256k1_tr_wasm::json::aggregate_signature_with_package_and_tweak(
+                            shares_json,
+                            &package.signing_package,
+                            public_key_package_json,
+                            merkle_root_hex,
+                        )
+                    }
+                    None => frost_secp256k1_tr_wasm::json::aggregate_signature_with_package(
+                        shares_json,
+                        &package.signing_package,
+                        public_key_package_json,
+                    ),
+                };
+                to_pretty_json(&signature.map_err(aggregate_error)?)
+            }
+        }
+    }
+
+    /// Verify a signature file against the group public key, or against the
+    /// Taproot output key derived from the public key package if a merkle
+    /// root is given
+    pub fn verify_signature(
+        self,
+        signature_file: &str,
+        message_hex: &str,
+        group_public_key_hex: &str,
+        public_key_package_json: &str,
+        merkle_root_hex: Option<&str>,
+    ) -> Result<VerifyResult, String> {
+        self.check_merkle_root(merkle_root_hex)?;
+        match self {
+            Backend::Ed25519 => {
+                let signature: AggregateSignature = from_json(signature_file, "signature")?;
+                frost_wasm::json::verify_signature(
+                    &signature.signature,
+                    message_hex,
+                    group_public_key_hex,
+                )
+            }
+            Backend::OrchardRedpallas => {
+                let signature: AggregateResult = from_json(signature_file, "signature")?;
+                frost_zcash_wasm::json::verify_signature(
+                    &signature.signature,
+                    message_hex,
+                    group_public_key_hex,
+                    &signature.randomizer,
+                )
+            }
+            Backend::SaplingRedjubjub => {
+                let signature: AggregateResult = from_json(signature_file, "signature")?;
+                frost_zcash_wasm::json::sapling_verify_signature(
+                    &signature.signature,
+                    message_hex,
+                    group_public_key_hex,
+                    &signature.randomizer,
+                )
+            }
+            Backend::Secp256k1Tr => {
+                let signature: AggregateSignature = from_json(signature_file, "signature")?;
+                let public_key_hex = match merkle_root_hex {
+                    Some(merkle_root_hex) => {
+                        frost_secp256k1_tr_wasm::json::tweak_group_public_key(
+                            public_key_package_json,
+                            merkle_root_hex,
+                        )
+                        .map_err(frost_error)?
+                        .output_key
+                    }
+                    None => group_public_key_hex.to_string(),
+                };
+                frost_secp256k1_tr_wasm::json::verify_signature(
+                    &signature.signature,
+                    message_hex,
+                    &public_key_hex,
+                )
+            }
+        }
+        .map_err(frost_error)
+    }
+
+    /// Only the Taproot backend has an output key to tweak
+    fn check_merkle_root(self, merkle_root_hex: Option<&str>) -> Result<(), String> {
+        match (self, merkle_root_hex) {
+            (Backend::Secp256k1Tr, _) | (_, None) => Ok(()),
+            (_, Some(_)) => Err("--merkle-root only applies to the secp256k1-tr backend".into()),
+        }
+    }
+}
+
+pub fn frost_error(error: FrostError) -> String {
+    format!("{}: {}", error.code, error.message)
+}
+
+fn aggregate_error(error: AggregateError) -> String {
+    if error.culprits.is_empty() {
+        format!("{}: {}", error.code, error.message)
+    } else {
+        format!(
+            "{}: {} (invalid shares from {:?})",
+            error.code, error.message, error.culprits
+        )
+    }
+}
+
+/// Parse the contents of a ceremony file
+pub fn from_json<T: DeserializeOwned>(json: &str, what: &str) -> Result<T, String> {
+    serde_json::from_str(json).map_err(|e| format!("Invalid {} file: {}", what, e))
+}
+
+/// Serialize a ceremony file, indented so operators can inspect it
+pub fn to_pretty_json<T: Serialize>(value: &T) -> Result<String, String> {
+    serde_json::to_string_pretty(value).map_err(|e| format!("Failed to serialize: {}", e))
+}
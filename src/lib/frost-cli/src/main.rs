@@ -0,0 +1,717 @@
+//! Offline FROST ceremonies for air-gapped signers
+//!
+//! Runs key generation, both signing rounds, aggregation and verification as
+//! file-in/file-out commands on top of the binding crates' Rust API. Files use
+//! the same JSON shapes as the WASM bindings (`KeyGenResult`, `Round1Result`,
+//! `SignatureShare`, ...), so they can be carried between an offline machine
+//! and the web UI on removable media.
+//!
+//! A 2-of-3 ceremony:
+//!
+//! ```text
+//! frost-cli keygen --threshold 2 --total 3 --out keygen.json --shares-dir shares
+//! frost-cli round1 --key-package shares/share-1.json --out round1-1.json \
+//!     --commitment-out commitment-1.json
+//! frost-cli signing-package --commitment commitment-1.json --commitment commitment-2.json \
+//!     --message-file sighash.bin --public-key-package keygen.json --out signing-package.json
+//! frost-cli round2 --key-package shares/share-1.json --round1 round1-1.json \
+//!     --signing-package signing-package.json --nonce-registry nonces.json --out share-1.json
+//! frost-cli aggregate --share share-1.json --share share-2.json \
+//!     --signing-package signing-package.json --public-key-package keygen.json --out signature.json
+//! frost-cli verify --signature signature.json --message-file sighash.bin \
+//!     --public-key-package keygen.json
+//! ```
+//!
+//! With `--backend secp256k1-tr`, passing `--merkle-root` to `round2`,
+//! `aggregate` and `verify` signs for the Taproot output key instead of the
+//! internal group key.
+
+mod backend;
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, Parser, Subcommand};
+use frost_common::{nonces, Commitment, Round1Result, SignatureShare};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use backend::{from_json, frost_error, to_pretty_json, Backend};
+
+#[derive(Parser)]
+#[command(
+    name = "frost-cli",
+    version,
+    about = "Offline FROST threshold signing ceremonies"
+)]
+struct Cli {
+    /// FROST backend, as named by the web UI
+    #[arg(long, value_enum, global = true, default_value_t = Backend::Ed25519)]
+    backend: Backend,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Trusted dealer key generation
+    Keygen {
+        /// Minimum signers required (t)
+        #[arg(long)]
+        threshold: u16,
+        /// Total number of signers (n)
+        #[arg(long)]
+        total: u16,
+        /// Output KeyGenResult - holds every share, KEEP SECRET
+        #[arg(long)]
+        out: PathBuf,
+        /// Also write each participant's KeyShare to DIR/share-<id>.json
+        #[arg(long, value_name = "DIR")]
+        shares_dir: Option<PathBuf>,
+    },
+    /// Round 1: generate nonces and the commitment to publish
+    Round1 {
+        /// Key package file (KeyShare or DkgResult)
+        #[arg(long)]
+        key_package: PathBuf,
+        /// Output Round1Result - holds the nonces, KEEP SECRET
+        #[arg(long)]
+        out: PathBuf,
+        /// Also write the Commitment alone, to carry to the coordinator
+        #[arg(long)]
+        commitment_out: Option<PathBuf>,
+    },
+    /// Coordinator: build the signing package from the signers' commitments
+    SigningPackage {
+        /// Commitment file, once per signer
+        #[arg(long = "commitment", required = true)]
+        commitments: Vec<PathBuf>,
+        #[command(flatten)]
+        message: MessageArgs,
+        /// Public key package file (KeyGenResult or DkgResult)
+        #[arg(long)]
+        public_key_package: PathBuf,
+        /// Output signing package (with the randomizer on rerandomized backends)
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Round 2: sign the coordinator's signing package
+    Round2 {
+        /// Key package file (KeyShare or DkgResult)
+        #[arg(long)]
+        key_package: PathBuf,
+        /// Round1Result from this signer's `round1`
+        #[arg(long)]
+        round1: PathBuf,
+        /// Signing package from the coordinator
+        #[arg(long)]
+        signing_package: PathBuf,
+        /// Nonce registry to check and update (NonceRegistryState, created if missing).
+        /// Required: each run is a new process, so this is the only record of spent nonces
+        #[arg(long)]
+        nonce_registry: PathBuf,
+        #[command(flatten)]
+        tweak: TweakArgs,
+        /// Output SignatureShare
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Coordinator: aggregate the signature shares
+    Aggregate {
+        /// SignatureShare file, once per signer
+        #[arg(long = "share", required = true)]
+        shares: Vec<PathBuf>,
+        /// Signing package the shares were made over
+        #[arg(long)]
+        signing_package: PathBuf,
+        /// Public key package file (KeyGenResult or DkgResult)
+        #[arg(long)]
+        public_key_package: PathBuf,
+        #[command(flatten)]
+        tweak: TweakArgs,
+        /// Output signature (with the randomizer on rerandomized backends)
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Verify an aggregate signature; exits non-zero if it is invalid
+    Verify {
+        /// Signature file from `aggregate`
+        #[arg(long)]
+        signature: PathBuf,
+        #[command(flatten)]
+        message: MessageArgs,
+        /// Public key package file (KeyGenResult or DkgResult)
+        #[arg(long)]
+        public_key_package: PathBuf,
+        #[command(flatten)]
+        tweak: TweakArgs,
+    },
+}
+
+/// The message being signed, given inline or as a file
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct MessageArgs {
+    /// Message (hex-encoded)
+    #[arg(long)]
+    message: Option<String>,
+    /// File holding the raw message bytes, e.g. a transaction sighash
+    #[arg(long)]
+    message_file: Option<PathBuf>,
+}
+
+impl MessageArgs {
+    fn message_hex(&self) -> Result<String, String> {
+        match (&self.message, &self.message_file) {
+            (Some(message), _) => Ok(message.clone()),
+            (None, Some(path)) => fs::read(path)
+                .map(hex::encode)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e)),
+            (None, None) => Err("No message given".into()),
+        }
+    }
+}
+
+/// The Taproot tweak (secp256k1-tr only)
+#[derive(Args)]
+struct TweakArgs {
+    /// Sign for the Taproot output key committing to this script tree merkle
+    /// root (hex, 32 bytes); pass "" for a key-path-only output (BIP-86)
+    #[arg(long)]
+    merkle_root: Option<String>,
+}
+
+/// Any file carrying a participant's key package (KeyShare, DkgResult)
+#[derive(Deserialize)]
+struct KeyPackageFile {
+    key_package: String,
+}
+
+/// Any file carrying the group's public key package (KeyGenResult, DkgResult)
+#[derive(Deserialize)]
+struct PublicKeyPackageFile {
+    group_public_key: String,
+    public_key_package: String,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let backend = cli.backend;
+
+    match cli.command {
+        Command::Keygen {
+            threshold,
+            total,
+            out,
+            shares_dir,
+        } => {
+            let keygen = backend.generate_key_shares(threshold, total)?;
+
+            if let Some(dir) = shares_dir {
+                fs::create_dir_all(&dir)
+                    .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+                for share in &keygen.shares {
+                    let path = dir.join(format!("share-{}.json", share.identifier));
+                    write_new(&path, &to_pretty_json(share)?)?;
+                }
+            }
+
+            write_new(&out, &to_pretty_json(&keygen)?)
+        }
+
+        Command::Round1 {
+            key_package,
+            out,
+            commitment_out,
+        } => {
+            let key_package: KeyPackageFile = read_json(&key_package, "key package")?;
+            let round1 = backend.generate_round1_commitment(&key_package.key_package)?;
+
+            write_new(&out, &to_pretty_json(&round1)?)?;
+            if let Some(path) = commitment_out {
+                write_new(&path, &to_pretty_json(&round1.commitment)?)?;
+            }
+            Ok(())
+        }
+
+        Command::SigningPackage {
+            commitments,
+            message,
+            public_key_package,
+            out,
+        } => {
+            let commitments = commitments
+                .iter()
+                .map(|path| read_json::<Commitment>(path, "commitment"))
+                .collect::<Result<Vec<_>, _>>()?;
+            let public_key_package: PublicKeyPackageFile =
+                read_json(&public_key_package, "public key package")?;
+
+            let signing_package = backend.create_signing_package(
+                &to_pretty_json(&commitments)?,
+                &message.message_hex()?,
+                &public_key_package.public_key_package,
+            )?;
+
+            write_new(&out, &signing_package)
+        }
+
+        Command::Round2 {
+            key_package,
+            round1,
+            signing_package,
+            nonce_registry,
+            tweak,
+            out,
+        } => {
+            let key_package: KeyPackageFile = read_json(&key_package, "key package")?;
+            let round1: Round1Result = read_json(&round1, "Round 1")?;
+            let signing_package = read_file(&signing_package)?;
+
+            if nonce_registry.exists() {
+                nonces::import_nonce_registry(&read_file(&nonce_registry)?).map_err(frost_error)?;
+            }
+
+            let share = backend.generate_round2_signature(
+                &key_package.key_package,
+                &to_pretty_json(&round1.nonces)?,
+                &signing_package,
+                tweak.merkle_root.as_deref(),
+            )?;
+
+            // Record the spent nonces before releasing the share, so a failed
+            // write can never leave them usable for a second signature
+            let registry = to_pretty_json(&nonces::export_nonce_registry())?;
+            write_replace(&nonce_registry, &registry)?;
+
+            write_new(&out, &to_pretty_json(&share)?)
+        }
+
+        Command::Aggregate {
+            shares,
+            signing_package,
+            public_key_package,
+            tweak,
+            out,
+        } => {
+            let shares = shares
+                .iter()
+                .map(|path| read_json::<SignatureShare>(path, "signature share"))
+                .collect::<Result<Vec<_>, _>>()?;
+            let public_key_package: PublicKeyPackageFile =
+                read_json(&public_key_package, "public key package")?;
+
+            let signature = backend.aggregate_signature(
+                &to_pretty_json(&shares)?,
+                &read_file(&signing_package)?,
+                &public_key_package.public_key_package,
+                tweak.merkle_root.as_deref(),
+            )?;
+
+            write_new(&out, &signature)
+        }
+
+        Command::Verify {
+            signature,
+            message,
+            public_key_package,
+            tweak,
+        } => {
+            let public_key_package: PublicKeyPackageFile =
+                read_json(&public_key_package, "public key package")?;
+
+            let result = backend.verify_signature(
+                &read_file(&signature)?,
+                &message.message_hex()?,
+                &public_key_package.group_public_key,
+                &public_key_package.public_key_package,
+                tweak.merkle_root.as_deref(),
+            )?;
+
+            println!("{}", to_pretty_json(&result)?);
+            if result.valid {
+                Ok(())
+            } else {
+                Err("Signature is not valid".into())
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path, what: &str) -> Result<T, String> {
+    from_json(&read_file(path)?, what)
+}
+
+/// Write an output file, refusing to overwrite one that exists (it may hold
+/// key material). Files are readable by the owner only.
+fn write_new(path: &Path, contents: &str) -> Result<(), String> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Atomically replace a file: write a temporary file next to it, flush it to
+/// disk and rename it over the target, so a crash never leaves it truncated.
+fn write_replace(path: &Path, contents: &str) -> Result<(), String> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| format!("Not a file path: {}", path.display()))?;
+    let mut temp_name = file_name.to_os_string();
+    temp_name.push(".tmp");
+    let temp = path.with_file_name(temp_name);
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    options
+        .open(&temp)
+        .and_then(|mut file| {
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&temp, path))
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frost_cli(args: &[&str]) -> Result<(), String> {
+        run(
+            Cli::try_parse_from(std::iter::once("frost-cli").chain(args.iter().copied()))
+                .map_err(|e| e.to_string())?,
+        )
+    }
+
+    #[test]
+    fn test_ceremony_files() {
+        for backend in [
+            "ed25519",
+            "orchard-redpallas",
+            "sapling-redjubjub",
+            "secp256k1-tr",
+        ] {
+            let dir = std::env::temp_dir().join(format!(
+                "frost-cli-test-{}-{}",
+                backend,
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            let path = |name: &str| dir.join(name).to_str().unwrap().to_string();
+            let message = "48656c6c6f20576f726c64";
+
+            frost_cli(&[
+                "--backend",
+                backend,
+                "keygen",
+                "--threshold",
+                "2",
+                "--total",
+                "3",
+                "--out",
+                &path("keygen.json"),
+                "--shares-dir",
+                &path("shares"),
+            ])
+            .expect("keygen should succeed");
+
+            for id in ["1", "3"] {
+                frost_cli(&[
+                    "--backend",
+                    backend,
+                    "round1",
+                    "--key-package",
+                    &path(&format!("shares/share-{}.json", id)),
+                    "--out",
+                    &path(&format!("round1-{}.json", id)),
+                    "--commitment-out",
+                    &path(&format!("commitment-{}.json", id)),
+                ])
+                .expect("round1 should succeed");
+            }
+
+            frost_cli(&[
+                "--backend",
+                backend,
+                "signing-package",
+                "--commitment",
+                &path("commitment-1.json"),
+                "--commitment",
+                &path("commitment-3.json"),
+                "--message",
+                message,
+                "--public-key-package",
+                &path("keygen.json"),
+                "--out",
+                &path("signing-package.json"),
+            ])
+            .expect("signing-package should succeed");
+
+            for id in ["1", "3"] {
+                frost_cli(&[
+                    "--backend",
+                    backend,
+                    "round2",
+                    "--key-package",
+                    &path(&format!("shares/share-{}.json", id)),
+                    "--round1",
+                    &path(&format!("round1-{}.json", id)),
+                    "--signing-package",
+                    &path("signing-package.json"),
+                    "--nonce-registry",
+                    &path("nonces.json"),
+                    "--out",
+                    &path(&format!("share-{}.json", id)),
+                ])
+                .expect("round2 should succeed");
+            }
+
+            let error = frost_cli(&[
+                "--backend",
+                backend,
+                "round2",
+                "--key-package",
+                &path("shares/share-1.json"),
+                "--round1",
+                &path("round1-1.json"),
+                "--signing-package",
+                &path("signing-package.json"),
+                "--nonce-registry",
+                &path("nonces.json"),
+                "--out",
+                &path("share-1-again.json"),
+            ])
+            .expect_err("Reusing Round 1 nonces should fail");
+            assert!(error.starts_with("NONCE_REUSED"), "{}", error);
+            assert!(!Path::new(&path("nonces.json.tmp")).exists());
+
+            frost_cli(&[
+                "--backend",
+                backend,
+                "round2",
+                "--key-package",
+                &path("shares/share-1.json"),
+                "--round1",
+                &path("round1-1.json"),
+                "--signing-package",
+                &path("signing-package.json"),
+                "--out",
+                &path("share-1-unchecked.json"),
+            ])
+            .expect_err("Round 2 without a nonce registry should be rejected");
+
+            frost_cli(&[
+                "--backend",
+                backend,
+                "aggregate",
+                "--share",
+                &path("share-1.json"),
+                "--share",
+                &path("share-3.json"),
+                "--signing-package",
+                &path("signing-package.json"),
+                "--public-key-package",
+                &path("keygen.json"),
+                "--out",
+                &path("signature.json"),
+            ])
+            .expect("aggregate should succeed");
+
+            let verify = |message: &str| {
+                frost_cli(&[
+                    "--backend",
+                    backend,
+                    "verify",
+                    "--signature",
+                    &path("signature.json"),
+                    "--message",
+                    message,
+                    "--public-key-package",
+                    &path("keygen.json"),
+                ])
+            };
+            verify(message).expect("Signature should verify");
+            verify("00").expect_err("Signature over another message should not verify");
+
+            let error = frost_cli(&[
+                "--backend",
+                backend,
+                "keygen",
+                "--threshold",
+                "2",
+                "--total",
+                "3",
+                "--out",
+                &path("keygen.json"),
+            ])
+            .expect_err("keygen should not overwrite key material");
+            assert!(error.contains("keygen.json"), "{}", error);
+
+            fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_taproot_merkle_root() {
+        let dir =
+            std::env::temp_dir().join(format!("frost-cli-test-taproot-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = |name: &str| dir.join(name).to_str().unwrap().to_string();
+        let message = "48656c6c6f20576f726c64";
+        let merkle_root = "11".repeat(32);
+        let taproot =
+            |args: &[&str]| frost_cli(&[["--backend", "secp256k1-tr"].as_slice(), args].concat());
+
+        taproot(&[
+            "keygen",
+            "--threshold",
+            "2",
+            "--total",
+            "3",
+            "--out",
+            &path("keygen.json"),
+            "--shares-dir",
+            &path("shares"),
+        ])
+        .expect("keygen should succeed");
+
+        for id in ["1", "2"] {
+            taproot(&[
+                "round1",
+                "--key-package",
+                &path(&format!("shares/share-{}.json", id)),
+                "--out",
+                &path(&format!("round1-{}.json", id)),
+                "--commitment-out",
+                &path(&format!("commitment-{}.json", id)),
+            ])
+            .expect("round1 should succeed");
+        }
+
+        taproot(&[
+            "signing-package",
+            "--commitment",
+            &path("commitment-1.json"),
+            "--commitment",
+            &path("commitment-2.json"),
+            "--message",
+            message,
+            "--public-key-package",
+            &path("keygen.json"),
+            "--out",
+            &path("signing-package.json"),
+        ])
+        .expect("signing-package should succeed");
+
+        for id in ["1", "2"] {
+            taproot(&[
+                "round2",
+                "--key-package",
+                &path(&format!("shares/share-{}.json", id)),
+                "--round1",
+                &path(&format!("round1-{}.json", id)),
+                "--signing-package",
+                &path("signing-package.json"),
+                "--nonce-registry",
+                &path("nonces.json"),
+                "--merkle-root",
+                &merkle_root,
+                "--out",
+                &path(&format!("share-{}.json", id)),
+            ])
+            .expect("tweaked round2 should succeed");
+        }
+
+        let aggregate = |out: &str, merkle_root: Option<&str>| {
+            let mut args = vec![
+                "aggregate",
+                "--share",
+                &path("share-1.json"),
+                "--share",
+                &path("share-2.json"),
+                "--signing-package",
+                &path("signing-package.json"),
+                "--public-key-package",
+                &path("keygen.json"),
+                "--out",
+                out,
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+            if let Some(merkle_root) = merkle_root {
+                args.extend(["--merkle-root".into(), merkle_root.into()]);
+            }
+            taproot(&args.iter().map(String::as_str).collect::<Vec<_>>())
+        };
+        aggregate(&path("untweaked.json"), None)
+            .expect_err("Tweaked shares should not aggregate for the internal key");
+        aggregate(&path("signature.json"), Some(&merkle_root)).expect("aggregate should succeed");
+
+        let verify = |merkle_root: Option<&str>| {
+            let mut args = vec![
+                "verify".to_string(),
+                "--signature".into(),
+                path("signature.json"),
+                "--message".into(),
+                message.into(),
+                "--public-key-package".into(),
+                path("keygen.json"),
+            ];
+            if let Some(merkle_root) = merkle_root {
+                args.extend(["--merkle-root".into(), merkle_root.into()]);
+            }
+            taproot(&args.iter().map(String::as_str).collect::<Vec<_>>())
+        };
+        verify(Some(&merkle_root)).expect("Signature should verify under the output key");
+        verify(None).expect_err("Signature should not verify under the internal key");
+        verify(Some("")).expect_err("Signature should not verify under the BIP-86 output key");
+
+        let error = frost_cli(&[
+            "verify",
+            "--signature",
+            &path("signature.json"),
+            "--message",
+            message,
+            "--public-key-package",
+            &path("keygen.json"),
+            "--merkle-root",
+            &merkle_root,
+        ])
+        .expect_err("--merkle-root should be rejected on Ed25519");
+        assert!(error.contains("--merkle-root"), "{}", error);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
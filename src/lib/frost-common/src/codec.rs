@@ -0,0 +1,200 @@
+//! Identifier, scalar and group element encodings
+
+use std::collections::BTreeSet;
+
+use frost_core::{self as frost, Ciphersuite, Field, Group};
+
+type ScalarField<C> = <<C as Ciphersuite>::Group as Group>::Field;
+
+/// Participant identifiers cross the wire as u16.
+///
+/// Default identifiers are small integers, so the u16 sits in the first two
+/// bytes of a little-endian scalar encoding and the last two of a big-endian one.
+/// Identifiers that are not a u16 (e.g. derived from a name) are rejected.
+pub fn identifier_to_u16<C: Ciphersuite>(identifier: &frost::Identifier<C>) -> Result<u16, String> {
+    let id_bytes = identifier.serialize();
+    let n = id_bytes.len();
+    let candidates = [
+        u16::from_le_bytes([id_bytes[0], id_bytes[1]]),
+        u16::from_be_bytes([id_bytes[n - 2], id_bytes[n - 1]]),
+    ];
+
+    candidates
+        .into_iter()
+        .find(|id| frost::Identifier::<C>::try_from(*id).ok().as_ref() == Some(identifier))
+        .ok_or_else(|| {
+            format!(
+                "Identifier {} is not a u16 participant index",
+                hex::encode(&id_bytes)
+            )
+        })
+}
+
+pub fn identifier_from_u16<C: Ciphersuite>(id: u16) -> Result<frost::Identifier<C>, String> {
+    frost::Identifier::try_from(id).map_err(|e| format!("Invalid identifier {}: {:?}", id, e))
+}
+
+/// Parse a JSON array of u16 identifiers, e.g. `[1, 2, 3]`
+pub fn parse_identifier_list<C: Ciphersuite>(
+    identifiers_json: &str,
+) -> Result<Vec<frost::Identifier<C>>, String> {
+    let ids: Vec<u16> = serde_json::from_str(identifiers_json)
+        .map_err(|e| format!("Invalid identifiers JSON: {}", e))?;
+
+    ids.into_iter().map(identifier_from_u16).collect()
+}
+
+pub fn verifying_key_hex<C: Ciphersuite>(
+    verifying_key: &frost::VerifyingKey<C>,
+) -> Result<String, String> {
+    let bytes = verifying_key
+        .serialize()
+        .map_err(|e| format!("Failed to serialize group public key: {:?}", e))?;
+    Ok(hex::encode(bytes))
+}
+
+pub fn verifying_share_hex<C: Ciphersuite>(
+    verifying_share: &frost::keys::VerifyingShare<C>,
+) -> Result<String, String> {
+    let bytes = verifying_share
+        .serialize()
+        .map_err(|e| format!("Failed to serialize verifying share: {:?}", e))?;
+    Ok(hex::encode(bytes))
+}
+
+pub fn scalar_to_hex<C: Ciphersuite>(scalar: &frost::Scalar<C>) -> String {
+    hex::encode(<ScalarField<C> as Field>::serialize(scalar))
+}
+
+pub fn scalar_from_hex<C: Ciphersuite>(scalar_hex: &str) -> Result<frost::Scalar<C>, String> {
+    let bytes = hex::decode(scalar_hex).map_err(|e| format!("Invalid scalar hex: {}", e))?;
+    scalar_from_bytes::<C>(&bytes)
+}
+
+pub fn scalar_from_bytes<C: Ciphersuite>(bytes: &[u8]) -> Result<frost::Scalar<C>, String> {
+    frost::keys::SigningShare::<C>::deserialize(bytes)
+        .map(|share| share.to_scalar())
+        .map_err(|e| format!("Invalid scalar: {:?}", e))
+}
+
+pub fn signing_share_from_scalar<C: Ciphersuite>(
+    scalar: &frost::Scalar<C>,
+) -> Result<frost::keys::SigningShare<C>, String> {
+    frost::keys::SigningShare::deserialize(<ScalarField<C> as Field>::serialize(scalar).as_ref())
+        .map_err(|e| format!("Invalid signing share: {:?}", e))
+}
+
+pub fn element_from_bytes<C: Ciphersuite>(bytes: &[u8]) -> Result<frost::Element<C>, String> {
+    frost::keys::VerifyingShare::<C>::deserialize(bytes)
+        .map(|share| share.to_element())
+        .map_err(|e| format!("Invalid point: {:?}", e))
+}
+
+pub fn verifying_share_from_element<C: Ciphersuite>(
+    element: &frost::Element<C>,
+) -> Result<frost::keys::VerifyingShare<C>, String> {
+    let bytes = <C::Group as Group>::serialize(element)
+        .map_err(|e| format!("Failed to serialize verifying share: {:?}", e))?;
+    frost::keys::VerifyingShare::deserialize(bytes.as_ref())
+        .map_err(|e| format!("Invalid verifying share: {:?}", e))
+}
+
+/// Lagrange coefficient at zero of `identifier` over `identifiers`
+pub fn lagrange_coefficient<C: Ciphersuite>(
+    identifiers: &[frost::Identifier<C>],
+    identifier: &frost::Identifier<C>,
+) -> Result<frost::Scalar<C>, String> {
+    if identifiers.iter().collect::<BTreeSet<_>>().len() != identifiers.len() {
+        return Err("Duplicate identifier in set".into());
+    }
+
+    let x_i = scalar_from_bytes::<C>(&identifier.serialize())?;
+
+    let mut numerator = <ScalarField<C> as Field>::one();
+    let mut denominator = <ScalarField<C> as Field>::one();
+    for other in identifiers.iter().filter(|other| *other != identifier) {
+        let x_j = scalar_from_bytes::<C>(&other.serialize())?;
+        numerator = numerator * x_j;
+        denominator = denominator * (x_j - x_i);
+    }
+
+    let inverse = <ScalarField<C> as Field>::invert(&denominator)
+        .map_err(|_| "Identifiers must be distinct scalars")?;
+    Ok(numerator * inverse)
+}
+
+/// Set the threshold on a public key package, which packages from before
+/// frost-core 3.0 don't carry; a package that does carry one must agree
+pub fn with_min_signers<C: Ciphersuite>(
+    pubkey_package: frost::keys::PublicKeyPackage<C>,
+    min_signers: u16,
+) -> Result<frost::keys::PublicKeyPackage<C>, String> {
+    match pubkey_package.min_signers() {
+        Some(threshold) if threshold == min_signers => Ok(pubkey_package),
+        Some(threshold) => Err(format!(
+            "Threshold {} does not match the group's threshold {}",
+            min_signers, threshold
+        )),
+        None => Ok(frost::keys::PublicKeyPackage::new(
+            pubkey_package.verifying_shares().clone(),
+            *pubkey_package.verifying_key(),
+            Some(min_signers),
+        )),
+    }
+}
+
+/// Thresholds must satisfy `2 <= min_signers <= max_signers <= 255`, as
+/// frost-core requires for every sharing
+pub fn check_threshold(min_signers: u16, max_signers: u16) -> Result<(), String> {
+    if min_signers < 2 || min_signers > max_signers {
+        return Err(format!(
+            "Invalid threshold: {} must be >= 2 and <= {}",
+            min_signers, max_signers
+        ));
+    }
+    if max_signers > 255 {
+        return Err("Total participants must be <= 255".into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<C: Ciphersuite>() {
+        for id in 1..=300u16 {
+            let identifier = identifier_from_u16::<C>(id).unwrap();
+            assert_eq!(identifier_to_u16(&identifier), Ok(id));
+        }
+
+        let derived = frost::Identifier::<C>::derive(b"alice").unwrap();
+        assert!(identifier_to_u16(&derived).is_err());
+    }
+
+    #[test]
+    fn test_identifier_round_trip() {
+        // Ed25519 scalars are little-endian, secp256k1 scalars big-endian
+        round_trip::<frost_ed25519::Ed25519Sha512>();
+        round_trip::<frost_secp256k1_tr::Secp256K1Sha256TR>();
+    }
+
+    #[test]
+    fn test_lagrange_coefficient_rejects_duplicates() {
+        type C = frost_ed25519::Ed25519Sha512;
+        let ids: Vec<_> = [1u16, 2, 2]
+            .iter()
+            .map(|id| identifier_from_u16::<C>(*id).unwrap())
+            .collect();
+        assert!(lagrange_coefficient(&ids, &ids[0]).is_err());
+        assert!(lagrange_coefficient(&ids[..2], &ids[0]).is_ok());
+    }
+
+    #[test]
+    fn test_check_threshold() {
+        assert!(check_threshold(1, 3).is_err());
+        assert!(check_threshold(2, 3).is_ok());
+        assert!(check_threshold(4, 3).is_err());
+        assert!(check_threshold(2, 256).is_err());
+    }
+}
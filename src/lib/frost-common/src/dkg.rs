@@ -0,0 +1,413 @@
+//! Distributed key generation
+
+use std::collections::BTreeMap;
+
+use frost_core::{self as frost, Ciphersuite};
+use rand::rngs::OsRng;
+
+use crate::codec::{
+    check_threshold, identifier_from_u16, identifier_to_u16, verifying_key_hex, verifying_share_hex,
+};
+use crate::{
+    DkgResult, DkgRound1Package, DkgRound1Result, DkgRound2Package, DkgRound2Result, FrostError,
+};
+
+/// Round 1 packages, keyed by sender
+pub type Round1Packages<C> = BTreeMap<frost::Identifier<C>, frost::keys::dkg::round1::Package<C>>;
+
+/// Round 2 packages, keyed by sender (incoming) or recipient (outgoing)
+pub type Round2Packages<C> = BTreeMap<frost::Identifier<C>, frost::keys::dkg::round2::Package<C>>;
+
+/// DKG part 1: generate this participant's secret polynomial and the Round 1
+/// package to broadcast (DKG_ERROR).
+pub fn part1<C: Ciphersuite>(
+    identifier: frost::Identifier<C>,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<
+    (
+        frost::keys::dkg::round1::SecretPackage<C>,
+        frost::keys::dkg::round1::Package<C>,
+    ),
+    FrostError,
+> {
+    part1_internal(identifier, max_signers, min_signers)
+        .map_err(|e| FrostError::new("DKG_ERROR", e))
+}
+
+fn part1_internal<C: Ciphersuite>(
+    identifier: frost::Identifier<C>,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<
+    (
+        frost::keys::dkg::round1::SecretPackage<C>,
+        frost::keys::dkg::round1::Package<C>,
+    ),
+    String,
+> {
+    check_threshold(min_signers, max_signers)?;
+
+    let rng = OsRng;
+
+    frost::keys::dkg::part1(identifier, max_signers, min_signers, rng)
+        .map_err(|e| format!("DKG part 1 failed: {:?}", e))
+}
+
+/// DKG part 2: verify the other participants' Round 1 packages and produce one
+/// Round 2 package per recipient (DKG_ERROR).
+pub fn part2<C: Ciphersuite>(
+    secret_package: frost::keys::dkg::round1::SecretPackage<C>,
+    round1_packages: &Round1Packages<C>,
+) -> Result<
+    (
+        frost::keys::dkg::round2::SecretPackage<C>,
+        Round2Packages<C>,
+    ),
+    FrostError,
+> {
+    part2_internal(secret_package, round1_packages).map_err(|e| FrostError::new("DKG_ERROR", e))
+}
+
+fn part2_internal<C: Ciphersuite>(
+    secret_package: frost::keys::dkg::round1::SecretPackage<C>,
+    round1_packages: &Round1Packages<C>,
+) -> Result<
+    (
+        frost::keys::dkg::round2::SecretPackage<C>,
+        Round2Packages<C>,
+    ),
+    String,
+> {
+    frost::keys::dkg::part2(secret_package, round1_packages)
+        .map_err(|e| format!("DKG part 2 failed: {:?}", e))
+}
+
+/// DKG part 3: verify the Round 2 packages addressed to this participant and
+/// derive the final key package and public key package (DKG_ERROR).
+pub fn part3<C: Ciphersuite>(
+    secret_package: &frost::keys::dkg::round2::SecretPackage<C>,
+    round1_packages: &Round1Packages<C>,
+    round2_packages: &Round2Packages<C>,
+) -> Result<(frost::keys::KeyPackage<C>, frost::keys::PublicKeyPackage<C>), FrostError> {
+    part3_internal(secret_package, round1_packages, round2_packages)
+        .map_err(|e| FrostError::new("DKG_ERROR", e))
+}
+
+fn part3_internal<C: Ciphersuite>(
+    secret_package: &frost::keys::dkg::round2::SecretPackage<C>,
+    round1_packages: &Round1Packages<C>,
+    round2_packages: &Round2Packages<C>,
+) -> Result<(frost::keys::KeyPackage<C>, frost::keys::PublicKeyPackage<C>), String> {
+    frost::keys::dkg::part3(secret_package, round1_packages, round2_packages)
+        .map_err(|e| format!("DKG part 3 failed: {:?}", e))
+}
+
+/// JSON form of `part1` (DKG_ERROR).
+pub fn dkg_part1<C: Ciphersuite>(
+    identifier: u16,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DkgRound1Result, FrostError> {
+    dkg_part1_internal::<C>(identifier, max_signers, min_signers)
+        .map_err(|e| FrostError::new("DKG_ERROR", e))
+}
+
+fn dkg_part1_internal<C: Ciphersuite>(
+    identifier: u16,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DkgRound1Result, String> {
+    let id = identifier_from_u16::<C>(identifier)?;
+
+    let (secret_package, package) = part1_internal(id, max_signers, min_signers)?;
+
+    round1_result(identifier, &secret_package, &package)
+}
+
+/// JSON form of `part2` (DKG_ERROR).
+pub fn dkg_part2<C: Ciphersuite>(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+) -> Result<DkgRound2Result, FrostError> {
+    dkg_part2_internal::<C>(secret_package_json, round1_packages_json)
+        .map_err(|e| FrostError::new("DKG_ERROR", e))
+}
+
+fn dkg_part2_internal<C: Ciphersuite>(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+) -> Result<DkgRound2Result, String> {
+    let secret_package: frost::keys::dkg::round1::SecretPackage<C> =
+        serde_json::from_str(secret_package_json)
+            .map_err(|e| format!("Invalid secret package JSON: {}", e))?;
+
+    let round1_packages = parse_dkg_round1_packages(round1_packages_json)?;
+
+    let (round2_secret_package, round2_packages) =
+        part2_internal(secret_package, &round1_packages)?;
+
+    round2_result(&round2_secret_package, round2_packages)
+}
+
+/// JSON form of `part3` (DKG_ERROR).
+pub fn dkg_part3<C: Ciphersuite>(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+) -> Result<DkgResult, FrostError> {
+    dkg_part3_internal::<C>(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+    )
+    .map_err(|e| FrostError::new("DKG_ERROR", e))
+}
+
+fn dkg_part3_internal<C: Ciphersuite>(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+) -> Result<DkgResult, String> {
+    let secret_package: frost::keys::dkg::round2::SecretPackage<C> =
+        serde_json::from_str(secret_package_json)
+            .map_err(|e| format!("Invalid secret package JSON: {}", e))?;
+
+    let round1_packages = parse_dkg_round1_packages(round1_packages_json)?;
+    let round2_packages =
+        parse_dkg_round2_packages(round2_packages_json, secret_package.identifier())?;
+
+    let (key_package, pubkey_package) =
+        part3_internal(&secret_package, &round1_packages, &round2_packages)?;
+
+    dkg_result(&key_package, &pubkey_package)
+}
+
+/// Serialize a participant's final key material
+pub fn dkg_result<C: Ciphersuite>(
+    key_package: &frost::keys::KeyPackage<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<DkgResult, String> {
+    let key_package_json = serde_json::to_string(key_package)
+        .map_err(|e| format!("Failed to serialize key package: {:?}", e))?;
+    let pubkey_package_json = serde_json::to_string(pubkey_package)
+        .map_err(|e| format!("Failed to serialize public key package: {:?}", e))?;
+
+    Ok(DkgResult {
+        identifier: identifier_to_u16(key_package.identifier())?,
+        key_package: key_package_json,
+        verifying_share: verifying_share_hex(key_package.verifying_share())?,
+        group_public_key: verifying_key_hex(pubkey_package.verifying_key())?,
+        public_key_package: pubkey_package_json,
+    })
+}
+
+/// Serialize a participant's Round 1 output
+pub fn round1_result<C: Ciphersuite>(
+    identifier: u16,
+    secret_package: &frost::keys::dkg::round1::SecretPackage<C>,
+    package: &frost::keys::dkg::round1::Package<C>,
+) -> Result<DkgRound1Result, String> {
+    let secret_package_json = serde_json::to_string(secret_package)
+        .map_err(|e| format!("Failed to serialize secret package: {:?}", e))?;
+    let package_json = serde_json::to_string(package)
+        .map_err(|e| format!("Failed to serialize round 1 package: {:?}", e))?;
+
+    Ok(DkgRound1Result {
+        secret_package: secret_package_json,
+        package: DkgRound1Package {
+            identifier,
+            package: package_json,
+        },
+    })
+}
+
+/// Serialize a participant's Round 2 output
+pub fn round2_result<C: Ciphersuite>(
+    secret_package: &frost::keys::dkg::round2::SecretPackage<C>,
+    packages: Round2Packages<C>,
+) -> Result<DkgRound2Result, String> {
+    let secret_package_json = serde_json::to_string(secret_package)
+        .map_err(|e| format!("Failed to serialize secret package: {:?}", e))?;
+
+    Ok(DkgRound2Result {
+        secret_package: secret_package_json,
+        packages: round2_packages(secret_package.identifier(), packages)?,
+    })
+}
+
+/// Serialize outgoing Round 2 packages, one per recipient
+pub fn round2_packages<C: Ciphersuite>(
+    sender: &frost::Identifier<C>,
+    packages: Round2Packages<C>,
+) -> Result<Vec<DkgRound2Package>, String> {
+    let sender = identifier_to_u16(sender)?;
+
+    let mut round2_packages = Vec::with_capacity(packages.len());
+    for (identifier, package) in packages {
+        let package_json = serde_json::to_string(&package)
+            .map_err(|e| format!("Failed to serialize round 2 package: {:?}", e))?;
+
+        round2_packages.push(DkgRound2Package {
+            sender,
+            recipient: identifier_to_u16(&identifier)?,
+            package: package_json,
+        });
+    }
+
+    Ok(round2_packages)
+}
+
+/// Parse Round 1 packages, keyed by sender
+pub fn parse_dkg_round1_packages<C: Ciphersuite>(
+    round1_packages_json: &str,
+) -> Result<Round1Packages<C>, String> {
+    let round1_list: Vec<DkgRound1Package> = serde_json::from_str(round1_packages_json)
+        .map_err(|e| format!("Invalid round 1 packages JSON: {}", e))?;
+
+    let mut round1_packages = BTreeMap::new();
+    for p in &round1_list {
+        let package: frost::keys::dkg::round1::Package<C> = serde_json::from_str(&p.package)
+            .map_err(|e| format!("Invalid round 1 package JSON: {}", e))?;
+
+        round1_packages.insert(identifier_from_u16(p.identifier)?, package);
+    }
+
+    Ok(round1_packages)
+}
+
+/// Parse the Round 2 packages addressed to `recipient`, keyed by sender
+pub fn parse_dkg_round2_packages<C: Ciphersuite>(
+    round2_packages_json: &str,
+    recipient: &frost::Identifier<C>,
+) -> Result<Round2Packages<C>, String> {
+    let round2_list: Vec<DkgRound2Package> = serde_json::from_str(round2_packages_json)
+        .map_err(|e| format!("Invalid round 2 packages JSON: {}", e))?;
+
+    let id = identifier_to_u16(recipient)?;
+
+    let mut round2_packages = BTreeMap::new();
+    for p in &round2_list {
+        if p.recipient != id {
+            return Err(format!(
+                "Round 2 package from {} is addressed to {}, not {}",
+                p.sender, p.recipient, id
+            ));
+        }
+
+        let package: frost::keys::dkg::round2::Package<C> = serde_json::from_str(&p.package)
+            .map_err(|e| format!("Invalid round 2 package JSON: {}", e))?;
+
+        round2_packages.insert(identifier_from_u16(p.sender)?, package);
+    }
+
+    Ok(round2_packages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::tests::sign_with;
+    use frost_ed25519::Ed25519Sha512;
+    use frost_secp256k1_tr::Secp256K1Sha256TR;
+
+    fn run_dkg<C: Ciphersuite>(min_signers: u16, max_signers: u16) -> Vec<DkgResult> {
+        let round1: Vec<DkgRound1Result> = (1..=max_signers)
+            .map(|id| dkg_part1::<C>(id, max_signers, min_signers).expect("DKG part 1 failed"))
+            .collect();
+
+        let others_round1 = |id: u16| {
+            let packages: Vec<DkgRound1Package> = round1
+                .iter()
+                .filter(|r| r.package.identifier != id)
+                .map(|r| r.package.clone())
+                .collect();
+            serde_json::to_string(&packages).unwrap()
+        };
+
+        let round2: Vec<DkgRound2Result> = round1
+            .iter()
+            .map(|r| {
+                dkg_part2::<C>(&r.secret_package, &others_round1(r.package.identifier))
+                    .expect("DKG part 2 failed")
+            })
+            .collect();
+
+        (1..=max_signers)
+            .map(|id| {
+                let inbox: Vec<DkgRound2Package> = round2
+                    .iter()
+                    .flat_map(|r| r.packages.iter())
+                    .filter(|p| p.recipient == id)
+                    .cloned()
+                    .collect();
+                dkg_part3::<C>(
+                    &round2[(id - 1) as usize].secret_package,
+                    &others_round1(id),
+                    &serde_json::to_string(&inbox).unwrap(),
+                )
+                .expect("DKG part 3 failed")
+            })
+            .collect()
+    }
+
+    fn dkg_signing_flow<C: Ciphersuite>() {
+        let results = run_dkg::<C>(2, 3);
+
+        // Everyone agrees on the group key and the public key package
+        assert!(results
+            .iter()
+            .all(|r| r.group_public_key == results[0].group_public_key
+                && r.public_key_package == results[0].public_key_package));
+        assert_eq!(
+            results.iter().map(|r| r.identifier).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        let signature = sign_with::<C>(
+            &[&results[1].key_package, &results[2].key_package],
+            &results[0].public_key_package,
+        )
+        .expect("Aggregation failed");
+
+        let verify = crate::signing::verify_signature::<C>(
+            &signature.signature,
+            crate::signing::tests::MESSAGE,
+            &results[0].group_public_key,
+        )
+        .expect("Verification failed");
+        assert!(verify.valid);
+    }
+
+    fn dkg_rejects_misaddressed_package<C: Ciphersuite>() {
+        let round1: Vec<DkgRound1Result> = (1..=3)
+            .map(|id| dkg_part1::<C>(id, 3, 2).expect("DKG part 1 failed"))
+            .collect();
+        let others: Vec<DkgRound1Package> = round1[1..].iter().map(|r| r.package.clone()).collect();
+        let others_json = serde_json::to_string(&others).unwrap();
+
+        let round2 = dkg_part2::<C>(&round1[0].secret_package, &others_json).unwrap();
+
+        // Participant 1 is handed its own outgoing package to participant 2
+        let Err(error) = dkg_part3::<C>(
+            &round2.secret_package,
+            &others_json,
+            &serde_json::to_string(&round2.packages[..1]).unwrap(),
+        ) else {
+            panic!("Misaddressed package should fail");
+        };
+        assert_eq!(error.code, "DKG_ERROR");
+    }
+
+    #[test]
+    fn test_dkg_signing_flow() {
+        dkg_signing_flow::<Ed25519Sha512>();
+        dkg_signing_flow::<Secp256K1Sha256TR>();
+    }
+
+    #[test]
+    fn test_dkg_rejects_misaddressed_package() {
+        dkg_rejects_misaddressed_package::<Ed25519Sha512>();
+        dkg_rejects_misaddressed_package::<Secp256K1Sha256TR>();
+    }
+}
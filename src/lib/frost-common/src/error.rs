@@ -0,0 +1,60 @@
+//! Error types shared by every binding
+
+use serde::{Deserialize, Serialize};
+
+/// Error result
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FrostError {
+    pub code: String,
+    pub message: String,
+}
+
+impl FrostError {
+    pub fn new(code: &str, message: impl Into<String>) -> Self {
+        FrostError {
+            code: code.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Aggregation error result
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AggregateError {
+    pub code: String,
+    pub message: String,
+    /// Identifiers of participants whose signature share is invalid (empty
+    /// if the failure was not caused by a bad share)
+    pub culprits: Vec<u16>,
+}
+
+impl From<String> for AggregateError {
+    fn from(message: String) -> Self {
+        AggregateError {
+            code: "AGGREGATE_ERROR".into(),
+            message,
+            culprits: Vec::new(),
+        }
+    }
+}
+
+/// Round 2 error; nonce reuse keeps its own code so it can't be mistaken for bad input
+pub(crate) struct Round2Error(pub(crate) FrostError);
+
+impl From<String> for Round2Error {
+    fn from(message: String) -> Self {
+        Round2Error(FrostError::new("ROUND2_ERROR", message))
+    }
+}
+
+/// Serialize a result as the JSON the bindings return: the value on success,
+/// the error object otherwise
+pub fn to_json<T: Serialize, E: Serialize>(result: Result<T, E>) -> String {
+    let json = match &result {
+        Ok(value) => serde_json::to_string(value),
+        Err(error) => serde_json::to_string(error),
+    };
+    json.unwrap_or_else(|e| {
+        serde_json::to_string(&FrostError::new("SERIALIZATION_ERROR", e.to_string())).unwrap()
+    })
+}
@@ -0,0 +1,261 @@
+//! Trusted dealer key generation and key package helpers
+
+use std::collections::BTreeMap;
+
+use frost_core::{self as frost, Ciphersuite};
+use rand::rngs::OsRng;
+
+use crate::codec::{check_threshold, identifier_to_u16, verifying_key_hex, verifying_share_hex};
+use crate::{FrostError, KeyGenResult, KeyShare, PublicKeyResult};
+
+/// A trusted dealer's output: each participant's secret share and the group's
+/// public key package
+pub type DealtShares<C> = (
+    BTreeMap<frost::Identifier<C>, frost::keys::SecretShare<C>>,
+    frost::keys::PublicKeyPackage<C>,
+);
+
+/// Deal key shares to participants `1..=total` (KEYGEN_ERROR).
+pub fn deal_key_shares<C: Ciphersuite>(
+    threshold: u16,
+    total: u16,
+) -> Result<DealtShares<C>, FrostError> {
+    deal_key_shares_internal::<C>(threshold, total).map_err(|e| FrostError::new("KEYGEN_ERROR", e))
+}
+
+fn deal_key_shares_internal<C: Ciphersuite>(
+    threshold: u16,
+    total: u16,
+) -> Result<DealtShares<C>, String> {
+    check_threshold(threshold, total)?;
+
+    let mut rng = OsRng;
+
+    frost::keys::generate_with_dealer::<C, _>(
+        total,
+        threshold,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .map_err(|e| format!("Key generation failed: {:?}", e))
+}
+
+/// Split an existing signing key into shares for participants `1..=total`,
+/// keeping its public key (KEYGEN_ERROR).
+pub fn split_signing_key<C: Ciphersuite>(
+    signing_key: &frost::SigningKey<C>,
+    threshold: u16,
+    total: u16,
+) -> Result<DealtShares<C>, FrostError> {
+    split_signing_key_internal(signing_key, threshold, total)
+        .map_err(|e| FrostError::new("KEYGEN_ERROR", e))
+}
+
+fn split_signing_key_internal<C: Ciphersuite>(
+    signing_key: &frost::SigningKey<C>,
+    threshold: u16,
+    total: u16,
+) -> Result<DealtShares<C>, String> {
+    check_threshold(threshold, total)?;
+
+    let mut rng = OsRng;
+
+    frost::keys::split(
+        signing_key,
+        total,
+        threshold,
+        frost::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .map_err(|e| format!("Key split failed: {:?}", e))
+}
+
+/// Generate key shares using trusted dealer key generation (KEYGEN_ERROR).
+pub fn generate_key_shares<C: Ciphersuite>(
+    threshold: u16,
+    total: u16,
+) -> Result<KeyGenResult, FrostError> {
+    generate_key_shares_internal::<C>(threshold, total)
+        .map_err(|e| FrostError::new("KEYGEN_ERROR", e))
+}
+
+fn generate_key_shares_internal<C: Ciphersuite>(
+    threshold: u16,
+    total: u16,
+) -> Result<KeyGenResult, String> {
+    let (shares, pubkey_package) = deal_key_shares_internal::<C>(threshold, total)?;
+
+    key_gen_result(shares, &pubkey_package, threshold, total)
+}
+
+/// Split an existing signing key into key shares, keeping its public key (KEYGEN_ERROR).
+///
+/// `signing_key` is the ciphersuite's serialized signing scalar.
+pub fn split_key_shares<C: Ciphersuite>(
+    signing_key: &[u8],
+    threshold: u16,
+    total: u16,
+) -> Result<KeyGenResult, FrostError> {
+    split_key_shares_internal::<C>(signing_key, threshold, total)
+        .map_err(|e| FrostError::new("KEYGEN_ERROR", e))
+}
+
+fn split_key_shares_internal<C: Ciphersuite>(
+    signing_key: &[u8],
+    threshold: u16,
+    total: u16,
+) -> Result<KeyGenResult, String> {
+    let signing_key = frost::SigningKey::<C>::deserialize(signing_key)
+        .map_err(|e| format!("Invalid signing key: {:?}", e))?;
+
+    let (shares, pubkey_package) = split_signing_key_internal(&signing_key, threshold, total)?;
+
+    key_gen_result(shares, &pubkey_package, threshold, total)
+}
+
+/// Serialize a dealer's output, with each participant's key package and secret share
+pub fn key_gen_result<C: Ciphersuite>(
+    shares: BTreeMap<frost::Identifier<C>, frost::keys::SecretShare<C>>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    threshold: u16,
+    total: u16,
+) -> Result<KeyGenResult, String> {
+    // Serialize the public key package
+    let pubkey_package_json = serde_json::to_string(pubkey_package)
+        .map_err(|e| format!("Failed to serialize public key package: {:?}", e))?;
+
+    let mut key_shares = Vec::with_capacity(total as usize);
+    for (_, secret_share) in shares {
+        // Build KeyPackage for this participant
+        let key_package = frost::keys::KeyPackage::try_from(secret_share.clone())
+            .map_err(|e| format!("Failed to create key package: {:?}", e))?;
+
+        let secret_share_json = serde_json::to_string(&secret_share)
+            .map_err(|e| format!("Failed to serialize secret share: {:?}", e))?;
+
+        key_shares.push(KeyShare {
+            secret_share: Some(secret_share_json),
+            ..key_share(&key_package)?
+        });
+    }
+
+    // Sort by identifier for consistency
+    key_shares.sort_by_key(|s| s.identifier);
+
+    Ok(KeyGenResult {
+        group_public_key: verifying_key_hex(pubkey_package.verifying_key())?,
+        shares: key_shares,
+        threshold,
+        total,
+        public_key_package: pubkey_package_json,
+    })
+}
+
+/// Wrap a key package as the KeyShare returned to its holder (no dealer secret share)
+pub fn key_share<C: Ciphersuite>(
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<KeyShare, String> {
+    let key_package_json = serde_json::to_string(key_package)
+        .map_err(|e| format!("Failed to serialize key package: {:?}", e))?;
+
+    Ok(KeyShare {
+        identifier: identifier_to_u16(key_package.identifier())?,
+        key_package: key_package_json,
+        verifying_share: verifying_share_hex(key_package.verifying_share())?,
+        secret_share: None,
+    })
+}
+
+/// Verify a dealer-issued secret share and turn it into a key package (VERIFY_ERROR).
+///
+/// Checks the share against the dealer's VSS commitment, and the commitment
+/// against the group's public key package.
+pub fn accept_share<C: Ciphersuite>(
+    secret_share: frost::keys::SecretShare<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<frost::keys::KeyPackage<C>, FrostError> {
+    accept_share_internal(secret_share, pubkey_package)
+        .map_err(|e| FrostError::new("VERIFY_ERROR", e))
+}
+
+fn accept_share_internal<C: Ciphersuite>(
+    secret_share: frost::keys::SecretShare<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<frost::keys::KeyPackage<C>, String> {
+    // Verifies the share against the dealer's commitment
+    let key_package = frost::keys::KeyPackage::try_from(secret_share)
+        .map_err(|e| format!("Secret share failed verification: {:?}", e))?;
+
+    if key_package.verifying_key() != pubkey_package.verifying_key() {
+        return Err("Dealer commitment does not match the group public key".into());
+    }
+    if pubkey_package
+        .verifying_shares()
+        .get(key_package.identifier())
+        != Some(key_package.verifying_share())
+    {
+        return Err("Verifying share does not match the public key package".into());
+    }
+
+    Ok(key_package)
+}
+
+/// JSON form of `accept_share` (VERIFY_ERROR).
+pub fn verify_and_accept_share<C: Ciphersuite>(
+    secret_share_json: &str,
+    public_key_package_json: &str,
+) -> Result<KeyShare, FrostError> {
+    verify_and_accept_share_internal::<C>(secret_share_json, public_key_package_json)
+        .map_err(|e| FrostError::new("VERIFY_ERROR", e))
+}
+
+fn verify_and_accept_share_internal<C: Ciphersuite>(
+    secret_share_json: &str,
+    public_key_package_json: &str,
+) -> Result<KeyShare, String> {
+    let secret_share: frost::keys::SecretShare<C> = serde_json::from_str(secret_share_json)
+        .map_err(|e| format!("Invalid secret share JSON: {}", e))?;
+
+    let pubkey_package: frost::keys::PublicKeyPackage<C> =
+        serde_json::from_str(public_key_package_json)
+            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+
+    key_share(&accept_share_internal(secret_share, &pubkey_package)?)
+}
+
+/// Get a participant's public key share from their key package (PUBKEY_ERROR).
+pub fn get_public_key<C: Ciphersuite>(
+    key_package_json: &str,
+) -> Result<PublicKeyResult, FrostError> {
+    get_public_key_internal::<C>(key_package_json).map_err(|e| FrostError::new("PUBKEY_ERROR", e))
+}
+
+fn get_public_key_internal<C: Ciphersuite>(
+    key_package_json: &str,
+) -> Result<PublicKeyResult, String> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    Ok(PublicKeyResult {
+        public_key: verifying_share_hex(key_package.verifying_share())?,
+        identifier: identifier_to_u16(key_package.identifier())?,
+    })
+}
+
+/// Get the hex-encoded group public key from a public key package (PUBKEY_ERROR).
+pub fn get_group_public_key<C: Ciphersuite>(
+    public_key_package_json: &str,
+) -> Result<String, FrostError> {
+    get_group_public_key_internal::<C>(public_key_package_json)
+        .map_err(|e| FrostError::new("PUBKEY_ERROR", e))
+}
+
+fn get_group_public_key_internal<C: Ciphersuite>(
+    public_key_package_json: &str,
+) -> Result<String, String> {
+    let pubkey_package: frost::keys::PublicKeyPackage<C> =
+        serde_json::from_str(public_key_package_json)
+            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+
+    verifying_key_hex(pubkey_package.verifying_key())
+}
@@ -0,0 +1,478 @@
+//! Keystore: key packages and nonces kept behind opaque handles
+//!
+//! Bindings hold one `Keystore` per ciphersuite (e.g. in a `thread_local!`),
+//! so secrets stay inside the module and callers only see `u32` handles.
+
+use std::collections::HashMap;
+
+use frost_core::{self as frost, Ciphersuite};
+use zeroize::Zeroize;
+
+use crate::codec::{identifier_to_u16, verifying_share_hex};
+use crate::error::Round2Error;
+use crate::nonces::{open_nonce_registry, seal_nonce_registry};
+use crate::signing::{
+    commitment_result, parse_signing_package, round1_commit, round2_sign_internal,
+    signature_share_result,
+};
+use crate::{
+    FrostError, KeyHandle, NonceRegistryState, Round1HandleResult, SealedNonceRegistry,
+    SignatureShare,
+};
+
+/// Secret held in the keystore, zeroized when removed or dropped
+struct Secret<T: Zeroize>(T);
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Key packages and nonces that never leave the keystore
+///
+/// Entries are boxed: when a map grows and rehashes it moves only the boxes,
+/// so each secret stays at one address until it is zeroized.
+pub struct Keystore<C: Ciphersuite> {
+    last_handle: u32,
+    key_packages: HashMap<u32, Box<Secret<frost::keys::KeyPackage<C>>>>,
+    nonces: HashMap<u32, Box<Secret<frost::round1::SigningNonces<C>>>>,
+    /// Last sealed registry counter exported or imported, per key handle
+    registry_counters: HashMap<u32, u64>,
+}
+
+// Not derived: the derive would require `C: Default`
+impl<C: Ciphersuite> Default for Keystore<C> {
+    fn default() -> Self {
+        Keystore {
+            last_handle: 0,
+            key_packages: HashMap::new(),
+            nonces: HashMap::new(),
+            registry_counters: HashMap::new(),
+        }
+    }
+}
+
+impl<C: Ciphersuite> Keystore<C> {
+    /// Allocate a fresh non-zero handle shared by keys and nonces
+    fn next_handle(&mut self) -> u32 {
+        loop {
+            self.last_handle = self.last_handle.wrapping_add(1);
+            let handle = self.last_handle;
+            if handle != 0
+                && !self.key_packages.contains_key(&handle)
+                && !self.nonces.contains_key(&handle)
+            {
+                return handle;
+            }
+        }
+    }
+
+    /// Load a key package and return an opaque handle
+    pub fn load_key_package(&mut self, key_package: frost::keys::KeyPackage<C>) -> u32 {
+        let handle = self.next_handle();
+        self.key_packages
+            .insert(handle, Box::new(Secret(key_package)));
+        handle
+    }
+
+    /// Identifier of the key package behind a key handle
+    pub fn identifier(&self, key_handle: u32) -> Option<frost::Identifier<C>> {
+        self.key_packages
+            .get(&key_handle)
+            .map(|key_package| *key_package.0.identifier())
+    }
+
+    /// Generate Round 1 commitments, keeping the nonces in the keystore behind
+    /// the returned nonce handle (ROUND1_ERROR).
+    pub fn round1_commit(
+        &mut self,
+        key_handle: u32,
+    ) -> Result<(u32, frost::round1::SigningCommitments<C>), FrostError> {
+        let key_package = self.key_packages.get(&key_handle).ok_or_else(|| {
+            FrostError::new("ROUND1_ERROR", format!("Unknown key handle {}", key_handle))
+        })?;
+
+        let (nonces, commitments) = round1_commit(&key_package.0);
+
+        let nonce_handle = self.next_handle();
+        self.nonces.insert(nonce_handle, Box::new(Secret(nonces)));
+
+        Ok((nonce_handle, commitments))
+    }
+
+    /// Sign from handles (ROUND2_ERROR, or NONCE_REUSED). The nonce handle is
+    /// consumed either way.
+    pub fn sign(
+        &mut self,
+        key_handle: u32,
+        nonce_handle: u32,
+        signing_package: &frost::SigningPackage<C>,
+    ) -> Result<frost::round2::SignatureShare<C>, FrostError> {
+        self.sign_with(key_handle, nonce_handle, |nonces, key_package| {
+            round2_sign_internal(signing_package, nonces, key_package)
+        })
+        .map_err(|e| e.0)
+    }
+
+    /// Run `sign` with the key package and the consumed nonces behind the handles
+    pub(crate) fn sign_with<T, F>(
+        &mut self,
+        key_handle: u32,
+        nonce_handle: u32,
+        sign: F,
+    ) -> Result<T, Round2Error>
+    where
+        F: FnOnce(
+            &frost::round1::SigningNonces<C>,
+            &frost::keys::KeyPackage<C>,
+        ) -> Result<T, Round2Error>,
+    {
+        // Take the nonces first so they are gone even if the key handle is bad
+        let nonces = self
+            .nonces
+            .remove(&nonce_handle)
+            .ok_or_else(|| format!("Unknown nonce handle {}", nonce_handle))?;
+        let key_package = self
+            .key_packages
+            .get(&key_handle)
+            .ok_or_else(|| format!("Unknown key handle {}", key_handle))?;
+
+        sign(&nonces.0, &key_package.0)
+    }
+
+    /// Export the nonce registry encrypted under a key derived from the key
+    /// package behind `key_handle`, so entries cannot be dropped without the
+    /// signing share (NONCE_REGISTRY_ERROR). Each export of a handle carries a
+    /// higher counter than the last one exported or imported for it, so after
+    /// a reload import the stored export before exporting again.
+    pub fn export_nonce_registry(
+        &mut self,
+        key_handle: u32,
+    ) -> Result<SealedNonceRegistry, FrostError> {
+        let counter = self.registry_counter(key_handle) + 1;
+        let sealed = self
+            .with_registry_key(key_handle, |key| seal_nonce_registry(key, counter))
+            .map_err(|e| FrostError::new("NONCE_REGISTRY_ERROR", e))?;
+        self.registry_counters.insert(key_handle, counter);
+        Ok(sealed)
+    }
+
+    /// Merge a registry from `export_nonce_registry` after checking it was
+    /// sealed under the key package behind `key_handle` (NONCE_REGISTRY_ERROR).
+    ///
+    /// Exports older than `min_counter` or than the last one exported or
+    /// imported for this handle are refused. The keystore forgets counters on
+    /// reload, so rollback across reloads is only caught if the caller keeps
+    /// the counter of its latest export somewhere the export cannot roll back
+    /// and passes it as `min_counter`.
+    pub fn import_nonce_registry(
+        &mut self,
+        key_handle: u32,
+        sealed: SealedNonceRegistry,
+        min_counter: u64,
+    ) -> Result<NonceRegistryState, FrostError> {
+        let min_counter = min_counter.max(self.registry_counter(key_handle));
+        let counter = sealed.counter;
+        let state = self
+            .with_registry_key(key_handle, |key| {
+                open_nonce_registry(key, sealed, min_counter)
+            })
+            .map_err(|e| FrostError::new("NONCE_REGISTRY_ERROR", e))?;
+        self.registry_counters.insert(key_handle, counter);
+        Ok(state)
+    }
+
+    /// Last sealed registry counter exported or imported for a key handle
+    fn registry_counter(&self, key_handle: u32) -> u64 {
+        self.registry_counters
+            .get(&key_handle)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Run `f` with the serialized signing share of a key package, wiping it
+    /// afterwards. The registry key is derived from it with HKDF.
+    fn with_registry_key<T>(
+        &self,
+        key_handle: u32,
+        f: impl FnOnce(&[u8]) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let key_package = self
+            .key_packages
+            .get(&key_handle)
+            .ok_or_else(|| format!("Unknown key handle {}", key_handle))?;
+
+        let mut key = key_package.0.signing_share().serialize();
+        let result = f(&key);
+        key.zeroize();
+        result
+    }
+
+    /// Destroy a key or nonce handle, zeroizing the secret it refers to.
+    /// Returns `true` if the handle existed.
+    pub fn destroy(&mut self, handle: u32) -> bool {
+        self.registry_counters.remove(&handle);
+        self.key_packages.remove(&handle).is_some() || self.nonces.remove(&handle).is_some()
+    }
+}
+
+/// JSON form of `Keystore::load_key_package` (KEYSTORE_ERROR).
+pub fn load_key_package<C: Ciphersuite>(
+    keystore: &mut Keystore<C>,
+    key_package_json: &str,
+) -> Result<KeyHandle, FrostError> {
+    load_key_package_internal(keystore, key_package_json)
+        .map_err(|e| FrostError::new("KEYSTORE_ERROR", e))
+}
+
+fn load_key_package_internal<C: Ciphersuite>(
+    keystore: &mut Keystore<C>,
+    key_package_json: &str,
+) -> Result<KeyHandle, String> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    key_handle(keystore, key_package)
+}
+
+/// Load a key package and describe the handle returned to the caller
+pub fn key_handle<C: Ciphersuite>(
+    keystore: &mut Keystore<C>,
+    key_package: frost::keys::KeyPackage<C>,
+) -> Result<KeyHandle, String> {
+    let identifier = identifier_to_u16(key_package.identifier())?;
+    let verifying_share = verifying_share_hex(key_package.verifying_share())?;
+
+    Ok(KeyHandle {
+        handle: keystore.load_key_package(key_package),
+        identifier,
+        verifying_share,
+    })
+}
+
+/// JSON form of `Keystore::round1_commit` (ROUND1_ERROR).
+pub fn generate_round1_commitment<C: Ciphersuite>(
+    keystore: &mut Keystore<C>,
+    key_handle: u32,
+) -> Result<Round1HandleResult, FrostError> {
+    let identifier = keystore.identifier(key_handle).ok_or_else(|| {
+        FrostError::new("ROUND1_ERROR", format!("Unknown key handle {}", key_handle))
+    })?;
+
+    let (nonce_handle, commitments) = keystore.round1_commit(key_handle)?;
+
+    Ok(Round1HandleResult {
+        commitment: commitment_result(&identifier, &commitments)
+            .map_err(|e| FrostError::new("ROUND1_ERROR", e))?,
+        nonce_handle,
+    })
+}
+
+/// JSON form of `Keystore::sign` (ROUND2_ERROR, or NONCE_REUSED). The nonce
+/// handle is consumed either way.
+pub fn generate_round2_signature<C: Ciphersuite>(
+    keystore: &mut Keystore<C>,
+    key_handle: u32,
+    nonce_handle: u32,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SignatureShare, FrostError> {
+    parse_signing_package::<C>(commitments_json, message_hex)
+        .map_err(Round2Error::from)
+        .and_then(|signing_package| {
+            keystore.sign_with(key_handle, nonce_handle, |nonces, key_package| {
+                let signature_share = round2_sign_internal(&signing_package, nonces, key_package)?;
+                Ok(signature_share_result(
+                    key_package.identifier(),
+                    &signature_share,
+                )?)
+            })
+        })
+        .map_err(|e| e.0)
+}
+
+/// JSON form of `Keystore::import_nonce_registry` (NONCE_REGISTRY_ERROR).
+pub fn import_nonce_registry<C: Ciphersuite>(
+    keystore: &mut Keystore<C>,
+    key_handle: u32,
+    sealed_json: &str,
+    min_counter: u64,
+) -> Result<NonceRegistryState, FrostError> {
+    let sealed: SealedNonceRegistry = serde_json::from_str(sealed_json).map_err(|e| {
+        FrostError::new(
+            "NONCE_REGISTRY_ERROR",
+            format!("Invalid sealed nonce registry JSON: {}", e),
+        )
+    })?;
+
+    keystore.import_nonce_registry(key_handle, sealed, min_counter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::deal_key_shares;
+    use crate::nonces::export_nonce_registry;
+    use frost_ed25519::Ed25519Sha512;
+    use frost_secp256k1_tr::Secp256K1Sha256TR;
+    use std::collections::BTreeMap;
+
+    fn sealed_nonce_registry<C: Ciphersuite>() {
+        let (shares, _) = deal_key_shares::<C>(2, 3).expect("Key generation failed");
+        let mut keystore = Keystore::<C>::default();
+        let key_handles: Vec<u32> = shares
+            .into_values()
+            .take(2)
+            .map(|share| keystore.load_key_package(share.try_into().unwrap()))
+            .collect();
+
+        // Sign once so the registry has an entry
+        let round1: Vec<_> = key_handles
+            .iter()
+            .map(|k| keystore.round1_commit(*k).unwrap())
+            .collect();
+        let commitments: BTreeMap<_, _> = key_handles
+            .iter()
+            .zip(&round1)
+            .map(|(k, (_, c))| (keystore.identifier(*k).unwrap(), *c))
+            .collect();
+        let signing_package = frost::SigningPackage::new(commitments, b"message");
+        keystore
+            .sign(key_handles[0], round1[0].0, &signing_package)
+            .expect("Signing failed");
+
+        let stale = keystore.export_nonce_registry(key_handles[0]).unwrap();
+        let sealed = keystore.export_nonce_registry(key_handles[0]).unwrap();
+        assert!(sealed.counter > stale.counter);
+        let sealed_json = serde_json::to_string(&sealed).unwrap();
+        let state = import_nonce_registry(&mut keystore, key_handles[0], &sealed_json, 0)
+            .expect("Sealed registry should open");
+        assert_eq!(state.used_nonces, export_nonce_registry().used_nonces);
+        assert!(!state.used_nonces.is_empty());
+
+        // Any change to the ciphertext or counter fails authentication
+        let mut tampered = sealed.clone();
+        tampered.counter += 1;
+        let Err(error) = keystore.import_nonce_registry(key_handles[0], tampered, 0) else {
+            panic!("Tampered registry should fail");
+        };
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+
+        // An older export cannot be rolled back to
+        let Err(error) = keystore.import_nonce_registry(key_handles[0], stale, 0) else {
+            panic!("Stale registry should fail");
+        };
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+        assert!(error.message.contains("older"));
+
+        // Only the key package that sealed it can open it
+        let Err(error) = import_nonce_registry(&mut keystore, key_handles[1], &sealed_json, 0)
+        else {
+            panic!("Registry sealed under another key should fail");
+        };
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+    }
+
+    fn sealed_nonce_registry_counters<C: Ciphersuite>() {
+        let (shares, _) = deal_key_shares::<C>(2, 3).expect("Key generation failed");
+        let key_packages: Vec<frost::keys::KeyPackage<C>> = shares
+            .into_values()
+            .take(2)
+            .map(|share| share.try_into().unwrap())
+            .collect();
+        let mut keystore = Keystore::<C>::default();
+        let key_handles: Vec<u32> = key_packages
+            .iter()
+            .map(|kp| keystore.load_key_package(kp.clone()))
+            .collect();
+
+        // Counters are per key: exporting key A often does not make key B's
+        // lower-counter export look stale
+        for _ in 0..3 {
+            keystore.export_nonce_registry(key_handles[0]).unwrap();
+        }
+        let stale = keystore.export_nonce_registry(key_handles[0]).unwrap();
+        let latest = keystore.export_nonce_registry(key_handles[0]).unwrap();
+        let other = keystore.export_nonce_registry(key_handles[1]).unwrap();
+        assert!(other.counter < latest.counter);
+        keystore
+            .import_nonce_registry(key_handles[1], other.clone(), other.counter)
+            .expect("Another key's export should open");
+        keystore
+            .import_nonce_registry(key_handles[0], latest.clone(), latest.counter)
+            .expect("Latest export should open");
+
+        // Simulate a reload: a fresh keystore has no counters, so the stored
+        // counter is what refuses the stale export
+        let mut keystore = Keystore::<C>::default();
+        let key_handle = keystore.load_key_package(key_packages[0].clone());
+        let Err(error) = keystore.import_nonce_registry(key_handle, stale.clone(), latest.counter)
+        else {
+            panic!("Stale registry should fail after a reload");
+        };
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+        assert!(error.message.contains("older"));
+
+        keystore
+            .import_nonce_registry(key_handle, latest.clone(), latest.counter)
+            .expect("Latest export should open after a reload");
+        let next = keystore.export_nonce_registry(key_handle).unwrap();
+        assert!(next.counter > latest.counter);
+
+        // Without the stored counter, the keystore still refuses exports older
+        // than the one it just imported
+        assert!(keystore
+            .import_nonce_registry(key_handle, stale, 0)
+            .is_err());
+    }
+
+    fn nonces_consumed_on_bad_key_handle<C: Ciphersuite>() {
+        let (shares, _) = deal_key_shares::<C>(2, 3).expect("Key generation failed");
+        let mut keystore = Keystore::<C>::default();
+        let key_handles: Vec<u32> = shares
+            .into_values()
+            .take(2)
+            .map(|share| keystore.load_key_package(share.try_into().unwrap()))
+            .collect();
+        let round1: Vec<_> = key_handles
+            .iter()
+            .map(|k| keystore.round1_commit(*k).unwrap())
+            .collect();
+        let commitments: BTreeMap<_, _> = key_handles
+            .iter()
+            .zip(&round1)
+            .map(|(k, (_, c))| (keystore.identifier(*k).unwrap(), *c))
+            .collect();
+        let signing_package = frost::SigningPackage::new(commitments, b"message");
+
+        let Err(error) = keystore.sign(u32::MAX, round1[0].0, &signing_package) else {
+            panic!("Unknown key handle should fail");
+        };
+        assert_eq!(error.code, "ROUND2_ERROR");
+
+        // The nonce handle was consumed by the failed call
+        let Err(error) = keystore.sign(key_handles[0], round1[0].0, &signing_package) else {
+            panic!("Nonce handle should be gone");
+        };
+        assert!(error.message.contains("Unknown nonce handle"));
+    }
+
+    #[test]
+    fn test_nonces_consumed_on_bad_key_handle() {
+        nonces_consumed_on_bad_key_handle::<Ed25519Sha512>();
+        nonces_consumed_on_bad_key_handle::<Secp256K1Sha256TR>();
+    }
+
+    #[test]
+    fn test_sealed_nonce_registry() {
+        sealed_nonce_registry::<Ed25519Sha512>();
+        sealed_nonce_registry::<Secp256K1Sha256TR>();
+    }
+
+    #[test]
+    fn test_sealed_nonce_registry_counters() {
+        sealed_nonce_registry_counters::<Ed25519Sha512>();
+        sealed_nonce_registry_counters::<Secp256K1Sha256TR>();
+    }
+}
@@ -0,0 +1,29 @@
+//! Ciphersuite-generic FROST operations
+//!
+//! Every operation exposed by the WASM crates, written once over
+//! `frost_core::Ciphersuite`. Each operation has a typed form over the
+//! `frost_core` types, and a JSON form on top of it that uses the same wire
+//! types regardless of curve, so a binding crate only has to pick a
+//! ciphersuite.
+//!
+//! Rerandomized signing (ZIP-312) is behind the `rerandomized` feature, for
+//! ciphersuites implementing `frost_rerandomized::RandomizedCiphersuite`.
+
+pub mod codec;
+pub mod dkg;
+pub mod error;
+pub mod keys;
+pub mod keystore;
+pub mod nonces;
+pub mod pool;
+pub mod reconstruct;
+pub mod refresh;
+pub mod repair;
+#[cfg(feature = "rerandomized")]
+pub mod rerandomized;
+pub mod reshare;
+pub mod signing;
+pub mod types;
+
+pub use error::{to_json, AggregateError, FrostError};
+pub use types::*;
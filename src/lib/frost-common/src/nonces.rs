@@ -0,0 +1,283 @@
+//! Registry of nonces already used in Round 2
+//!
+//! Signing twice with the same nonces leaks the signing share, so Round 2
+//! refuses nonces whose commitments are in the registry. The registry is
+//! shared by the whole process (one per WASM instance) and can be exported
+//! and re-imported to survive page reloads. The plain export can be edited by whoever stores it;
+//! `Keystore::export_nonce_registry` seals it under a key package instead.
+
+use std::collections::BTreeSet;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use frost_core::{self as frost, Ciphersuite};
+use hkdf::Hkdf;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use crate::error::Round2Error;
+use crate::{FrostError, NonceRegistryState, SealedNonceRegistry};
+
+/// HKDF salt separating the registry key from other uses of the signing share
+const REGISTRY_HKDF_SALT: &[u8] = b"frost-common nonce registry v2";
+const REGISTRY_HKDF_INFO: &[u8] = b"aes-gcm-256";
+
+/// Commitments of every nonce pair already used in Round 2
+static USED_NONCES: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// Lock the registry. A panic while it was held cannot leave it half-updated,
+/// so a poisoned lock is still safe to use.
+fn used_nonces() -> MutexGuard<'static, BTreeSet<String>> {
+    USED_NONCES.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Identify nonces by their public commitments, so the registry holds no secrets
+fn nonce_fingerprint<C: Ciphersuite>(
+    nonces: &frost::round1::SigningNonces<C>,
+) -> Result<String, String> {
+    let bytes = nonces
+        .commitments()
+        .serialize()
+        .map_err(|e| format!("Failed to serialize commitments: {:?}", e))?;
+    Ok(hex::encode(bytes))
+}
+
+/// Record nonces as consumed before they produce a signature share, rejecting
+/// nonces already in the registry. Checking and recording under one lock means
+/// two concurrent Round 2 calls cannot both sign with the same nonces; nonces
+/// whose signing then fails stay consumed.
+pub(crate) fn claim_nonces<C: Ciphersuite>(
+    nonces: &frost::round1::SigningNonces<C>,
+) -> Result<(), Round2Error> {
+    let fingerprint = nonce_fingerprint(nonces)?;
+    if !used_nonces().insert(fingerprint) {
+        return Err(Round2Error(FrostError::new(
+            "NONCE_REUSED",
+            "These nonces were already used to sign; run Round 1 again",
+        )));
+    }
+    Ok(())
+}
+
+/// Whether nonces were already claimed by a Round 2 call
+pub(crate) fn nonces_used<C: Ciphersuite>(
+    nonces: &frost::round1::SigningNonces<C>,
+) -> Result<bool, String> {
+    let fingerprint = nonce_fingerprint(nonces)?;
+    Ok(used_nonces().contains(&fingerprint))
+}
+
+/// Snapshot the registry so it can be persisted
+pub fn export_nonce_registry() -> NonceRegistryState {
+    let used_nonces = used_nonces().iter().cloned().collect();
+    NonceRegistryState { used_nonces }
+}
+
+/// Merge a previously exported registry into the current one (NONCE_REGISTRY_ERROR).
+///
+/// Entries are only ever added, so importing stale state cannot make used
+/// nonces usable again.
+pub fn merge_nonce_registry(state: NonceRegistryState) -> Result<NonceRegistryState, FrostError> {
+    merge_nonce_registry_internal(state).map_err(|e| FrostError::new("NONCE_REGISTRY_ERROR", e))
+}
+
+fn merge_nonce_registry_internal(state: NonceRegistryState) -> Result<NonceRegistryState, String> {
+    for fingerprint in &state.used_nonces {
+        hex::decode(fingerprint).map_err(|e| format!("Invalid registry entry hex: {}", e))?;
+    }
+
+    let mut used = used_nonces();
+    used.extend(state.used_nonces);
+    let used_nonces = used.iter().cloned().collect();
+
+    Ok(NonceRegistryState { used_nonces })
+}
+
+/// AES-256-GCM keyed by HKDF over the serialized signing share
+fn registry_cipher(signing_share: &[u8]) -> Result<Aes256Gcm, String> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Hkdf::<Sha256>::new(Some(REGISTRY_HKDF_SALT), signing_share)
+        .expand(REGISTRY_HKDF_INFO, key.as_mut())
+        .map_err(|e| format!("Key derivation failed: {:?}", e))?;
+    Aes256Gcm::new_from_slice(key.as_ref()).map_err(|e| format!("Invalid key: {:?}", e))
+}
+
+/// Snapshot the registry and encrypt it under a key derived from
+/// `signing_share`, bound to `counter`
+pub(crate) fn seal_nonce_registry(
+    signing_share: &[u8],
+    counter: u64,
+) -> Result<SealedNonceRegistry, String> {
+    let cipher = registry_cipher(signing_share)?;
+    let NonceRegistryState { used_nonces } = export_nonce_registry();
+    let plaintext = serde_json::to_vec(&used_nonces)
+        .map_err(|e| format!("Failed to serialize registry: {}", e))?;
+
+    let mut nonce = [0u8; 12];
+    OsRng.fill_bytes(&mut nonce);
+    let ciphertext = cipher
+        .encrypt(
+            &Nonce::from(nonce),
+            Payload {
+                msg: &plaintext,
+                aad: &counter.to_be_bytes(),
+            },
+        )
+        .map_err(|e| format!("Encryption failed: {:?}", e))?;
+
+    Ok(SealedNonceRegistry {
+        counter,
+        nonce: hex::encode(nonce),
+        ciphertext: hex::encode(ciphertext),
+    })
+}
+
+/// Decrypt a sealed registry with the key derived from `signing_share`, then
+/// merge it into the current one. Exports with a counter below `min_counter`
+/// are refused, so a stale copy cannot be replayed.
+pub(crate) fn open_nonce_registry(
+    signing_share: &[u8],
+    sealed: SealedNonceRegistry,
+    min_counter: u64,
+) -> Result<NonceRegistryState, String> {
+    let nonce: [u8; 12] = hex::decode(&sealed.nonce)
+        .map_err(|e| format!("Invalid nonce hex: {}", e))?
+        .try_into()
+        .map_err(|_| "Nonce must be 12 bytes")?;
+    let ciphertext =
+        hex::decode(&sealed.ciphertext).map_err(|e| format!("Invalid ciphertext hex: {}", e))?;
+
+    let plaintext = registry_cipher(signing_share)?
+        .decrypt(
+            &Nonce::from(nonce),
+            Payload {
+                msg: &ciphertext,
+                aad: &sealed.counter.to_be_bytes(),
+            },
+        )
+        .map_err(|_| "Nonce registry was modified or sealed under another key")?;
+    let used_nonces: Vec<String> = serde_json::from_slice(&plaintext)
+        .map_err(|e| format!("Invalid registry entries: {}", e))?;
+
+    if sealed.counter < min_counter {
+        return Err(format!(
+            "Nonce registry export {} is older than export {}",
+            sealed.counter, min_counter
+        ));
+    }
+
+    merge_nonce_registry_internal(NonceRegistryState { used_nonces })
+}
+
+/// JSON form of `merge_nonce_registry` (NONCE_REGISTRY_ERROR).
+pub fn import_nonce_registry(state_json: &str) -> Result<NonceRegistryState, FrostError> {
+    import_nonce_registry_internal(state_json)
+        .map_err(|e| FrostError::new("NONCE_REGISTRY_ERROR", e))
+}
+
+fn import_nonce_registry_internal(state_json: &str) -> Result<NonceRegistryState, String> {
+    let state: NonceRegistryState = serde_json::from_str(state_json)
+        .map_err(|e| format!("Invalid nonce registry JSON: {}", e))?;
+
+    merge_nonce_registry_internal(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::generate_key_shares;
+    use crate::signing::tests::MESSAGE;
+    use crate::signing::{generate_round1_commitment, generate_round2_signature};
+    use crate::{Commitment, Round1Result};
+    use frost_ed25519::Ed25519Sha512;
+    use frost_secp256k1_tr::Secp256K1Sha256TR;
+
+    fn rejects_nonce_reuse<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+        let key_packages = [&keygen.shares[0].key_package, &keygen.shares[1].key_package];
+
+        let round1: Vec<Round1Result> = key_packages
+            .iter()
+            .map(|kp| generate_round1_commitment::<C>(kp).expect("Round 1 failed"))
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+        let nonces = serde_json::to_string(&round1[0].nonces).unwrap();
+
+        generate_round2_signature::<C>(key_packages[0], &nonces, &commitments_json, MESSAGE)
+            .expect("First use should succeed");
+
+        // Signing a second message with the same nonces would leak the share
+        let Err(error) = generate_round2_signature::<C>(
+            key_packages[0],
+            &nonces,
+            &commitments_json,
+            "6261642062616420626164",
+        ) else {
+            panic!("Second use should fail");
+        };
+        assert_eq!(error.code, "NONCE_REUSED");
+
+        let state = export_nonce_registry();
+        assert!(!state.used_nonces.is_empty());
+
+        let merged = import_nonce_registry(&serde_json::to_string(&state).unwrap())
+            .expect("Import should succeed");
+        assert_eq!(merged.used_nonces, state.used_nonces);
+
+        let Err(error) = import_nonce_registry(r#"{"used_nonces":["zz"]}"#) else {
+            panic!("Invalid entries should fail");
+        };
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+    }
+
+    #[test]
+    fn test_rejects_nonce_reuse() {
+        rejects_nonce_reuse::<Ed25519Sha512>();
+        rejects_nonce_reuse::<Secp256K1Sha256TR>();
+    }
+
+    fn rejects_concurrent_nonce_reuse<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+        let key_packages = [&keygen.shares[0].key_package, &keygen.shares[1].key_package];
+
+        let round1: Vec<Round1Result> = key_packages
+            .iter()
+            .map(|kp| generate_round1_commitment::<C>(kp).expect("Round 1 failed"))
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+        let nonces = serde_json::to_string(&round1[0].nonces).unwrap();
+
+        // Only one of several threads racing on the same nonces may sign
+        let results: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| {
+                    scope.spawn(|| {
+                        generate_round2_signature::<C>(
+                            key_packages[0],
+                            &nonces,
+                            &commitments_json,
+                            MESSAGE,
+                        )
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        for error in results.into_iter().filter_map(Result::err) {
+            assert_eq!(error.code, "NONCE_REUSED");
+        }
+    }
+
+    #[test]
+    fn test_rejects_concurrent_nonce_reuse() {
+        rejects_concurrent_nonce_reuse::<Ed25519Sha512>();
+        rejects_concurrent_nonce_reuse::<Secp256K1Sha256TR>();
+    }
+}
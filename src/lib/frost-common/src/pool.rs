@@ -0,0 +1,409 @@
+//! Commitment pools: preprocess Round 1 so signing needs a single round
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use frost_core::{self as frost, Ciphersuite};
+
+use crate::codec::identifier_to_u16;
+use crate::error::Round2Error;
+use crate::nonces::nonces_used;
+use crate::signing::{
+    parse_signing_package, round1_commit, round2_sign_internal, signature_share_result,
+};
+use crate::{
+    Commitment, CommitmentPool, CommitmentPoolResult, FrostError, NoncePool, PoolSelection,
+    PoolSignatureResult,
+};
+
+/// Maximum commitments a signer can preprocess in one batch
+pub const MAX_POOL_SIZE: u32 = 1000;
+
+/// Preprocess a batch of Round 1 nonces; each carries the commitments to
+/// publish (ROUND1_ERROR).
+pub fn preprocess<C: Ciphersuite>(
+    key_package: &frost::keys::KeyPackage<C>,
+    count: u32,
+) -> Result<Vec<frost::round1::SigningNonces<C>>, FrostError> {
+    preprocess_internal(key_package, count).map_err(|e| FrostError::new("ROUND1_ERROR", e))
+}
+
+fn preprocess_internal<C: Ciphersuite>(
+    key_package: &frost::keys::KeyPackage<C>,
+    count: u32,
+) -> Result<Vec<frost::round1::SigningNonces<C>>, String> {
+    if count == 0 || count > MAX_POOL_SIZE {
+        return Err(format!("Pool size must be between 1 and {}", MAX_POOL_SIZE));
+    }
+
+    Ok((0..count).map(|_| round1_commit(key_package).0).collect())
+}
+
+/// JSON form of `preprocess` (ROUND1_ERROR).
+pub fn generate_commitment_pool<C: Ciphersuite>(
+    key_package_json: &str,
+    count: u32,
+) -> Result<CommitmentPoolResult, FrostError> {
+    generate_commitment_pool_internal::<C>(key_package_json, count)
+        .map_err(|e| FrostError::new("ROUND1_ERROR", e))
+}
+
+fn generate_commitment_pool_internal<C: Ciphersuite>(
+    key_package_json: &str,
+    count: u32,
+) -> Result<CommitmentPoolResult, String> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    let id = identifier_to_u16(key_package.identifier())?;
+
+    let pool = preprocess_internal(&key_package, count)?;
+
+    let mut commitments = Vec::with_capacity(pool.len());
+    let mut nonces = Vec::with_capacity(pool.len());
+    for signing_nonces in &pool {
+        nonces.push(
+            serde_json::to_string(signing_nonces)
+                .map_err(|e| format!("Failed to serialize nonces: {:?}", e))?,
+        );
+        commitments.push(
+            serde_json::to_string(signing_nonces.commitments())
+                .map_err(|e| format!("Failed to serialize commitments: {:?}", e))?,
+        );
+    }
+
+    Ok(CommitmentPoolResult {
+        commitments: CommitmentPool {
+            identifier: id,
+            commitments,
+        },
+        nonces: NoncePool {
+            identifier: id,
+            nonces,
+        },
+    })
+}
+
+/// Take the oldest unused commitment from each signer's pool (POOL_ERROR).
+pub fn select_commitments<C: Ciphersuite>(
+    pools: &mut BTreeMap<frost::Identifier<C>, Vec<frost::round1::SigningCommitments<C>>>,
+    signers: &[frost::Identifier<C>],
+) -> Result<BTreeMap<frost::Identifier<C>, frost::round1::SigningCommitments<C>>, FrostError> {
+    select_commitments_internal(pools, signers).map_err(|e| FrostError::new("POOL_ERROR", e))
+}
+
+fn select_commitments_internal<C: Ciphersuite>(
+    pools: &mut BTreeMap<frost::Identifier<C>, Vec<frost::round1::SigningCommitments<C>>>,
+    signers: &[frost::Identifier<C>],
+) -> Result<BTreeMap<frost::Identifier<C>, frost::round1::SigningCommitments<C>>, String> {
+    let signers: BTreeSet<_> = signers.iter().collect();
+
+    // Check every pool before taking from any, so a failure leaves them untouched
+    for signer in &signers {
+        let Some(pool) = pools.get(signer) else {
+            return Err(format!(
+                "No commitment pool for participant {}",
+                identifier_to_u16(signer)?
+            ));
+        };
+        if pool.is_empty() {
+            return Err(format!(
+                "Commitment pool for participant {} is exhausted",
+                identifier_to_u16(signer)?
+            ));
+        }
+    }
+
+    Ok(signers
+        .into_iter()
+        .filter_map(|signer| Some((*signer, pools.get_mut(signer)?.remove(0))))
+        .collect())
+}
+
+/// Take the oldest unused commitment from each signer's pool (POOL_ERROR).
+///
+/// Commitments are opaque here, so this works for any ciphersuite.
+pub fn select_pool_commitments(
+    pools_json: &str,
+    signers_json: &str,
+) -> Result<PoolSelection, FrostError> {
+    select_pool_commitments_internal(pools_json, signers_json)
+        .map_err(|e| FrostError::new("POOL_ERROR", e))
+}
+
+fn select_pool_commitments_internal(
+    pools_json: &str,
+    signers_json: &str,
+) -> Result<PoolSelection, String> {
+    let mut pools: Vec<CommitmentPool> =
+        serde_json::from_str(pools_json).map_err(|e| format!("Invalid pools JSON: {}", e))?;
+    let signers: BTreeSet<u16> =
+        serde_json::from_str(signers_json).map_err(|e| format!("Invalid signers JSON: {}", e))?;
+
+    let mut commitments = Vec::with_capacity(signers.len());
+    for signer in signers {
+        let pool = pools
+            .iter_mut()
+            .find(|p| p.identifier == signer)
+            .ok_or_else(|| format!("No commitment pool for participant {}", signer))?;
+        if pool.commitments.is_empty() {
+            return Err(format!(
+                "Commitment pool for participant {} is exhausted",
+                signer
+            ));
+        }
+        commitments.push(Commitment {
+            identifier: signer,
+            commitment: pool.commitments.remove(0),
+        });
+    }
+
+    Ok(PoolSelection { commitments, pools })
+}
+
+/// Sign with the pooled nonces matching the coordinator's selection, removing
+/// them from the pool even if signing fails (ROUND2_ERROR, or NONCE_REUSED).
+pub fn sign_from_pool<C: Ciphersuite>(
+    pool: &mut Vec<frost::round1::SigningNonces<C>>,
+    signing_package: &frost::SigningPackage<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<frost::round2::SignatureShare<C>, FrostError> {
+    let index = pooled_nonces_index(pool, signing_package, key_package)
+        .map_err(|e| FrostError::new("ROUND2_ERROR", e))?;
+
+    // The nonces are claimed before signing, so they are spent either way
+    let nonces = pool.remove(index);
+    round2_sign_internal(signing_package, &nonces, key_package).map_err(|e| e.0)
+}
+
+/// JSON form of `sign_from_pool`; the returned pool drops the used nonces and
+/// any left spent by an earlier failed attempt (ROUND2_ERROR, or NONCE_REUSED).
+pub fn generate_round2_signature_from_pool<C: Ciphersuite>(
+    key_package_json: &str,
+    nonce_pool_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<PoolSignatureResult, FrostError> {
+    generate_round2_from_pool_internal::<C>(
+        key_package_json,
+        nonce_pool_json,
+        commitments_json,
+        message_hex,
+    )
+    .map_err(|e| e.0)
+}
+
+fn generate_round2_from_pool_internal<C: Ciphersuite>(
+    key_package_json: &str,
+    nonce_pool_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<PoolSignatureResult, Round2Error> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+    let mut pool: NoncePool = serde_json::from_str(nonce_pool_json)
+        .map_err(|e| format!("Invalid nonce pool JSON: {}", e))?;
+    let nonces = parse_nonce_pool::<C>(&pool)?;
+
+    let signing_package = parse_signing_package(commitments_json, message_hex)?;
+    let index = pooled_nonces_index(&nonces, &signing_package, &key_package)?;
+
+    let signature_share = round2_sign_internal(&signing_package, &nonces[index], &key_package)?;
+    retain_unused_nonces(&mut pool, &nonces)?;
+
+    Ok(PoolSignatureResult {
+        signature_share: signature_share_result(key_package.identifier(), &signature_share)?,
+        nonces: pool,
+    })
+}
+
+/// Parse the nonces held in a NoncePool
+pub fn parse_nonce_pool<C: Ciphersuite>(
+    pool: &NoncePool,
+) -> Result<Vec<frost::round1::SigningNonces<C>>, String> {
+    pool.nonces
+        .iter()
+        .map(|nonces_json| {
+            serde_json::from_str(nonces_json).map_err(|e| format!("Invalid nonces JSON: {}", e))
+        })
+        .collect()
+}
+
+/// Drop the entries of a NoncePool whose nonces (parsed as `nonces`) are
+/// already in the nonce registry
+pub(crate) fn retain_unused_nonces<C: Ciphersuite>(
+    pool: &mut NoncePool,
+    nonces: &[frost::round1::SigningNonces<C>],
+) -> Result<(), String> {
+    let mut unused = Vec::with_capacity(pool.nonces.len());
+    for (nonces_json, nonces) in pool.nonces.drain(..).zip(nonces) {
+        if !nonces_used(nonces)? {
+            unused.push(nonces_json);
+        }
+    }
+    pool.nonces = unused;
+    Ok(())
+}
+
+/// Find the pooled nonces whose commitment the coordinator selected for this signer
+pub(crate) fn pooled_nonces_index<C: Ciphersuite>(
+    pool: &[frost::round1::SigningNonces<C>],
+    signing_package: &frost::SigningPackage<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<usize, String> {
+    let selected = signing_package
+        .signing_commitment(key_package.identifier())
+        .ok_or("No commitment for this participant in the signing set")?;
+
+    pool.iter()
+        .position(|nonces| *nonces.commitments() == selected)
+        .ok_or_else(|| "Selected commitment is not in the nonce pool".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::generate_key_shares;
+    use crate::signing::tests::MESSAGE;
+    use crate::signing::{aggregate_signature, generate_round2_signature, round2_sign};
+    use crate::SigningNonces;
+    use frost_ed25519::Ed25519Sha512;
+    use frost_secp256k1_tr::Secp256K1Sha256TR;
+
+    fn single_round_signing_from_pools<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+        let signers = &keygen.shares[..2];
+
+        // Signers preprocess ahead of time and publish their commitments
+        let mut nonce_pools: Vec<String> = Vec::new();
+        let mut pools: Vec<CommitmentPool> = Vec::new();
+        for share in signers {
+            let result = generate_commitment_pool::<C>(&share.key_package, 2)
+                .expect("Preprocessing should succeed");
+            assert_eq!(result.commitments.commitments.len(), 2);
+            nonce_pools.push(serde_json::to_string(&result.nonces).unwrap());
+            pools.push(result.commitments);
+        }
+
+        for message in ["48656c6c6f20576f726c64", "6261642062616420626164"] {
+            let selection =
+                select_pool_commitments(&serde_json::to_string(&pools).unwrap(), "[1, 2]")
+                    .expect("Selection should succeed");
+            pools = selection.pools;
+            let commitments_json = serde_json::to_string(&selection.commitments).unwrap();
+
+            let mut shares = Vec::new();
+            for (share, nonce_pool) in signers.iter().zip(nonce_pools.iter_mut()) {
+                let result = generate_round2_signature_from_pool::<C>(
+                    &share.key_package,
+                    nonce_pool,
+                    &commitments_json,
+                    message,
+                )
+                .expect("Signing from pool should succeed");
+                *nonce_pool = serde_json::to_string(&result.nonces).unwrap();
+                shares.push(result.signature_share);
+            }
+
+            aggregate_signature::<C>(
+                &serde_json::to_string(&shares).unwrap(),
+                &commitments_json,
+                message,
+                &keygen.public_key_package,
+            )
+            .expect("Should aggregate");
+        }
+
+        // Both pools are used up
+        let Err(error) = select_pool_commitments(&serde_json::to_string(&pools).unwrap(), "[1, 2]")
+        else {
+            panic!("Empty pools should fail");
+        };
+        assert_eq!(error.code, "POOL_ERROR");
+    }
+
+    #[test]
+    fn test_single_round_signing_from_pools() {
+        single_round_signing_from_pools::<Ed25519Sha512>();
+        single_round_signing_from_pools::<Secp256K1Sha256TR>();
+    }
+
+    fn drops_spent_pool_nonces<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+        let signers = &keygen.shares[..2];
+
+        let results: Vec<CommitmentPoolResult> = signers
+            .iter()
+            .map(|share| generate_commitment_pool::<C>(&share.key_package, 2).unwrap())
+            .collect();
+        let mut pools: Vec<CommitmentPool> =
+            results.iter().map(|r| r.commitments.clone()).collect();
+        let nonce_pool = &results[0].nonces;
+
+        let selection =
+            select_pool_commitments(&serde_json::to_string(&pools).unwrap(), "[1, 2]").unwrap();
+        pools = selection.pools;
+        let commitments_json = serde_json::to_string(&selection.commitments).unwrap();
+
+        // Spend the selected nonces outside the pool
+        let spent = SigningNonces {
+            identifier: nonce_pool.identifier,
+            nonces: nonce_pool.nonces[0].clone(),
+        };
+        generate_round2_signature::<C>(
+            &signers[0].key_package,
+            &serde_json::to_string(&spent).unwrap(),
+            &commitments_json,
+            MESSAGE,
+        )
+        .expect("First use should succeed");
+        let Err(error) = generate_round2_signature_from_pool::<C>(
+            &signers[0].key_package,
+            &serde_json::to_string(nonce_pool).unwrap(),
+            &commitments_json,
+            MESSAGE,
+        ) else {
+            panic!("Spent nonces should fail");
+        };
+        assert_eq!(error.code, "NONCE_REUSED");
+
+        // The next signature drops the spent entry along with the one it uses
+        let selection =
+            select_pool_commitments(&serde_json::to_string(&pools).unwrap(), "[1, 2]").unwrap();
+        let result = generate_round2_signature_from_pool::<C>(
+            &signers[0].key_package,
+            &serde_json::to_string(nonce_pool).unwrap(),
+            &serde_json::to_string(&selection.commitments).unwrap(),
+            MESSAGE,
+        )
+        .expect("Signing from pool should succeed");
+        assert!(result.nonces.nonces.is_empty());
+
+        // The typed pool loses the selected nonces even when signing fails
+        let key_packages: Vec<frost::keys::KeyPackage<C>> = signers
+            .iter()
+            .map(|share| serde_json::from_str(&share.key_package).unwrap())
+            .collect();
+        let mut pool = preprocess(&key_packages[0], 1).unwrap();
+        let (_, other_commitments) = round1_commit(&key_packages[1]);
+        let signing_package = frost::SigningPackage::new(
+            BTreeMap::from([
+                (*key_packages[0].identifier(), *pool[0].commitments()),
+                (*key_packages[1].identifier(), other_commitments),
+            ]),
+            b"pool",
+        );
+        round2_sign(&signing_package, &pool[0], &key_packages[0]).unwrap();
+
+        let Err(error) = sign_from_pool(&mut pool, &signing_package, &key_packages[0]) else {
+            panic!("Spent nonces should fail");
+        };
+        assert_eq!(error.code, "NONCE_REUSED");
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn test_drops_spent_pool_nonces() {
+        drops_spent_pool_nonces::<Ed25519Sha512>();
+        drops_spent_pool_nonces::<Secp256K1Sha256TR>();
+    }
+}
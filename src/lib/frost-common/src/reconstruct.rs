@@ -0,0 +1,127 @@
+//! Emergency reconstruction of the group signing key
+
+use std::collections::BTreeSet;
+
+use frost_core::{self as frost, Ciphersuite};
+
+use crate::codec::verifying_key_hex;
+use crate::{FrostError, ReconstructedKey};
+
+/// DANGER: reassemble the group signing key from `min_signers` key packages
+/// (RECONSTRUCT_ERROR).
+///
+/// Whoever runs this holds the whole key. It exists for disaster recovery and
+/// migrating off FROST only.
+pub fn reconstruct_signing_key<C: Ciphersuite>(
+    key_packages: &[frost::keys::KeyPackage<C>],
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<frost::SigningKey<C>, FrostError> {
+    reconstruct_signing_key_internal(key_packages, pubkey_package)
+        .map_err(|e| FrostError::new("RECONSTRUCT_ERROR", e))
+}
+
+fn reconstruct_signing_key_internal<C: Ciphersuite>(
+    key_packages: &[frost::keys::KeyPackage<C>],
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<frost::SigningKey<C>, String> {
+    let min_signers = *key_packages
+        .first()
+        .ok_or("No key packages provided")?
+        .min_signers();
+    let distinct: BTreeSet<_> = key_packages.iter().map(|kp| *kp.identifier()).collect();
+    if distinct.len() < min_signers as usize {
+        return Err(format!(
+            "Need key packages from at least {} distinct participants, got {}",
+            min_signers,
+            distinct.len()
+        ));
+    }
+
+    let signing_key = frost::keys::reconstruct(key_packages)
+        .map_err(|e| format!("Reconstruction failed: {:?}", e))?;
+
+    if frost::VerifyingKey::from(&signing_key) != *pubkey_package.verifying_key() {
+        return Err("Reconstructed key does not match the public key package".into());
+    }
+
+    Ok(signing_key)
+}
+
+/// DANGER: JSON form of `reconstruct_signing_key` (RECONSTRUCT_ERROR).
+pub fn emergency_reconstruct_signing_key<C: Ciphersuite>(
+    key_packages_json: &str,
+    public_key_package_json: &str,
+) -> Result<ReconstructedKey, FrostError> {
+    emergency_reconstruct_internal::<C>(key_packages_json, public_key_package_json)
+        .map_err(|e| FrostError::new("RECONSTRUCT_ERROR", e))
+}
+
+fn emergency_reconstruct_internal<C: Ciphersuite>(
+    key_packages_json: &str,
+    public_key_package_json: &str,
+) -> Result<ReconstructedKey, String> {
+    let key_packages = parse_key_packages::<C>(key_packages_json)?;
+
+    let pubkey_package: frost::keys::PublicKeyPackage<C> =
+        serde_json::from_str(public_key_package_json)
+            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+
+    let signing_key = reconstruct_signing_key_internal(&key_packages, &pubkey_package)?;
+
+    Ok(ReconstructedKey {
+        signing_key: hex::encode(signing_key.serialize()),
+        group_public_key: verifying_key_hex(pubkey_package.verifying_key())?,
+    })
+}
+
+/// Parse a JSON array of key package JSON strings
+pub fn parse_key_packages<C: Ciphersuite>(
+    key_packages_json: &str,
+) -> Result<Vec<frost::keys::KeyPackage<C>>, String> {
+    let key_package_strs: Vec<String> = serde_json::from_str(key_packages_json)
+        .map_err(|e| format!("Invalid key packages JSON: {}", e))?;
+
+    key_package_strs
+        .iter()
+        .map(|kp| {
+            serde_json::from_str::<frost::keys::KeyPackage<C>>(kp)
+                .map_err(|e| format!("Invalid key package JSON: {}", e))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::generate_key_shares;
+    use frost_ed25519::Ed25519Sha512;
+    use frost_secp256k1_tr::Secp256K1Sha256TR;
+
+    fn emergency_reconstruct<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+
+        let two =
+            serde_json::to_string(&[&keygen.shares[1].key_package, &keygen.shares[2].key_package])
+                .unwrap();
+        let key = emergency_reconstruct_signing_key::<C>(&two, &keygen.public_key_package)
+            .expect("Reconstruction should succeed");
+        assert_eq!(key.group_public_key, keygen.group_public_key);
+
+        // The same key package twice is still one participant
+        let repeated =
+            serde_json::to_string(&[&keygen.shares[0].key_package, &keygen.shares[0].key_package])
+                .unwrap();
+        let Err(error) =
+            emergency_reconstruct_signing_key::<C>(&repeated, &keygen.public_key_package)
+        else {
+            panic!("Too few shares should fail");
+        };
+        assert_eq!(error.code, "RECONSTRUCT_ERROR");
+    }
+
+    #[test]
+    fn test_emergency_reconstruct() {
+        emergency_reconstruct::<Ed25519Sha512>();
+        emergency_reconstruct::<Secp256K1Sha256TR>();
+    }
+}
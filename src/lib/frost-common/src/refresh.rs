@@ -0,0 +1,512 @@
+//! Share refresh: rotate every key share without changing the group public key
+
+use frost_core::{self as frost, Ciphersuite};
+use rand::rngs::OsRng;
+
+use crate::codec::{
+    check_threshold, identifier_from_u16, identifier_to_u16, parse_identifier_list,
+    with_min_signers,
+};
+use crate::dkg::{
+    dkg_result, parse_dkg_round1_packages, parse_dkg_round2_packages, round1_result,
+    round2_packages, Round1Packages, Round2Packages,
+};
+use crate::keys::key_share;
+use crate::{
+    DkgResult, DkgRound1Result, DkgRound2Result, FrostError, KeyShare, RefreshSharesResult,
+    RefreshingShare,
+};
+
+/// Dealer-based refresh: deal zero-secret shares to the given participants and
+/// derive the updated public key package (REFRESH_ERROR).
+pub fn deal_refreshing_shares<C: Ciphersuite>(
+    pubkey_package: frost::keys::PublicKeyPackage<C>,
+    identifiers: &[frost::Identifier<C>],
+    min_signers: u16,
+) -> Result<
+    (
+        Vec<frost::keys::SecretShare<C>>,
+        frost::keys::PublicKeyPackage<C>,
+    ),
+    FrostError,
+> {
+    deal_refreshing_shares_internal(pubkey_package, identifiers, min_signers)
+        .map_err(|e| FrostError::new("REFRESH_ERROR", e))
+}
+
+fn deal_refreshing_shares_internal<C: Ciphersuite>(
+    pubkey_package: frost::keys::PublicKeyPackage<C>,
+    identifiers: &[frost::Identifier<C>],
+    min_signers: u16,
+) -> Result<
+    (
+        Vec<frost::keys::SecretShare<C>>,
+        frost::keys::PublicKeyPackage<C>,
+    ),
+    String,
+> {
+    let max_signers = identifiers.len() as u16;
+    check_threshold(min_signers, max_signers)?;
+
+    let pubkey_package = with_min_signers(pubkey_package, min_signers)?;
+
+    let mut rng = OsRng;
+
+    frost::keys::refresh::compute_refreshing_shares(pubkey_package, identifiers, &mut rng)
+        .map_err(|e| format!("Refresh failed: {:?}", e))
+}
+
+/// Apply a refreshing share from `deal_refreshing_shares` to a key package (REFRESH_ERROR).
+pub fn apply_refreshing_share<C: Ciphersuite>(
+    refreshing_share: frost::keys::SecretShare<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<frost::keys::KeyPackage<C>, FrostError> {
+    apply_refreshing_share_internal(refreshing_share, key_package)
+        .map_err(|e| FrostError::new("REFRESH_ERROR", e))
+}
+
+fn apply_refreshing_share_internal<C: Ciphersuite>(
+    refreshing_share: frost::keys::SecretShare<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<frost::keys::KeyPackage<C>, String> {
+    if refreshing_share.identifier() != key_package.identifier() {
+        return Err(format!(
+            "Refreshing share is for participant {}, not this key package",
+            identifier_to_u16(refreshing_share.identifier())?
+        ));
+    }
+
+    let refreshed = frost::keys::refresh::refresh_share(refreshing_share, key_package)
+        .map_err(|e| format!("Refresh failed: {:?}", e))?;
+
+    // frost-core keeps the old verifying share here; recompute it from
+    // the refreshed signing share so it matches the new public key package
+    Ok(frost::keys::KeyPackage::new(
+        *refreshed.identifier(),
+        *refreshed.signing_share(),
+        frost::keys::VerifyingShare::from(*refreshed.signing_share()),
+        *refreshed.verifying_key(),
+        *refreshed.min_signers(),
+    ))
+}
+
+/// Refresh DKG part 1: like `dkg::part1`, with a zero constant term (REFRESH_ERROR).
+pub fn refresh_part1<C: Ciphersuite>(
+    identifier: frost::Identifier<C>,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<
+    (
+        frost::keys::dkg::round1::SecretPackage<C>,
+        frost::keys::dkg::round1::Package<C>,
+    ),
+    FrostError,
+> {
+    refresh_part1_internal(identifier, max_signers, min_signers)
+        .map_err(|e| FrostError::new("REFRESH_ERROR", e))
+}
+
+fn refresh_part1_internal<C: Ciphersuite>(
+    identifier: frost::Identifier<C>,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<
+    (
+        frost::keys::dkg::round1::SecretPackage<C>,
+        frost::keys::dkg::round1::Package<C>,
+    ),
+    String,
+> {
+    check_threshold(min_signers, max_signers)?;
+
+    let rng = OsRng;
+
+    frost::keys::refresh::refresh_dkg_part1(identifier, max_signers, min_signers, rng)
+        .map_err(|e| format!("Refresh DKG part 1 failed: {:?}", e))
+}
+
+/// Refresh DKG part 2: like `dkg::part2` (REFRESH_ERROR).
+///
+/// Deterministic in the Round 1 secret package, so it can be re-run to
+/// recover the Round 2 secret package.
+pub fn refresh_part2<C: Ciphersuite>(
+    secret_package: frost::keys::dkg::round1::SecretPackage<C>,
+    round1_packages: &Round1Packages<C>,
+) -> Result<
+    (
+        frost::keys::dkg::round2::SecretPackage<C>,
+        Round2Packages<C>,
+    ),
+    FrostError,
+> {
+    refresh_part2_internal(secret_package, round1_packages)
+        .map_err(|e| FrostError::new("REFRESH_ERROR", e))
+}
+
+fn refresh_part2_internal<C: Ciphersuite>(
+    secret_package: frost::keys::dkg::round1::SecretPackage<C>,
+    round1_packages: &Round1Packages<C>,
+) -> Result<
+    (
+        frost::keys::dkg::round2::SecretPackage<C>,
+        Round2Packages<C>,
+    ),
+    String,
+> {
+    frost::keys::refresh::refresh_dkg_part2(secret_package, round1_packages)
+        .map_err(|e| format!("Refresh DKG part 2 failed: {:?}", e))
+}
+
+/// Refresh DKG part 3: add the zero-secret shares to this participant's
+/// current key package and derive the updated public key package (REFRESH_ERROR).
+pub fn refresh_part3<C: Ciphersuite>(
+    secret_package: &frost::keys::dkg::round2::SecretPackage<C>,
+    round1_packages: &Round1Packages<C>,
+    round2_packages: &Round2Packages<C>,
+    pubkey_package: frost::keys::PublicKeyPackage<C>,
+    key_package: frost::keys::KeyPackage<C>,
+) -> Result<(frost::keys::KeyPackage<C>, frost::keys::PublicKeyPackage<C>), FrostError> {
+    refresh_part3_internal(
+        secret_package,
+        round1_packages,
+        round2_packages,
+        pubkey_package,
+        key_package,
+    )
+    .map_err(|e| FrostError::new("REFRESH_ERROR", e))
+}
+
+fn refresh_part3_internal<C: Ciphersuite>(
+    secret_package: &frost::keys::dkg::round2::SecretPackage<C>,
+    round1_packages: &Round1Packages<C>,
+    round2_packages: &Round2Packages<C>,
+    pubkey_package: frost::keys::PublicKeyPackage<C>,
+    key_package: frost::keys::KeyPackage<C>,
+) -> Result<(frost::keys::KeyPackage<C>, frost::keys::PublicKeyPackage<C>), String> {
+    frost::keys::refresh::refresh_dkg_shares(
+        secret_package,
+        round1_packages,
+        round2_packages,
+        pubkey_package,
+        key_package,
+    )
+    .map_err(|e| format!("Refresh DKG part 3 failed: {:?}", e))
+}
+
+/// JSON form of `deal_refreshing_shares` (REFRESH_ERROR).
+pub fn compute_refreshing_shares<C: Ciphersuite>(
+    public_key_package_json: &str,
+    identifiers_json: &str,
+    min_signers: u16,
+) -> Result<RefreshSharesResult, FrostError> {
+    compute_refreshing_shares_internal::<C>(public_key_package_json, identifiers_json, min_signers)
+        .map_err(|e| FrostError::new("REFRESH_ERROR", e))
+}
+
+fn compute_refreshing_shares_internal<C: Ciphersuite>(
+    public_key_package_json: &str,
+    identifiers_json: &str,
+    min_signers: u16,
+) -> Result<RefreshSharesResult, String> {
+    let pubkey_package: frost::keys::PublicKeyPackage<C> =
+        serde_json::from_str(public_key_package_json)
+            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+
+    let identifiers = parse_identifier_list::<C>(identifiers_json)?;
+
+    let (refreshing_shares, new_pubkey_package) =
+        deal_refreshing_shares_internal(pubkey_package, &identifiers, min_signers)?;
+
+    let mut shares = Vec::with_capacity(refreshing_shares.len());
+    for share in refreshing_shares {
+        let share_json = serde_json::to_string(&share)
+            .map_err(|e| format!("Failed to serialize refreshing share: {:?}", e))?;
+
+        shares.push(RefreshingShare {
+            identifier: identifier_to_u16(share.identifier())?,
+            refreshing_share: share_json,
+        });
+    }
+
+    let pubkey_package_json = serde_json::to_string(&new_pubkey_package)
+        .map_err(|e| format!("Failed to serialize public key package: {:?}", e))?;
+
+    Ok(RefreshSharesResult {
+        shares,
+        public_key_package: pubkey_package_json,
+    })
+}
+
+/// JSON form of `apply_refreshing_share` (REFRESH_ERROR).
+pub fn refresh_share<C: Ciphersuite>(
+    refreshing_share_json: &str,
+    key_package_json: &str,
+) -> Result<KeyShare, FrostError> {
+    refresh_share_internal::<C>(refreshing_share_json, key_package_json)
+        .map_err(|e| FrostError::new("REFRESH_ERROR", e))
+}
+
+fn refresh_share_internal<C: Ciphersuite>(
+    refreshing_share_json: &str,
+    key_package_json: &str,
+) -> Result<KeyShare, String> {
+    let wrapper: RefreshingShare = serde_json::from_str(refreshing_share_json)
+        .map_err(|e| format!("Invalid refreshing share wrapper JSON: {}", e))?;
+    let zero_share: frost::keys::SecretShare<C> =
+        serde_json::from_str(&wrapper.refreshing_share)
+            .map_err(|e| format!("Invalid refreshing share JSON: {}", e))?;
+
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    key_share(&apply_refreshing_share_internal(zero_share, &key_package)?)
+}
+
+/// JSON form of `refresh_part1` (REFRESH_ERROR).
+pub fn refresh_dkg_part1<C: Ciphersuite>(
+    identifier: u16,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DkgRound1Result, FrostError> {
+    refresh_dkg_part1_internal::<C>(identifier, max_signers, min_signers)
+        .map_err(|e| FrostError::new("REFRESH_ERROR", e))
+}
+
+fn refresh_dkg_part1_internal<C: Ciphersuite>(
+    identifier: u16,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DkgRound1Result, String> {
+    let id = identifier_from_u16::<C>(identifier)?;
+
+    let (secret_package, package) = refresh_part1_internal(id, max_signers, min_signers)?;
+
+    round1_result(identifier, &secret_package, &package)
+}
+
+/// JSON form of `refresh_part2`: same package format as `dkg_part2` (REFRESH_ERROR).
+///
+/// The secret package returned for part 3 is the Round 1 secret package.
+pub fn refresh_dkg_part2<C: Ciphersuite>(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+) -> Result<DkgRound2Result, FrostError> {
+    refresh_dkg_part2_internal::<C>(secret_package_json, round1_packages_json)
+        .map_err(|e| FrostError::new("REFRESH_ERROR", e))
+}
+
+fn refresh_dkg_part2_internal<C: Ciphersuite>(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+) -> Result<DkgRound2Result, String> {
+    let secret_package: frost::keys::dkg::round1::SecretPackage<C> =
+        serde_json::from_str(secret_package_json)
+            .map_err(|e| format!("Invalid secret package JSON: {}", e))?;
+
+    let round1_packages = parse_dkg_round1_packages(round1_packages_json)?;
+
+    // Part 3 re-derives the Round 2 secret package from the Round 1 secret
+    // package (this step is deterministic), so only one secret is kept
+    // between rounds; frost-core 2.x could not serialize the refresh Round 2
+    // secret package at all
+    let secret_package_json = serde_json::to_string(&secret_package)
+        .map_err(|e| format!("Failed to serialize secret package: {:?}", e))?;
+
+    let (round2_secret_package, packages) =
+        refresh_part2_internal(secret_package, &round1_packages)?;
+
+    Ok(DkgRound2Result {
+        secret_package: secret_package_json,
+        packages: round2_packages(round2_secret_package.identifier(), packages)?,
+    })
+}
+
+/// JSON form of `refresh_part3`, taking the Round 1 secret package returned by
+/// `refresh_dkg_part2` (REFRESH_ERROR).
+pub fn refresh_dkg_part3<C: Ciphersuite>(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+    public_key_package_json: &str,
+    key_package_json: &str,
+) -> Result<DkgResult, FrostError> {
+    refresh_dkg_part3_internal::<C>(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+        public_key_package_json,
+        key_package_json,
+    )
+    .map_err(|e| FrostError::new("REFRESH_ERROR", e))
+}
+
+fn refresh_dkg_part3_internal<C: Ciphersuite>(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+    public_key_package_json: &str,
+    key_package_json: &str,
+) -> Result<DkgResult, String> {
+    let round1_secret_package: frost::keys::dkg::round1::SecretPackage<C> =
+        serde_json::from_str(secret_package_json)
+            .map_err(|e| format!("Invalid secret package JSON: {}", e))?;
+
+    let round1_packages = parse_dkg_round1_packages(round1_packages_json)?;
+    let (secret_package, _) = refresh_part2_internal(round1_secret_package, &round1_packages)?;
+    let round2_packages =
+        parse_dkg_round2_packages(round2_packages_json, secret_package.identifier())?;
+
+    let pubkey_package: frost::keys::PublicKeyPackage<C> =
+        serde_json::from_str(public_key_package_json)
+            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    let (new_key_package, new_pubkey_package) = refresh_part3_internal(
+        &secret_package,
+        &round1_packages,
+        &round2_packages,
+        pubkey_package,
+        key_package,
+    )?;
+
+    dkg_result(&new_key_package, &new_pubkey_package)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::generate_key_shares;
+    use crate::signing::tests::{sign_with, MESSAGE};
+    use crate::signing::verify_signature;
+    use crate::{DkgRound1Package, DkgRound2Package};
+    use frost_ed25519::Ed25519Sha512;
+    use frost_secp256k1_tr::Secp256K1Sha256TR;
+
+    fn refresh_keeps_group_key<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+
+        let refresh = compute_refreshing_shares::<C>(&keygen.public_key_package, "[1, 2, 3]", 2)
+            .expect("Refresh should succeed");
+
+        let refreshed: Vec<KeyShare> = keygen
+            .shares
+            .iter()
+            .zip(&refresh.shares)
+            .map(|(old, share)| {
+                refresh_share::<C>(&serde_json::to_string(share).unwrap(), &old.key_package)
+                    .expect("Applying refresh should succeed")
+            })
+            .collect();
+        assert_ne!(
+            refreshed[0].verifying_share,
+            keygen.shares[0].verifying_share
+        );
+
+        // New shares sign for the unchanged group key
+        let signature = sign_with::<C>(
+            &[&refreshed[0].key_package, &refreshed[2].key_package],
+            &refresh.public_key_package,
+        )
+        .expect("Aggregation with refreshed shares failed");
+        let verify = verify_signature::<C>(&signature.signature, MESSAGE, &keygen.group_public_key)
+            .expect("Verification failed");
+        assert!(verify.valid);
+
+        // Old and new shares no longer combine
+        let Err(error) = sign_with::<C>(
+            &[&keygen.shares[0].key_package, &refreshed[1].key_package],
+            &refresh.public_key_package,
+        ) else {
+            panic!("Mixing shares should fail");
+        };
+        assert_eq!(error.code, "AGGREGATE_ERROR");
+    }
+
+    fn refresh_dkg_keeps_group_key<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+
+        // Part 1: every participant broadcasts a zero-constant Round 1 package
+        let round1: Vec<DkgRound1Result> = (1..=3)
+            .map(|id| refresh_dkg_part1::<C>(id, 3, 2).expect("Refresh DKG part 1 failed"))
+            .collect();
+
+        let others_round1 = |id: u16| {
+            let packages: Vec<DkgRound1Package> = round1
+                .iter()
+                .filter(|r| r.package.identifier != id)
+                .map(|r| r.package.clone())
+                .collect();
+            serde_json::to_string(&packages).unwrap()
+        };
+
+        // Part 2: every participant sends one Round 2 package to each other participant
+        let round2: Vec<DkgRound2Result> = round1
+            .iter()
+            .map(|r| {
+                refresh_dkg_part2::<C>(&r.secret_package, &others_round1(r.package.identifier))
+                    .expect("Refresh DKG part 2 failed")
+            })
+            .collect();
+
+        // Part 3: every participant refreshes its current key package
+        let refreshed: Vec<DkgResult> = keygen
+            .shares
+            .iter()
+            .map(|share| {
+                let id = share.identifier;
+                let inbox: Vec<DkgRound2Package> = round2
+                    .iter()
+                    .flat_map(|r| r.packages.iter())
+                    .filter(|p| p.recipient == id)
+                    .cloned()
+                    .collect();
+                refresh_dkg_part3::<C>(
+                    &round2[(id - 1) as usize].secret_package,
+                    &others_round1(id),
+                    &serde_json::to_string(&inbox).unwrap(),
+                    &keygen.public_key_package,
+                    &share.key_package,
+                )
+                .expect("Refresh DKG part 3 failed")
+            })
+            .collect();
+
+        // The group key is unchanged and everyone agrees on the new verifying shares
+        assert!(refreshed
+            .iter()
+            .all(|r| r.group_public_key == keygen.group_public_key
+                && r.public_key_package == refreshed[0].public_key_package));
+        assert_ne!(refreshed[0].public_key_package, keygen.public_key_package);
+
+        let signature = sign_with::<C>(
+            &[&refreshed[1].key_package, &refreshed[2].key_package],
+            &refreshed[0].public_key_package,
+        )
+        .expect("Aggregation with refreshed shares failed");
+        let verify = verify_signature::<C>(&signature.signature, MESSAGE, &keygen.group_public_key)
+            .expect("Verification failed");
+        assert!(verify.valid);
+
+        let Err(error) = sign_with::<C>(
+            &[&keygen.shares[0].key_package, &refreshed[1].key_package],
+            &refreshed[0].public_key_package,
+        ) else {
+            panic!("Mixing shares should fail");
+        };
+        assert_eq!(error.code, "AGGREGATE_ERROR");
+    }
+
+    #[test]
+    fn test_refresh_keeps_group_key() {
+        refresh_keeps_group_key::<Ed25519Sha512>();
+        refresh_keeps_group_key::<Secp256K1Sha256TR>();
+    }
+
+    #[test]
+    fn test_refresh_dkg_keeps_group_key() {
+        refresh_dkg_keeps_group_key::<Ed25519Sha512>();
+        refresh_dkg_keeps_group_key::<Secp256K1Sha256TR>();
+    }
+}
@@ -0,0 +1,299 @@
+//! Share repair: rebuild a lost share with help from `min_signers` other participants
+
+use std::collections::BTreeMap;
+
+use frost_core::{self as frost, Ciphersuite};
+use rand::rngs::OsRng;
+
+use crate::codec::{
+    identifier_from_u16, identifier_to_u16, parse_identifier_list, scalar_from_hex, scalar_to_hex,
+    with_min_signers,
+};
+use crate::keys::key_share;
+use crate::{FrostError, KeyShare, RepairDelta, RepairSigma, RepairStep1Result};
+
+/// Repair step 1 (helper): split this helper's contribution into one delta
+/// per helper, keyed by the helper it goes to (REPAIR_ERROR).
+pub fn repair_step_1<C: Ciphersuite>(
+    helpers: &[frost::Identifier<C>],
+    key_package: &frost::keys::KeyPackage<C>,
+    participant: frost::Identifier<C>,
+) -> Result<BTreeMap<frost::Identifier<C>, frost::Scalar<C>>, FrostError> {
+    repair_step_1_internal(helpers, key_package, participant)
+        .map_err(|e| FrostError::new("REPAIR_ERROR", e))
+}
+
+fn repair_step_1_internal<C: Ciphersuite>(
+    helpers: &[frost::Identifier<C>],
+    key_package: &frost::keys::KeyPackage<C>,
+    participant: frost::Identifier<C>,
+) -> Result<BTreeMap<frost::Identifier<C>, frost::Scalar<C>>, String> {
+    if helpers.len() < *key_package.min_signers() as usize {
+        return Err(format!(
+            "Need at least {} helpers, got {}",
+            key_package.min_signers(),
+            helpers.len()
+        ));
+    }
+    if helpers.contains(&participant) {
+        return Err(format!(
+            "Participant {} cannot help repair its own share",
+            identifier_to_u16(&participant)?
+        ));
+    }
+    if !helpers.contains(key_package.identifier()) {
+        return Err("This key package's identifier is not in the helper set".into());
+    }
+
+    let mut rng = OsRng;
+    let deltas =
+        frost::keys::repairable::repair_share_part1(helpers, key_package, &mut rng, participant)
+            .map_err(|e| format!("Repair step 1 failed: {:?}", e))?;
+
+    Ok(deltas
+        .into_iter()
+        .map(|(id, delta)| (id, delta.to_scalar()))
+        .collect())
+}
+
+/// Repair step 2 (helper): combine the deltas addressed to this helper into its sigma.
+pub fn repair_step_2<C: Ciphersuite>(deltas: &[frost::Scalar<C>]) -> frost::Scalar<C> {
+    let deltas = deltas
+        .iter()
+        .map(|delta| frost::keys::repairable::Delta::new(*delta))
+        .collect::<Vec<_>>();
+    frost::keys::repairable::repair_share_part2::<C>(&deltas).to_scalar()
+}
+
+/// Repair step 3 (participant being repaired): rebuild the key package from
+/// the helpers' sigmas and check it against the public key package (REPAIR_ERROR).
+pub fn repair_step_3<C: Ciphersuite>(
+    sigmas: &[frost::Scalar<C>],
+    identifier: frost::Identifier<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    min_signers: u16,
+) -> Result<frost::keys::KeyPackage<C>, FrostError> {
+    repair_step_3_internal(sigmas, identifier, pubkey_package, min_signers)
+        .map_err(|e| FrostError::new("REPAIR_ERROR", e))
+}
+
+fn repair_step_3_internal<C: Ciphersuite>(
+    sigmas: &[frost::Scalar<C>],
+    identifier: frost::Identifier<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    min_signers: u16,
+) -> Result<frost::keys::KeyPackage<C>, String> {
+    if sigmas.len() < min_signers as usize {
+        return Err(format!(
+            "Need a sigma from at least {} helpers, got {}",
+            min_signers,
+            sigmas.len()
+        ));
+    }
+
+    let Some(verifying_share) = pubkey_package.verifying_shares().get(&identifier).copied() else {
+        return Err(format!(
+            "Participant {} is not in the public key package",
+            identifier_to_u16(&identifier)?
+        ));
+    };
+
+    let sigmas = sigmas
+        .iter()
+        .map(|sigma| frost::keys::repairable::Sigma::new(*sigma))
+        .collect::<Vec<_>>();
+    let key_package = frost::keys::repairable::repair_share_part3(
+        &sigmas,
+        identifier,
+        &with_min_signers(pubkey_package.clone(), min_signers)?,
+    )
+    .map_err(|e| format!("Repair step 3 failed: {:?}", e))?;
+
+    if *key_package.verifying_share() != verifying_share {
+        return Err("Repaired share does not match the public key package".into());
+    }
+
+    Ok(key_package)
+}
+
+/// JSON form of `repair_step_1` (REPAIR_ERROR).
+pub fn repair_share_step_1<C: Ciphersuite>(
+    helpers_json: &str,
+    key_package_json: &str,
+    participant: u16,
+) -> Result<RepairStep1Result, FrostError> {
+    repair_share_step_1_internal::<C>(helpers_json, key_package_json, participant)
+        .map_err(|e| FrostError::new("REPAIR_ERROR", e))
+}
+
+fn repair_share_step_1_internal<C: Ciphersuite>(
+    helpers_json: &str,
+    key_package_json: &str,
+    participant: u16,
+) -> Result<RepairStep1Result, String> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    let helpers = parse_identifier_list::<C>(helpers_json)?;
+    let participant_id = identifier_from_u16::<C>(participant)?;
+
+    let deltas = repair_step_1_internal(&helpers, &key_package, participant_id)?;
+
+    let sender = identifier_to_u16(key_package.identifier())?;
+
+    let deltas = deltas
+        .iter()
+        .map(|(id, delta)| {
+            Ok(RepairDelta {
+                sender,
+                recipient: identifier_to_u16(id)?,
+                delta: scalar_to_hex::<C>(delta),
+            })
+        })
+        .collect::<Result<_, String>>()?;
+
+    Ok(RepairStep1Result { deltas })
+}
+
+/// JSON form of `repair_step_2`, checking every delta has the same recipient (REPAIR_ERROR).
+pub fn repair_share_step_2<C: Ciphersuite>(deltas_json: &str) -> Result<RepairSigma, FrostError> {
+    repair_share_step_2_internal::<C>(deltas_json).map_err(|e| FrostError::new("REPAIR_ERROR", e))
+}
+
+fn repair_share_step_2_internal<C: Ciphersuite>(deltas_json: &str) -> Result<RepairSigma, String> {
+    let deltas: Vec<RepairDelta> =
+        serde_json::from_str(deltas_json).map_err(|e| format!("Invalid deltas JSON: {}", e))?;
+
+    let recipient = deltas.first().ok_or("No deltas provided")?.recipient;
+    if let Some(d) = deltas.iter().find(|d| d.recipient != recipient) {
+        return Err(format!(
+            "Delta from {} is addressed to {}, not {}",
+            d.sender, d.recipient, recipient
+        ));
+    }
+
+    let deltas = deltas
+        .iter()
+        .map(|d| scalar_from_hex::<C>(&d.delta))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(RepairSigma {
+        identifier: recipient,
+        sigma: scalar_to_hex::<C>(&repair_step_2::<C>(&deltas)),
+    })
+}
+
+/// JSON form of `repair_step_3` (REPAIR_ERROR).
+pub fn repair_share_step_3<C: Ciphersuite>(
+    sigmas_json: &str,
+    identifier: u16,
+    public_key_package_json: &str,
+    min_signers: u16,
+) -> Result<KeyShare, FrostError> {
+    repair_share_step_3_internal::<C>(
+        sigmas_json,
+        identifier,
+        public_key_package_json,
+        min_signers,
+    )
+    .map_err(|e| FrostError::new("REPAIR_ERROR", e))
+}
+
+fn repair_share_step_3_internal<C: Ciphersuite>(
+    sigmas_json: &str,
+    identifier: u16,
+    public_key_package_json: &str,
+    min_signers: u16,
+) -> Result<KeyShare, String> {
+    let sigmas: Vec<RepairSigma> =
+        serde_json::from_str(sigmas_json).map_err(|e| format!("Invalid sigmas JSON: {}", e))?;
+
+    let pubkey_package: frost::keys::PublicKeyPackage<C> =
+        serde_json::from_str(public_key_package_json)
+            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+
+    let id = identifier_from_u16::<C>(identifier)?;
+
+    let sigmas = sigmas
+        .iter()
+        .map(|s| scalar_from_hex::<C>(&s.sigma))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    key_share(&repair_step_3_internal(
+        &sigmas,
+        id,
+        &pubkey_package,
+        min_signers,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::generate_key_shares;
+    use crate::signing::tests::sign_with;
+    use frost_ed25519::Ed25519Sha512;
+    use frost_secp256k1_tr::Secp256K1Sha256TR;
+
+    fn repair_lost_share<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+        let helpers = [&keygen.shares[0], &keygen.shares[1]];
+
+        // Participant 3 lost their share; participants 1 and 2 help
+        let step1: Vec<RepairStep1Result> = helpers
+            .iter()
+            .map(|h| {
+                repair_share_step_1::<C>("[1, 2]", &h.key_package, 3)
+                    .expect("Repair step 1 should succeed")
+            })
+            .collect();
+
+        let sigmas: Vec<RepairSigma> = helpers
+            .iter()
+            .map(|h| {
+                let inbox: Vec<RepairDelta> = step1
+                    .iter()
+                    .flat_map(|r| r.deltas.iter())
+                    .filter(|d| d.recipient == h.identifier)
+                    .cloned()
+                    .collect();
+                repair_share_step_2::<C>(&serde_json::to_string(&inbox).unwrap())
+                    .expect("Repair step 2 should succeed")
+            })
+            .collect();
+
+        let repaired = repair_share_step_3::<C>(
+            &serde_json::to_string(&sigmas).unwrap(),
+            3,
+            &keygen.public_key_package,
+            2,
+        )
+        .expect("Repair step 3 should succeed");
+        assert_eq!(repaired.verifying_share, keygen.shares[2].verifying_share);
+
+        sign_with::<C>(
+            &[&keygen.shares[0].key_package, &repaired.key_package],
+            &keygen.public_key_package,
+        )
+        .expect("Repaired share should sign");
+
+        // A wrong sigma is caught against the public key package
+        let mut bad_sigmas = sigmas.clone();
+        bad_sigmas[0].sigma = bad_sigmas[1].sigma.clone();
+        let Err(error) = repair_share_step_3::<C>(
+            &serde_json::to_string(&bad_sigmas).unwrap(),
+            3,
+            &keygen.public_key_package,
+            2,
+        ) else {
+            panic!("Bad repair should fail");
+        };
+        assert_eq!(error.code, "REPAIR_ERROR");
+    }
+
+    #[test]
+    fn test_repair_lost_share() {
+        repair_lost_share::<Ed25519Sha512>();
+        repair_lost_share::<Secp256K1Sha256TR>();
+    }
+}
@@ -0,0 +1,853 @@
+//! Rerandomized FROST (ZIP-312)
+//!
+//! The coordinator picks a randomizer per signature and ships it with the
+//! serialized SigningPackage; signers and the aggregator use the randomized
+//! group key. Used for RedPallas (Orchard) and RedJubjub (Sapling).
+
+use std::collections::BTreeMap;
+
+use frost_core as frost;
+use frost_rerandomized::{RandomizedCiphersuite, RandomizedParams, Randomizer};
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+
+use crate::codec::verifying_share_from_element;
+use crate::error::Round2Error;
+use crate::keystore::Keystore;
+use crate::nonces::claim_nonces;
+use crate::pool::{parse_nonce_pool, pooled_nonces_index, retain_unused_nonces};
+use crate::signing::{
+    aggregate_error, find_culprits, parse_signature, parse_signature_shares, parse_signing_nonces,
+    parse_signing_package, parse_verifying_key, signature_hex, signature_share_result,
+    SignatureShares,
+};
+use crate::{
+    AggregateError, AggregateResult, FrostError, NoncePool, PoolSignatureResult,
+    ShareVerificationResult, SignatureShare, SigningPackageResult, VerifyResult,
+};
+
+// =============================================================================
+// Signing Package Creation
+// =============================================================================
+
+/// Randomizer of a signing package, in the form the coordinator ships it
+///
+/// A fresh randomizer travels as its seed, so each signer derives it from the
+/// seed and the signing commitments rather than trusting the coordinator's
+/// RNG. One fixed outside the ceremony (Orchard's alpha, chosen by the
+/// transaction builder) travels as the randomizer itself. In JSON the seed is
+/// `{"seed": "<hex>"}` and the randomizer a hex string.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(bound = "C: RandomizedCiphersuite", untagged)]
+pub enum SigningRandomizer<C: RandomizedCiphersuite> {
+    /// Seed from `RandomizedParams::new_from_commitments`
+    Seed {
+        #[serde(with = "hex")]
+        seed: Vec<u8>,
+    },
+    /// Randomizer chosen outside the ceremony
+    Randomizer(Randomizer<C>),
+}
+
+impl<C: RandomizedCiphersuite> SigningRandomizer<C> {
+    /// The randomizer for `signing_package`, derived from the seed if there is one
+    pub fn randomizer(
+        &self,
+        signing_package: &frost::SigningPackage<C>,
+    ) -> Result<Randomizer<C>, String> {
+        match self {
+            SigningRandomizer::Seed { seed } => Randomizer::regenerate_from_seed_and_commitments(
+                seed,
+                signing_package.signing_commitments(),
+            )
+            .map_err(|e| format!("Invalid randomizer seed: {:?}", e)),
+            SigningRandomizer::Randomizer(randomizer) => Ok(*randomizer),
+        }
+    }
+}
+
+/// Draw a fresh randomizer seed for a signing package (SIGNING_PACKAGE_ERROR).
+///
+/// The seed must reach every signer along with the signing package.
+pub fn randomizer<C: RandomizedCiphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<SigningRandomizer<C>, FrostError> {
+    randomizer_internal(signing_package, pubkey_package)
+        .map_err(|e| FrostError::new("SIGNING_PACKAGE_ERROR", e))
+}
+
+fn randomizer_internal<C: RandomizedCiphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<SigningRandomizer<C>, String> {
+    let rng = OsRng;
+
+    let (_, seed) = RandomizedParams::new_from_commitments(
+        pubkey_package.verifying_key(),
+        signing_package.signing_commitments(),
+        rng,
+    )
+    .map_err(|e| format!("Failed to create randomized params: {:?}", e))?;
+
+    Ok(SigningRandomizer::Seed { seed })
+}
+
+/// Check that a randomizer chosen by the transaction builder yields the
+/// randomized group key `rk` committed to in the transaction (SIGNING_PACKAGE_ERROR).
+pub fn check_rk<C: RandomizedCiphersuite>(
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    randomizer: Randomizer<C>,
+    rk: &[u8],
+) -> Result<(), FrostError> {
+    check_rk_internal(pubkey_package, randomizer, rk)
+        .map_err(|e| FrostError::new("SIGNING_PACKAGE_ERROR", e))
+}
+
+fn check_rk_internal<C: RandomizedCiphersuite>(
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    randomizer: Randomizer<C>,
+    rk: &[u8],
+) -> Result<(), String> {
+    let randomized_params =
+        RandomizedParams::from_randomizer(pubkey_package.verifying_key(), randomizer);
+
+    let randomized_key = randomized_params
+        .randomized_verifying_key()
+        .serialize()
+        .map_err(|e| format!("Failed to serialize randomized key: {:?}", e))?;
+    if randomized_key != rk {
+        return Err(format!(
+            "Randomized verifying key {} does not match rk {}",
+            hex::encode(randomized_key),
+            hex::encode(rk)
+        ));
+    }
+
+    Ok(())
+}
+
+/// Build the signing package and draw a fresh randomizer seed (SIGNING_PACKAGE_ERROR).
+///
+/// The seed must reach every signer along with the signing package.
+pub fn create_signing_package<C: RandomizedCiphersuite>(
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<SigningPackageResult, FrostError> {
+    create_signing_package_internal::<C>(commitments_json, message_hex, public_key_package_json)
+        .map_err(|e| FrostError::new("SIGNING_PACKAGE_ERROR", e))
+}
+
+fn create_signing_package_internal<C: RandomizedCiphersuite>(
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<SigningPackageResult, String> {
+    let signing_package = parse_signing_package::<C>(commitments_json, message_hex)?;
+    let pubkey_package = parse_public_key_package::<C>(public_key_package_json)?;
+
+    let randomizer = randomizer_internal(&signing_package, &pubkey_package)?;
+
+    signing_package_result(&signing_package, &randomizer)
+}
+
+/// Build the signing package with a randomizer chosen by the transaction
+/// builder (SIGNING_PACKAGE_ERROR).
+///
+/// If `rk_hex` is non-empty, the randomized group key must equal it.
+pub fn create_signing_package_with_randomizer<C: RandomizedCiphersuite>(
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+    randomizer_hex: &str,
+    rk_hex: &str,
+) -> Result<SigningPackageResult, FrostError> {
+    create_signing_package_with_randomizer_internal::<C>(
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+        randomizer_hex,
+        rk_hex,
+    )
+    .map_err(|e| FrostError::new("SIGNING_PACKAGE_ERROR", e))
+}
+
+fn create_signing_package_with_randomizer_internal<C: RandomizedCiphersuite>(
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+    randomizer_hex: &str,
+    rk_hex: &str,
+) -> Result<SigningPackageResult, String> {
+    let signing_package = parse_signing_package::<C>(commitments_json, message_hex)?;
+    let pubkey_package = parse_public_key_package::<C>(public_key_package_json)?;
+
+    let randomizer = parse_randomizer_hex::<C>(randomizer_hex)?;
+
+    if !rk_hex.is_empty() {
+        let rk = hex::decode(rk_hex).map_err(|e| format!("Invalid rk hex: {}", e))?;
+        check_rk_internal(&pubkey_package, randomizer, &rk)?;
+    }
+
+    signing_package_result(&signing_package, &SigningRandomizer::Randomizer(randomizer))
+}
+
+/// Serialize a signing package and its randomizer for the signers
+pub fn signing_package_result<C: RandomizedCiphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    randomizer: &SigningRandomizer<C>,
+) -> Result<SigningPackageResult, String> {
+    let signing_package_json = serde_json::to_string(signing_package)
+        .map_err(|e| format!("Failed to serialize signing package: {:?}", e))?;
+    let randomizer_json = serde_json::to_string(randomizer)
+        .map_err(|e| format!("Failed to serialize randomizer: {:?}", e))?;
+
+    Ok(SigningPackageResult {
+        signing_package: signing_package_json,
+        randomizer: randomizer_json,
+    })
+}
+
+/// Parse a hex-encoded randomizer scalar
+pub fn parse_randomizer_hex<C: RandomizedCiphersuite>(
+    randomizer_hex: &str,
+) -> Result<Randomizer<C>, String> {
+    let randomizer_bytes =
+        hex::decode(randomizer_hex).map_err(|e| format!("Invalid randomizer hex: {}", e))?;
+    Randomizer::<C>::deserialize(&randomizer_bytes)
+        .map_err(|e| format!("Invalid randomizer: {:?}", e))
+}
+
+// =============================================================================
+// Round 2: Signature Share Generation
+// =============================================================================
+
+/// Produce this participant's rerandomized signature share (ROUND2_ERROR, or
+/// NONCE_REUSED).
+pub fn round2_sign<C: RandomizedCiphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    nonces: &frost::round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    randomizer: &SigningRandomizer<C>,
+) -> Result<frost::round2::SignatureShare<C>, FrostError> {
+    round2_sign_internal(signing_package, nonces, key_package, randomizer).map_err(|e| e.0)
+}
+
+fn round2_sign_internal<C: RandomizedCiphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    nonces: &frost::round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    randomizer: &SigningRandomizer<C>,
+) -> Result<frost::round2::SignatureShare<C>, Round2Error> {
+    claim_nonces(nonces)?;
+
+    let signature_share = match randomizer {
+        SigningRandomizer::Seed { seed } => frost_rerandomized::sign_with_randomizer_seed(
+            signing_package,
+            nonces,
+            key_package,
+            seed,
+        ),
+        // Orchard's alpha is fixed by the transaction builder, so there is no
+        // seed to derive it from
+        #[allow(deprecated)]
+        SigningRandomizer::Randomizer(randomizer) => {
+            frost_rerandomized::sign(signing_package, nonces, key_package, *randomizer)
+        }
+    }
+    .map_err(|e| format!("Signing failed: {:?}", e))?;
+
+    Ok(signature_share)
+}
+
+/// Rerandomized signing with the pooled nonces matching the coordinator's
+/// selection, removing them from the pool even if signing fails (ROUND2_ERROR,
+/// or NONCE_REUSED).
+pub fn sign_from_pool<C: RandomizedCiphersuite>(
+    pool: &mut Vec<frost::round1::SigningNonces<C>>,
+    signing_package: &frost::SigningPackage<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+    randomizer: &SigningRandomizer<C>,
+) -> Result<frost::round2::SignatureShare<C>, FrostError> {
+    let index = pooled_nonces_index(pool, signing_package, key_package)
+        .map_err(|e| FrostError::new("ROUND2_ERROR", e))?;
+
+    // The nonces are claimed before signing, so they are spent either way
+    let nonces = pool.remove(index);
+    round2_sign(signing_package, &nonces, key_package, randomizer)
+}
+
+/// Rerandomized signing from keystore handles (ROUND2_ERROR, or NONCE_REUSED).
+/// The nonce handle is consumed either way.
+pub fn sign_with_handles<C: RandomizedCiphersuite>(
+    keystore: &mut Keystore<C>,
+    key_handle: u32,
+    nonce_handle: u32,
+    signing_package: &frost::SigningPackage<C>,
+    randomizer: &SigningRandomizer<C>,
+) -> Result<frost::round2::SignatureShare<C>, FrostError> {
+    keystore
+        .sign_with(key_handle, nonce_handle, |nonces, key_package| {
+            round2_sign_internal(signing_package, nonces, key_package, randomizer)
+        })
+        .map_err(|e| e.0)
+}
+
+/// JSON form of `round2_sign` (ROUND2_ERROR, or NONCE_REUSED).
+pub fn generate_round2_signature<C: RandomizedCiphersuite>(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<SignatureShare, FrostError> {
+    generate_round2_internal::<C>(
+        key_package_json,
+        nonces_json,
+        signing_package_json,
+        randomizer_json,
+    )
+    .map_err(|e| e.0)
+}
+
+fn generate_round2_internal<C: RandomizedCiphersuite>(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<SignatureShare, Round2Error> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    let nonces = parse_signing_nonces::<C>(nonces_json)?;
+
+    let (signing_package, randomizer) =
+        parse_randomized_signing_package::<C>(signing_package_json, randomizer_json)?;
+
+    let signature_share =
+        round2_sign_internal(&signing_package, &nonces, &key_package, &randomizer)?;
+
+    Ok(signature_share_result(
+        key_package.identifier(),
+        &signature_share,
+    )?)
+}
+
+/// JSON form of `sign_from_pool`; the returned pool drops the used nonces and
+/// any left spent by an earlier failed attempt (ROUND2_ERROR, or NONCE_REUSED).
+pub fn generate_round2_signature_from_pool<C: RandomizedCiphersuite>(
+    key_package_json: &str,
+    nonce_pool_json: &str,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<PoolSignatureResult, FrostError> {
+    generate_round2_from_pool_internal::<C>(
+        key_package_json,
+        nonce_pool_json,
+        signing_package_json,
+        randomizer_json,
+    )
+    .map_err(|e| e.0)
+}
+
+fn generate_round2_from_pool_internal<C: RandomizedCiphersuite>(
+    key_package_json: &str,
+    nonce_pool_json: &str,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<PoolSignatureResult, Round2Error> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+    let mut pool: NoncePool = serde_json::from_str(nonce_pool_json)
+        .map_err(|e| format!("Invalid nonce pool JSON: {}", e))?;
+    let nonces = parse_nonce_pool::<C>(&pool)?;
+
+    let (signing_package, randomizer) =
+        parse_randomized_signing_package::<C>(signing_package_json, randomizer_json)?;
+    let index = pooled_nonces_index(&nonces, &signing_package, &key_package)?;
+
+    let signature_share =
+        round2_sign_internal(&signing_package, &nonces[index], &key_package, &randomizer)?;
+    retain_unused_nonces(&mut pool, &nonces)?;
+
+    Ok(PoolSignatureResult {
+        signature_share: signature_share_result(key_package.identifier(), &signature_share)?,
+        nonces: pool,
+    })
+}
+
+/// JSON form of `sign_with_handles` (ROUND2_ERROR, or NONCE_REUSED).
+/// The nonce handle is consumed either way.
+pub fn generate_round2_signature_with_handles<C: RandomizedCiphersuite>(
+    keystore: &mut Keystore<C>,
+    key_handle: u32,
+    nonce_handle: u32,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<SignatureShare, FrostError> {
+    parse_randomized_signing_package::<C>(signing_package_json, randomizer_json)
+        .map_err(Round2Error::from)
+        .and_then(|(signing_package, randomizer)| {
+            keystore.sign_with(key_handle, nonce_handle, |nonces, key_package| {
+                let signature_share =
+                    round2_sign_internal(&signing_package, nonces, key_package, &randomizer)?;
+                Ok(signature_share_result(
+                    key_package.identifier(),
+                    &signature_share,
+                )?)
+            })
+        })
+        .map_err(|e| e.0)
+}
+
+// =============================================================================
+// Signature Aggregation
+// =============================================================================
+
+/// Aggregate rerandomized signature shares (AGGREGATE_ERROR with culprits).
+pub fn aggregate<C: RandomizedCiphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    shares: &SignatureShares<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    randomizer: &SigningRandomizer<C>,
+) -> Result<frost::Signature<C>, AggregateError> {
+    let randomizer = randomizer.randomizer(signing_package)?;
+    let randomized_pubkey_package = randomize_public_key_package(pubkey_package, randomizer)?;
+
+    // Same as frost_rerandomized::aggregate, keeping the randomized package
+    // around so invalid shares are checked against randomized verifying shares
+    frost::aggregate(signing_package, shares, &randomized_pubkey_package).map_err(|e| {
+        match find_culprits(signing_package, shares, &randomized_pubkey_package) {
+            Ok(culprits) => aggregate_error(e, culprits),
+            Err(message) => message.into(),
+        }
+    })
+}
+
+/// JSON form of `aggregate`; the randomizer (derived from the seed on the
+/// fresh path) is returned alongside the signature for verification.
+pub fn aggregate_signature<C: RandomizedCiphersuite>(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    randomizer_json: &str,
+) -> Result<AggregateResult, AggregateError> {
+    let (signing_package, randomizer) =
+        parse_randomized_signing_package::<C>(signing_package_json, randomizer_json)?;
+    let shares = parse_signature_shares::<C>(shares_json)?;
+    let pubkey_package = parse_public_key_package::<C>(public_key_package_json)?;
+
+    let signature = aggregate(&signing_package, &shares, &pubkey_package, &randomizer)?;
+
+    let randomizer_json = serde_json::to_string(&randomizer.randomizer(&signing_package)?)
+        .map_err(|e| format!("Failed to serialize randomizer: {:?}", e))?;
+
+    Ok(AggregateResult {
+        signature: signature_hex(&signature)?,
+        randomizer: randomizer_json,
+    })
+}
+
+/// Check each rerandomized signature share without aggregating (VERIFY_ERROR).
+pub fn verify_shares<C: RandomizedCiphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    shares: &SignatureShares<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    randomizer: &SigningRandomizer<C>,
+) -> Result<ShareVerificationResult, FrostError> {
+    verify_shares_internal(signing_package, shares, pubkey_package, randomizer)
+        .map_err(|e| FrostError::new("VERIFY_ERROR", e))
+}
+
+fn verify_shares_internal<C: RandomizedCiphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    shares: &SignatureShares<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    randomizer: &SigningRandomizer<C>,
+) -> Result<ShareVerificationResult, String> {
+    let randomizer = randomizer.randomizer(signing_package)?;
+    let randomized_pubkey_package = randomize_public_key_package(pubkey_package, randomizer)?;
+
+    let culprits = find_culprits(signing_package, shares, &randomized_pubkey_package)?;
+
+    Ok(ShareVerificationResult {
+        valid: culprits.is_empty(),
+        culprits,
+    })
+}
+
+/// JSON form of `verify_shares` (VERIFY_ERROR).
+pub fn verify_signature_shares<C: RandomizedCiphersuite>(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    randomizer_json: &str,
+) -> Result<ShareVerificationResult, FrostError> {
+    verify_signature_shares_internal::<C>(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+        randomizer_json,
+    )
+    .map_err(|e| FrostError::new("VERIFY_ERROR", e))
+}
+
+fn verify_signature_shares_internal<C: RandomizedCiphersuite>(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    randomizer_json: &str,
+) -> Result<ShareVerificationResult, String> {
+    let (signing_package, randomizer) =
+        parse_randomized_signing_package::<C>(signing_package_json, randomizer_json)?;
+    let shares = parse_signature_shares::<C>(shares_json)?;
+    let pubkey_package = parse_public_key_package::<C>(public_key_package_json)?;
+
+    verify_shares_internal(&signing_package, &shares, &pubkey_package, &randomizer)
+}
+
+// =============================================================================
+// Verification
+// =============================================================================
+
+/// Verify a rerandomized signature against the randomized group key
+pub fn verify<C: RandomizedCiphersuite>(
+    signature: &frost::Signature<C>,
+    message: &[u8],
+    verifying_key: &frost::VerifyingKey<C>,
+    randomizer: Randomizer<C>,
+) -> bool {
+    let randomized_params = RandomizedParams::from_randomizer(verifying_key, randomizer);
+
+    randomized_params
+        .randomized_verifying_key()
+        .verify(message, signature)
+        .is_ok()
+}
+
+/// JSON form of `verify` (VERIFY_ERROR).
+pub fn verify_signature<C: RandomizedCiphersuite>(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+    randomizer_json: &str,
+) -> Result<VerifyResult, FrostError> {
+    verify_internal::<C>(
+        signature_hex,
+        message_hex,
+        group_public_key_hex,
+        randomizer_json,
+    )
+    .map(|valid| VerifyResult { valid })
+    .map_err(|e| FrostError::new("VERIFY_ERROR", e))
+}
+
+fn verify_internal<C: RandomizedCiphersuite>(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+    randomizer_json: &str,
+) -> Result<bool, String> {
+    let signature = parse_signature::<C>(signature_hex)?;
+
+    let message = hex::decode(message_hex).map_err(|e| format!("Invalid message hex: {}", e))?;
+
+    let verifying_key = parse_verifying_key::<C>(group_public_key_hex)?;
+
+    let randomizer = match serde_json::from_str(randomizer_json)
+        .map_err(|e| format!("Invalid randomizer JSON: {}", e))?
+    {
+        SigningRandomizer::<C>::Randomizer(randomizer) => randomizer,
+        SigningRandomizer::Seed { .. } => {
+            return Err(
+                "A randomizer seed needs the signing package; pass the randomizer returned by aggregation"
+                    .into(),
+            )
+        }
+    };
+
+    Ok(verify(&signature, &message, &verifying_key, randomizer))
+}
+
+/// Parse a serialized SigningPackage and its randomizer (or randomizer seed)
+pub fn parse_randomized_signing_package<C: RandomizedCiphersuite>(
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<(frost::SigningPackage<C>, SigningRandomizer<C>), String> {
+    let signing_package: frost::SigningPackage<C> = serde_json::from_str(signing_package_json)
+        .map_err(|e| format!("Invalid signing package JSON: {}", e))?;
+
+    let randomizer: SigningRandomizer<C> = serde_json::from_str(randomizer_json)
+        .map_err(|e| format!("Invalid randomizer JSON: {}", e))?;
+
+    Ok((signing_package, randomizer))
+}
+
+/// Shift the group key and every verifying share by the randomizer, as
+/// `frost_rerandomized::aggregate` does internally
+fn randomize_public_key_package<C: RandomizedCiphersuite>(
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    randomizer: Randomizer<C>,
+) -> Result<frost::keys::PublicKeyPackage<C>, String> {
+    let randomized_params =
+        RandomizedParams::from_randomizer(pubkey_package.verifying_key(), randomizer);
+    let randomized_key = *randomized_params.randomized_verifying_key();
+
+    // alpha*G, the same offset for the group key and each verifying share
+    let offset = randomized_key.to_element() - pubkey_package.verifying_key().to_element();
+
+    let verifying_shares = pubkey_package
+        .verifying_shares()
+        .iter()
+        .map(|(id, share)| {
+            verifying_share_from_element::<C>(&(share.to_element() + offset))
+                .map(|share| (*id, share))
+        })
+        .collect::<Result<BTreeMap<_, _>, String>>()?;
+
+    Ok(frost::keys::PublicKeyPackage::new(
+        verifying_shares,
+        randomized_key,
+        pubkey_package.min_signers(),
+    ))
+}
+
+pub(crate) fn parse_public_key_package<C: RandomizedCiphersuite>(
+    public_key_package_json: &str,
+) -> Result<frost::keys::PublicKeyPackage<C>, String> {
+    serde_json::from_str(public_key_package_json)
+        .map_err(|e| format!("Invalid public key package JSON: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::generate_key_shares;
+    use crate::pool::{generate_commitment_pool, select_pool_commitments};
+    use crate::signing::generate_round1_commitment;
+    use crate::signing::tests::MESSAGE;
+    use crate::{Commitment, CommitmentPool, KeyGenResult, Round1Result};
+    use frost_ed25519::Ed25519Sha512;
+    use frost_secp256k1_tr::Secp256K1Sha256TR;
+
+    /// Round 1 for the given signers, returning their results and the
+    /// commitments as JSON
+    fn round1<C: RandomizedCiphersuite>(key_packages: &[&str]) -> (Vec<Round1Result>, String) {
+        let round1: Vec<Round1Result> = key_packages
+            .iter()
+            .map(|kp| generate_round1_commitment::<C>(kp).expect("Round 1 failed"))
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        (round1, serde_json::to_string(&commitments).unwrap())
+    }
+
+    fn signing_flow<C: RandomizedCiphersuite>() {
+        let keygen: KeyGenResult = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+        let key_packages = [&keygen.shares[0].key_package, &keygen.shares[2].key_package];
+        let (round1, commitments_json) = round1::<C>(&key_packages.map(String::as_str));
+
+        let package =
+            create_signing_package::<C>(&commitments_json, MESSAGE, &keygen.public_key_package)
+                .expect("Signing package should build");
+
+        // Signers get the seed, not the randomizer derived from it
+        let randomizer: SigningRandomizer<C> = serde_json::from_str(&package.randomizer).unwrap();
+        assert!(matches!(randomizer, SigningRandomizer::Seed { .. }));
+
+        let shares: Vec<SignatureShare> = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(kp, r)| {
+                generate_round2_signature::<C>(
+                    kp,
+                    &serde_json::to_string(&r.nonces).unwrap(),
+                    &package.signing_package,
+                    &package.randomizer,
+                )
+                .expect("Round 2 failed")
+            })
+            .collect();
+
+        let signature = aggregate_signature::<C>(
+            &serde_json::to_string(&shares).unwrap(),
+            &package.signing_package,
+            &keygen.public_key_package,
+            &package.randomizer,
+        )
+        .expect("Aggregation failed");
+
+        let verify = verify_signature::<C>(
+            &signature.signature,
+            MESSAGE,
+            &keygen.group_public_key,
+            &signature.randomizer,
+        )
+        .expect("Verification failed");
+        assert!(verify.valid);
+
+        let verify = verify_signature::<C>(
+            &signature.signature,
+            "00",
+            &keygen.group_public_key,
+            &signature.randomizer,
+        )
+        .expect("Verification failed");
+        assert!(!verify.valid);
+
+        // Verification needs the derived randomizer, not the seed
+        let Err(error) = verify_signature::<C>(
+            &signature.signature,
+            MESSAGE,
+            &keygen.group_public_key,
+            &package.randomizer,
+        ) else {
+            panic!("Verifying against a seed should fail");
+        };
+        assert_eq!(error.code, "VERIFY_ERROR");
+
+        // A randomizer chosen outside the ceremony is shipped and signed with as is
+        let randomizer_hex: String = serde_json::from_str(&signature.randomizer).unwrap();
+        let (round1_again, commitments_json) = self::round1::<C>(&key_packages.map(String::as_str));
+        let package = create_signing_package_with_randomizer::<C>(
+            &commitments_json,
+            MESSAGE,
+            &keygen.public_key_package,
+            &randomizer_hex,
+            "",
+        )
+        .expect("Signing package should build");
+        assert_eq!(package.randomizer, signature.randomizer);
+
+        let shares: Vec<SignatureShare> = key_packages
+            .iter()
+            .zip(&round1_again)
+            .map(|(kp, r)| {
+                generate_round2_signature::<C>(
+                    kp,
+                    &serde_json::to_string(&r.nonces).unwrap(),
+                    &package.signing_package,
+                    &package.randomizer,
+                )
+                .expect("Round 2 failed")
+            })
+            .collect();
+        let signature = aggregate_signature::<C>(
+            &serde_json::to_string(&shares).unwrap(),
+            &package.signing_package,
+            &keygen.public_key_package,
+            &package.randomizer,
+        )
+        .expect("Aggregation failed");
+        assert_eq!(signature.randomizer, package.randomizer);
+
+        let verify = verify_signature::<C>(
+            &signature.signature,
+            MESSAGE,
+            &keygen.group_public_key,
+            &signature.randomizer,
+        )
+        .expect("Verification failed");
+        assert!(verify.valid);
+    }
+
+    fn rejects_nonce_reuse<C: RandomizedCiphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+        let key_packages = [&keygen.shares[0].key_package, &keygen.shares[1].key_package];
+        let (round1, commitments_json) = round1::<C>(&key_packages.map(String::as_str));
+        let nonces = serde_json::to_string(&round1[0].nonces).unwrap();
+
+        let packages: Vec<SigningPackageResult> = [MESSAGE, "6261642062616420626164"]
+            .iter()
+            .map(|message| {
+                create_signing_package::<C>(&commitments_json, message, &keygen.public_key_package)
+                    .expect("Signing package should build")
+            })
+            .collect();
+
+        generate_round2_signature::<C>(
+            key_packages[0],
+            &nonces,
+            &packages[0].signing_package,
+            &packages[0].randomizer,
+        )
+        .expect("First use should succeed");
+
+        // Signing a second message with the same nonces would leak the share
+        let Err(error) = generate_round2_signature::<C>(
+            key_packages[0],
+            &nonces,
+            &packages[1].signing_package,
+            &packages[1].randomizer,
+        ) else {
+            panic!("Second use should fail");
+        };
+        assert_eq!(error.code, "NONCE_REUSED");
+    }
+
+    fn single_round_signing_from_pools<C: RandomizedCiphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+        let signers = &keygen.shares[..2];
+
+        let mut nonce_pools: Vec<String> = Vec::new();
+        let mut pools: Vec<CommitmentPool> = Vec::new();
+        for share in signers {
+            let result = generate_commitment_pool::<C>(&share.key_package, 2)
+                .expect("Preprocessing should succeed");
+            nonce_pools.push(serde_json::to_string(&result.nonces).unwrap());
+            pools.push(result.commitments);
+        }
+
+        for message in [MESSAGE, "6261642062616420626164"] {
+            let selection =
+                select_pool_commitments(&serde_json::to_string(&pools).unwrap(), "[1, 2]")
+                    .expect("Selection should succeed");
+            pools = selection.pools;
+
+            let package = create_signing_package::<C>(
+                &serde_json::to_string(&selection.commitments).unwrap(),
+                message,
+                &keygen.public_key_package,
+            )
+            .expect("Signing package should build");
+
+            let mut shares = Vec::new();
+            for (share, nonce_pool) in signers.iter().zip(nonce_pools.iter_mut()) {
+                let result = generate_round2_signature_from_pool::<C>(
+                    &share.key_package,
+                    nonce_pool,
+                    &package.signing_package,
+                    &package.randomizer,
+                )
+                .expect("Signing from pool should succeed");
+                *nonce_pool = serde_json::to_string(&result.nonces).unwrap();
+                shares.push(result.signature_share);
+            }
+
+            aggregate_signature::<C>(
+                &serde_json::to_string(&shares).unwrap(),
+                &package.signing_package,
+                &keygen.public_key_package,
+                &package.randomizer,
+            )
+            .expect("Should aggregate");
+        }
+    }
+
+    #[test]
+    fn test_signing_flow() {
+        signing_flow::<Ed25519Sha512>();
+        signing_flow::<Secp256K1Sha256TR>();
+    }
+
+    #[test]
+    fn test_rejects_nonce_reuse() {
+        rejects_nonce_reuse::<Ed25519Sha512>();
+        rejects_nonce_reuse::<Secp256K1Sha256TR>();
+    }
+
+    #[test]
+    fn test_single_round_signing_from_pools() {
+        single_round_signing_from_pools::<Ed25519Sha512>();
+        single_round_signing_from_pools::<Secp256K1Sha256TR>();
+    }
+}
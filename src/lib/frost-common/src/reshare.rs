@@ -0,0 +1,588 @@
+//! Resharing: move the group key to a new participant set and threshold
+//!
+//! Resharing does not revoke anything: the old key shares stay valid under
+//! the old PublicKeyPackage, so a removed participant who kept theirs can
+//! still sign with any `min_signers` old holders. Old holders must delete
+//! their previous key packages once the new ones are in place.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use frost_core::{self as frost, Ciphersuite, Field, Group};
+use rand::rngs::OsRng;
+
+use crate::codec::{
+    check_threshold, element_from_bytes, identifier_from_u16, identifier_to_u16,
+    lagrange_coefficient, parse_identifier_list, signing_share_from_scalar,
+    verifying_share_from_element,
+};
+use crate::dkg::dkg_result;
+use crate::{DkgResult, FrostError, ReshareCommitment, ReshareRound1Result, ReshareSubShare};
+
+/// A dealer's VSS commitment and its sub-shares, keyed by recipient
+pub type DealtSubShares<C> = (
+    frost::keys::VerifiableSecretSharingCommitment<C>,
+    BTreeMap<frost::Identifier<C>, frost::keys::SecretShare<C>>,
+);
+
+/// Reshare part 1 (current holder): deal this holder's Lagrange-weighted share
+/// to the new participant set (RESHARE_ERROR).
+pub fn deal_sub_shares<C: Ciphersuite>(
+    key_package: &frost::keys::KeyPackage<C>,
+    dealers: &[frost::Identifier<C>],
+    new_identifiers: &[frost::Identifier<C>],
+    new_min_signers: u16,
+) -> Result<DealtSubShares<C>, FrostError> {
+    deal_sub_shares_internal(key_package, dealers, new_identifiers, new_min_signers)
+        .map_err(|e| FrostError::new("RESHARE_ERROR", e))
+}
+
+fn deal_sub_shares_internal<C: Ciphersuite>(
+    key_package: &frost::keys::KeyPackage<C>,
+    dealers: &[frost::Identifier<C>],
+    new_identifiers: &[frost::Identifier<C>],
+    new_min_signers: u16,
+) -> Result<DealtSubShares<C>, String> {
+    if dealers.len() < *key_package.min_signers() as usize {
+        return Err(format!(
+            "Need at least {} dealers, got {}",
+            key_package.min_signers(),
+            dealers.len()
+        ));
+    }
+    if !dealers.contains(key_package.identifier()) {
+        return Err("This key package's identifier is not in the dealer set".into());
+    }
+    if dealers.iter().collect::<BTreeSet<_>>().len() != dealers.len() {
+        return Err("Duplicate identifier in the dealer set".into());
+    }
+    if new_identifiers.iter().collect::<BTreeSet<_>>().len() != new_identifiers.len() {
+        return Err("Duplicate identifier in the new participant set".into());
+    }
+
+    let new_max_signers = new_identifiers.len() as u16;
+    check_threshold(new_min_signers, new_max_signers)?;
+
+    // Lagrange-weighted shares of the dealer set sum to the group secret
+    let lambda = lagrange_coefficient(dealers, key_package.identifier())?;
+    let weighted_share = lambda * key_package.signing_share().to_scalar();
+    let signing_key = frost::SigningKey::from_scalar(weighted_share)
+        .map_err(|e| format!("Invalid weighted share: {:?}", e))?;
+
+    let mut rng = OsRng;
+    let (sub_shares, _) = frost::keys::split(
+        &signing_key,
+        new_max_signers,
+        new_min_signers,
+        frost::keys::IdentifierList::Custom(new_identifiers),
+        &mut rng,
+    )
+    .map_err(|e| format!("Resharing split failed: {:?}", e))?;
+
+    // Every sub-share carries the same VSS commitment; broadcast it once
+    let mut vss_commitment = sub_shares
+        .values()
+        .next()
+        .map(|sub_share| sub_share.commitment().clone())
+        .ok_or("Resharing split produced no sub-shares")?;
+
+    // Ciphersuites such as RedPallas negate a fresh sharing whose key has an
+    // odd Y coordinate; undo that so the sub-shares sum to the weighted share
+    let dealt = frost::VerifyingKey::from_commitment(&vss_commitment)
+        .map_err(|e| format!("Invalid commitment: {:?}", e))?;
+    if dealt.to_element() == <C::Group as Group>::generator() * weighted_share {
+        return Ok((vss_commitment, sub_shares));
+    }
+
+    vss_commitment = negate_commitment(&vss_commitment)?;
+
+    let mut negated = BTreeMap::new();
+    for (id, sub_share) in sub_shares {
+        let scalar =
+            <<C::Group as Group>::Field as Field>::zero() - sub_share.signing_share().to_scalar();
+        negated.insert(
+            id,
+            frost::keys::SecretShare::new(
+                id,
+                signing_share_from_scalar::<C>(&scalar)?,
+                vss_commitment.clone(),
+            ),
+        );
+    }
+
+    Ok((vss_commitment, negated))
+}
+
+/// Negate every coefficient commitment of a VSS commitment, matching a
+/// sharing whose shares have all been negated
+fn negate_commitment<C: Ciphersuite>(
+    commitment: &frost::keys::VerifiableSecretSharingCommitment<C>,
+) -> Result<frost::keys::VerifiableSecretSharingCommitment<C>, String> {
+    let coefficients = commitment
+        .serialize()
+        .map_err(|e| format!("Invalid commitment: {:?}", e))?
+        .iter()
+        .map(|bytes| {
+            let element = <C::Group as Group>::identity() - element_from_bytes::<C>(bytes)?;
+            <C::Group as Group>::serialize(&element)
+                .map(|bytes| bytes.as_ref().to_vec())
+                .map_err(|e| format!("Failed to serialize commitment: {:?}", e))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    frost::keys::VerifiableSecretSharingCommitment::deserialize(coefficients)
+        .map_err(|e| format!("Invalid commitment: {:?}", e))
+}
+
+/// Reshare part 2 (new participant): combine the dealers' sub-shares into a
+/// key package for the new participant set (RESHARE_ERROR).
+///
+/// `sub_shares` and `commitments` are keyed by dealer. Every dealer must
+/// commit to a degree `new_min_signers - 1` polynomial whose constant term
+/// matches its Lagrange-weighted verifying share in the old public key
+/// package. Each sub-share is verified against its dealer's commitment, the
+/// new verifying shares are derived from the summed commitments, and the new
+/// group key must equal the old one.
+pub fn combine_sub_shares<C: Ciphersuite>(
+    identifier: frost::Identifier<C>,
+    sub_shares: &BTreeMap<frost::Identifier<C>, frost::keys::SecretShare<C>>,
+    commitments: &BTreeMap<frost::Identifier<C>, frost::keys::VerifiableSecretSharingCommitment<C>>,
+    old_pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    new_identifiers: &[frost::Identifier<C>],
+    new_min_signers: u16,
+) -> Result<(frost::keys::KeyPackage<C>, frost::keys::PublicKeyPackage<C>), FrostError> {
+    combine_sub_shares_internal(
+        identifier,
+        sub_shares,
+        commitments,
+        old_pubkey_package,
+        new_identifiers,
+        new_min_signers,
+    )
+    .map_err(|e| FrostError::new("RESHARE_ERROR", e))
+}
+
+fn combine_sub_shares_internal<C: Ciphersuite>(
+    id: frost::Identifier<C>,
+    sub_shares: &BTreeMap<frost::Identifier<C>, frost::keys::SecretShare<C>>,
+    commitments: &BTreeMap<frost::Identifier<C>, frost::keys::VerifiableSecretSharingCommitment<C>>,
+    old_pubkey_package: &frost::keys::PublicKeyPackage<C>,
+    new_identifiers: &[frost::Identifier<C>],
+    new_min_signers: u16,
+) -> Result<(frost::keys::KeyPackage<C>, frost::keys::PublicKeyPackage<C>), String> {
+    let identifier = identifier_to_u16(&id)?;
+
+    let new_identifier_set: BTreeSet<_> = new_identifiers.iter().copied().collect();
+    if new_identifier_set.len() != new_identifiers.len() {
+        return Err("Duplicate identifier in the new participant set".into());
+    }
+    if !new_identifier_set.contains(&id) {
+        return Err("This participant is not in the new participant set".into());
+    }
+    check_threshold(new_min_signers, new_identifiers.len() as u16)?;
+
+    if commitments.is_empty() {
+        return Err("No commitments provided".into());
+    }
+    if let Some(stray) = sub_shares.keys().find(|d| !commitments.contains_key(d)) {
+        return Err(format!(
+            "Sub-share from unknown dealer {}",
+            identifier_to_u16(stray)?
+        ));
+    }
+
+    let dealers: Vec<_> = commitments.keys().copied().collect();
+
+    let mut signing_share = <<C::Group as Group>::Field as Field>::zero();
+    let mut group_element = <C::Group as Group>::identity();
+    let mut verifying_elements: BTreeMap<frost::Identifier<C>, frost::Element<C>> = BTreeMap::new();
+
+    for (dealer, vss_commitment) in commitments {
+        let dealer_id = identifier_to_u16(dealer)?;
+
+        let degree = vss_commitment
+            .serialize()
+            .map_err(|e| format!("Invalid commitment from {}: {:?}", dealer_id, e))?
+            .len();
+        if degree != new_min_signers as usize {
+            return Err(format!(
+                "Dealer {} committed to {} coefficients, expected {}",
+                dealer_id, degree, new_min_signers
+            ));
+        }
+
+        // The dealer must have dealt its own Lagrange-weighted share
+        let old_verifying_share = old_pubkey_package
+            .verifying_shares()
+            .get(dealer)
+            .ok_or_else(|| format!("Dealer {} is not in the public key package", dealer_id))?;
+        let expected = old_verifying_share.to_element() * lagrange_coefficient(&dealers, dealer)?;
+        let dealt = frost::VerifyingKey::from_commitment(vss_commitment)
+            .map_err(|e| format!("Invalid commitment from {}: {:?}", dealer_id, e))?;
+        if dealt.to_element() != expected {
+            return Err(format!(
+                "Dealer {} did not reshare its own key share",
+                dealer_id
+            ));
+        }
+
+        // One sub-share per dealer, verified against its commitment
+        let received = sub_shares
+            .get(dealer)
+            .ok_or_else(|| format!("Missing sub-share from dealer {}", dealer_id))?;
+        if *received.identifier() != id {
+            return Err(format!(
+                "Sub-share from {} is not for participant {}",
+                dealer_id, identifier
+            ));
+        }
+        frost::keys::SecretShare::new(id, *received.signing_share(), vss_commitment.clone())
+            .verify()
+            .map_err(|_| format!("Sub-share from {} does not match its commitment", dealer_id))?;
+
+        // Every new verifying share is the sum of the dealers' commitments
+        // evaluated at that participant
+        let sub_pubkey_package =
+            frost::keys::PublicKeyPackage::from_commitment(&new_identifier_set, vss_commitment)
+                .map_err(|e| format!("Invalid commitment from {}: {:?}", dealer_id, e))?;
+        for (recipient, verifying_share) in sub_pubkey_package.verifying_shares() {
+            let sum = verifying_elements
+                .entry(*recipient)
+                .or_insert_with(<C::Group as Group>::identity);
+            *sum = *sum + verifying_share.to_element();
+        }
+        group_element = group_element + dealt.to_element();
+
+        signing_share = signing_share + received.signing_share().to_scalar();
+    }
+
+    // Fewer than t dealers (or a wrong dealer set) yields a different key
+    let verifying_key = *old_pubkey_package.verifying_key();
+    if group_element != verifying_key.to_element() {
+        return Err("Resharing does not preserve the group key; need at least t dealers".into());
+    }
+
+    let mut verifying_shares = BTreeMap::new();
+    for (recipient, element) in verifying_elements {
+        verifying_shares.insert(recipient, verifying_share_from_element::<C>(&element)?);
+    }
+    let pubkey_package =
+        frost::keys::PublicKeyPackage::new(verifying_shares, verifying_key, Some(new_min_signers));
+
+    let verifying_share = *pubkey_package
+        .verifying_shares()
+        .get(&id)
+        .ok_or("This participant is not in the new participant set")?;
+
+    let key_package = frost::keys::KeyPackage::new(
+        id,
+        signing_share_from_scalar::<C>(&signing_share)?,
+        verifying_share,
+        verifying_key,
+        new_min_signers,
+    );
+
+    Ok((key_package, pubkey_package))
+}
+
+/// JSON form of `deal_sub_shares` (RESHARE_ERROR).
+pub fn reshare_part1<C: Ciphersuite>(
+    key_package_json: &str,
+    dealers_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> Result<ReshareRound1Result, FrostError> {
+    reshare_part1_internal::<C>(
+        key_package_json,
+        dealers_json,
+        new_identifiers_json,
+        new_min_signers,
+    )
+    .map_err(|e| FrostError::new("RESHARE_ERROR", e))
+}
+
+fn reshare_part1_internal<C: Ciphersuite>(
+    key_package_json: &str,
+    dealers_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> Result<ReshareRound1Result, String> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    let dealers = parse_identifier_list::<C>(dealers_json)?;
+    let new_identifiers = parse_identifier_list::<C>(new_identifiers_json)?;
+
+    let (vss_commitment, sub_shares) =
+        deal_sub_shares_internal(&key_package, &dealers, &new_identifiers, new_min_signers)?;
+
+    let dealer = identifier_to_u16(key_package.identifier())?;
+
+    let mut recipients = Vec::with_capacity(sub_shares.len());
+    let mut shares = Vec::with_capacity(sub_shares.len());
+    for (id, sub_share) in sub_shares {
+        recipients.push(identifier_to_u16(&id)?);
+        shares.push(ReshareSubShare {
+            dealer,
+            recipient: identifier_to_u16(&id)?,
+            sub_share: serde_json::to_string(&sub_share)
+                .map_err(|e| format!("Failed to serialize sub-share: {:?}", e))?,
+        });
+    }
+
+    let vss_commitment_json = serde_json::to_string(&vss_commitment)
+        .map_err(|e| format!("Failed to serialize commitment: {:?}", e))?;
+
+    Ok(ReshareRound1Result {
+        commitment: ReshareCommitment {
+            dealer,
+            recipients,
+            commitment: vss_commitment_json,
+        },
+        sub_shares: shares,
+    })
+}
+
+/// JSON form of `combine_sub_shares` (RESHARE_ERROR).
+///
+/// Additionally checks that every dealer's broadcast recipient list is exactly
+/// `new_identifiers`, and that each sub-share is addressed to `identifier`.
+pub fn reshare_part2<C: Ciphersuite>(
+    identifier: u16,
+    sub_shares_json: &str,
+    commitments_json: &str,
+    public_key_package_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> Result<DkgResult, FrostError> {
+    reshare_part2_internal::<C>(
+        identifier,
+        sub_shares_json,
+        commitments_json,
+        public_key_package_json,
+        new_identifiers_json,
+        new_min_signers,
+    )
+    .map_err(|e| FrostError::new("RESHARE_ERROR", e))
+}
+
+fn reshare_part2_internal<C: Ciphersuite>(
+    identifier: u16,
+    sub_shares_json: &str,
+    commitments_json: &str,
+    public_key_package_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> Result<DkgResult, String> {
+    let id = identifier_from_u16::<C>(identifier)?;
+
+    let old_pubkey_package: frost::keys::PublicKeyPackage<C> =
+        serde_json::from_str(public_key_package_json)
+            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+
+    let new_identifiers = parse_identifier_list::<C>(new_identifiers_json)?;
+    let new_identifier_set: BTreeSet<_> = new_identifiers.iter().copied().collect();
+
+    let sub_share_list: Vec<ReshareSubShare> = serde_json::from_str(sub_shares_json)
+        .map_err(|e| format!("Invalid sub-shares JSON: {}", e))?;
+    let commitment_list: Vec<ReshareCommitment> = serde_json::from_str(commitments_json)
+        .map_err(|e| format!("Invalid commitments JSON: {}", e))?;
+
+    let mut commitments = BTreeMap::new();
+    for commitment in &commitment_list {
+        let dealer = identifier_from_u16::<C>(commitment.dealer)
+            .map_err(|e| format!("Invalid dealer identifier {}: {}", commitment.dealer, e))?;
+
+        // The dealer must deal to exactly the agreed participant set
+        let recipients = commitment
+            .recipients
+            .iter()
+            .map(|r| identifier_from_u16::<C>(*r))
+            .collect::<Result<BTreeSet<_>, _>>()?;
+        if recipients.len() != commitment.recipients.len() || recipients != new_identifier_set {
+            return Err(format!(
+                "Dealer {} did not deal to exactly the new participant set",
+                commitment.dealer
+            ));
+        }
+
+        let vss_commitment: frost::keys::VerifiableSecretSharingCommitment<C> =
+            serde_json::from_str(&commitment.commitment)
+                .map_err(|e| format!("Invalid commitment from {}: {}", commitment.dealer, e))?;
+        if commitments.insert(dealer, vss_commitment).is_some() {
+            return Err("Duplicate dealer in commitments".into());
+        }
+    }
+
+    let mut sub_shares = BTreeMap::new();
+    for sub_share in &sub_share_list {
+        if sub_share.recipient != identifier {
+            return Err(format!(
+                "Sub-share from {} is addressed to {}, not {}",
+                sub_share.dealer, sub_share.recipient, identifier
+            ));
+        }
+        let received: frost::keys::SecretShare<C> = serde_json::from_str(&sub_share.sub_share)
+            .map_err(|e| format!("Invalid sub-share JSON: {}", e))?;
+        if sub_shares
+            .insert(identifier_from_u16::<C>(sub_share.dealer)?, received)
+            .is_some()
+        {
+            return Err(format!(
+                "More than one sub-share from dealer {}",
+                sub_share.dealer
+            ));
+        }
+    }
+
+    let (key_package, pubkey_package) = combine_sub_shares_internal(
+        id,
+        &sub_shares,
+        &commitments,
+        &old_pubkey_package,
+        &new_identifiers,
+        new_min_signers,
+    )?;
+
+    dkg_result(&key_package, &pubkey_package)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::generate_key_shares;
+    use crate::signing::tests::{sign_with, MESSAGE};
+    use crate::signing::verify_signature;
+    use crate::KeyShare;
+    use frost_ed25519::Ed25519Sha512;
+    use frost_secp256k1_tr::Secp256K1Sha256TR;
+
+    fn reshare_to_new_threshold<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+
+        // Holders 1 and 3 move the key from 2-of-3 to 3-of-4, dropping participant 2
+        let new_ids: [u16; 4] = [1, 3, 4, 5];
+        let round1: Vec<ReshareRound1Result> = [&keygen.shares[0], &keygen.shares[2]]
+            .iter()
+            .map(|s| {
+                reshare_part1::<C>(&s.key_package, "[1, 3]", "[1, 3, 4, 5]", 3)
+                    .expect("Reshare part 1 should succeed")
+            })
+            .collect();
+        let commitments: Vec<ReshareCommitment> =
+            round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let results: Vec<DkgResult> = new_ids
+            .iter()
+            .map(|&id| {
+                let inbox: Vec<ReshareSubShare> = round1
+                    .iter()
+                    .flat_map(|r| r.sub_shares.iter())
+                    .filter(|s| s.recipient == id)
+                    .cloned()
+                    .collect();
+                reshare_part2::<C>(
+                    id,
+                    &serde_json::to_string(&inbox).unwrap(),
+                    &commitments_json,
+                    &keygen.public_key_package,
+                    "[1, 3, 4, 5]",
+                    3,
+                )
+                .expect("Reshare part 2 should succeed")
+            })
+            .collect();
+        assert!(results
+            .iter()
+            .all(|r| r.group_public_key == keygen.group_public_key));
+
+        let signature = sign_with::<C>(
+            &[
+                &results[1].key_package,
+                &results[2].key_package,
+                &results[3].key_package,
+            ],
+            &results[0].public_key_package,
+        )
+        .expect("New participants should sign");
+        let verify = verify_signature::<C>(&signature.signature, MESSAGE, &keygen.group_public_key)
+            .expect("Verification failed");
+        assert!(verify.valid);
+
+        // A single dealer cannot reshare a 2-of-3 key
+        let Err(error) =
+            reshare_part1::<C>(&keygen.shares[0].key_package, "[1]", "[1, 3, 4, 5]", 3)
+        else {
+            panic!("Too few dealers should fail");
+        };
+        assert_eq!(error.code, "RESHARE_ERROR");
+    }
+
+    fn reshare_rejects_dishonest_dealer<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+
+        let deal = |share: &KeyShare, new_ids: &str| -> ReshareRound1Result {
+            reshare_part1::<C>(&share.key_package, "[1, 3]", new_ids, 3)
+                .expect("Reshare part 1 should succeed")
+        };
+        let combine = |id: u16, round1: &[ReshareRound1Result]| -> FrostError {
+            let inbox: Vec<ReshareSubShare> = round1
+                .iter()
+                .flat_map(|r| r.sub_shares.iter())
+                .filter(|s| s.recipient == id)
+                .cloned()
+                .collect();
+            let commitments: Vec<ReshareCommitment> =
+                round1.iter().map(|r| r.commitment.clone()).collect();
+            let Err(error) = reshare_part2::<C>(
+                id,
+                &serde_json::to_string(&inbox).unwrap(),
+                &serde_json::to_string(&commitments).unwrap(),
+                &keygen.public_key_package,
+                "[1, 3, 4, 5]",
+                3,
+            ) else {
+                panic!("Dishonest dealer should be rejected");
+            };
+            error
+        };
+
+        // Dealer 3 sends participant 4 a share that does not match its commitment
+        let honest = deal(&keygen.shares[0], "[1, 3, 4, 5]");
+        let mut cheating = deal(&keygen.shares[2], "[1, 3, 4, 5]");
+        let other: serde_json::Value =
+            serde_json::from_str(&cheating.sub_shares[3].sub_share).unwrap();
+        let mut forged: serde_json::Value =
+            serde_json::from_str(&cheating.sub_shares[2].sub_share).unwrap();
+        forged["signing_share"] = other["signing_share"].clone();
+        cheating.sub_shares[2].sub_share = forged.to_string();
+        let error = combine(4, &[honest.clone(), cheating]);
+        assert_eq!(error.code, "RESHARE_ERROR");
+        assert!(error.message.contains("does not match its commitment"));
+
+        // Dealer 3 leaves participant 5 out of its sharing
+        let partial = deal(&keygen.shares[2], "[1, 3, 4]");
+        let error = combine(1, &[honest.clone(), partial]);
+        assert_eq!(error.code, "RESHARE_ERROR");
+        assert!(error.message.contains("new participant set"));
+
+        // Dealer 3 claims the full set but shares with a lower threshold
+        let mut low =
+            reshare_part1::<C>(&keygen.shares[2].key_package, "[1, 3]", "[1, 3, 4, 5]", 2).unwrap();
+        low.commitment.recipients = vec![1, 3, 4, 5];
+        let error = combine(1, &[honest, low]);
+        assert_eq!(error.code, "RESHARE_ERROR");
+        assert!(error.message.contains("coefficients"));
+    }
+
+    #[test]
+    fn test_reshare_to_new_threshold() {
+        reshare_to_new_threshold::<Ed25519Sha512>();
+        reshare_to_new_threshold::<Secp256K1Sha256TR>();
+    }
+
+    #[test]
+    fn test_reshare_rejects_dishonest_dealer() {
+        reshare_rejects_dishonest_dealer::<Ed25519Sha512>();
+        reshare_rejects_dishonest_dealer::<Secp256K1Sha256TR>();
+    }
+}
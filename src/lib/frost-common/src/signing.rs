@@ -0,0 +1,589 @@
+//! Two-round signing, aggregation and verification
+
+use std::collections::BTreeMap;
+
+use frost_core::{self as frost, Ciphersuite};
+use rand::rngs::OsRng;
+
+use crate::codec::{identifier_from_u16, identifier_to_u16};
+use crate::error::Round2Error;
+use crate::nonces::claim_nonces;
+use crate::{
+    AggregateError, AggregateSignature, Commitment, FrostError, Round1Result,
+    ShareVerificationResult, SignatureShare, SigningNonces, SigningPackage, VerifyResult,
+};
+
+/// Signature shares, keyed by signer
+pub type SignatureShares<C> = BTreeMap<frost::Identifier<C>, frost::round2::SignatureShare<C>>;
+
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
+
+/// Generate nonces and the matching public commitments
+pub fn round1_commit<C: Ciphersuite>(
+    key_package: &frost::keys::KeyPackage<C>,
+) -> (
+    frost::round1::SigningNonces<C>,
+    frost::round1::SigningCommitments<C>,
+) {
+    let mut rng = OsRng;
+
+    frost::round1::commit(key_package.signing_share(), &mut rng)
+}
+
+/// Generate Round 1 nonces and the commitment to publish (ROUND1_ERROR).
+pub fn generate_round1_commitment<C: Ciphersuite>(
+    key_package_json: &str,
+) -> Result<Round1Result, FrostError> {
+    generate_round1_internal::<C>(key_package_json).map_err(|e| FrostError::new("ROUND1_ERROR", e))
+}
+
+fn generate_round1_internal<C: Ciphersuite>(
+    key_package_json: &str,
+) -> Result<Round1Result, String> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    let (nonces, commitments) = round1_commit(&key_package);
+
+    let nonces_json = serde_json::to_string(&nonces)
+        .map_err(|e| format!("Failed to serialize nonces: {:?}", e))?;
+
+    Ok(Round1Result {
+        nonces: SigningNonces {
+            identifier: identifier_to_u16(key_package.identifier())?,
+            nonces: nonces_json,
+        },
+        commitment: commitment_result(key_package.identifier(), &commitments)?,
+    })
+}
+
+/// Serialize a signer's commitments as the Commitment to publish
+pub fn commitment_result<C: Ciphersuite>(
+    identifier: &frost::Identifier<C>,
+    commitments: &frost::round1::SigningCommitments<C>,
+) -> Result<Commitment, String> {
+    let commitments_json = serde_json::to_string(commitments)
+        .map_err(|e| format!("Failed to serialize commitments: {:?}", e))?;
+
+    Ok(Commitment {
+        identifier: identifier_to_u16(identifier)?,
+        commitment: commitments_json,
+    })
+}
+
+// =============================================================================
+// Signing Package Creation
+// =============================================================================
+
+/// Build the signing package from the collected commitments (SIGNING_PACKAGE_ERROR).
+pub fn create_signing_package<C: Ciphersuite>(
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SigningPackage, FrostError> {
+    create_signing_package_internal::<C>(commitments_json, message_hex)
+        .map_err(|e| FrostError::new("SIGNING_PACKAGE_ERROR", e))
+}
+
+fn create_signing_package_internal<C: Ciphersuite>(
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SigningPackage, String> {
+    let signing_package = parse_signing_package::<C>(commitments_json, message_hex)?;
+
+    let signing_package_json = serde_json::to_string(&signing_package)
+        .map_err(|e| format!("Failed to serialize signing package: {:?}", e))?;
+
+    Ok(SigningPackage {
+        signing_package: signing_package_json,
+    })
+}
+
+// =============================================================================
+// Round 2: Signature Share Generation
+// =============================================================================
+
+/// Produce this participant's signature share (ROUND2_ERROR, or NONCE_REUSED
+/// if the nonces already signed).
+pub fn round2_sign<C: Ciphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    nonces: &frost::round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<frost::round2::SignatureShare<C>, FrostError> {
+    round2_sign_internal(signing_package, nonces, key_package).map_err(|e| e.0)
+}
+
+pub(crate) fn round2_sign_internal<C: Ciphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    nonces: &frost::round1::SigningNonces<C>,
+    key_package: &frost::keys::KeyPackage<C>,
+) -> Result<frost::round2::SignatureShare<C>, Round2Error> {
+    claim_nonces(nonces)?;
+
+    let signature_share = frost::round2::sign(signing_package, nonces, key_package)
+        .map_err(|e| format!("Signing failed: {:?}", e))?;
+
+    Ok(signature_share)
+}
+
+/// Generate this participant's Round 2 signature share (ROUND2_ERROR, or
+/// NONCE_REUSED if the nonces already signed).
+pub fn generate_round2_signature<C: Ciphersuite>(
+    key_package_json: &str,
+    nonces_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SignatureShare, FrostError> {
+    generate_round2_internal::<C>(key_package_json, nonces_json, commitments_json, message_hex)
+        .map_err(|e| e.0)
+}
+
+fn generate_round2_internal<C: Ciphersuite>(
+    key_package_json: &str,
+    nonces_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SignatureShare, Round2Error> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    let nonces = parse_signing_nonces::<C>(nonces_json)?;
+
+    let signing_package = parse_signing_package(commitments_json, message_hex)?;
+
+    let signature_share = round2_sign_internal(&signing_package, &nonces, &key_package)?;
+
+    Ok(signature_share_result(
+        key_package.identifier(),
+        &signature_share,
+    )?)
+}
+
+/// Generate a signature share over the coordinator's serialized SigningPackage
+/// (ROUND2_ERROR, or NONCE_REUSED).
+///
+/// Every signer signs the exact package the coordinator built, rather than
+/// rebuilding it from the commitments and message.
+pub fn generate_round2_signature_with_package<C: Ciphersuite>(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+) -> Result<SignatureShare, FrostError> {
+    generate_round2_with_package_internal::<C>(key_package_json, nonces_json, signing_package_json)
+        .map_err(|e| e.0)
+}
+
+fn generate_round2_with_package_internal<C: Ciphersuite>(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+) -> Result<SignatureShare, Round2Error> {
+    let key_package: frost::keys::KeyPackage<C> = serde_json::from_str(key_package_json)
+        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+
+    let nonces = parse_signing_nonces::<C>(nonces_json)?;
+
+    let signing_package: frost::SigningPackage<C> = serde_json::from_str(signing_package_json)
+        .map_err(|e| format!("Invalid signing package JSON: {}", e))?;
+
+    let signature_share = round2_sign_internal(&signing_package, &nonces, &key_package)?;
+
+    Ok(signature_share_result(
+        key_package.identifier(),
+        &signature_share,
+    )?)
+}
+
+/// Parse the SigningNonces wrapper returned by Round 1
+pub fn parse_signing_nonces<C: Ciphersuite>(
+    nonces_json: &str,
+) -> Result<frost::round1::SigningNonces<C>, String> {
+    let wrapper: SigningNonces = serde_json::from_str(nonces_json)
+        .map_err(|e| format!("Invalid nonces wrapper JSON: {}", e))?;
+    serde_json::from_str(&wrapper.nonces).map_err(|e| format!("Invalid nonces JSON: {}", e))
+}
+
+/// Parse a JSON array of Commitment objects, keyed by signer
+pub fn parse_commitments<C: Ciphersuite>(
+    commitments_json: &str,
+) -> Result<BTreeMap<frost::Identifier<C>, frost::round1::SigningCommitments<C>>, String> {
+    let commitments_list: Vec<Commitment> = serde_json::from_str(commitments_json)
+        .map_err(|e| format!("Invalid commitments JSON: {}", e))?;
+
+    let mut signing_commitments = BTreeMap::new();
+    for c in &commitments_list {
+        let commitment: frost::round1::SigningCommitments<C> = serde_json::from_str(&c.commitment)
+            .map_err(|e| format!("Invalid commitment JSON: {}", e))?;
+
+        signing_commitments.insert(identifier_from_u16(c.identifier)?, commitment);
+    }
+
+    Ok(signing_commitments)
+}
+
+/// Build the SigningPackage from Commitment objects and a hex message
+pub fn parse_signing_package<C: Ciphersuite>(
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<frost::SigningPackage<C>, String> {
+    let signing_commitments = parse_commitments(commitments_json)?;
+
+    let message = hex::decode(message_hex).map_err(|e| format!("Invalid message hex: {}", e))?;
+
+    Ok(frost::SigningPackage::new(signing_commitments, &message))
+}
+
+/// Serialize a signer's signature share
+pub fn signature_share_result<C: Ciphersuite>(
+    identifier: &frost::Identifier<C>,
+    signature_share: &frost::round2::SignatureShare<C>,
+) -> Result<SignatureShare, String> {
+    let share_json = serde_json::to_string(signature_share)
+        .map_err(|e| format!("Failed to serialize signature share: {:?}", e))?;
+
+    Ok(SignatureShare {
+        identifier: identifier_to_u16(identifier)?,
+        share: share_json,
+    })
+}
+
+// =============================================================================
+// Signature Aggregation
+// =============================================================================
+
+/// Aggregate signature shares into the final signature.
+///
+/// If a share is invalid, the AggregateError's `culprits` names who sent it.
+pub fn aggregate<C: Ciphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    shares: &SignatureShares<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<frost::Signature<C>, AggregateError> {
+    frost::aggregate(signing_package, shares, pubkey_package).map_err(|e| {
+        match find_culprits(signing_package, shares, pubkey_package) {
+            Ok(culprits) => aggregate_error(e, culprits),
+            Err(message) => message.into(),
+        }
+    })
+}
+
+/// JSON form of `aggregate`, rebuilding the signing package from the commitments.
+pub fn aggregate_signature<C: Ciphersuite>(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<AggregateSignature, AggregateError> {
+    let signing_package = parse_signing_package::<C>(commitments_json, message_hex)?;
+
+    aggregate_package(&signing_package, shares_json, public_key_package_json)
+}
+
+/// JSON form of `aggregate`, over the coordinator's serialized SigningPackage.
+pub fn aggregate_signature_with_package<C: Ciphersuite>(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+) -> Result<AggregateSignature, AggregateError> {
+    let signing_package: frost::SigningPackage<C> = serde_json::from_str(signing_package_json)
+        .map_err(|e| format!("Invalid signing package JSON: {}", e))?;
+
+    aggregate_package(&signing_package, shares_json, public_key_package_json)
+}
+
+fn aggregate_package<C: Ciphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    shares_json: &str,
+    public_key_package_json: &str,
+) -> Result<AggregateSignature, AggregateError> {
+    let shares = parse_signature_shares::<C>(shares_json)?;
+
+    let pubkey_package: frost::keys::PublicKeyPackage<C> =
+        serde_json::from_str(public_key_package_json)
+            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+
+    let signature = aggregate(signing_package, &shares, &pubkey_package)?;
+
+    Ok(AggregateSignature {
+        signature: signature_hex(&signature)?,
+    })
+}
+
+/// Hex-encode a signature
+pub fn signature_hex<C: Ciphersuite>(signature: &frost::Signature<C>) -> Result<String, String> {
+    let signature_bytes = signature
+        .serialize()
+        .map_err(|e| format!("Failed to serialize signature: {:?}", e))?;
+
+    Ok(hex::encode(signature_bytes))
+}
+
+/// Check each signature share against its signer's verifying share, without
+/// aggregating (VERIFY_ERROR).
+pub fn verify_shares<C: Ciphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    shares: &SignatureShares<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<ShareVerificationResult, FrostError> {
+    verify_shares_internal(signing_package, shares, pubkey_package)
+        .map_err(|e| FrostError::new("VERIFY_ERROR", e))
+}
+
+fn verify_shares_internal<C: Ciphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    shares: &SignatureShares<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<ShareVerificationResult, String> {
+    let culprits = find_culprits(signing_package, shares, pubkey_package)?;
+
+    Ok(ShareVerificationResult {
+        valid: culprits.is_empty(),
+        culprits,
+    })
+}
+
+/// JSON form of `verify_shares` (VERIFY_ERROR).
+pub fn verify_signature_shares<C: Ciphersuite>(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<ShareVerificationResult, FrostError> {
+    verify_signature_shares_internal::<C>(
+        shares_json,
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    )
+    .map_err(|e| FrostError::new("VERIFY_ERROR", e))
+}
+
+fn verify_signature_shares_internal<C: Ciphersuite>(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<ShareVerificationResult, String> {
+    let signing_package = parse_signing_package::<C>(commitments_json, message_hex)?;
+    let shares = parse_signature_shares::<C>(shares_json)?;
+
+    let pubkey_package: frost::keys::PublicKeyPackage<C> =
+        serde_json::from_str(public_key_package_json)
+            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+
+    verify_shares_internal(&signing_package, &shares, &pubkey_package)
+}
+
+/// Parse a JSON array of SignatureShare objects, keyed by signer
+pub fn parse_signature_shares<C: Ciphersuite>(
+    shares_json: &str,
+) -> Result<SignatureShares<C>, String> {
+    let shares: Vec<SignatureShare> =
+        serde_json::from_str(shares_json).map_err(|e| format!("Invalid shares JSON: {}", e))?;
+
+    let mut signature_shares = BTreeMap::new();
+    for s in &shares {
+        let share: frost::round2::SignatureShare<C> = serde_json::from_str(&s.share)
+            .map_err(|e| format!("Invalid signature share JSON: {}", e))?;
+
+        signature_shares.insert(identifier_from_u16(s.identifier)?, share);
+    }
+
+    Ok(signature_shares)
+}
+
+/// Identifiers whose signature share does not verify against their verifying share.
+///
+/// Rerandomized shares must be checked against a randomized public key
+/// package, in which both the group key and every verifying share are
+/// shifted by the randomizer.
+pub fn find_culprits<C: Ciphersuite>(
+    signing_package: &frost::SigningPackage<C>,
+    shares: &SignatureShares<C>,
+    pubkey_package: &frost::keys::PublicKeyPackage<C>,
+) -> Result<Vec<u16>, String> {
+    shares
+        .iter()
+        .filter(
+            |(id, share)| match pubkey_package.verifying_shares().get(id) {
+                Some(verifying_share) => frost::verify_signature_share(
+                    **id,
+                    verifying_share,
+                    share,
+                    signing_package,
+                    pubkey_package.verifying_key(),
+                )
+                .is_err(),
+                None => true,
+            },
+        )
+        .map(|(id, _)| identifier_to_u16(id))
+        .collect()
+}
+
+/// Build the aggregation error, naming culprits if any shares were invalid
+pub fn aggregate_error(error: impl std::fmt::Debug, culprits: Vec<u16>) -> AggregateError {
+    let message = if culprits.is_empty() {
+        format!("Aggregation failed: {:?}", error)
+    } else {
+        format!("Invalid signature shares from {:?}", culprits)
+    };
+
+    AggregateError {
+        code: "AGGREGATE_ERROR".into(),
+        message,
+        culprits,
+    }
+}
+
+// =============================================================================
+// Verification
+// =============================================================================
+
+/// Verify a signature against the group public key (VERIFY_ERROR for bad input;
+/// a well-formed but wrong signature is `valid: false`).
+pub fn verify_signature<C: Ciphersuite>(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+) -> Result<VerifyResult, FrostError> {
+    verify_internal::<C>(signature_hex, message_hex, group_public_key_hex)
+        .map(|valid| VerifyResult { valid })
+        .map_err(|e| FrostError::new("VERIFY_ERROR", e))
+}
+
+fn verify_internal<C: Ciphersuite>(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+) -> Result<bool, String> {
+    let signature = parse_signature::<C>(signature_hex)?;
+
+    let message = hex::decode(message_hex).map_err(|e| format!("Invalid message hex: {}", e))?;
+
+    let verifying_key = parse_verifying_key::<C>(group_public_key_hex)?;
+
+    Ok(verifying_key.verify(&message, &signature).is_ok())
+}
+
+/// Decode a hex-encoded signature
+pub fn parse_signature<C: Ciphersuite>(signature_hex: &str) -> Result<frost::Signature<C>, String> {
+    let bytes = hex::decode(signature_hex).map_err(|e| format!("Invalid signature hex: {}", e))?;
+    frost::Signature::deserialize(&bytes).map_err(|e| format!("Invalid signature: {:?}", e))
+}
+
+/// Decode a hex-encoded group public key
+pub fn parse_verifying_key<C: Ciphersuite>(
+    group_public_key_hex: &str,
+) -> Result<frost::VerifyingKey<C>, String> {
+    let bytes = hex::decode(group_public_key_hex)
+        .map_err(|e| format!("Invalid group public key hex: {}", e))?;
+    frost::VerifyingKey::deserialize(&bytes)
+        .map_err(|e| format!("Invalid group public key: {:?}", e))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::keys::generate_key_shares;
+    use frost_ed25519::Ed25519Sha512;
+    use frost_secp256k1_tr::Secp256K1Sha256TR;
+
+    pub(crate) const MESSAGE: &str = "48656c6c6f20576f726c64";
+
+    /// Run both signing rounds, letting `message_for` pick each signer's message
+    fn sign_each<C: Ciphersuite>(
+        key_packages: &[&str],
+        public_key_package: &str,
+        message_for: impl Fn(usize) -> &'static str,
+    ) -> Result<AggregateSignature, AggregateError> {
+        let round1: Vec<Round1Result> = key_packages
+            .iter()
+            .map(|kp| generate_round1_commitment::<C>(kp).expect("Round 1 failed"))
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let shares: Vec<SignatureShare> = key_packages
+            .iter()
+            .zip(&round1)
+            .enumerate()
+            .map(|(i, (kp, r))| {
+                generate_round2_signature::<C>(
+                    kp,
+                    &serde_json::to_string(&r.nonces).unwrap(),
+                    &commitments_json,
+                    message_for(i),
+                )
+                .expect("Round 2 failed")
+            })
+            .collect();
+
+        aggregate_signature::<C>(
+            &serde_json::to_string(&shares).unwrap(),
+            &commitments_json,
+            MESSAGE,
+            public_key_package,
+        )
+    }
+
+    /// Sign `MESSAGE` with the given key packages
+    pub(crate) fn sign_with<C: Ciphersuite>(
+        key_packages: &[&str],
+        public_key_package: &str,
+    ) -> Result<AggregateSignature, AggregateError> {
+        sign_each::<C>(key_packages, public_key_package, |_| MESSAGE)
+    }
+
+    fn signing_flow<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+
+        let signature = sign_with::<C>(
+            &[&keygen.shares[0].key_package, &keygen.shares[2].key_package],
+            &keygen.public_key_package,
+        )
+        .expect("Aggregation failed");
+
+        let verify = verify_signature::<C>(&signature.signature, MESSAGE, &keygen.group_public_key)
+            .expect("Verification failed");
+        assert!(verify.valid);
+
+        let verify = verify_signature::<C>(&signature.signature, "00", &keygen.group_public_key)
+            .expect("Verification failed");
+        assert!(!verify.valid);
+    }
+
+    fn names_culprits<C: Ciphersuite>() {
+        let keygen = generate_key_shares::<C>(2, 3).expect("Key generation failed");
+
+        // Participant 3 signs a different message
+        let Err(error) = sign_each::<C>(
+            &[&keygen.shares[0].key_package, &keygen.shares[2].key_package],
+            &keygen.public_key_package,
+            |i| {
+                if i == 0 {
+                    MESSAGE
+                } else {
+                    "6261642062616420626164"
+                }
+            },
+        ) else {
+            panic!("Aggregation should fail");
+        };
+        assert_eq!(error.code, "AGGREGATE_ERROR");
+        assert_eq!(error.culprits, vec![3]);
+    }
+
+    #[test]
+    fn test_signing_flow() {
+        signing_flow::<Ed25519Sha512>();
+        signing_flow::<Secp256K1Sha256TR>();
+    }
+
+    #[test]
+    fn test_names_culprits() {
+        names_culprits::<Ed25519Sha512>();
+        names_culprits::<Secp256K1Sha256TR>();
+    }
+}
@@ -0,0 +1,351 @@
+//! JSON wire types shared by every ciphersuite
+//!
+//! FROST objects (key packages, commitments, shares, ...) are carried as their
+//! serde JSON encoding inside these wrappers, so the wrappers look the same
+//! whichever curve produced them.
+
+use serde::{Deserialize, Serialize};
+
+/// A participant's key share
+#[derive(Serialize, Deserialize)]
+pub struct KeyShare {
+    /// Participant identifier (1-indexed)
+    pub identifier: u16,
+    /// Serialized KeyPackage (JSON)
+    pub key_package: String,
+    /// Verifying share (hex-encoded public key share)
+    pub verifying_share: String,
+    /// Serialized SecretShare with the dealer's VSS commitment (JSON) - KEEP SECRET.
+    /// Send this to the participant, who checks it with `verify_and_accept_share`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_share: Option<String>,
+}
+
+/// Result of key generation
+#[derive(Serialize, Deserialize)]
+pub struct KeyGenResult {
+    /// Group public key (hex-encoded)
+    pub group_public_key: String,
+    /// Individual key shares for each participant
+    pub shares: Vec<KeyShare>,
+    /// Threshold required for signing
+    pub threshold: u16,
+    /// Total number of participants
+    pub total: u16,
+    /// Serialized PublicKeyPackage (JSON)
+    pub public_key_package: String,
+}
+
+/// A commitment for Round 1 of signing
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Commitment {
+    /// Participant identifier
+    pub identifier: u16,
+    /// Serialized SigningCommitments (JSON)
+    pub commitment: String,
+}
+
+/// Nonces generated during Round 1 (must be kept secret!)
+#[derive(Serialize, Deserialize)]
+pub struct SigningNonces {
+    /// Participant identifier
+    pub identifier: u16,
+    /// Serialized SigningNonces (JSON) - KEEP SECRET
+    pub nonces: String,
+}
+
+/// Result of Round 1 commitment generation
+#[derive(Serialize, Deserialize)]
+pub struct Round1Result {
+    /// Public commitment to broadcast
+    pub commitment: Commitment,
+    /// Secret nonces to keep for Round 2
+    pub nonces: SigningNonces,
+}
+
+/// A signer's preprocessed commitments, published to the coordinator
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CommitmentPool {
+    /// Participant identifier
+    pub identifier: u16,
+    /// Unused serialized SigningCommitments (JSON), oldest first
+    pub commitments: Vec<String>,
+}
+
+/// Nonces matching a CommitmentPool (must be kept secret!)
+#[derive(Serialize, Deserialize)]
+pub struct NoncePool {
+    /// Participant identifier
+    pub identifier: u16,
+    /// Unused serialized SigningNonces (JSON) - KEEP SECRET
+    pub nonces: Vec<String>,
+}
+
+/// Result of preprocessing a batch of commitments
+#[derive(Serialize, Deserialize)]
+pub struct CommitmentPoolResult {
+    /// Commitments to publish to the coordinator
+    pub commitments: CommitmentPool,
+    /// Secret nonces to keep for signing
+    pub nonces: NoncePool,
+}
+
+/// Commitments chosen by the coordinator for one signing session
+#[derive(Serialize, Deserialize)]
+pub struct PoolSelection {
+    /// One commitment per signer, ready for Round 2 and aggregation
+    pub commitments: Vec<Commitment>,
+    /// Pools with the chosen commitments removed
+    pub pools: Vec<CommitmentPool>,
+}
+
+/// Signature share produced from a nonce pool
+#[derive(Serialize, Deserialize)]
+pub struct PoolSignatureResult {
+    /// This participant's signature share
+    pub signature_share: SignatureShare,
+    /// Remaining nonces, with the one just used removed
+    pub nonces: NoncePool,
+}
+
+/// Serializable nonce registry (commitments of consumed nonces, hex-encoded)
+#[derive(Serialize, Deserialize)]
+pub struct NonceRegistryState {
+    pub used_nonces: Vec<String>,
+}
+
+/// Nonce registry encrypted under a key package held in the keystore
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SealedNonceRegistry {
+    /// Export counter per key handle, authenticated with the entries; imports
+    /// below the caller's `min_counter` are refused
+    pub counter: u64,
+    /// AES-GCM nonce (hex)
+    pub nonce: String,
+    /// Commitments of consumed nonces, AES-256-GCM encrypted under a key
+    /// derived from the sealing key package's signing share (hex)
+    pub ciphertext: String,
+}
+
+/// Opaque handle to a key package held in WASM memory
+#[derive(Serialize, Deserialize)]
+pub struct KeyHandle {
+    /// Keystore handle (pass to the `*_with_handle(s)` functions)
+    pub handle: u32,
+    /// Participant identifier
+    pub identifier: u16,
+    /// Verifying share (hex-encoded public key share)
+    pub verifying_share: String,
+}
+
+/// Result of Round 1 with the nonces kept in WASM memory
+#[derive(Serialize, Deserialize)]
+pub struct Round1HandleResult {
+    /// Public commitment to broadcast
+    pub commitment: Commitment,
+    /// Keystore handle to the secret nonces (consumed by Round 2)
+    pub nonce_handle: u32,
+}
+
+/// A signature share from Round 2
+#[derive(Serialize, Deserialize)]
+pub struct SignatureShare {
+    /// Participant identifier
+    pub identifier: u16,
+    /// Serialized SignatureShare (JSON)
+    pub share: String,
+}
+
+/// Final aggregate signature
+#[derive(Serialize, Deserialize)]
+pub struct AggregateSignature {
+    /// Full signature (hex-encoded)
+    pub signature: String,
+}
+
+/// A DKG Round 1 package broadcast to every other participant
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DkgRound1Package {
+    /// Sender identifier
+    pub identifier: u16,
+    /// Serialized round1::Package (JSON)
+    pub package: String,
+}
+
+/// Result of DKG part 1
+#[derive(Serialize, Deserialize)]
+pub struct DkgRound1Result {
+    /// Serialized round1::SecretPackage (JSON) - KEEP SECRET, needed for part 2
+    pub secret_package: String,
+    /// Public package to broadcast
+    pub package: DkgRound1Package,
+}
+
+/// A DKG Round 2 package sent privately to a single recipient
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DkgRound2Package {
+    /// Sender identifier
+    pub sender: u16,
+    /// Recipient identifier
+    pub recipient: u16,
+    /// Serialized round2::Package (JSON) - send over a confidential channel
+    pub package: String,
+}
+
+/// Result of DKG part 2
+#[derive(Serialize, Deserialize)]
+pub struct DkgRound2Result {
+    /// Serialized round2::SecretPackage (JSON) - KEEP SECRET, needed for part 3
+    pub secret_package: String,
+    /// One package per other participant
+    pub packages: Vec<DkgRound2Package>,
+}
+
+/// Result of DKG part 3 (this participant's final key material)
+#[derive(Serialize, Deserialize)]
+pub struct DkgResult {
+    /// Participant identifier
+    pub identifier: u16,
+    /// Serialized KeyPackage (JSON) - KEEP SECRET
+    pub key_package: String,
+    /// Verifying share (hex-encoded public key share)
+    pub verifying_share: String,
+    /// Group public key (hex-encoded)
+    pub group_public_key: String,
+    /// Serialized PublicKeyPackage (JSON)
+    pub public_key_package: String,
+}
+
+/// A refreshing share dealt to one participant (send privately)
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RefreshingShare {
+    /// Recipient identifier
+    pub identifier: u16,
+    /// Serialized zero-secret SecretShare (JSON) - KEEP SECRET
+    pub refreshing_share: String,
+}
+
+/// Result of dealer-based share refresh
+#[derive(Serialize, Deserialize)]
+pub struct RefreshSharesResult {
+    /// One refreshing share per participant
+    pub shares: Vec<RefreshingShare>,
+    /// Updated PublicKeyPackage (JSON) - same group public key, new verifying shares
+    pub public_key_package: String,
+}
+
+/// A repair delta sent privately from one helper to another
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RepairDelta {
+    /// Helper that computed the delta
+    pub sender: u16,
+    /// Helper that receives the delta
+    pub recipient: u16,
+    /// Delta scalar (hex-encoded) - send over a confidential channel
+    pub delta: String,
+}
+
+/// Result of share repair step 1
+#[derive(Serialize, Deserialize)]
+pub struct RepairStep1Result {
+    /// One delta per helper, including this helper
+    pub deltas: Vec<RepairDelta>,
+}
+
+/// A helper's sigma, sent privately to the participant being repaired
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RepairSigma {
+    /// Helper identifier
+    pub identifier: u16,
+    /// Sigma scalar (hex-encoded) - send over a confidential channel
+    pub sigma: String,
+}
+
+/// A resharing dealer's public output, broadcast to every new participant
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReshareCommitment {
+    /// Dealer (current holder) identifier
+    pub dealer: u16,
+    /// New participant identifiers the dealer dealt sub-shares to
+    pub recipients: Vec<u16>,
+    /// Serialized VerifiableSecretSharingCommitment of the dealer's sub-sharing (JSON)
+    pub commitment: String,
+}
+
+/// A sub-share sent privately from a resharing dealer to one new participant
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReshareSubShare {
+    /// Dealer (current holder) identifier
+    pub dealer: u16,
+    /// New participant identifier
+    pub recipient: u16,
+    /// Serialized SecretShare (JSON) - send over a confidential channel
+    pub sub_share: String,
+}
+
+/// Result of reshare part 1
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReshareRound1Result {
+    /// Public commitment to broadcast to the new participants
+    pub commitment: ReshareCommitment,
+    /// One sub-share per new participant
+    pub sub_shares: Vec<ReshareSubShare>,
+}
+
+/// Group signing key reassembled by `emergency_reconstruct_signing_key`
+#[derive(Serialize, Deserialize)]
+pub struct ReconstructedKey {
+    /// Group signing scalar (hex-encoded) - the WHOLE private key, KEEP SECRET
+    pub signing_key: String,
+    /// Group public key it was checked against (hex-encoded)
+    pub group_public_key: String,
+}
+
+/// Result of per-share verification
+#[derive(Serialize, Deserialize)]
+pub struct ShareVerificationResult {
+    /// True if every signature share is valid
+    pub valid: bool,
+    /// Identifiers of participants whose signature share is invalid
+    pub culprits: Vec<u16>,
+}
+
+/// Result of signature verification
+#[derive(Serialize, Deserialize)]
+pub struct VerifyResult {
+    pub valid: bool,
+}
+
+/// A participant's public key share
+#[derive(Serialize, Deserialize)]
+pub struct PublicKeyResult {
+    /// Verifying share (hex-encoded public key share)
+    pub public_key: String,
+    /// Participant identifier
+    pub identifier: u16,
+}
+
+/// Signing package built once by the coordinator and sent to every signer
+#[derive(Serialize, Deserialize)]
+pub struct SigningPackage {
+    /// Serialized SigningPackage (JSON)
+    pub signing_package: String,
+}
+
+/// Signing package with the randomizer chosen for it (rerandomized FROST)
+#[derive(Serialize, Deserialize)]
+pub struct SigningPackageResult {
+    /// Serialized SigningPackage (JSON)
+    pub signing_package: String,
+    /// Serialized randomizer (JSON) - needed for signing and verification
+    pub randomizer: String,
+}
+
+/// Final rerandomized signature
+#[derive(Serialize, Deserialize)]
+pub struct AggregateResult {
+    /// Full signature (hex-encoded)
+    pub signature: String,
+    /// Randomizer used (JSON) - needed for verification
+    pub randomizer: String,
+}
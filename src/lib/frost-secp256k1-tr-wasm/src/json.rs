@@ -0,0 +1,1077 @@
+//! JSON API
+//!
+//! Each function takes and returns the serialized forms used on the wire
+//! (JSON strings and hex), parses its arguments and calls the typed function
+//! of the same name in the crate root. Group public keys are returned x-only.
+//! The wasm-bindgen exports in the `wasm` module are thin wrappers over these
+//! functions.
+
+use frost_common::keystore;
+use frost_common::{dkg, keys, nonces, pool, reconstruct, refresh, repair, reshare, signing};
+use frost_secp256k1_tr::Secp256K1Sha256TR;
+
+use crate::frost;
+use crate::frost::keys::Tweak;
+use crate::{
+    AggregateError, AggregateSignature, CommitmentPoolResult, DkgResult, DkgRound1Result,
+    DkgRound2Result, FrostError, KeyGenResult, KeyHandle, KeyShare, NonceRegistryState,
+    PoolSelection, PoolSignatureResult, PublicKeyResult, ReconstructedKey, RefreshSharesResult,
+    RefreshedKeyPackage, RepairSigma, RepairStep1Result, ReshareRound1Result, Round1HandleResult,
+    Round1Result, ShareVerificationResult, SignatureShare, SigningPackage, TaprootKeyResult,
+    VerifyResult,
+};
+
+// =============================================================================
+// Key Generation
+// =============================================================================
+
+/// Generate key shares using trusted dealer key generation.
+///
+/// # Arguments
+/// * `threshold` - Minimum number of signers required (t)
+/// * `total` - Total number of participants (n)
+///
+/// # Returns
+/// KeyGenResult (with an x-only group public key) or FrostError
+pub fn generate_key_shares(threshold: u16, total: u16) -> Result<KeyGenResult, FrostError> {
+    keys::generate_key_shares::<Secp256K1Sha256TR>(threshold, total).map(x_only_key_gen)
+}
+
+/// Split an existing secp256k1 private key into key shares, keeping its public key.
+///
+/// # Arguments
+/// * `secret_hex` - 32-byte private key (hex-encoded, big-endian)
+/// * `threshold` - Minimum number of signers required (t)
+/// * `total` - Total number of participants (n)
+///
+/// # Returns
+/// KeyGenResult (with an x-only group public key) or FrostError
+pub fn split_key_shares(
+    secret_hex: &str,
+    threshold: u16,
+    total: u16,
+) -> Result<KeyGenResult, FrostError> {
+    split_key_shares_internal(secret_hex, threshold, total)
+        .map_err(|e| FrostError::new("KEYGEN_ERROR", e))
+}
+
+fn split_key_shares_internal(
+    secret_hex: &str,
+    threshold: u16,
+    total: u16,
+) -> Result<KeyGenResult, String> {
+    let secret = hex::decode(secret_hex).map_err(|e| format!("Invalid secret hex: {}", e))?;
+    let signing_key = frost::SigningKey::deserialize(&secret)
+        .map_err(|e| format!("Invalid signing key: {:?}", e))?;
+
+    let (shares, pubkey_package) =
+        crate::split_key_shares(&signing_key, threshold, total).map_err(|e| e.message)?;
+
+    keys::key_gen_result(shares, &pubkey_package, threshold, total).map(x_only_key_gen)
+}
+
+/// Verify a dealer-issued secret share and turn it into a key package.
+///
+/// # Arguments
+/// * `secret_share_json` - This participant's `secret_share` from KeyGenResult (JSON)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+///
+/// # Returns
+/// KeyShare or FrostError
+pub fn verify_and_accept_share(
+    secret_share_json: &str,
+    public_key_package_json: &str,
+) -> Result<KeyShare, FrostError> {
+    keys::verify_and_accept_share::<Secp256K1Sha256TR>(secret_share_json, public_key_package_json)
+}
+
+// =============================================================================
+// Distributed Key Generation
+// =============================================================================
+
+/// DKG part 1: generate this participant's secret polynomial and the
+/// Round 1 package to broadcast.
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier (1-indexed)
+/// * `max_signers` - Total number of participants (n)
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// DkgRound1Result or FrostError
+pub fn dkg_part1(
+    identifier: u16,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DkgRound1Result, FrostError> {
+    dkg::dkg_part1::<Secp256K1Sha256TR>(identifier, max_signers, min_signers)
+}
+
+/// DKG part 2: verify the other participants' Round 1 packages and produce
+/// one Round 2 package per recipient.
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from `dkg_part1` (JSON)
+/// * `round1_packages_json` - JSON array of the other participants' DkgRound1Package objects
+///
+/// # Returns
+/// DkgRound2Result or FrostError
+pub fn dkg_part2(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+) -> Result<DkgRound2Result, FrostError> {
+    dkg::dkg_part2::<Secp256K1Sha256TR>(secret_package_json, round1_packages_json)
+}
+
+/// DKG part 3: verify the Round 2 packages addressed to this participant and
+/// derive the final key package and public key package.
+///
+/// The ciphersuite commits the DKG group key to an unspendable script path
+/// (BIP-341), so it already is a key-path-only output key.
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from `dkg_part2` (JSON)
+/// * `round1_packages_json` - JSON array of the other participants' DkgRound1Package objects
+/// * `round2_packages_json` - JSON array of DkgRound2Package objects addressed to this participant
+///
+/// # Returns
+/// DkgResult (with an x-only group public key) or FrostError
+pub fn dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+) -> Result<DkgResult, FrostError> {
+    dkg::dkg_part3::<Secp256K1Sha256TR>(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+    )
+    .map(x_only_dkg)
+}
+
+// =============================================================================
+// Share Refresh
+// =============================================================================
+
+/// Dealer-based refresh: deal zero-secret shares that rotate every
+/// participant's key share without changing the group public key.
+///
+/// # Arguments
+/// * `public_key_package_json` - Current PublicKeyPackage (JSON)
+/// * `identifiers_json` - JSON array of the participants' identifiers, e.g. `[1, 2, 3]`
+/// * `min_signers` - Minimum number of signers required (t), unchanged from keygen
+///
+/// # Returns
+/// RefreshSharesResult or FrostError
+pub fn compute_refreshing_shares(
+    public_key_package_json: &str,
+    identifiers_json: &str,
+    min_signers: u16,
+) -> Result<RefreshSharesResult, FrostError> {
+    refresh::compute_refreshing_shares::<Secp256K1Sha256TR>(
+        public_key_package_json,
+        identifiers_json,
+        min_signers,
+    )
+}
+
+/// Apply a refreshing share from `compute_refreshing_shares` to a key package.
+///
+/// # Arguments
+/// * `refreshing_share_json` - This participant's RefreshingShare (JSON)
+/// * `key_package_json` - The participant's current key package (JSON)
+///
+/// # Returns
+/// RefreshedKeyPackage or FrostError
+pub fn refresh_share(
+    refreshing_share_json: &str,
+    key_package_json: &str,
+) -> Result<RefreshedKeyPackage, FrostError> {
+    refresh::refresh_share::<Secp256K1Sha256TR>(refreshing_share_json, key_package_json)
+}
+
+/// Refresh DKG part 1: like `dkg_part1`, but the secret polynomial has a
+/// zero constant term so the group key is unchanged.
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier
+/// * `max_signers` - Total number of participants (n)
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// DkgRound1Result or FrostError
+pub fn refresh_dkg_part1(
+    identifier: u16,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DkgRound1Result, FrostError> {
+    refresh::refresh_dkg_part1::<Secp256K1Sha256TR>(identifier, max_signers, min_signers)
+}
+
+/// Refresh DKG part 2: same wire format as `dkg_part2`.
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from `refresh_dkg_part1` (JSON)
+/// * `round1_packages_json` - JSON array of the other participants' DkgRound1Package objects
+///
+/// # Returns
+/// DkgRound2Result or FrostError
+pub fn refresh_dkg_part2(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+) -> Result<DkgRound2Result, FrostError> {
+    refresh::refresh_dkg_part2::<Secp256K1Sha256TR>(secret_package_json, round1_packages_json)
+}
+
+/// Refresh DKG part 3: add the zero-secret shares to this participant's
+/// current key package and derive the updated public key package.
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from `refresh_dkg_part2` (JSON)
+/// * `round1_packages_json` - JSON array of the other participants' DkgRound1Package objects
+/// * `round2_packages_json` - JSON array of DkgRound2Package objects addressed to this participant
+/// * `public_key_package_json` - Current PublicKeyPackage (JSON)
+/// * `key_package_json` - This participant's current key package (JSON)
+///
+/// # Returns
+/// DkgResult (with an x-only group public key) or FrostError
+pub fn refresh_dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+    public_key_package_json: &str,
+    key_package_json: &str,
+) -> Result<DkgResult, FrostError> {
+    refresh::refresh_dkg_part3::<Secp256K1Sha256TR>(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+        public_key_package_json,
+        key_package_json,
+    )
+    .map(x_only_dkg)
+}
+
+// =============================================================================
+// Share Repair
+// =============================================================================
+
+/// Share repair step 1 (helper): split this helper's contribution to the
+/// lost share into one delta per helper.
+///
+/// # Arguments
+/// * `helpers_json` - Helper identifiers including this one (JSON array, e.g. `[1, 2]`)
+/// * `key_package_json` - This helper's key package (JSON)
+/// * `participant` - Identifier of the participant whose share is being repaired
+///
+/// # Returns
+/// RepairStep1Result or FrostError
+pub fn repair_share_step_1(
+    helpers_json: &str,
+    key_package_json: &str,
+    participant: u16,
+) -> Result<RepairStep1Result, FrostError> {
+    repair::repair_share_step_1::<Secp256K1Sha256TR>(helpers_json, key_package_json, participant)
+}
+
+/// Share repair step 2 (helper): combine the deltas received from every
+/// helper into this helper's sigma.
+///
+/// # Arguments
+/// * `deltas_json` - Deltas addressed to this helper, one from each helper (JSON array)
+///
+/// # Returns
+/// RepairSigma or FrostError
+pub fn repair_share_step_2(deltas_json: &str) -> Result<RepairSigma, FrostError> {
+    repair::repair_share_step_2::<Secp256K1Sha256TR>(deltas_json)
+}
+
+/// Share repair step 3 (participant being repaired): rebuild the lost key
+/// package from the helpers' sigmas.
+///
+/// # Arguments
+/// * `sigmas_json` - One RepairSigma from each helper (JSON array)
+/// * `identifier` - This participant's identifier
+/// * `public_key_package_json` - Group public key package (JSON)
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// KeyShare or FrostError
+pub fn repair_share_step_3(
+    sigmas_json: &str,
+    identifier: u16,
+    public_key_package_json: &str,
+    min_signers: u16,
+) -> Result<KeyShare, FrostError> {
+    repair::repair_share_step_3::<Secp256K1Sha256TR>(
+        sigmas_json,
+        identifier,
+        public_key_package_json,
+        min_signers,
+    )
+}
+
+// =============================================================================
+// Resharing
+// =============================================================================
+
+/// Reshare part 1 (current holder): deal this holder's weighted share to a
+/// new participant set with a new threshold.
+///
+/// # Arguments
+/// * `key_package_json` - This dealer's current key package (JSON)
+/// * `dealers_json` - Dealer identifiers including this one (JSON array, e.g. `[1, 2]`)
+/// * `new_identifiers_json` - New participant identifiers (JSON array, e.g. `[1, 2, 4, 5, 6]`)
+/// * `new_min_signers` - New threshold (t')
+///
+/// # Returns
+/// ReshareRound1Result or FrostError
+pub fn reshare_part1(
+    key_package_json: &str,
+    dealers_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> Result<ReshareRound1Result, FrostError> {
+    reshare::reshare_part1::<Secp256K1Sha256TR>(
+        key_package_json,
+        dealers_json,
+        new_identifiers_json,
+        new_min_signers,
+    )
+}
+
+/// Reshare part 2 (new participant): combine the dealers' sub-shares into a
+/// key package for the new participant set.
+///
+/// # Arguments
+/// * `identifier` - This new participant's identifier
+/// * `sub_shares_json` - Sub-shares addressed to this participant, one per dealer (JSON array)
+/// * `commitments_json` - Every dealer's ReshareCommitment (JSON array)
+/// * `public_key_package_json` - The old PublicKeyPackage (JSON)
+/// * `new_identifiers_json` - New participant identifiers, as passed to part 1 (JSON array)
+/// * `new_min_signers` - New threshold (t'), as passed to part 1
+///
+/// # Returns
+/// DkgResult (with an x-only group public key) or FrostError
+pub fn reshare_part2(
+    identifier: u16,
+    sub_shares_json: &str,
+    commitments_json: &str,
+    public_key_package_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> Result<DkgResult, FrostError> {
+    reshare::reshare_part2::<Secp256K1Sha256TR>(
+        identifier,
+        sub_shares_json,
+        commitments_json,
+        public_key_package_json,
+        new_identifiers_json,
+        new_min_signers,
+    )
+    .map(x_only_dkg)
+}
+
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
+
+/// Generate Round 1 commitment and nonces.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON, from KeyGenResult)
+///
+/// # Returns
+/// Round1Result or FrostError
+pub fn generate_round1_commitment(key_package_json: &str) -> Result<Round1Result, FrostError> {
+    signing::generate_round1_commitment::<Secp256K1Sha256TR>(key_package_json)
+}
+
+// =============================================================================
+// Signing Package Creation
+// =============================================================================
+
+/// Create the signing package for one signing session.
+///
+/// # Arguments
+/// * `commitments_json` - All participants' commitments (JSON array)
+/// * `message_hex` - Message to sign (hex-encoded)
+///
+/// # Returns
+/// SigningPackage or FrostError
+pub fn create_signing_package(
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SigningPackage, FrostError> {
+    signing::create_signing_package::<Secp256K1Sha256TR>(commitments_json, message_hex)
+}
+
+// =============================================================================
+// Round 2: Signature Share Generation
+// =============================================================================
+
+/// Generate Round 2 signature share for the untweaked group key.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `nonces_json` - The participant's SigningNonces (JSON from Round1)
+/// * `commitments_json` - JSON array of all participants' Commitment objects
+/// * `message_hex` - Message to sign (hex-encoded)
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature(
+    key_package_json: &str,
+    nonces_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SignatureShare, FrostError> {
+    signing::generate_round2_signature::<Secp256K1Sha256TR>(
+        key_package_json,
+        nonces_json,
+        commitments_json,
+        message_hex,
+    )
+}
+
+/// Generate Round 2 signature share for the Taproot output key.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `nonces_json` - The participant's SigningNonces (JSON from Round1)
+/// * `commitments_json` - JSON array of all participants' Commitment objects
+/// * `message_hex` - Message to sign, e.g. the BIP-341 sighash (hex-encoded)
+/// * `merkle_root_hex` - Script tree merkle root (hex, 32 bytes) or empty for a
+///   key-path-only output (BIP-86)
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_with_tweak(
+    key_package_json: &str,
+    nonces_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    merkle_root_hex: &str,
+) -> Result<SignatureShare, FrostError> {
+    let round2_error = |e| FrostError::new("ROUND2_ERROR", e);
+
+    let key_package = parse_key_package(key_package_json).map_err(round2_error)?;
+    let nonces = signing::parse_signing_nonces(nonces_json).map_err(round2_error)?;
+    let signing_package =
+        signing::parse_signing_package(commitments_json, message_hex).map_err(round2_error)?;
+    let merkle_root = parse_merkle_root(merkle_root_hex).map_err(round2_error)?;
+
+    let signature_share = crate::generate_round2_signature_with_tweak(
+        &signing_package,
+        &nonces,
+        &key_package,
+        merkle_root.as_ref(),
+    )?;
+
+    signing::signature_share_result(key_package.identifier(), &signature_share)
+        .map_err(round2_error)
+}
+
+/// Generate Round 2 signature share over the coordinator's signing package.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `nonces_json` - The participant's SigningNonces (JSON from Round1)
+/// * `signing_package_json` - Serialized SigningPackage (from create_signing_package)
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_with_package(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+) -> Result<SignatureShare, FrostError> {
+    signing::generate_round2_signature_with_package::<Secp256K1Sha256TR>(
+        key_package_json,
+        nonces_json,
+        signing_package_json,
+    )
+}
+
+/// Generate Round 2 signature share for the Taproot output key over the
+/// coordinator's signing package.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `nonces_json` - The participant's SigningNonces (JSON from Round1)
+/// * `signing_package_json` - Serialized SigningPackage (from create_signing_package)
+/// * `merkle_root_hex` - Script tree merkle root (hex, 32 bytes) or empty for a
+///   key-path-only output (BIP-86)
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_with_package_and_tweak(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+    merkle_root_hex: &str,
+) -> Result<SignatureShare, FrostError> {
+    let round2_error = |e| FrostError::new("ROUND2_ERROR", e);
+
+    let key_package = parse_key_package(key_package_json).map_err(round2_error)?;
+    let nonces = signing::parse_signing_nonces(nonces_json).map_err(round2_error)?;
+    let signing_package =
+        parse_serialized_signing_package(signing_package_json).map_err(round2_error)?;
+    let merkle_root = parse_merkle_root(merkle_root_hex).map_err(round2_error)?;
+
+    let signature_share = crate::generate_round2_signature_with_tweak(
+        &signing_package,
+        &nonces,
+        &key_package,
+        merkle_root.as_ref(),
+    )?;
+
+    signing::signature_share_result(key_package.identifier(), &signature_share)
+        .map_err(round2_error)
+}
+
+// =============================================================================
+// Commitment Pools (Preprocessing)
+// =============================================================================
+
+/// Preprocess a batch of Round 1 commitments.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `count` - Number of commitments to generate
+///
+/// # Returns
+/// CommitmentPoolResult or FrostError
+pub fn generate_commitment_pool(
+    key_package_json: &str,
+    count: u32,
+) -> Result<CommitmentPoolResult, FrostError> {
+    pool::generate_commitment_pool::<Secp256K1Sha256TR>(key_package_json, count)
+}
+
+/// Take the oldest unused commitment from each signer's pool (coordinator).
+///
+/// # Arguments
+/// * `pools_json` - JSON array of the signers' CommitmentPool objects
+/// * `signers_json` - JSON array of participant identifiers taking part
+///
+/// # Returns
+/// PoolSelection or FrostError
+pub fn select_pool_commitments(
+    pools_json: &str,
+    signers_json: &str,
+) -> Result<PoolSelection, FrostError> {
+    pool::select_pool_commitments(pools_json, signers_json)
+}
+
+/// Generate a Round 2 signature share using the pooled nonces the
+/// coordinator selected.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `nonce_pool_json` - The participant's NoncePool (JSON)
+/// * `commitments_json` - JSON array of the selected Commitment objects
+/// * `message_hex` - Message to sign (hex-encoded)
+///
+/// # Returns
+/// PoolSignatureResult or FrostError
+pub fn generate_round2_signature_from_pool(
+    key_package_json: &str,
+    nonce_pool_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<PoolSignatureResult, FrostError> {
+    pool::generate_round2_signature_from_pool::<Secp256K1Sha256TR>(
+        key_package_json,
+        nonce_pool_json,
+        commitments_json,
+        message_hex,
+    )
+}
+
+// =============================================================================
+// Nonce Registry
+// =============================================================================
+
+/// Merge a previously exported registry into the current one.
+///
+/// # Arguments
+/// * `state_json` - NonceRegistryState from `export_nonce_registry` (JSON)
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_nonce_registry(state_json: &str) -> Result<NonceRegistryState, FrostError> {
+    nonces::import_nonce_registry(state_json)
+}
+
+/// Merge a sealed registry into the current one after decrypting it and
+/// checking it is not older than `min_counter`.
+///
+/// # Arguments
+/// * `key_handle` - Handle to the key package that sealed it
+/// * `sealed_json` - SealedNonceRegistry from `export_sealed_nonce_registry` (JSON)
+/// * `min_counter` - Counter of the latest export stored, or 0
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_sealed_nonce_registry(
+    key_handle: u32,
+    sealed_json: &str,
+    min_counter: u64,
+) -> Result<NonceRegistryState, FrostError> {
+    crate::KEYSTORE.with(|keystore| {
+        keystore::import_nonce_registry(
+            &mut keystore.borrow_mut(),
+            key_handle,
+            sealed_json,
+            min_counter,
+        )
+    })
+}
+
+// =============================================================================
+// Keystore: Opaque Key and Nonce Handles
+// =============================================================================
+
+/// Load a key package into the keystore and return an opaque handle.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+///
+/// # Returns
+/// KeyHandle or FrostError
+pub fn load_key_package(key_package_json: &str) -> Result<KeyHandle, FrostError> {
+    crate::KEYSTORE
+        .with(|keystore| keystore::load_key_package(&mut keystore.borrow_mut(), key_package_json))
+}
+
+/// Load a key package tweaked for a Taproot output key into the keystore.
+///
+/// Signature shares from the returned handle verify under the output key, as
+/// with `generate_round2_signature_with_tweak`.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `merkle_root_hex` - Script tree merkle root (hex, 32 bytes) or empty for a
+///   key-path-only output (BIP-86)
+///
+/// # Returns
+/// KeyHandle or FrostError
+pub fn load_key_package_with_tweak(
+    key_package_json: &str,
+    merkle_root_hex: &str,
+) -> Result<KeyHandle, FrostError> {
+    load_key_package_with_tweak_internal(key_package_json, merkle_root_hex)
+        .map_err(|e| FrostError::new("KEYSTORE_ERROR", e))
+}
+
+fn load_key_package_with_tweak_internal(
+    key_package_json: &str,
+    merkle_root_hex: &str,
+) -> Result<KeyHandle, String> {
+    let key_package = parse_key_package(key_package_json)?;
+    let merkle_root = parse_merkle_root(merkle_root_hex)?;
+
+    crate::KEYSTORE.with(|keystore| {
+        keystore::key_handle(
+            &mut keystore.borrow_mut(),
+            key_package.tweak(merkle_root.as_ref()),
+        )
+    })
+}
+
+/// Generate a Round 1 commitment, keeping the nonces in the keystore.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+///
+/// # Returns
+/// Round1HandleResult or FrostError
+pub fn generate_round1_commitment_with_handle(
+    key_handle: u32,
+) -> Result<Round1HandleResult, FrostError> {
+    crate::KEYSTORE.with(|keystore| {
+        keystore::generate_round1_commitment(&mut keystore.borrow_mut(), key_handle)
+    })
+}
+
+/// Generate a Round 2 signature share from keystore handles.
+///
+/// The nonce handle is consumed and its nonces zeroized, so it cannot be
+/// used for a second signature.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package` or `load_key_package_with_tweak`
+/// * `nonce_handle` - Handle from `generate_round1_commitment_with_handle`
+/// * `commitments_json` - JSON array of all participants' Commitment objects
+/// * `message_hex` - Message to sign (hex-encoded)
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SignatureShare, FrostError> {
+    crate::KEYSTORE.with(|keystore| {
+        keystore::generate_round2_signature(
+            &mut keystore.borrow_mut(),
+            key_handle,
+            nonce_handle,
+            commitments_json,
+            message_hex,
+        )
+    })
+}
+
+// =============================================================================
+// Signature Aggregation
+// =============================================================================
+
+/// Aggregate signature shares into a BIP-340 signature for the untweaked group key.
+///
+/// If aggregation fails because of invalid signature shares, the error is an
+/// AggregateError whose `culprits` lists the participants who sent them.
+///
+/// # Arguments
+/// * `shares_json` - JSON array of SignatureShare objects
+/// * `commitments_json` - JSON array of Commitment objects
+/// * `message_hex` - Message that was signed (hex-encoded)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+///
+/// # Returns
+/// AggregateSignature or AggregateError
+pub fn aggregate_signature(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<AggregateSignature, AggregateError> {
+    signing::aggregate_signature::<Secp256K1Sha256TR>(
+        shares_json,
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    )
+}
+
+/// Aggregate signature shares into a BIP-340 signature for the Taproot output key.
+///
+/// Shares are checked against the tweaked verifying shares, so `culprits`
+/// works as for `aggregate_signature`.
+///
+/// # Arguments
+/// * `shares_json` - JSON array of SignatureShare objects
+/// * `commitments_json` - JSON array of Commitment objects
+/// * `message_hex` - Message that was signed (hex-encoded)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+/// * `merkle_root_hex` - Script tree merkle root used in Round 2 (hex) or empty
+///
+/// # Returns
+/// AggregateSignature or AggregateError
+pub fn aggregate_signature_with_tweak(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+    merkle_root_hex: &str,
+) -> Result<AggregateSignature, AggregateError> {
+    let signing_package = signing::parse_signing_package(commitments_json, message_hex)?;
+    let shares = signing::parse_signature_shares(shares_json)?;
+    let pubkey_package = parse_public_key_package(public_key_package_json)?;
+    let merkle_root = parse_merkle_root(merkle_root_hex)?;
+
+    let signature = crate::aggregate_signature_with_tweak(
+        &signing_package,
+        &shares,
+        &pubkey_package,
+        merkle_root.as_ref(),
+    )?;
+
+    Ok(AggregateSignature {
+        signature: signing::signature_hex(&signature)?,
+    })
+}
+
+/// Aggregate signature shares over the coordinator's signing package.
+///
+/// # Arguments
+/// * `shares_json` - JSON array of SignatureShare objects
+/// * `signing_package_json` - Serialized SigningPackage (from create_signing_package)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+///
+/// # Returns
+/// AggregateSignature or AggregateError
+pub fn aggregate_signature_with_package(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+) -> Result<AggregateSignature, AggregateError> {
+    signing::aggregate_signature_with_package::<Secp256K1Sha256TR>(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+    )
+}
+
+/// Aggregate signature shares over the coordinator's signing package into a
+/// BIP-340 signature for the Taproot output key.
+///
+/// # Arguments
+/// * `shares_json` - JSON array of SignatureShare objects
+/// * `signing_package_json` - Serialized SigningPackage (from create_signing_package)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+/// * `merkle_root_hex` - Script tree merkle root used in Round 2 (hex) or empty
+///
+/// # Returns
+/// AggregateSignature or AggregateError
+pub fn aggregate_signature_with_package_and_tweak(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    merkle_root_hex: &str,
+) -> Result<AggregateSignature, AggregateError> {
+    let signing_package = parse_serialized_signing_package(signing_package_json)?;
+    let shares = signing::parse_signature_shares(shares_json)?;
+    let pubkey_package = parse_public_key_package(public_key_package_json)?;
+    let merkle_root = parse_merkle_root(merkle_root_hex)?;
+
+    let signature = crate::aggregate_signature_with_tweak(
+        &signing_package,
+        &shares,
+        &pubkey_package,
+        merkle_root.as_ref(),
+    )?;
+
+    Ok(AggregateSignature {
+        signature: signing::signature_hex(&signature)?,
+    })
+}
+
+/// Verify each signature share against the signer's verifying share.
+///
+/// # Arguments
+/// * `shares_json` - JSON array of SignatureShare objects
+/// * `commitments_json` - JSON array of Commitment objects
+/// * `message_hex` - Message that was signed (hex-encoded)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+///
+/// # Returns
+/// ShareVerificationResult or FrostError
+pub fn verify_signature_shares(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<ShareVerificationResult, FrostError> {
+    signing::verify_signature_shares::<Secp256K1Sha256TR>(
+        shares_json,
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    )
+}
+
+// =============================================================================
+// Taproot Tweaking
+// =============================================================================
+
+/// Derive the Taproot output key for the group.
+///
+/// The output key is `Q = P + H_TapTweak(P || merkle_root) * G` where `P` is
+/// the x-only group key (BIP-341). Signatures from the `*_with_tweak`
+/// functions with the same merkle root verify under `Q`.
+///
+/// # Arguments
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON)
+/// * `merkle_root_hex` - Script tree merkle root (hex, 32 bytes) or empty for a
+///   key-path-only output (BIP-86)
+///
+/// # Returns
+/// TaprootKeyResult or FrostError
+pub fn tweak_group_public_key(
+    public_key_package_json: &str,
+    merkle_root_hex: &str,
+) -> Result<TaprootKeyResult, FrostError> {
+    tweak_group_public_key_internal(public_key_package_json, merkle_root_hex)
+        .map_err(|e| FrostError::new("PUBKEY_ERROR", e))
+}
+
+fn tweak_group_public_key_internal(
+    public_key_package_json: &str,
+    merkle_root_hex: &str,
+) -> Result<TaprootKeyResult, String> {
+    let pubkey_package = parse_public_key_package(public_key_package_json)?;
+    let merkle_root = parse_merkle_root(merkle_root_hex)?;
+
+    let internal_key = verifying_key_bytes(pubkey_package.verifying_key())?;
+    let output_key = verifying_key_bytes(&crate::tweak_group_public_key(
+        &pubkey_package,
+        merkle_root.as_ref(),
+    ))?;
+
+    Ok(TaprootKeyResult {
+        internal_key: hex::encode(&internal_key[1..]),
+        output_key: hex::encode(&output_key[1..]),
+        // SEC1 compressed prefix is 0x02 for even y and 0x03 for odd y
+        output_key_parity: output_key[0] - 2,
+    })
+}
+
+/// Parse a Taproot merkle root; empty input means no script tree (BIP-86)
+fn parse_merkle_root(merkle_root_hex: &str) -> Result<Option<[u8; 32]>, String> {
+    let merkle_root =
+        hex::decode(merkle_root_hex).map_err(|e| format!("Invalid merkle root hex: {}", e))?;
+    if merkle_root.is_empty() {
+        return Ok(None);
+    }
+    merkle_root
+        .try_into()
+        .map(Some)
+        .map_err(|_| "Merkle root must be 32 bytes or empty".into())
+}
+
+fn parse_key_package(key_package_json: &str) -> Result<frost::keys::KeyPackage, String> {
+    serde_json::from_str(key_package_json).map_err(|e| format!("Invalid key package JSON: {}", e))
+}
+
+fn parse_serialized_signing_package(
+    signing_package_json: &str,
+) -> Result<frost::SigningPackage, String> {
+    serde_json::from_str(signing_package_json)
+        .map_err(|e| format!("Invalid signing package JSON: {}", e))
+}
+
+fn parse_public_key_package(
+    public_key_package_json: &str,
+) -> Result<frost::keys::PublicKeyPackage, String> {
+    serde_json::from_str(public_key_package_json)
+        .map_err(|e| format!("Invalid public key package JSON: {}", e))
+}
+
+// =============================================================================
+// Verification
+// =============================================================================
+
+/// Verify a BIP-340 Schnorr signature.
+///
+/// # Arguments
+/// * `signature_hex` - The aggregate signature (hex-encoded, 64 bytes)
+/// * `message_hex` - The message that was signed (hex-encoded)
+/// * `group_public_key_hex` - The x-only public key (hex-encoded, 32 bytes);
+///   a 33-byte compressed key is also accepted
+///
+/// # Returns
+/// VerifyResult or FrostError
+pub fn verify_signature(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+) -> Result<VerifyResult, FrostError> {
+    even_y_key_hex(group_public_key_hex)
+        .map_err(|e| FrostError::new("VERIFY_ERROR", e))
+        .and_then(|even_key_hex| {
+            signing::verify_signature::<Secp256K1Sha256TR>(
+                signature_hex,
+                message_hex,
+                &even_key_hex,
+            )
+        })
+}
+
+/// BIP-340 keys are x-only with implicitly even y: re-encode as a compressed
+/// key with the even prefix
+fn even_y_key_hex(group_public_key_hex: &str) -> Result<String, String> {
+    let group_key_bytes = hex::decode(group_public_key_hex)
+        .map_err(|e| format!("Invalid group public key hex: {}", e))?;
+
+    let x_only: &[u8] = match group_key_bytes.len() {
+        32 => &group_key_bytes,
+        33 => &group_key_bytes[1..],
+        _ => return Err("Public key must be 32 bytes (x-only) or 33 bytes (compressed)".into()),
+    };
+    Ok(format!("02{}", hex::encode(x_only)))
+}
+
+// =============================================================================
+// Emergency Reconstruction
+// =============================================================================
+
+/// DANGER: reassemble the group signing key from `min_signers` key packages.
+///
+/// This defeats the point of threshold signing: whoever runs it holds the
+/// whole key. Only use it for disaster recovery or migrating off FROST, on an
+/// offline machine, and retire the shares afterwards.
+///
+/// # Arguments
+/// * `key_packages_json` - At least `min_signers` key packages (JSON array of KeyPackage JSON strings)
+/// * `public_key_package_json` - Group public key package (JSON)
+///
+/// # Returns
+/// ReconstructedKey (with an x-only group public key) or FrostError
+pub fn emergency_reconstruct_signing_key(
+    key_packages_json: &str,
+    public_key_package_json: &str,
+) -> Result<ReconstructedKey, FrostError> {
+    reconstruct::emergency_reconstruct_signing_key::<Secp256K1Sha256TR>(
+        key_packages_json,
+        public_key_package_json,
+    )
+    .map(|key| ReconstructedKey {
+        group_public_key: x_only_hex(&key.group_public_key),
+        ..key
+    })
+}
+
+// =============================================================================
+// Utility Functions
+// =============================================================================
+
+/// Get the public key from a key package
+///
+/// # Arguments
+/// * `key_package_json` - Key package (JSON)
+///
+/// # Returns
+/// PublicKeyResult or FrostError
+pub fn get_public_key(key_package_json: &str) -> Result<PublicKeyResult, FrostError> {
+    keys::get_public_key::<Secp256K1Sha256TR>(key_package_json)
+}
+
+/// Get the x-only group public key from a public key package
+///
+/// # Arguments
+/// * `public_key_package_json` - Public key package (JSON)
+///
+/// # Returns
+/// Hex-encoded x-only group public key or FrostError
+pub fn get_group_public_key(public_key_package_json: &str) -> Result<String, FrostError> {
+    keys::get_group_public_key::<Secp256K1Sha256TR>(public_key_package_json)
+        .map(|key| x_only_hex(&key))
+}
+
+fn verifying_key_bytes(verifying_key: &frost::VerifyingKey) -> Result<Vec<u8>, String> {
+    verifying_key
+        .serialize()
+        .map_err(|e| format!("Failed to serialize group public key: {:?}", e))
+}
+
+/// Drop the SEC1 prefix byte from a hex-encoded compressed key, leaving the x-only key
+fn x_only_hex(compressed_hex: &str) -> String {
+    compressed_hex[2..].to_string()
+}
+
+fn x_only_key_gen(result: KeyGenResult) -> KeyGenResult {
+    KeyGenResult {
+        group_public_key: x_only_hex(&result.group_public_key),
+        ..result
+    }
+}
+
+fn x_only_dkg(result: DkgResult) -> DkgResult {
+    DkgResult {
+        group_public_key: x_only_hex(&result.group_public_key),
+        ..result
+    }
+}
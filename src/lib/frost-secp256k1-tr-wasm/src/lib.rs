@@ -0,0 +1,910 @@
+//! FROST WASM Bindings for secp256k1 Taproot
+//!
+//! Provides WebAssembly bindings for FROST threshold signature operations
+//! using the secp256k1 BIP-340 ciphersuite (FROST(secp256k1, SHA-256-TR)),
+//! so that the aggregate signature is a valid Bitcoin Taproot key-path
+//! signature.
+//!
+//! The protocol logic lives in `frost-common`; this crate instantiates it with
+//! secp256k1 and adds the Taproot-specific parts:
+//! - Group public keys are x-only (32 bytes), as used by BIP-340 and Taproot
+//! - `*_with_tweak` variants sign for the Taproot output key derived from the
+//!   group key and a script tree merkle root (BIP-341)
+//!
+//! The crate root is the native Rust API: typed functions over the
+//! `frost_secp256k1_tr` types (re-exported as `frost`) returning
+//! `Result<T, FrostError>`. The `json` module layers the serialized wire
+//! format (JSON strings and hex) on top of it, and the `wasm` feature (on by
+//! default) exports that layer through wasm-bindgen in the `wasm` module.
+
+use frost::keys::Tweak;
+use frost_common::keystore::Keystore;
+use frost_common::{dkg, keys, nonces, pool, reconstruct, refresh, repair, reshare, signing};
+use frost_secp256k1_tr::Secp256K1Sha256TR;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+pub use frost_secp256k1_tr as frost;
+
+pub mod json;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+// =============================================================================
+// Types
+// =============================================================================
+
+pub use frost_common::{
+    AggregateError, AggregateSignature, Commitment, CommitmentPool, CommitmentPoolResult,
+    DkgResult, DkgRound1Package, DkgRound1Result, DkgRound2Package, DkgRound2Result, FrostError,
+    KeyGenResult, KeyHandle, KeyShare, NoncePool, NonceRegistryState, PoolSelection,
+    PoolSignatureResult, PublicKeyResult, ReconstructedKey, RefreshSharesResult, RefreshingShare,
+    RepairDelta, RepairSigma, RepairStep1Result, ReshareCommitment, ReshareRound1Result,
+    ReshareSubShare, Round1HandleResult, Round1Result, SealedNonceRegistry,
+    ShareVerificationResult, SignatureShare, SigningNonces, SigningPackage, VerifyResult,
+};
+
+/// A trusted dealer's output: each participant's secret share and the group's
+/// public key package
+pub type DealtShares = keys::DealtShares<Secp256K1Sha256TR>;
+
+/// DKG Round 1 packages, keyed by sender
+pub type Round1Packages = dkg::Round1Packages<Secp256K1Sha256TR>;
+
+/// DKG Round 2 packages, keyed by sender (incoming) or recipient (outgoing)
+pub type Round2Packages = dkg::Round2Packages<Secp256K1Sha256TR>;
+
+/// A resharing dealer's VSS commitment and sub-shares, keyed by new participant
+pub type DealtSubShares = reshare::DealtSubShares<Secp256K1Sha256TR>;
+
+/// Signature shares, keyed by signer
+pub type SignatureShares = signing::SignatureShares<Secp256K1Sha256TR>;
+
+/// Commitments, keyed by signer
+pub type SigningCommitmentsMap = BTreeMap<frost::Identifier, frost::round1::SigningCommitments>;
+
+/// A scalar of the secp256k1 group order, as exchanged during share repair
+pub type Scalar = <frost::Secp256K1ScalarField as frost::Field>::Scalar;
+
+/// A participant's key package after applying a refreshing share
+pub type RefreshedKeyPackage = KeyShare;
+
+/// Taproot internal and output keys for a group
+#[derive(Serialize, Deserialize)]
+pub struct TaprootKeyResult {
+    /// Untweaked group public key (hex-encoded, x-only)
+    pub internal_key: String,
+    /// Tweaked output key Q (hex-encoded, x-only) - goes in the P2TR output
+    pub output_key: String,
+    /// Parity of Q's y coordinate (0 = even, 1 = odd), needed for script-path control blocks
+    pub output_key_parity: u8,
+}
+
+// =============================================================================
+// Key Generation
+// =============================================================================
+
+/// Generate key shares using trusted dealer key generation.
+///
+/// # Arguments
+/// * `threshold` - Minimum number of signers required (t)
+/// * `total` - Total number of participants (n)
+///
+/// # Returns
+/// Each participant's secret share and the public key package, or FrostError
+pub fn generate_key_shares(threshold: u16, total: u16) -> Result<DealtShares, FrostError> {
+    keys::deal_key_shares::<Secp256K1Sha256TR>(threshold, total)
+}
+
+/// Split an existing secp256k1 private key into key shares, keeping its public key.
+///
+/// # Arguments
+/// * `signing_key` - The key to split
+/// * `threshold` - Minimum number of signers required (t)
+/// * `total` - Total number of participants (n)
+///
+/// # Returns
+/// Each participant's secret share and the public key package, or FrostError
+pub fn split_key_shares(
+    signing_key: &frost::SigningKey,
+    threshold: u16,
+    total: u16,
+) -> Result<DealtShares, FrostError> {
+    keys::split_signing_key::<Secp256K1Sha256TR>(signing_key, threshold, total)
+}
+
+/// Verify a dealer-issued secret share and turn it into a key package.
+///
+/// # Arguments
+/// * `secret_share` - This participant's secret share from the dealer
+/// * `public_key_package` - The group's public key package from the dealer
+///
+/// # Returns
+/// KeyPackage or FrostError
+pub fn verify_and_accept_share(
+    secret_share: frost::keys::SecretShare,
+    public_key_package: &frost::keys::PublicKeyPackage,
+) -> Result<frost::keys::KeyPackage, FrostError> {
+    keys::accept_share::<Secp256K1Sha256TR>(secret_share, public_key_package)
+}
+
+// =============================================================================
+// Distributed Key Generation
+// =============================================================================
+
+/// DKG part 1: generate this participant's secret polynomial and the
+/// Round 1 package to broadcast.
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier
+/// * `max_signers` - Total number of participants (n)
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// The Round 1 secret package to keep and the package to broadcast, or FrostError
+pub fn dkg_part1(
+    identifier: frost::Identifier,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<
+    (
+        frost::keys::dkg::round1::SecretPackage,
+        frost::keys::dkg::round1::Package,
+    ),
+    FrostError,
+> {
+    dkg::part1::<Secp256K1Sha256TR>(identifier, max_signers, min_signers)
+}
+
+/// DKG part 2: verify the other participants' Round 1 packages and produce
+/// one Round 2 package per recipient.
+///
+/// # Arguments
+/// * `secret_package` - Secret package from `dkg_part1`
+/// * `round1_packages` - The other participants' Round 1 packages
+///
+/// # Returns
+/// The Round 2 secret package to keep and the packages to send, or FrostError
+pub fn dkg_part2(
+    secret_package: frost::keys::dkg::round1::SecretPackage,
+    round1_packages: &Round1Packages,
+) -> Result<(frost::keys::dkg::round2::SecretPackage, Round2Packages), FrostError> {
+    dkg::part2::<Secp256K1Sha256TR>(secret_package, round1_packages)
+}
+
+/// DKG part 3: verify the Round 2 packages addressed to this participant and
+/// derive the final key package and public key package.
+///
+/// The ciphersuite commits the DKG group key to an unspendable script path
+/// (BIP-341), so it already is a key-path-only output key.
+///
+/// # Arguments
+/// * `secret_package` - Secret package from `dkg_part2`
+/// * `round1_packages` - The other participants' Round 1 packages
+/// * `round2_packages` - The Round 2 packages addressed to this participant
+///
+/// # Returns
+/// KeyPackage and PublicKeyPackage, or FrostError
+pub fn dkg_part3(
+    secret_package: &frost::keys::dkg::round2::SecretPackage,
+    round1_packages: &Round1Packages,
+    round2_packages: &Round2Packages,
+) -> Result<(frost::keys::KeyPackage, frost::keys::PublicKeyPackage), FrostError> {
+    dkg::part3::<Secp256K1Sha256TR>(secret_package, round1_packages, round2_packages)
+}
+
+// =============================================================================
+// Share Refresh
+// =============================================================================
+
+/// Dealer-based refresh: deal zero-secret shares that rotate every
+/// participant's key share without changing the group public key.
+///
+/// # Arguments
+/// * `public_key_package` - Current PublicKeyPackage
+/// * `identifiers` - The participants' identifiers
+/// * `min_signers` - Minimum number of signers required (t), unchanged from keygen
+///
+/// # Returns
+/// The refreshing shares, in `identifiers` order, and the refreshed
+/// PublicKeyPackage, or FrostError
+pub fn compute_refreshing_shares(
+    public_key_package: frost::keys::PublicKeyPackage,
+    identifiers: &[frost::Identifier],
+    min_signers: u16,
+) -> Result<(Vec<frost::keys::SecretShare>, frost::keys::PublicKeyPackage), FrostError> {
+    refresh::deal_refreshing_shares::<Secp256K1Sha256TR>(
+        public_key_package,
+        identifiers,
+        min_signers,
+    )
+}
+
+/// Apply a refreshing share from `compute_refreshing_shares` to a key package.
+///
+/// # Arguments
+/// * `refreshing_share` - This participant's refreshing share
+/// * `key_package` - The participant's current key package
+///
+/// # Returns
+/// The refreshed KeyPackage or FrostError
+pub fn refresh_share(
+    refreshing_share: frost::keys::SecretShare,
+    key_package: &frost::keys::KeyPackage,
+) -> Result<frost::keys::KeyPackage, FrostError> {
+    refresh::apply_refreshing_share::<Secp256K1Sha256TR>(refreshing_share, key_package)
+}
+
+/// Refresh DKG part 1: like `dkg_part1`, but the secret polynomial has a
+/// zero constant term so the group key is unchanged.
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier
+/// * `max_signers` - Total number of participants (n)
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// The Round 1 secret package to keep and the package to broadcast, or FrostError
+pub fn refresh_dkg_part1(
+    identifier: frost::Identifier,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<
+    (
+        frost::keys::dkg::round1::SecretPackage,
+        frost::keys::dkg::round1::Package,
+    ),
+    FrostError,
+> {
+    refresh::refresh_part1::<Secp256K1Sha256TR>(identifier, max_signers, min_signers)
+}
+
+/// Refresh DKG part 2: same as `dkg_part2` over the zero-secret polynomials.
+///
+/// # Arguments
+/// * `secret_package` - Secret package from `refresh_dkg_part1`
+/// * `round1_packages` - The other participants' Round 1 packages
+///
+/// # Returns
+/// The Round 2 secret package to keep and the packages to send, or FrostError
+pub fn refresh_dkg_part2(
+    secret_package: frost::keys::dkg::round1::SecretPackage,
+    round1_packages: &Round1Packages,
+) -> Result<(frost::keys::dkg::round2::SecretPackage, Round2Packages), FrostError> {
+    refresh::refresh_part2::<Secp256K1Sha256TR>(secret_package, round1_packages)
+}
+
+/// Refresh DKG part 3: add the zero-secret shares to this participant's
+/// current key package and derive the updated public key package.
+///
+/// # Arguments
+/// * `secret_package` - Secret package from `refresh_dkg_part2`
+/// * `round1_packages` - The other participants' Round 1 packages
+/// * `round2_packages` - The Round 2 packages addressed to this participant
+/// * `public_key_package` - Current PublicKeyPackage
+/// * `key_package` - This participant's current key package
+///
+/// # Returns
+/// The refreshed KeyPackage and PublicKeyPackage, or FrostError
+pub fn refresh_dkg_part3(
+    secret_package: &frost::keys::dkg::round2::SecretPackage,
+    round1_packages: &Round1Packages,
+    round2_packages: &Round2Packages,
+    public_key_package: frost::keys::PublicKeyPackage,
+    key_package: frost::keys::KeyPackage,
+) -> Result<(frost::keys::KeyPackage, frost::keys::PublicKeyPackage), FrostError> {
+    refresh::refresh_part3::<Secp256K1Sha256TR>(
+        secret_package,
+        round1_packages,
+        round2_packages,
+        public_key_package,
+        key_package,
+    )
+}
+
+// =============================================================================
+// Share Repair
+// =============================================================================
+
+/// Share repair step 1 (helper): split this helper's contribution to the
+/// lost share into one delta per helper.
+///
+/// # Arguments
+/// * `helpers` - Helper identifiers including this one
+/// * `key_package` - This helper's key package
+/// * `participant` - Identifier of the participant whose share is being repaired
+///
+/// # Returns
+/// The deltas, keyed by the helper each goes to, or FrostError
+pub fn repair_share_step_1(
+    helpers: &[frost::Identifier],
+    key_package: &frost::keys::KeyPackage,
+    participant: frost::Identifier,
+) -> Result<BTreeMap<frost::Identifier, Scalar>, FrostError> {
+    repair::repair_step_1::<Secp256K1Sha256TR>(helpers, key_package, participant)
+}
+
+/// Share repair step 2 (helper): combine the deltas received from every
+/// helper into this helper's sigma.
+///
+/// # Arguments
+/// * `deltas` - Deltas addressed to this helper, one from each helper
+///
+/// # Returns
+/// This helper's sigma
+pub fn repair_share_step_2(deltas: &[Scalar]) -> Scalar {
+    repair::repair_step_2::<Secp256K1Sha256TR>(deltas)
+}
+
+/// Share repair step 3 (participant being repaired): rebuild the lost key
+/// package from the helpers' sigmas.
+///
+/// # Arguments
+/// * `sigmas` - One sigma from each helper
+/// * `identifier` - This participant's identifier
+/// * `public_key_package` - Group public key package
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// KeyPackage or FrostError
+pub fn repair_share_step_3(
+    sigmas: &[Scalar],
+    identifier: frost::Identifier,
+    public_key_package: &frost::keys::PublicKeyPackage,
+    min_signers: u16,
+) -> Result<frost::keys::KeyPackage, FrostError> {
+    repair::repair_step_3::<Secp256K1Sha256TR>(sigmas, identifier, public_key_package, min_signers)
+}
+
+// =============================================================================
+// Resharing
+// =============================================================================
+
+/// Reshare part 1 (current holder): deal this holder's weighted share to a
+/// new participant set with a new threshold.
+///
+/// # Arguments
+/// * `key_package` - This dealer's current key package
+/// * `dealers` - Dealer identifiers including this one
+/// * `new_identifiers` - New participant identifiers
+/// * `new_min_signers` - New threshold (t')
+///
+/// # Returns
+/// This dealer's VSS commitment and one sub-share per new participant, or FrostError
+pub fn reshare_part1(
+    key_package: &frost::keys::KeyPackage,
+    dealers: &[frost::Identifier],
+    new_identifiers: &[frost::Identifier],
+    new_min_signers: u16,
+) -> Result<DealtSubShares, FrostError> {
+    reshare::deal_sub_shares::<Secp256K1Sha256TR>(
+        key_package,
+        dealers,
+        new_identifiers,
+        new_min_signers,
+    )
+}
+
+/// Reshare part 2 (new participant): combine the dealers' sub-shares into a
+/// key package for the new participant set.
+///
+/// # Arguments
+/// * `identifier` - This new participant's identifier
+/// * `sub_shares` - Sub-shares addressed to this participant, keyed by dealer
+/// * `commitments` - Every dealer's VSS commitment, keyed by dealer
+/// * `public_key_package` - The old PublicKeyPackage
+/// * `new_identifiers` - New participant identifiers, as passed to part 1
+/// * `new_min_signers` - New threshold (t'), as passed to part 1
+///
+/// # Returns
+/// The new KeyPackage and PublicKeyPackage, or FrostError
+pub fn reshare_part2(
+    identifier: frost::Identifier,
+    sub_shares: &BTreeMap<frost::Identifier, frost::keys::SecretShare>,
+    commitments: &BTreeMap<frost::Identifier, frost::keys::VerifiableSecretSharingCommitment>,
+    public_key_package: &frost::keys::PublicKeyPackage,
+    new_identifiers: &[frost::Identifier],
+    new_min_signers: u16,
+) -> Result<(frost::keys::KeyPackage, frost::keys::PublicKeyPackage), FrostError> {
+    reshare::combine_sub_shares::<Secp256K1Sha256TR>(
+        identifier,
+        sub_shares,
+        commitments,
+        public_key_package,
+        new_identifiers,
+        new_min_signers,
+    )
+}
+
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
+
+/// Generate Round 1 commitment and nonces.
+///
+/// # Arguments
+/// * `key_package` - The participant's key package
+///
+/// # Returns
+/// The nonces to keep secret and the commitments to publish
+pub fn generate_round1_commitment(
+    key_package: &frost::keys::KeyPackage,
+) -> (
+    frost::round1::SigningNonces,
+    frost::round1::SigningCommitments,
+) {
+    signing::round1_commit::<Secp256K1Sha256TR>(key_package)
+}
+
+// =============================================================================
+// Signing Package Creation
+// =============================================================================
+
+/// Create the signing package for one signing session.
+///
+/// # Arguments
+/// * `commitments` - All participants' commitments
+/// * `message` - Message to sign
+///
+/// # Returns
+/// SigningPackage
+pub fn create_signing_package(
+    commitments: SigningCommitmentsMap,
+    message: &[u8],
+) -> frost::SigningPackage {
+    frost::SigningPackage::new(commitments, message)
+}
+
+// =============================================================================
+// Round 2: Signature Share Generation
+// =============================================================================
+
+/// Generate Round 2 signature share for the untweaked group key.
+///
+/// Fails with NONCE_REUSED if the nonces already produced a signature share.
+///
+/// # Arguments
+/// * `signing_package` - The coordinator's signing package
+/// * `nonces` - The participant's nonces from Round 1
+/// * `key_package` - The participant's key package
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature(
+    signing_package: &frost::SigningPackage,
+    nonces: &frost::round1::SigningNonces,
+    key_package: &frost::keys::KeyPackage,
+) -> Result<frost::round2::SignatureShare, FrostError> {
+    signing::round2_sign::<Secp256K1Sha256TR>(signing_package, nonces, key_package)
+}
+
+/// Generate Round 2 signature share for the Taproot output key.
+///
+/// # Arguments
+/// * `signing_package` - The coordinator's signing package, e.g. over the BIP-341 sighash
+/// * `nonces` - The participant's nonces from Round 1
+/// * `key_package` - The participant's (untweaked) key package
+/// * `merkle_root` - Script tree merkle root, or `None` for a key-path-only
+///   output (BIP-86)
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_with_tweak(
+    signing_package: &frost::SigningPackage,
+    nonces: &frost::round1::SigningNonces,
+    key_package: &frost::keys::KeyPackage,
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<frost::round2::SignatureShare, FrostError> {
+    signing::round2_sign::<Secp256K1Sha256TR>(
+        signing_package,
+        nonces,
+        &key_package.clone().tweak(merkle_root),
+    )
+}
+
+// =============================================================================
+// Commitment Pools (Preprocessing)
+// =============================================================================
+
+/// Preprocess a batch of Round 1 nonces.
+///
+/// # Arguments
+/// * `key_package` - The participant's key package
+/// * `count` - Number of nonces to generate
+///
+/// # Returns
+/// The nonce pool or FrostError
+pub fn generate_commitment_pool(
+    key_package: &frost::keys::KeyPackage,
+    count: u32,
+) -> Result<Vec<frost::round1::SigningNonces>, FrostError> {
+    pool::preprocess::<Secp256K1Sha256TR>(key_package, count)
+}
+
+/// Take the oldest unused commitment from each signer's pool (coordinator).
+///
+/// # Arguments
+/// * `pools` - The signers' published commitment pools, keyed by signer
+/// * `signers` - Participant identifiers taking part
+///
+/// # Returns
+/// The selected commitments or FrostError
+pub fn select_pool_commitments(
+    pools: &mut BTreeMap<frost::Identifier, Vec<frost::round1::SigningCommitments>>,
+    signers: &[frost::Identifier],
+) -> Result<SigningCommitmentsMap, FrostError> {
+    pool::select_commitments::<Secp256K1Sha256TR>(pools, signers)
+}
+
+/// Generate a Round 2 signature share using the pooled nonces the
+/// coordinator selected, removing them from the pool even if signing fails.
+///
+/// # Arguments
+/// * `nonce_pool` - The participant's nonce pool
+/// * `signing_package` - The coordinator's signing package
+/// * `key_package` - The participant's key package
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_from_pool(
+    nonce_pool: &mut Vec<frost::round1::SigningNonces>,
+    signing_package: &frost::SigningPackage,
+    key_package: &frost::keys::KeyPackage,
+) -> Result<frost::round2::SignatureShare, FrostError> {
+    pool::sign_from_pool::<Secp256K1Sha256TR>(nonce_pool, signing_package, key_package)
+}
+
+// =============================================================================
+// Nonce Registry
+// =============================================================================
+
+/// Export the nonce registry so it can be persisted across page reloads.
+///
+/// The export is not authenticated, so whoever stores it can drop entries;
+/// `export_sealed_nonce_registry` prevents that.
+///
+/// # Returns
+/// NonceRegistryState
+pub fn export_nonce_registry() -> NonceRegistryState {
+    nonces::export_nonce_registry()
+}
+
+/// Merge a previously exported registry into the current one.
+///
+/// # Arguments
+/// * `state` - NonceRegistryState from `export_nonce_registry`
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_nonce_registry(state: NonceRegistryState) -> Result<NonceRegistryState, FrostError> {
+    nonces::merge_nonce_registry(state)
+}
+
+/// Export the nonce registry sealed under a key package in the keystore.
+///
+/// The entries are encrypted under a key derived from the signing share, so
+/// they cannot be dropped without it. Each export carries a higher counter
+/// than the last one exported or imported for the handle, so after a reload
+/// import the stored export before exporting again.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+///
+/// # Returns
+/// SealedNonceRegistry or FrostError
+pub fn export_sealed_nonce_registry(key_handle: u32) -> Result<SealedNonceRegistry, FrostError> {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().export_nonce_registry(key_handle))
+}
+
+/// Merge a sealed registry into the current one after decrypting it and
+/// checking it is not older than `min_counter`.
+///
+/// Counters are forgotten on reload, so store the `counter` of each export
+/// where the export itself cannot roll it back and pass it here; otherwise a
+/// stale export is accepted after a reload.
+///
+/// # Arguments
+/// * `key_handle` - Handle to the key package that sealed it
+/// * `sealed` - SealedNonceRegistry from `export_sealed_nonce_registry`
+/// * `min_counter` - Counter of the latest export stored, or 0
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_sealed_nonce_registry(
+    key_handle: u32,
+    sealed: SealedNonceRegistry,
+    min_counter: u64,
+) -> Result<NonceRegistryState, FrostError> {
+    KEYSTORE.with(|keystore| {
+        keystore
+            .borrow_mut()
+            .import_nonce_registry(key_handle, sealed, min_counter)
+    })
+}
+
+// =============================================================================
+// Keystore: Opaque Key and Nonce Handles
+// =============================================================================
+
+thread_local! {
+    pub(crate) static KEYSTORE: RefCell<Keystore<Secp256K1Sha256TR>> = RefCell::new(Keystore::default());
+}
+
+/// Load a key package into the keystore and return an opaque handle.
+///
+/// # Arguments
+/// * `key_package` - The participant's key package
+///
+/// # Returns
+/// The key handle
+pub fn load_key_package(key_package: frost::keys::KeyPackage) -> u32 {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().load_key_package(key_package))
+}
+
+/// Load a key package tweaked for a Taproot output key into the keystore.
+///
+/// Signature shares from the returned handle verify under the output key, as
+/// with `generate_round2_signature_with_tweak`.
+///
+/// # Arguments
+/// * `key_package` - The participant's (untweaked) key package
+/// * `merkle_root` - Script tree merkle root, or `None` for a key-path-only
+///   output (BIP-86)
+///
+/// # Returns
+/// The key handle
+pub fn load_key_package_with_tweak(
+    key_package: frost::keys::KeyPackage,
+    merkle_root: Option<&[u8; 32]>,
+) -> u32 {
+    load_key_package(key_package.tweak(merkle_root))
+}
+
+/// Generate a Round 1 commitment, keeping the nonces in the keystore.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+///
+/// # Returns
+/// The nonce handle and the commitments to publish, or FrostError
+pub fn generate_round1_commitment_with_handle(
+    key_handle: u32,
+) -> Result<(u32, frost::round1::SigningCommitments), FrostError> {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().round1_commit(key_handle))
+}
+
+/// Generate a Round 2 signature share from keystore handles.
+///
+/// The nonce handle is consumed and its nonces zeroized, so it cannot be
+/// used for a second signature.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package` or `load_key_package_with_tweak`
+/// * `nonce_handle` - Handle from `generate_round1_commitment_with_handle`
+/// * `signing_package` - The coordinator's signing package
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    signing_package: &frost::SigningPackage,
+) -> Result<frost::round2::SignatureShare, FrostError> {
+    KEYSTORE.with(|keystore| {
+        keystore
+            .borrow_mut()
+            .sign(key_handle, nonce_handle, signing_package)
+    })
+}
+
+/// Destroy a key or nonce handle, zeroizing the secret it refers to.
+///
+/// # Returns
+/// `true` if the handle existed
+pub fn destroy(handle: u32) -> bool {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().destroy(handle))
+}
+
+// =============================================================================
+// Signature Aggregation
+// =============================================================================
+
+/// Aggregate signature shares into a BIP-340 signature for the untweaked group key.
+///
+/// If aggregation fails because of invalid signature shares, the error is an
+/// AggregateError whose `culprits` lists the participants who sent them.
+///
+/// # Arguments
+/// * `signing_package` - The coordinator's signing package
+/// * `shares` - Signature shares, keyed by signer
+/// * `public_key_package` - The group's public key package
+///
+/// # Returns
+/// Signature or AggregateError
+pub fn aggregate_signature(
+    signing_package: &frost::SigningPackage,
+    shares: &SignatureShares,
+    public_key_package: &frost::keys::PublicKeyPackage,
+) -> Result<frost::Signature, AggregateError> {
+    signing::aggregate::<Secp256K1Sha256TR>(signing_package, shares, public_key_package)
+}
+
+/// Aggregate signature shares into a BIP-340 signature for the Taproot output key.
+///
+/// Shares are checked against the tweaked verifying shares, so `culprits`
+/// works as for `aggregate_signature`.
+///
+/// # Arguments
+/// * `signing_package` - The coordinator's signing package
+/// * `shares` - Signature shares, keyed by signer
+/// * `public_key_package` - The group's (untweaked) public key package
+/// * `merkle_root` - Script tree merkle root used in Round 2, or `None`
+///
+/// # Returns
+/// Signature or AggregateError
+pub fn aggregate_signature_with_tweak(
+    signing_package: &frost::SigningPackage,
+    shares: &SignatureShares,
+    public_key_package: &frost::keys::PublicKeyPackage,
+    merkle_root: Option<&[u8; 32]>,
+) -> Result<frost::Signature, AggregateError> {
+    signing::aggregate::<Secp256K1Sha256TR>(
+        signing_package,
+        shares,
+        &public_key_package.clone().tweak(merkle_root),
+    )
+}
+
+/// Verify each signature share against the signer's verifying share.
+///
+/// # Arguments
+/// * `signing_package` - The coordinator's signing package
+/// * `shares` - Signature shares, keyed by signer
+/// * `public_key_package` - The group's public key package
+///
+/// # Returns
+/// ShareVerificationResult or FrostError
+pub fn verify_signature_shares(
+    signing_package: &frost::SigningPackage,
+    shares: &SignatureShares,
+    public_key_package: &frost::keys::PublicKeyPackage,
+) -> Result<ShareVerificationResult, FrostError> {
+    signing::verify_shares::<Secp256K1Sha256TR>(signing_package, shares, public_key_package)
+}
+
+// =============================================================================
+// Taproot Tweaking
+// =============================================================================
+
+/// Derive the Taproot output key for the group.
+///
+/// The output key is `Q = P + H_TapTweak(P || merkle_root) * G` where `P` is
+/// the x-only group key (BIP-341). Signatures from the `*_with_tweak`
+/// functions with the same merkle root verify under `Q`.
+///
+/// # Arguments
+/// * `public_key_package` - The group's public key package
+/// * `merkle_root` - Script tree merkle root, or `None` for a key-path-only
+///   output (BIP-86)
+///
+/// # Returns
+/// The output key Q
+pub fn tweak_group_public_key(
+    public_key_package: &frost::keys::PublicKeyPackage,
+    merkle_root: Option<&[u8; 32]>,
+) -> frost::VerifyingKey {
+    *public_key_package
+        .clone()
+        .tweak(merkle_root)
+        .verifying_key()
+}
+
+// =============================================================================
+// Verification
+// =============================================================================
+
+/// Verify a BIP-340 Schnorr signature.
+///
+/// # Arguments
+/// * `signature` - The aggregate signature
+/// * `message` - The message that was signed
+/// * `group_public_key` - The group public key (for an x-only key, the even-y
+///   point with that x coordinate)
+///
+/// # Returns
+/// `true` if the signature is valid
+pub fn verify_signature(
+    signature: &frost::Signature,
+    message: &[u8],
+    group_public_key: &frost::VerifyingKey,
+) -> bool {
+    group_public_key.verify(message, signature).is_ok()
+}
+
+// =============================================================================
+// Emergency Reconstruction
+// =============================================================================
+
+/// DANGER: reassemble the group signing key from `min_signers` key packages.
+///
+/// This defeats the point of threshold signing: whoever runs it holds the
+/// whole key. Only use it for disaster recovery or migrating off FROST, on an
+/// offline machine, and retire the shares afterwards.
+///
+/// # Arguments
+/// * `key_packages` - At least `min_signers` key packages
+/// * `public_key_package` - Group public key package
+///
+/// # Returns
+/// The group SigningKey or FrostError
+pub fn emergency_reconstruct_signing_key(
+    key_packages: &[frost::keys::KeyPackage],
+    public_key_package: &frost::keys::PublicKeyPackage,
+) -> Result<frost::SigningKey, FrostError> {
+    reconstruct::reconstruct_signing_key::<Secp256K1Sha256TR>(key_packages, public_key_package)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_signing_flow() {
+        let (shares, public_key_package) =
+            generate_key_shares(2, 3).expect("Key generation failed");
+        let key_packages: Vec<frost::keys::KeyPackage> = shares
+            .into_values()
+            .take(2)
+            .map(|share| verify_and_accept_share(share, &public_key_package).unwrap())
+            .collect();
+        let message = b"Hello World";
+        let merkle_root = [7u8; 32];
+
+        let round1: Vec<_> = key_packages
+            .iter()
+            .map(generate_round1_commitment)
+            .collect();
+        let commitments = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(k, (_, c))| (*k.identifier(), *c))
+            .collect();
+        let signing_package = create_signing_package(commitments, message);
+
+        let shares: SignatureShares = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(k, (nonces, _))| {
+                let share = generate_round2_signature_with_tweak(
+                    &signing_package,
+                    nonces,
+                    k,
+                    Some(&merkle_root),
+                )
+                .expect("Round 2 failed");
+                (*k.identifier(), share)
+            })
+            .collect();
+
+        let signature = aggregate_signature_with_tweak(
+            &signing_package,
+            &shares,
+            &public_key_package,
+            Some(&merkle_root),
+        )
+        .expect("Aggregation failed");
+
+        let output_key = tweak_group_public_key(&public_key_package, Some(&merkle_root));
+        assert!(verify_signature(&signature, message, &output_key));
+        assert!(!verify_signature(
+            &signature,
+            message,
+            public_key_package.verifying_key()
+        ));
+    }
+}
@@ -0,0 +1,1197 @@
+//! wasm-bindgen exports
+//!
+//! Each export takes the same arguments as the function of the same name in
+//! the `json` module and returns its result as a JSON string: the value on
+//! success, the FrostError (or AggregateError) otherwise.
+
+use frost_common::to_json;
+use wasm_bindgen::prelude::*;
+
+use crate::FrostError;
+
+// Initialize panic hook for better error messages in WASM
+#[wasm_bindgen(start)]
+pub fn init() {
+    #[cfg(feature = "console_error_panic_hook")]
+    console_error_panic_hook::set_once();
+}
+
+// =============================================================================
+// Key Generation
+// =============================================================================
+
+/// Generate key shares using trusted dealer key generation.
+///
+/// # Returns
+/// JSON string containing KeyGenResult or FrostError
+#[wasm_bindgen]
+pub fn generate_key_shares(threshold: u16, total: u16) -> String {
+    to_json(crate::json::generate_key_shares(threshold, total))
+}
+
+/// Split an existing secp256k1 private key into key shares, keeping its public key.
+///
+/// # Returns
+/// JSON string containing KeyGenResult or FrostError
+#[wasm_bindgen]
+pub fn split_key_shares(secret_hex: &str, threshold: u16, total: u16) -> String {
+    to_json(crate::json::split_key_shares(secret_hex, threshold, total))
+}
+
+/// Verify a dealer-issued secret share and turn it into a key package.
+///
+/// # Returns
+/// JSON string containing KeyShare or FrostError
+#[wasm_bindgen]
+pub fn verify_and_accept_share(secret_share_json: &str, public_key_package_json: &str) -> String {
+    to_json(crate::json::verify_and_accept_share(
+        secret_share_json,
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Distributed Key Generation
+// =============================================================================
+
+/// DKG part 1: generate this participant's secret polynomial and the
+/// Round 1 package to broadcast.
+///
+/// # Returns
+/// JSON string containing DkgRound1Result or FrostError
+#[wasm_bindgen]
+pub fn dkg_part1(identifier: u16, max_signers: u16, min_signers: u16) -> String {
+    to_json(crate::json::dkg_part1(identifier, max_signers, min_signers))
+}
+
+/// DKG part 2: verify the other participants' Round 1 packages and produce
+/// one Round 2 package per recipient.
+///
+/// # Returns
+/// JSON string containing DkgRound2Result or FrostError
+#[wasm_bindgen]
+pub fn dkg_part2(secret_package_json: &str, round1_packages_json: &str) -> String {
+    to_json(crate::json::dkg_part2(
+        secret_package_json,
+        round1_packages_json,
+    ))
+}
+
+/// DKG part 3: verify the Round 2 packages addressed to this participant and
+/// derive the final key package and public key package.
+///
+/// # Returns
+/// JSON string containing DkgResult or FrostError
+#[wasm_bindgen]
+pub fn dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+) -> String {
+    to_json(crate::json::dkg_part3(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+    ))
+}
+
+// =============================================================================
+// Share Refresh
+// =============================================================================
+
+/// Dealer-based refresh: deal zero-secret shares that rotate every
+/// participant's key share without changing the group public key.
+///
+/// # Returns
+/// JSON string containing RefreshSharesResult or FrostError
+#[wasm_bindgen]
+pub fn compute_refreshing_shares(
+    public_key_package_json: &str,
+    identifiers_json: &str,
+    min_signers: u16,
+) -> String {
+    to_json(crate::json::compute_refreshing_shares(
+        public_key_package_json,
+        identifiers_json,
+        min_signers,
+    ))
+}
+
+/// Apply a refreshing share from `compute_refreshing_shares` to a key package.
+///
+/// # Returns
+/// JSON string containing RefreshedKeyPackage or FrostError
+#[wasm_bindgen]
+pub fn refresh_share(refreshing_share_json: &str, key_package_json: &str) -> String {
+    to_json(crate::json::refresh_share(
+        refreshing_share_json,
+        key_package_json,
+    ))
+}
+
+/// Refresh DKG part 1: like `dkg_part1`, but the secret polynomial has a
+/// zero constant term so the group key is unchanged.
+///
+/// # Returns
+/// JSON string containing DkgRound1Result or FrostError
+#[wasm_bindgen]
+pub fn refresh_dkg_part1(identifier: u16, max_signers: u16, min_signers: u16) -> String {
+    to_json(crate::json::refresh_dkg_part1(
+        identifier,
+        max_signers,
+        min_signers,
+    ))
+}
+
+/// Refresh DKG part 2: same wire format as `dkg_part2`.
+///
+/// # Returns
+/// JSON string containing DkgRound2Result or FrostError
+#[wasm_bindgen]
+pub fn refresh_dkg_part2(secret_package_json: &str, round1_packages_json: &str) -> String {
+    to_json(crate::json::refresh_dkg_part2(
+        secret_package_json,
+        round1_packages_json,
+    ))
+}
+
+/// Refresh DKG part 3: add the zero-secret shares to this participant's
+/// current key package and derive the updated public key package.
+///
+/// # Returns
+/// JSON string containing DkgResult or FrostError
+#[wasm_bindgen]
+pub fn refresh_dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+    public_key_package_json: &str,
+    key_package_json: &str,
+) -> String {
+    to_json(crate::json::refresh_dkg_part3(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+        public_key_package_json,
+        key_package_json,
+    ))
+}
+
+// =============================================================================
+// Share Repair
+// =============================================================================
+
+/// Share repair step 1 (helper): split this helper's contribution to the
+/// lost share into one delta per helper.
+///
+/// # Returns
+/// JSON string containing RepairStep1Result or FrostError
+#[wasm_bindgen]
+pub fn repair_share_step_1(helpers_json: &str, key_package_json: &str, participant: u16) -> String {
+    to_json(crate::json::repair_share_step_1(
+        helpers_json,
+        key_package_json,
+        participant,
+    ))
+}
+
+/// Share repair step 2 (helper): combine the deltas received from every
+/// helper into this helper's sigma.
+///
+/// # Returns
+/// JSON string containing RepairSigma or FrostError
+#[wasm_bindgen]
+pub fn repair_share_step_2(deltas_json: &str) -> String {
+    to_json(crate::json::repair_share_step_2(deltas_json))
+}
+
+/// Share repair step 3 (participant being repaired): rebuild the lost key
+/// package from the helpers' sigmas.
+///
+/// # Returns
+/// JSON string containing KeyShare or FrostError
+#[wasm_bindgen]
+pub fn repair_share_step_3(
+    sigmas_json: &str,
+    identifier: u16,
+    public_key_package_json: &str,
+    min_signers: u16,
+) -> String {
+    to_json(crate::json::repair_share_step_3(
+        sigmas_json,
+        identifier,
+        public_key_package_json,
+        min_signers,
+    ))
+}
+
+// =============================================================================
+// Resharing
+// =============================================================================
+
+/// Reshare part 1 (current holder): deal this holder's weighted share to a
+/// new participant set with a new threshold.
+///
+/// # Returns
+/// JSON string containing ReshareRound1Result or FrostError
+#[wasm_bindgen]
+pub fn reshare_part1(
+    key_package_json: &str,
+    dealers_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> String {
+    to_json(crate::json::reshare_part1(
+        key_package_json,
+        dealers_json,
+        new_identifiers_json,
+        new_min_signers,
+    ))
+}
+
+/// Reshare part 2 (new participant): combine the dealers' sub-shares into a
+/// key package for the new participant set.
+///
+/// # Returns
+/// JSON string containing DkgResult or FrostError
+#[wasm_bindgen]
+pub fn reshare_part2(
+    identifier: u16,
+    sub_shares_json: &str,
+    commitments_json: &str,
+    public_key_package_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> String {
+    to_json(crate::json::reshare_part2(
+        identifier,
+        sub_shares_json,
+        commitments_json,
+        public_key_package_json,
+        new_identifiers_json,
+        new_min_signers,
+    ))
+}
+
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
+
+/// Generate Round 1 commitment and nonces.
+///
+/// # Returns
+/// JSON string containing Round1Result or FrostError
+#[wasm_bindgen]
+pub fn generate_round1_commitment(key_package_json: &str) -> String {
+    to_json(crate::json::generate_round1_commitment(key_package_json))
+}
+
+// =============================================================================
+// Signing Package Creation
+// =============================================================================
+
+/// Create the signing package for one signing session.
+///
+/// # Returns
+/// JSON string containing SigningPackage or FrostError
+#[wasm_bindgen]
+pub fn create_signing_package(commitments_json: &str, message_hex: &str) -> String {
+    to_json(crate::json::create_signing_package(
+        commitments_json,
+        message_hex,
+    ))
+}
+
+// =============================================================================
+// Round 2: Signature Share Generation
+// =============================================================================
+
+/// Generate Round 2 signature share for the untweaked group key.
+///
+/// # Returns
+/// JSON string containing SignatureShare or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature(
+    key_package_json: &str,
+    nonces_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature(
+        key_package_json,
+        nonces_json,
+        commitments_json,
+        message_hex,
+    ))
+}
+
+/// Generate Round 2 signature share for the Taproot output key.
+///
+/// # Returns
+/// JSON string containing SignatureShare or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature_with_tweak(
+    key_package_json: &str,
+    nonces_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    merkle_root_hex: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature_with_tweak(
+        key_package_json,
+        nonces_json,
+        commitments_json,
+        message_hex,
+        merkle_root_hex,
+    ))
+}
+
+/// Generate Round 2 signature share over the coordinator's signing package.
+///
+/// # Returns
+/// JSON string containing SignatureShare or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature_with_package(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature_with_package(
+        key_package_json,
+        nonces_json,
+        signing_package_json,
+    ))
+}
+
+/// Generate Round 2 signature share for the Taproot output key over the
+/// coordinator's signing package.
+///
+/// # Returns
+/// JSON string containing SignatureShare or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature_with_package_and_tweak(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+    merkle_root_hex: &str,
+) -> String {
+    to_json(
+        crate::json::generate_round2_signature_with_package_and_tweak(
+            key_package_json,
+            nonces_json,
+            signing_package_json,
+            merkle_root_hex,
+        ),
+    )
+}
+
+// =============================================================================
+// Commitment Pools (Preprocessing)
+// =============================================================================
+
+/// Preprocess a batch of Round 1 commitments.
+///
+/// # Returns
+/// JSON string containing CommitmentPoolResult or FrostError
+#[wasm_bindgen]
+pub fn generate_commitment_pool(key_package_json: &str, count: u32) -> String {
+    to_json(crate::json::generate_commitment_pool(
+        key_package_json,
+        count,
+    ))
+}
+
+/// Take the oldest unused commitment from each signer's pool (coordinator).
+///
+/// # Returns
+/// JSON string containing PoolSelection or FrostError
+#[wasm_bindgen]
+pub fn select_pool_commitments(pools_json: &str, signers_json: &str) -> String {
+    to_json(crate::json::select_pool_commitments(
+        pools_json,
+        signers_json,
+    ))
+}
+
+/// Generate a Round 2 signature share using the pooled nonces the
+/// coordinator selected.
+///
+/// # Returns
+/// JSON string containing PoolSignatureResult or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature_from_pool(
+    key_package_json: &str,
+    nonce_pool_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature_from_pool(
+        key_package_json,
+        nonce_pool_json,
+        commitments_json,
+        message_hex,
+    ))
+}
+
+// =============================================================================
+// Nonce Registry
+// =============================================================================
+
+/// Export the nonce registry so it can be persisted across page reloads.
+///
+/// # Returns
+/// JSON string containing NonceRegistryState
+#[wasm_bindgen]
+pub fn export_nonce_registry() -> String {
+    to_json::<_, FrostError>(Ok(crate::export_nonce_registry()))
+}
+
+/// Merge a previously exported registry into the current one.
+///
+/// # Returns
+/// JSON string containing the merged NonceRegistryState or FrostError
+#[wasm_bindgen]
+pub fn import_nonce_registry(state_json: &str) -> String {
+    to_json(crate::json::import_nonce_registry(state_json))
+}
+
+/// Export the nonce registry sealed under a key package in the keystore.
+///
+/// # Returns
+/// JSON string containing SealedNonceRegistry or FrostError
+#[wasm_bindgen]
+pub fn export_sealed_nonce_registry(key_handle: u32) -> String {
+    to_json(crate::export_sealed_nonce_registry(key_handle))
+}
+
+/// Merge a sealed registry into the current one after decrypting it and
+/// checking it is not older than `min_counter` (a BigInt in JavaScript: the
+/// `counter` of the latest export stored, or 0).
+///
+/// # Returns
+/// JSON string containing the merged NonceRegistryState or FrostError
+#[wasm_bindgen]
+pub fn import_sealed_nonce_registry(
+    key_handle: u32,
+    sealed_json: &str,
+    min_counter: u64,
+) -> String {
+    to_json(crate::json::import_sealed_nonce_registry(
+        key_handle,
+        sealed_json,
+        min_counter,
+    ))
+}
+
+// =============================================================================
+// Keystore: Opaque Key and Nonce Handles
+// =============================================================================
+
+/// Load a key package into the keystore and return an opaque handle.
+///
+/// # Returns
+/// JSON string containing KeyHandle or FrostError
+#[wasm_bindgen]
+pub fn load_key_package(key_package_json: &str) -> String {
+    to_json(crate::json::load_key_package(key_package_json))
+}
+
+/// Load a key package tweaked for a Taproot output key into the keystore.
+///
+/// # Returns
+/// JSON string containing KeyHandle or FrostError
+#[wasm_bindgen]
+pub fn load_key_package_with_tweak(key_package_json: &str, merkle_root_hex: &str) -> String {
+    to_json(crate::json::load_key_package_with_tweak(
+        key_package_json,
+        merkle_root_hex,
+    ))
+}
+
+/// Generate a Round 1 commitment, keeping the nonces in the keystore.
+///
+/// # Returns
+/// JSON string containing Round1HandleResult or FrostError
+#[wasm_bindgen]
+pub fn generate_round1_commitment_with_handle(key_handle: u32) -> String {
+    to_json(crate::json::generate_round1_commitment_with_handle(
+        key_handle,
+    ))
+}
+
+/// Generate a Round 2 signature share from keystore handles.
+///
+/// # Returns
+/// JSON string containing SignatureShare or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    commitments_json: &str,
+    message_hex: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature_with_handles(
+        key_handle,
+        nonce_handle,
+        commitments_json,
+        message_hex,
+    ))
+}
+
+/// Destroy a key or nonce handle, zeroizing the secret it refers to.
+///
+/// # Returns
+/// `true` if the handle existed
+#[wasm_bindgen]
+pub fn destroy(handle: u32) -> bool {
+    crate::destroy(handle)
+}
+
+// =============================================================================
+// Signature Aggregation
+// =============================================================================
+
+/// Aggregate signature shares into a BIP-340 signature for the untweaked group key.
+///
+/// # Returns
+/// JSON string containing AggregateSignature or AggregateError
+#[wasm_bindgen]
+pub fn aggregate_signature(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::aggregate_signature(
+        shares_json,
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    ))
+}
+
+/// Aggregate signature shares into a BIP-340 signature for the Taproot output key.
+///
+/// # Returns
+/// JSON string containing AggregateSignature or AggregateError
+#[wasm_bindgen]
+pub fn aggregate_signature_with_tweak(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+    merkle_root_hex: &str,
+) -> String {
+    to_json(crate::json::aggregate_signature_with_tweak(
+        shares_json,
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+        merkle_root_hex,
+    ))
+}
+
+/// Aggregate signature shares over the coordinator's signing package.
+///
+/// # Returns
+/// JSON string containing AggregateSignature or AggregateError
+#[wasm_bindgen]
+pub fn aggregate_signature_with_package(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::aggregate_signature_with_package(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+    ))
+}
+
+/// Aggregate signature shares over the coordinator's signing package into a
+/// BIP-340 signature for the Taproot output key.
+///
+/// # Returns
+/// JSON string containing AggregateSignature or AggregateError
+#[wasm_bindgen]
+pub fn aggregate_signature_with_package_and_tweak(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    merkle_root_hex: &str,
+) -> String {
+    to_json(crate::json::aggregate_signature_with_package_and_tweak(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+        merkle_root_hex,
+    ))
+}
+
+/// Verify each signature share against the signer's verifying share.
+///
+/// # Returns
+/// JSON string containing ShareVerificationResult or FrostError
+#[wasm_bindgen]
+pub fn verify_signature_shares(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::verify_signature_shares(
+        shares_json,
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Taproot Tweaking
+// =============================================================================
+
+/// Derive the Taproot output key for the group.
+///
+/// # Returns
+/// JSON string containing TaprootKeyResult or FrostError
+#[wasm_bindgen]
+pub fn tweak_group_public_key(public_key_package_json: &str, merkle_root_hex: &str) -> String {
+    to_json(crate::json::tweak_group_public_key(
+        public_key_package_json,
+        merkle_root_hex,
+    ))
+}
+
+// =============================================================================
+// Verification
+// =============================================================================
+
+/// Verify a BIP-340 Schnorr signature.
+///
+/// # Returns
+/// JSON string containing { "valid": bool } or FrostError
+#[wasm_bindgen]
+pub fn verify_signature(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+) -> String {
+    to_json(crate::json::verify_signature(
+        signature_hex,
+        message_hex,
+        group_public_key_hex,
+    ))
+}
+
+// =============================================================================
+// Emergency Reconstruction
+// =============================================================================
+
+/// DANGER: reassemble the group signing key from `min_signers` key packages.
+///
+/// # Returns
+/// JSON string containing ReconstructedKey or FrostError
+#[wasm_bindgen]
+pub fn emergency_reconstruct_signing_key(
+    key_packages_json: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::emergency_reconstruct_signing_key(
+        key_packages_json,
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Utility Functions
+// =============================================================================
+
+/// Get the public key from a key package
+///
+/// # Returns
+/// JSON string with public key (hex) or FrostError
+#[wasm_bindgen]
+pub fn get_public_key(key_package_json: &str) -> String {
+    to_json(crate::json::get_public_key(key_package_json))
+}
+
+/// Get the x-only group public key from a public key package
+///
+/// # Returns
+/// Hex-encoded x-only group public key or error
+#[wasm_bindgen]
+pub fn get_group_public_key(public_key_package_json: &str) -> String {
+    crate::json::get_group_public_key(public_key_package_json)
+        .unwrap_or_else(|e| to_json::<(), _>(Err(e)))
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AggregateError, AggregateSignature, Commitment, CommitmentPoolResult, DkgResult,
+        DkgRound1Package, DkgRound1Result, DkgRound2Package, DkgRound2Result, KeyGenResult,
+        KeyHandle, NonceRegistryState, ReconstructedKey, RefreshSharesResult, RefreshedKeyPackage,
+        RepairStep1Result, ReshareRound1Result, Round1HandleResult, Round1Result,
+        SealedNonceRegistry, ShareVerificationResult, SignatureShare, TaprootKeyResult,
+    };
+
+    /// Run a 2-of-3 ceremony with participants 1 and 2, returning the signature hex
+    fn sign(keygen: &KeyGenResult, message: &str, merkle_root: Option<&str>) -> String {
+        let signers = [&keygen.shares[0], &keygen.shares[1]];
+
+        let round1: Vec<Round1Result> = signers
+            .iter()
+            .map(|s| {
+                serde_json::from_str(&generate_round1_commitment(&s.key_package))
+                    .expect("Round 1 failed")
+            })
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let shares: Vec<SignatureShare> = signers
+            .iter()
+            .zip(&round1)
+            .map(|(s, r)| {
+                let nonces = serde_json::to_string(&r.nonces).unwrap();
+                let result = match merkle_root {
+                    None => generate_round2_signature(
+                        &s.key_package,
+                        &nonces,
+                        &commitments_json,
+                        message,
+                    ),
+                    Some(root) => generate_round2_signature_with_tweak(
+                        &s.key_package,
+                        &nonces,
+                        &commitments_json,
+                        message,
+                        root,
+                    ),
+                };
+                serde_json::from_str(&result).expect("Round 2 failed")
+            })
+            .collect();
+        let shares_json = serde_json::to_string(&shares).unwrap();
+
+        let agg_result = match merkle_root {
+            None => aggregate_signature(
+                &shares_json,
+                &commitments_json,
+                message,
+                &keygen.public_key_package,
+            ),
+            Some(root) => aggregate_signature_with_tweak(
+                &shares_json,
+                &commitments_json,
+                message,
+                &keygen.public_key_package,
+                root,
+            ),
+        };
+        let agg: AggregateSignature =
+            serde_json::from_str(&agg_result).expect("Aggregation failed");
+        agg.signature
+    }
+
+    /// Run both signing rounds with the given key packages, returning the raw aggregate output
+    fn sign_with(key_packages: &[&str], public_key_package: &str, message: &str) -> String {
+        let round1: Vec<Round1Result> = key_packages
+            .iter()
+            .map(|kp| serde_json::from_str(&generate_round1_commitment(kp)).unwrap())
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let shares: Vec<SignatureShare> = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(kp, r)| {
+                let nonces = serde_json::to_string(&r.nonces).unwrap();
+                serde_json::from_str(&generate_round2_signature(
+                    kp,
+                    &nonces,
+                    &commitments_json,
+                    message,
+                ))
+                .unwrap()
+            })
+            .collect();
+
+        aggregate_signature(
+            &serde_json::to_string(&shares).unwrap(),
+            &commitments_json,
+            message,
+            public_key_package,
+        )
+    }
+
+    fn is_valid(signature: &str, message: &str, public_key: &str) -> bool {
+        let verify: serde_json::Value =
+            serde_json::from_str(&verify_signature(signature, message, public_key)).unwrap();
+        verify["valid"] == true
+    }
+
+    #[test]
+    fn test_full_signing_flow() {
+        let keygen: KeyGenResult =
+            serde_json::from_str(&generate_key_shares(2, 3)).expect("Key generation failed");
+        assert_eq!(keygen.shares.len(), 3);
+        assert_eq!(keygen.shares[2].identifier, 3);
+
+        // x-only group key
+        assert_eq!(keygen.group_public_key.len(), 64);
+
+        let message = "48656c6c6f20576f726c64"; // "Hello World" in hex
+        let signature = sign(&keygen, message, None);
+        assert_eq!(signature.len(), 128);
+        assert!(is_valid(&signature, message, &keygen.group_public_key));
+    }
+
+    #[test]
+    fn test_taproot_tweaked_signing() {
+        let keygen: KeyGenResult =
+            serde_json::from_str(&generate_key_shares(2, 3)).expect("Key generation failed");
+        let message = "aa".repeat(32);
+
+        for merkle_root in ["".to_string(), "5c".repeat(32)] {
+            let taproot: TaprootKeyResult = serde_json::from_str(&tweak_group_public_key(
+                &keygen.public_key_package,
+                &merkle_root,
+            ))
+            .expect("Tweak failed");
+            assert_eq!(taproot.internal_key, keygen.group_public_key);
+            assert_ne!(taproot.output_key, taproot.internal_key);
+
+            let signature = sign(&keygen, &message, Some(merkle_root.as_str()));
+            assert!(is_valid(&signature, &message, &taproot.output_key));
+            assert!(!is_valid(&signature, &message, &taproot.internal_key));
+        }
+    }
+
+    #[test]
+    fn test_taproot_signing_with_handles() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let merkle_root = "5c".repeat(32);
+        let message = "aa".repeat(32);
+
+        let key_handles: Vec<KeyHandle> = keygen.shares[..2]
+            .iter()
+            .map(|share| {
+                serde_json::from_str(&load_key_package_with_tweak(
+                    &share.key_package,
+                    &merkle_root,
+                ))
+                .expect("Loading a tweaked key should succeed")
+            })
+            .collect();
+        let round1: Vec<Round1HandleResult> = key_handles
+            .iter()
+            .map(|k| {
+                serde_json::from_str(&generate_round1_commitment_with_handle(k.handle)).unwrap()
+            })
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let shares: Vec<SignatureShare> = key_handles
+            .iter()
+            .zip(&round1)
+            .map(|(k, r)| {
+                serde_json::from_str(&generate_round2_signature_with_handles(
+                    k.handle,
+                    r.nonce_handle,
+                    &commitments_json,
+                    &message,
+                ))
+                .expect("Round 2 with handles should succeed")
+            })
+            .collect();
+
+        let agg: AggregateSignature = serde_json::from_str(&aggregate_signature_with_tweak(
+            &serde_json::to_string(&shares).unwrap(),
+            &commitments_json,
+            &message,
+            &keygen.public_key_package,
+            &merkle_root,
+        ))
+        .expect("Should aggregate");
+
+        let taproot: TaprootKeyResult = serde_json::from_str(&tweak_group_public_key(
+            &keygen.public_key_package,
+            &merkle_root,
+        ))
+        .unwrap();
+        assert!(is_valid(&agg.signature, &message, &taproot.output_key));
+
+        for k in &key_handles {
+            assert!(destroy(k.handle));
+        }
+
+        let error: FrostError = serde_json::from_str(&load_key_package_with_tweak(
+            &keygen.shares[0].key_package,
+            "ab",
+        ))
+        .expect("A short merkle root should fail");
+        assert_eq!(error.code, "KEYSTORE_ERROR");
+    }
+
+    #[test]
+    fn test_identifies_cheating_signer_with_tweak() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let signers = [&keygen.shares[0], &keygen.shares[1]];
+        let merkle_root = "5c".repeat(32);
+
+        let round1: Vec<Round1Result> = signers
+            .iter()
+            .map(|s| serde_json::from_str(&generate_round1_commitment(&s.key_package)).unwrap())
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        // Participant 2 signs for the untweaked key instead of the output key
+        let message = "48656c6c6f20576f726c64";
+        let shares: Vec<SignatureShare> = signers
+            .iter()
+            .zip(&round1)
+            .zip([Some(merkle_root.as_str()), None])
+            .map(|((s, r), root)| {
+                let nonces = serde_json::to_string(&r.nonces).unwrap();
+                let result = match root {
+                    Some(root) => generate_round2_signature_with_tweak(
+                        &s.key_package,
+                        &nonces,
+                        &commitments_json,
+                        message,
+                        root,
+                    ),
+                    None => generate_round2_signature(
+                        &s.key_package,
+                        &nonces,
+                        &commitments_json,
+                        message,
+                    ),
+                };
+                serde_json::from_str(&result).unwrap()
+            })
+            .collect();
+        let shares_json = serde_json::to_string(&shares).unwrap();
+
+        let error: AggregateError = serde_json::from_str(&aggregate_signature_with_tweak(
+            &shares_json,
+            &commitments_json,
+            message,
+            &keygen.public_key_package,
+            &merkle_root,
+        ))
+        .expect("Aggregation should fail");
+        assert_eq!(error.code, "AGGREGATE_ERROR");
+        assert_eq!(error.culprits, vec![2]);
+
+        // Against the untweaked key it is participant 1's share that is wrong
+        let check: ShareVerificationResult = serde_json::from_str(&verify_signature_shares(
+            &shares_json,
+            &commitments_json,
+            message,
+            &keygen.public_key_package,
+        ))
+        .expect("Share verification should run");
+        assert_eq!(check.culprits, vec![1]);
+    }
+
+    #[test]
+    fn test_dkg_signing_flow() {
+        let (min_signers, max_signers) = (2u16, 3u16);
+
+        let round1: Vec<DkgRound1Result> = (1..=max_signers)
+            .map(|id| {
+                let result = dkg_part1(id, max_signers, min_signers);
+                serde_json::from_str(&result).expect("DKG part 1 failed")
+            })
+            .collect();
+
+        let others_round1 = |id: u16| {
+            let packages: Vec<DkgRound1Package> = round1
+                .iter()
+                .filter(|r| r.package.identifier != id)
+                .map(|r| r.package.clone())
+                .collect();
+            serde_json::to_string(&packages).unwrap()
+        };
+
+        let round2: Vec<DkgRound2Result> = round1
+            .iter()
+            .map(|r| {
+                let result = dkg_part2(&r.secret_package, &others_round1(r.package.identifier));
+                serde_json::from_str(&result).expect("DKG part 2 failed")
+            })
+            .collect();
+
+        let results: Vec<DkgResult> = (1..=max_signers)
+            .map(|id| {
+                let inbox: Vec<DkgRound2Package> = round2
+                    .iter()
+                    .flat_map(|r| r.packages.iter())
+                    .filter(|p| p.recipient == id)
+                    .cloned()
+                    .collect();
+                let result = dkg_part3(
+                    &round2[(id - 1) as usize].secret_package,
+                    &others_round1(id),
+                    &serde_json::to_string(&inbox).unwrap(),
+                );
+                serde_json::from_str(&result).expect("DKG part 3 failed")
+            })
+            .collect();
+
+        let group_public_key = &results[0].group_public_key;
+        assert_eq!(group_public_key.len(), 64);
+        assert!(results
+            .iter()
+            .all(|r| &r.group_public_key == group_public_key));
+
+        let message = "48656c6c6f20576f726c64";
+        let agg: AggregateSignature = serde_json::from_str(&sign_with(
+            &[&results[0].key_package, &results[2].key_package],
+            &results[1].public_key_package,
+            message,
+        ))
+        .expect("Aggregation failed");
+        assert!(is_valid(&agg.signature, message, group_public_key));
+    }
+
+    #[test]
+    fn test_split_existing_key() {
+        // BIP-340 test vector 1
+        let secret = "b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef";
+        let public_key = "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659";
+
+        let keygen: KeyGenResult = serde_json::from_str(&split_key_shares(secret, 2, 3))
+            .expect("Splitting a key should succeed");
+        assert_eq!(keygen.group_public_key, public_key);
+
+        let message = "48656c6c6f20576f726c64";
+        let agg: AggregateSignature = serde_json::from_str(&sign_with(
+            &[&keygen.shares[0].key_package, &keygen.shares[2].key_package],
+            &keygen.public_key_package,
+            message,
+        ))
+        .expect("Split shares should sign");
+        assert!(is_valid(&agg.signature, message, public_key));
+
+        let key: ReconstructedKey = serde_json::from_str(&emergency_reconstruct_signing_key(
+            &serde_json::to_string(&[&keygen.shares[1].key_package, &keygen.shares[2].key_package])
+                .unwrap(),
+            &keygen.public_key_package,
+        ))
+        .expect("Reconstruction should succeed");
+        assert_eq!(key.signing_key, secret);
+        assert_eq!(key.group_public_key, public_key);
+
+        let error: FrostError =
+            serde_json::from_str(&split_key_shares("00", 2, 3)).expect("Bad key");
+        assert_eq!(error.code, "KEYGEN_ERROR");
+    }
+
+    /// Key maintenance and the nonce registry are tested generically in
+    /// frost-common; check the exports round-trip through JSON
+    #[test]
+    fn test_maintenance_exports_round_trip() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let key_package = &keygen.shares[0].key_package;
+
+        let refresh: RefreshSharesResult = serde_json::from_str(&compute_refreshing_shares(
+            &keygen.public_key_package,
+            "[1, 2, 3]",
+            2,
+        ))
+        .expect("Refresh should succeed");
+        let _: RefreshedKeyPackage = serde_json::from_str(&refresh_share(
+            &serde_json::to_string(&refresh.shares[0]).unwrap(),
+            key_package,
+        ))
+        .expect("Applying refresh should succeed");
+
+        let _: RepairStep1Result =
+            serde_json::from_str(&repair_share_step_1("[1, 2]", key_package, 3))
+                .expect("Repair step 1 should succeed");
+
+        let _: ReshareRound1Result =
+            serde_json::from_str(&reshare_part1(key_package, "[1, 3]", "[1, 3, 4, 5]", 3))
+                .expect("Reshare part 1 should succeed");
+        let error: FrostError =
+            serde_json::from_str(&reshare_part1(key_package, "[1]", "[1, 3, 4, 5]", 3))
+                .expect("Too few dealers");
+        assert_eq!(error.code, "RESHARE_ERROR");
+
+        let pool: CommitmentPoolResult =
+            serde_json::from_str(&generate_commitment_pool(key_package, 2)).unwrap();
+        assert_eq!(pool.commitments.commitments.len(), 2);
+
+        let state: NonceRegistryState = serde_json::from_str(&export_nonce_registry()).unwrap();
+        let _: NonceRegistryState = serde_json::from_str(&import_nonce_registry(
+            &serde_json::to_string(&state).unwrap(),
+        ))
+        .unwrap();
+        let error: FrostError =
+            serde_json::from_str(&import_nonce_registry(r#"{"used_nonces":["zz"]}"#)).unwrap();
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+
+        let key_handle: KeyHandle = serde_json::from_str(&load_key_package(key_package)).unwrap();
+        let sealed_json = export_sealed_nonce_registry(key_handle.handle);
+        let mut sealed: SealedNonceRegistry = serde_json::from_str(&sealed_json).unwrap();
+        let _: NonceRegistryState = serde_json::from_str(&import_sealed_nonce_registry(
+            key_handle.handle,
+            &sealed_json,
+            sealed.counter,
+        ))
+        .expect("Sealed registry should open");
+        sealed.counter += 1;
+        let error: FrostError = serde_json::from_str(&import_sealed_nonce_registry(
+            key_handle.handle,
+            &serde_json::to_string(&sealed).unwrap(),
+            0,
+        ))
+        .unwrap();
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+    }
+
+    #[test]
+    fn test_tweak_keeps_nonces_spent() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let key_package = &keygen.shares[0].key_package;
+
+        let round1: Vec<Round1Result> = keygen.shares[..2]
+            .iter()
+            .map(|s| serde_json::from_str(&generate_round1_commitment(&s.key_package)).unwrap())
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+        let nonces = serde_json::to_string(&round1[0].nonces).unwrap();
+
+        let _: SignatureShare = serde_json::from_str(&generate_round2_signature_with_tweak(
+            key_package,
+            &nonces,
+            &commitments_json,
+            "48656c6c6f20576f726c64",
+            "",
+        ))
+        .expect("First use should succeed");
+
+        // The tweak does not change the nonces, so they are still spent
+        let error: FrostError = serde_json::from_str(&generate_round2_signature(
+            key_package,
+            &nonces,
+            &commitments_json,
+            "6261642062616420626164",
+        ))
+        .expect("Second use should fail");
+        assert_eq!(error.code, "NONCE_REUSED");
+    }
+}
@@ -39,6 +39,11 @@ export interface Round1Result {
   nonces: SigningNonces;
 }
 
+export interface SigningPackage {
+  /** Serialized SigningPackage (JSON), built once by the coordinator */
+  signing_package: string;
+}
+
 export interface SignatureShare {
   identifier: number;
   share: string;
@@ -50,9 +55,31 @@ export interface AggregateSignature {
   signature: string;
 }
 
+export interface PublicKeyResult {
+  public_key: string;
+  identifier: number;
+}
+
 export interface FrostError {
   code: string;
   message: string;
+  /** Identifiers whose signature share is invalid (aggregation errors only) */
+  culprits?: number[];
+}
+
+/**
+ * Error thrown by the wrappers, keeping the code and culprits of the FrostError
+ */
+export class FrostWasmError extends Error {
+  readonly code: string;
+  readonly culprits: number[];
+
+  constructor(error: FrostError) {
+    super(`FROST Error [${error.code}]: ${error.message}`);
+    this.name = 'FrostWasmError';
+    this.code = error.code;
+    this.culprits = error.culprits ?? [];
+  }
 }
 
 export type FrostResult<T> = T | FrostError;
@@ -64,6 +91,7 @@ export type FrostResult<T> = T | FrostError;
 interface FrostWasmModule {
   generate_key_shares(threshold: number, total: number): string;
   generate_round1_commitment(signing_share_hex: string, identifier: number): string;
+  create_signing_package(commitments_json: string, message_hex: string): string;
   generate_round2_signature(
     signing_share_hex: string,
     nonces_json: string,
@@ -71,17 +99,29 @@ interface FrostWasmModule {
     message_hex: string,
     identifier: number
   ): string;
+  generate_round2_signature_with_package(
+    key_package_json: string,
+    nonces_json: string,
+    signing_package_json: string
+  ): string;
   aggregate_signature(
     shares_json: string,
     commitments_json: string,
     message_hex: string,
     group_public_key_hex: string
   ): string;
+  aggregate_signature_with_package(
+    shares_json: string,
+    signing_package_json: string,
+    public_key_package_json: string
+  ): string;
   verify_signature(
     signature_hex: string,
     message_hex: string,
     group_public_key_hex: string
   ): string;
+  get_public_key(key_package_json: string): string;
+  get_group_public_key(public_key_package_json: string): string;
 }
 
 // =============================================================================
@@ -174,7 +214,7 @@ function isError(result: unknown): result is FrostError {
 function parseResult<T>(json: string): T {
   const parsed = JSON.parse(json);
   if (isError(parsed)) {
-    throw new Error(`FROST Error [${parsed.code}]: ${parsed.message}`);
+    throw new FrostWasmError(parsed);
   }
   return parsed as T;
 }
@@ -215,6 +255,24 @@ export async function generateRound1Commitment(
   return parseResult<Round1Result>(result);
 }
 
+/**
+ * Create the signing package for a signing session (coordinator).
+ *
+ * Send the result to every signer so they all sign the same package.
+ *
+ * @param commitments - All participants' commitments
+ * @param messageHex - Message to sign (hex-encoded)
+ * @returns Serialized signing package
+ */
+export async function createSigningPackage(
+  commitments: Commitment[],
+  messageHex: string
+): Promise<SigningPackage> {
+  const wasm = await loadFrostWasm();
+  const result = wasm.create_signing_package(JSON.stringify(commitments), messageHex);
+  return parseResult<SigningPackage>(result);
+}
+
 /**
  * Generate Round 2 signature share.
  *
@@ -243,6 +301,28 @@ export async function generateRound2Signature(
   return parseResult<SignatureShare>(result);
 }
 
+/**
+ * Generate Round 2 signature share over the coordinator's signing package.
+ *
+ * @param keyPackageJson - The participant's key package (JSON)
+ * @param nonces - The nonces from Round 1 (keep secret!)
+ * @param signingPackage - Signing package from createSigningPackage
+ * @returns Signature share
+ */
+export async function generateRound2SignatureWithPackage(
+  keyPackageJson: string,
+  nonces: SigningNonces,
+  signingPackage: SigningPackage
+): Promise<SignatureShare> {
+  const wasm = await loadFrostWasm();
+  const result = wasm.generate_round2_signature_with_package(
+    keyPackageJson,
+    JSON.stringify(nonces),
+    signingPackage.signing_package
+  );
+  return parseResult<SignatureShare>(result);
+}
+
 /**
  * Aggregate signature shares into final signature.
  *
@@ -268,6 +348,28 @@ export async function aggregateSignature(
   return parseResult<AggregateSignature>(result);
 }
 
+/**
+ * Aggregate signature shares over the coordinator's signing package.
+ *
+ * @param shares - All signature shares
+ * @param signingPackage - Signing package from createSigningPackage
+ * @param publicKeyPackageJson - Serialized PublicKeyPackage (from KeyGenResult)
+ * @returns Aggregate signature
+ */
+export async function aggregateSignatureWithPackage(
+  shares: SignatureShare[],
+  signingPackage: SigningPackage,
+  publicKeyPackageJson: string
+): Promise<AggregateSignature> {
+  const wasm = await loadFrostWasm();
+  const result = wasm.aggregate_signature_with_package(
+    JSON.stringify(shares),
+    signingPackage.signing_package,
+    publicKeyPackageJson
+  );
+  return parseResult<AggregateSignature>(result);
+}
+
 /**
  * Verify a signature.
  *
@@ -287,6 +389,27 @@ export async function verifySignature(
   return parsed.valid;
 }
 
+/**
+ * Get a participant's public key share from their key package.
+ */
+export async function getPublicKey(keyPackageJson: string): Promise<PublicKeyResult> {
+  const wasm = await loadFrostWasm();
+  return parseResult<PublicKeyResult>(wasm.get_public_key(keyPackageJson));
+}
+
+/**
+ * Get the group public key (hex-encoded) from a public key package.
+ */
+export async function getGroupPublicKey(publicKeyPackageJson: string): Promise<string> {
+  const wasm = await loadFrostWasm();
+  const result = wasm.get_group_public_key(publicKeyPackageJson);
+  // Errors come back as FrostError JSON, success as raw hex
+  if (result.startsWith('{')) {
+    return parseResult<never>(result);
+  }
+  return result;
+}
+
 // =============================================================================
 // Mock Implementation (fallback when WASM unavailable)
 // =============================================================================
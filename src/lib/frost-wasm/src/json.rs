@@ -0,0 +1,932 @@
+//! JSON API
+//!
+//! Each function takes and returns the serialized forms used on the wire
+//! (JSON strings and hex), parses its arguments and calls the typed function
+//! of the same name in the crate root. The wasm-bindgen exports in the `wasm`
+//! module are thin wrappers over these functions.
+
+use frost_common::codec::{
+    identifier_from_u16, identifier_to_u16, verifying_key_hex, verifying_share_hex,
+};
+use frost_common::keystore;
+use frost_common::{dkg, keys, nonces, pool, reconstruct, refresh, repair, reshare, signing};
+use frost_ed25519::Ed25519Sha512;
+use zeroize::Zeroizing;
+
+use crate::{
+    AggregateError, AggregateSignature, CommitmentPoolResult, DkgResult, DkgRound1Result,
+    DkgRound2Result, FrostError, KeyGenResult, KeyHandle, KeyShare, NonceRegistryState,
+    PoolSelection, PoolSignatureResult, PublicKeyResult, ReconstructedKey, RefreshSharesResult,
+    RefreshedKeyPackage, RepairSigma, RepairStep1Result, ReshareRound1Result, Round1HandleResult,
+    Round1Result, SealedKeyGenResult, SealedKeyShare, ShareVerificationResult, SignatureShare,
+    SigningPackage, VerifyResult,
+};
+
+// =============================================================================
+// Key Generation
+// =============================================================================
+
+/// Generate key shares using trusted dealer key generation.
+///
+/// # Arguments
+/// * `threshold` - Minimum number of signers required (t)
+/// * `total` - Total number of participants (n)
+///
+/// # Returns
+/// KeyGenResult or FrostError
+pub fn generate_key_shares(threshold: u16, total: u16) -> Result<KeyGenResult, FrostError> {
+    keys::generate_key_shares::<Ed25519Sha512>(threshold, total)
+}
+
+/// Split an existing Ed25519 private key into key shares, keeping its public key.
+///
+/// # Arguments
+/// * `secret_hex` - 32-byte private key (hex-encoded)
+/// * `key_format` - `"seed"` for an RFC 8032 private key seed, `"scalar"` for a
+///   canonical little-endian signing scalar
+/// * `threshold` - Minimum number of signers required (t)
+/// * `total` - Total number of participants (n)
+///
+/// # Returns
+/// KeyGenResult or FrostError
+pub fn split_key_shares(
+    secret_hex: &str,
+    key_format: &str,
+    threshold: u16,
+    total: u16,
+) -> Result<KeyGenResult, FrostError> {
+    split_key_shares_internal(secret_hex, key_format, threshold, total)
+        .map_err(|e| FrostError::new("KEYGEN_ERROR", e))
+}
+
+fn split_key_shares_internal(
+    secret_hex: &str,
+    key_format: &str,
+    threshold: u16,
+    total: u16,
+) -> Result<KeyGenResult, String> {
+    let signing_key = parse_signing_key(secret_hex, key_format)?;
+
+    let (shares, pubkey_package) =
+        crate::split_key_shares(&signing_key, threshold, total).map_err(|e| e.message)?;
+
+    keys::key_gen_result(shares, &pubkey_package, threshold, total)
+}
+
+/// Decode the private key passed to `split_key_shares` into a signing key
+fn parse_signing_key(
+    secret_hex: &str,
+    key_format: &str,
+) -> Result<crate::frost::SigningKey, String> {
+    let secret_bytes =
+        Zeroizing::new(hex::decode(secret_hex).map_err(|e| format!("Invalid secret hex: {}", e))?);
+    let secret_bytes: Zeroizing<[u8; 32]> = Zeroizing::new(
+        secret_bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| "Secret must be 32 bytes")?,
+    );
+
+    match key_format {
+        "seed" => crate::signing_key_from_seed(&secret_bytes).map_err(|e| e.message),
+        "scalar" => crate::frost::SigningKey::deserialize(&secret_bytes[..])
+            .map_err(|e| format!("Invalid signing key: {:?}", e)),
+        _ => Err(format!(
+            "Unknown key format: {} (expected seed or scalar)",
+            key_format
+        )),
+    }
+}
+
+/// Verify a dealer-issued secret share and turn it into a key package.
+///
+/// Checks the share against the dealer's VSS commitment, and the commitment
+/// against the group's public key package, so a participant never stores a
+/// share that is inconsistent with everyone else's.
+///
+/// # Arguments
+/// * `secret_share_json` - This participant's `secret_share` from KeyGenResult (JSON)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+///
+/// # Returns
+/// KeyShare or FrostError
+pub fn verify_and_accept_share(
+    secret_share_json: &str,
+    public_key_package_json: &str,
+) -> Result<KeyShare, FrostError> {
+    keys::verify_and_accept_share::<Ed25519Sha512>(secret_share_json, public_key_package_json)
+}
+
+// =============================================================================
+// Sealed Share Delivery
+// =============================================================================
+
+/// Generate key shares with each secret share sealed to its recipient.
+///
+/// Participant `i` (1-indexed) receives the share sealed to the `i`-th public
+/// key; see the typed `generate_sealed_key_shares`.
+///
+/// # Arguments
+/// * `threshold` - Minimum number of signers required (t)
+/// * `recipient_public_keys_json` - Participants' X25519 public keys (JSON array of hex strings)
+///
+/// # Returns
+/// SealedKeyGenResult or FrostError
+pub fn generate_sealed_key_shares(
+    threshold: u16,
+    recipient_public_keys_json: &str,
+) -> Result<SealedKeyGenResult, FrostError> {
+    generate_sealed_key_shares_internal(threshold, recipient_public_keys_json)
+        .map_err(|e| FrostError::new("KEYGEN_ERROR", e))
+}
+
+fn generate_sealed_key_shares_internal(
+    threshold: u16,
+    recipient_public_keys_json: &str,
+) -> Result<SealedKeyGenResult, String> {
+    let recipient_keys: Vec<String> = serde_json::from_str(recipient_public_keys_json)
+        .map_err(|e| format!("Invalid recipient public keys JSON: {}", e))?;
+    let recipient_keys = recipient_keys
+        .iter()
+        .map(|k| parse_x25519_key(k).map(x25519_dalek::PublicKey::from))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let (sealed_shares, pubkey_package) =
+        crate::generate_sealed_key_shares(threshold, &recipient_keys).map_err(|e| e.message)?;
+
+    let mut shares = Vec::with_capacity(sealed_shares.len());
+    for (identifier, sealed_secret_share) in sealed_shares {
+        let verifying_share = pubkey_package
+            .verifying_shares()
+            .get(&identifier)
+            .ok_or("Public key package is missing a verifying share")?;
+        shares.push(SealedKeyShare {
+            identifier: identifier_to_u16(&identifier)?,
+            verifying_share: verifying_share_hex(verifying_share)?,
+            sealed_secret_share,
+        });
+    }
+
+    Ok(SealedKeyGenResult {
+        group_public_key: verifying_key_hex(pubkey_package.verifying_key())?,
+        shares,
+        threshold,
+        total: recipient_keys.len() as u16,
+        public_key_package: serde_json::to_string(&pubkey_package)
+            .map_err(|e| format!("Failed to serialize public key package: {:?}", e))?,
+    })
+}
+
+/// Open a sealed key share with the participant's X25519 private key.
+///
+/// The decrypted secret share is checked against the dealer's VSS commitment
+/// and the public key package, like `verify_and_accept_share`.
+///
+/// # Arguments
+/// * `sealed_share_json` - This participant's SealedKeyShare (JSON)
+/// * `private_key_hex` - This participant's X25519 private key (hex-encoded)
+/// * `public_key_package_json` - The group's PublicKeyPackage (JSON)
+///
+/// # Returns
+/// KeyShare or FrostError
+pub fn open_share(
+    sealed_share_json: &str,
+    private_key_hex: &str,
+    public_key_package_json: &str,
+) -> Result<KeyShare, FrostError> {
+    open_share_internal(sealed_share_json, private_key_hex, public_key_package_json)
+        .map_err(|e| FrostError::new("DECRYPT_ERROR", e))
+}
+
+fn open_share_internal(
+    sealed_share_json: &str,
+    private_key_hex: &str,
+    public_key_package_json: &str,
+) -> Result<KeyShare, String> {
+    let sealed_share: SealedKeyShare = serde_json::from_str(sealed_share_json)
+        .map_err(|e| format!("Invalid sealed share JSON: {}", e))?;
+
+    let secret = x25519_dalek::StaticSecret::from(parse_x25519_key(private_key_hex)?);
+
+    let pubkey_package: crate::frost::keys::PublicKeyPackage =
+        serde_json::from_str(public_key_package_json)
+            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+
+    let key_package = crate::open_share(
+        identifier_from_u16(sealed_share.identifier)?,
+        &sealed_share.sealed_secret_share,
+        &secret,
+        &pubkey_package,
+    )
+    .map_err(|e| e.message)?;
+
+    keys::key_share(&key_package)
+}
+
+fn parse_x25519_key(key_hex: &str) -> Result<[u8; 32], String> {
+    let bytes = hex::decode(key_hex).map_err(|e| format!("Invalid X25519 key hex: {}", e))?;
+    bytes
+        .try_into()
+        .map_err(|_| "X25519 key must be 32 bytes".into())
+}
+
+// =============================================================================
+// Distributed Key Generation
+// =============================================================================
+
+/// DKG part 1: generate this participant's secret polynomial and the
+/// Round 1 package to broadcast.
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier (1-indexed)
+/// * `max_signers` - Total number of participants (n)
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// DkgRound1Result or FrostError
+pub fn dkg_part1(
+    identifier: u16,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DkgRound1Result, FrostError> {
+    dkg::dkg_part1::<Ed25519Sha512>(identifier, max_signers, min_signers)
+}
+
+/// DKG part 2: verify the other participants' Round 1 packages and produce
+/// one Round 2 package per recipient.
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from `dkg_part1` (JSON)
+/// * `round1_packages_json` - JSON array of the other participants' DkgRound1Package objects
+///
+/// # Returns
+/// DkgRound2Result or FrostError
+pub fn dkg_part2(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+) -> Result<DkgRound2Result, FrostError> {
+    dkg::dkg_part2::<Ed25519Sha512>(secret_package_json, round1_packages_json)
+}
+
+/// DKG part 3: verify the Round 2 packages addressed to this participant and
+/// derive the final key package and public key package.
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from `dkg_part2` (JSON)
+/// * `round1_packages_json` - JSON array of the other participants' DkgRound1Package objects
+/// * `round2_packages_json` - JSON array of DkgRound2Package objects addressed to this participant
+///
+/// # Returns
+/// DkgResult or FrostError
+pub fn dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+) -> Result<DkgResult, FrostError> {
+    dkg::dkg_part3::<Ed25519Sha512>(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+    )
+}
+
+// =============================================================================
+// Share Refresh
+// =============================================================================
+
+/// Dealer-based refresh: deal zero-secret shares that rotate every
+/// participant's key share without changing the group public key.
+///
+/// Every participant must apply their refreshing share with `refresh_share`;
+/// old and refreshed shares cannot be combined afterwards.
+///
+/// # Arguments
+/// * `public_key_package_json` - Current PublicKeyPackage (JSON)
+/// * `identifiers_json` - JSON array of the participants' identifiers, e.g. `[1, 2, 3]`
+/// * `min_signers` - Minimum number of signers required (t), unchanged from keygen
+///
+/// # Returns
+/// RefreshSharesResult or FrostError
+pub fn compute_refreshing_shares(
+    public_key_package_json: &str,
+    identifiers_json: &str,
+    min_signers: u16,
+) -> Result<RefreshSharesResult, FrostError> {
+    refresh::compute_refreshing_shares::<Ed25519Sha512>(
+        public_key_package_json,
+        identifiers_json,
+        min_signers,
+    )
+}
+
+/// Apply a refreshing share from `compute_refreshing_shares` to a key package.
+///
+/// # Arguments
+/// * `refreshing_share_json` - This participant's RefreshingShare (JSON)
+/// * `key_package_json` - The participant's current key package (JSON)
+///
+/// # Returns
+/// RefreshedKeyPackage or FrostError
+pub fn refresh_share(
+    refreshing_share_json: &str,
+    key_package_json: &str,
+) -> Result<RefreshedKeyPackage, FrostError> {
+    refresh::refresh_share::<Ed25519Sha512>(refreshing_share_json, key_package_json)
+}
+
+/// Refresh DKG part 1: like `dkg_part1`, but the secret polynomial has a
+/// zero constant term so the group key is unchanged.
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier
+/// * `max_signers` - Total number of participants (n)
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// DkgRound1Result or FrostError
+pub fn refresh_dkg_part1(
+    identifier: u16,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DkgRound1Result, FrostError> {
+    refresh::refresh_dkg_part1::<Ed25519Sha512>(identifier, max_signers, min_signers)
+}
+
+/// Refresh DKG part 2: same wire format as `dkg_part2`.
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from `refresh_dkg_part1` (JSON)
+/// * `round1_packages_json` - JSON array of the other participants' DkgRound1Package objects
+///
+/// # Returns
+/// DkgRound2Result or FrostError
+pub fn refresh_dkg_part2(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+) -> Result<DkgRound2Result, FrostError> {
+    refresh::refresh_dkg_part2::<Ed25519Sha512>(secret_package_json, round1_packages_json)
+}
+
+/// Refresh DKG part 3: add the zero-secret shares to this participant's
+/// current key package and derive the updated public key package.
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from `refresh_dkg_part2` (JSON)
+/// * `round1_packages_json` - JSON array of the other participants' DkgRound1Package objects
+/// * `round2_packages_json` - JSON array of DkgRound2Package objects addressed to this participant
+/// * `public_key_package_json` - Current PublicKeyPackage (JSON)
+/// * `key_package_json` - This participant's current key package (JSON)
+///
+/// # Returns
+/// DkgResult or FrostError
+pub fn refresh_dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+    public_key_package_json: &str,
+    key_package_json: &str,
+) -> Result<DkgResult, FrostError> {
+    refresh::refresh_dkg_part3::<Ed25519Sha512>(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+        public_key_package_json,
+        key_package_json,
+    )
+}
+
+// =============================================================================
+// Share Repair
+// =============================================================================
+
+/// Share repair step 1 (helper): split this helper's contribution to the
+/// lost share into one delta per helper.
+///
+/// Any `min_signers` participants can act as helpers. Each delta must be sent
+/// privately to its recipient; helpers never learn each other's shares.
+///
+/// # Arguments
+/// * `helpers_json` - Helper identifiers including this one (JSON array, e.g. `[1, 2]`)
+/// * `key_package_json` - This helper's key package (JSON)
+/// * `participant` - Identifier of the participant whose share is being repaired
+///
+/// # Returns
+/// RepairStep1Result or FrostError
+pub fn repair_share_step_1(
+    helpers_json: &str,
+    key_package_json: &str,
+    participant: u16,
+) -> Result<RepairStep1Result, FrostError> {
+    repair::repair_share_step_1::<Ed25519Sha512>(helpers_json, key_package_json, participant)
+}
+
+/// Share repair step 2 (helper): combine the deltas received from every
+/// helper into this helper's sigma.
+///
+/// # Arguments
+/// * `deltas_json` - Deltas addressed to this helper, one from each helper (JSON array)
+///
+/// # Returns
+/// RepairSigma or FrostError
+pub fn repair_share_step_2(deltas_json: &str) -> Result<RepairSigma, FrostError> {
+    repair::repair_share_step_2::<Ed25519Sha512>(deltas_json)
+}
+
+/// Share repair step 3 (participant being repaired): rebuild the lost key
+/// package from the helpers' sigmas.
+///
+/// The result is checked against the participant's verifying share in the
+/// public key package.
+///
+/// # Arguments
+/// * `sigmas_json` - One RepairSigma from each helper (JSON array)
+/// * `identifier` - This participant's identifier
+/// * `public_key_package_json` - Group public key package (JSON)
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// KeyShare or FrostError
+pub fn repair_share_step_3(
+    sigmas_json: &str,
+    identifier: u16,
+    public_key_package_json: &str,
+    min_signers: u16,
+) -> Result<KeyShare, FrostError> {
+    repair::repair_share_step_3::<Ed25519Sha512>(
+        sigmas_json,
+        identifier,
+        public_key_package_json,
+        min_signers,
+    )
+}
+
+// =============================================================================
+// Resharing
+// =============================================================================
+
+/// Reshare part 1 (current holder): deal this holder's weighted share to a
+/// new participant set with a new threshold.
+///
+/// At least `min_signers` current holders must act as dealers, and all of
+/// them must use the same dealer set. The group public key is unchanged.
+/// Old key shares stay valid under the old PublicKeyPackage, so a removed
+/// participant is only cut off once the remaining holders delete theirs.
+///
+/// # Arguments
+/// * `key_package_json` - This dealer's current key package (JSON)
+/// * `dealers_json` - Dealer identifiers including this one (JSON array, e.g. `[1, 2]`)
+/// * `new_identifiers_json` - New participant identifiers (JSON array, e.g. `[1, 2, 4, 5, 6]`)
+/// * `new_min_signers` - New threshold (t')
+///
+/// # Returns
+/// ReshareRound1Result or FrostError
+pub fn reshare_part1(
+    key_package_json: &str,
+    dealers_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> Result<ReshareRound1Result, FrostError> {
+    reshare::reshare_part1::<Ed25519Sha512>(
+        key_package_json,
+        dealers_json,
+        new_identifiers_json,
+        new_min_signers,
+    )
+}
+
+/// Reshare part 2 (new participant): combine the dealers' sub-shares into a
+/// key package for the new participant set.
+///
+/// Each dealer's VSS commitment must cover exactly `new_identifiers` with
+/// threshold `new_min_signers` and match its verifying share in the old public
+/// key package; each sub-share is verified against its dealer's commitment,
+/// and the new group key must equal the old one.
+///
+/// # Arguments
+/// * `identifier` - This new participant's identifier
+/// * `sub_shares_json` - Sub-shares addressed to this participant, one per dealer (JSON array)
+/// * `commitments_json` - Every dealer's ReshareCommitment (JSON array)
+/// * `public_key_package_json` - The old PublicKeyPackage (JSON)
+/// * `new_identifiers_json` - New participant identifiers, as passed to part 1 (JSON array)
+/// * `new_min_signers` - New threshold (t'), as passed to part 1
+///
+/// # Returns
+/// DkgResult or FrostError
+pub fn reshare_part2(
+    identifier: u16,
+    sub_shares_json: &str,
+    commitments_json: &str,
+    public_key_package_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> Result<DkgResult, FrostError> {
+    reshare::reshare_part2::<Ed25519Sha512>(
+        identifier,
+        sub_shares_json,
+        commitments_json,
+        public_key_package_json,
+        new_identifiers_json,
+        new_min_signers,
+    )
+}
+
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
+
+/// Generate Round 1 commitment and nonces.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON, from KeyGenResult)
+///
+/// # Returns
+/// Round1Result or FrostError
+pub fn generate_round1_commitment(key_package_json: &str) -> Result<Round1Result, FrostError> {
+    signing::generate_round1_commitment::<Ed25519Sha512>(key_package_json)
+}
+
+// =============================================================================
+// Signing Package Creation
+// =============================================================================
+
+/// Create the signing package for one signing session.
+///
+/// The coordinator calls this once after collecting the commitments and sends
+/// the result to every signer, so all signers sign the same package.
+///
+/// # Arguments
+/// * `commitments_json` - All participants' commitments (JSON array)
+/// * `message_hex` - Message to sign (hex-encoded)
+///
+/// # Returns
+/// SigningPackage or FrostError
+pub fn create_signing_package(
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SigningPackage, FrostError> {
+    signing::create_signing_package::<Ed25519Sha512>(commitments_json, message_hex)
+}
+
+// =============================================================================
+// Round 2: Signature Share Generation
+// =============================================================================
+
+/// Generate Round 2 signature share.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `nonces_json` - The participant's SigningNonces (JSON from Round1)
+/// * `commitments_json` - JSON array of all participants' Commitment objects
+/// * `message_hex` - Message to sign (hex-encoded)
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature(
+    key_package_json: &str,
+    nonces_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SignatureShare, FrostError> {
+    signing::generate_round2_signature::<Ed25519Sha512>(
+        key_package_json,
+        nonces_json,
+        commitments_json,
+        message_hex,
+    )
+}
+
+/// Generate Round 2 signature share over the coordinator's signing package.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `nonces_json` - The participant's SigningNonces (JSON from Round1)
+/// * `signing_package_json` - Serialized SigningPackage (from create_signing_package)
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_with_package(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+) -> Result<SignatureShare, FrostError> {
+    signing::generate_round2_signature_with_package::<Ed25519Sha512>(
+        key_package_json,
+        nonces_json,
+        signing_package_json,
+    )
+}
+
+// =============================================================================
+// Commitment Pools (Preprocessing)
+// =============================================================================
+
+/// Preprocess a batch of Round 1 commitments.
+///
+/// The signer publishes the commitment pool ahead of time, so signing later
+/// needs only a single round.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `count` - Number of commitments to generate
+///
+/// # Returns
+/// CommitmentPoolResult or FrostError
+pub fn generate_commitment_pool(
+    key_package_json: &str,
+    count: u32,
+) -> Result<CommitmentPoolResult, FrostError> {
+    pool::generate_commitment_pool::<Ed25519Sha512>(key_package_json, count)
+}
+
+/// Take the oldest unused commitment from each signer's pool (coordinator).
+///
+/// # Arguments
+/// * `pools_json` - JSON array of the signers' CommitmentPool objects
+/// * `signers_json` - JSON array of participant identifiers taking part
+///
+/// # Returns
+/// PoolSelection or FrostError
+pub fn select_pool_commitments(
+    pools_json: &str,
+    signers_json: &str,
+) -> Result<PoolSelection, FrostError> {
+    pool::select_pool_commitments(pools_json, signers_json)
+}
+
+/// Generate a Round 2 signature share using the pooled nonces the
+/// coordinator selected.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+/// * `nonce_pool_json` - The participant's NoncePool (JSON)
+/// * `commitments_json` - JSON array of the selected Commitment objects
+/// * `message_hex` - Message to sign (hex-encoded)
+///
+/// # Returns
+/// PoolSignatureResult or FrostError
+pub fn generate_round2_signature_from_pool(
+    key_package_json: &str,
+    nonce_pool_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<PoolSignatureResult, FrostError> {
+    pool::generate_round2_signature_from_pool::<Ed25519Sha512>(
+        key_package_json,
+        nonce_pool_json,
+        commitments_json,
+        message_hex,
+    )
+}
+
+// =============================================================================
+// Nonce Registry
+// =============================================================================
+
+/// Merge a previously exported registry into the current one.
+///
+/// # Arguments
+/// * `state_json` - NonceRegistryState from `export_nonce_registry` (JSON)
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_nonce_registry(state_json: &str) -> Result<NonceRegistryState, FrostError> {
+    nonces::import_nonce_registry(state_json)
+}
+
+/// Merge a sealed registry into the current one after decrypting it and
+/// checking it is not older than `min_counter`.
+///
+/// # Arguments
+/// * `key_handle` - Handle to the key package that sealed it
+/// * `sealed_json` - SealedNonceRegistry from `export_sealed_nonce_registry` (JSON)
+/// * `min_counter` - Counter of the latest export stored, or 0
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_sealed_nonce_registry(
+    key_handle: u32,
+    sealed_json: &str,
+    min_counter: u64,
+) -> Result<NonceRegistryState, FrostError> {
+    crate::KEYSTORE.with(|keystore| {
+        keystore::import_nonce_registry(
+            &mut keystore.borrow_mut(),
+            key_handle,
+            sealed_json,
+            min_counter,
+        )
+    })
+}
+
+// =============================================================================
+// Keystore: Opaque Key and Nonce Handles
+// =============================================================================
+
+/// Load a key package into the keystore and return an opaque handle.
+///
+/// # Arguments
+/// * `key_package_json` - The participant's key package (JSON)
+///
+/// # Returns
+/// KeyHandle or FrostError
+pub fn load_key_package(key_package_json: &str) -> Result<KeyHandle, FrostError> {
+    crate::KEYSTORE
+        .with(|keystore| keystore::load_key_package(&mut keystore.borrow_mut(), key_package_json))
+}
+
+/// Generate a Round 1 commitment, keeping the nonces in the keystore.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+///
+/// # Returns
+/// Round1HandleResult or FrostError
+pub fn generate_round1_commitment_with_handle(
+    key_handle: u32,
+) -> Result<Round1HandleResult, FrostError> {
+    crate::KEYSTORE.with(|keystore| {
+        keystore::generate_round1_commitment(&mut keystore.borrow_mut(), key_handle)
+    })
+}
+
+/// Generate a Round 2 signature share from keystore handles.
+///
+/// The nonce handle is consumed and its nonces zeroized, so it cannot be
+/// used for a second signature.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+/// * `nonce_handle` - Handle from `generate_round1_commitment_with_handle`
+/// * `commitments_json` - JSON array of all participants' Commitment objects
+/// * `message_hex` - Message to sign (hex-encoded)
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    commitments_json: &str,
+    message_hex: &str,
+) -> Result<SignatureShare, FrostError> {
+    crate::KEYSTORE.with(|keystore| {
+        keystore::generate_round2_signature(
+            &mut keystore.borrow_mut(),
+            key_handle,
+            nonce_handle,
+            commitments_json,
+            message_hex,
+        )
+    })
+}
+
+// =============================================================================
+// Signature Aggregation
+// =============================================================================
+
+/// Aggregate signature shares into final signature.
+///
+/// If aggregation fails because of invalid signature shares, the error is an
+/// AggregateError whose `culprits` lists the participants who sent them.
+///
+/// # Arguments
+/// * `shares_json` - JSON array of SignatureShare objects
+/// * `commitments_json` - JSON array of Commitment objects
+/// * `message_hex` - Message that was signed (hex-encoded)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+///
+/// # Returns
+/// AggregateSignature or AggregateError
+pub fn aggregate_signature(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<AggregateSignature, AggregateError> {
+    signing::aggregate_signature::<Ed25519Sha512>(
+        shares_json,
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    )
+}
+
+/// Aggregate signature shares over the coordinator's signing package.
+///
+/// # Arguments
+/// * `shares_json` - JSON array of SignatureShare objects
+/// * `signing_package_json` - Serialized SigningPackage (from create_signing_package)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+///
+/// # Returns
+/// AggregateSignature or AggregateError
+pub fn aggregate_signature_with_package(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+) -> Result<AggregateSignature, AggregateError> {
+    signing::aggregate_signature_with_package::<Ed25519Sha512>(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+    )
+}
+
+/// Verify each signature share against the signer's verifying share.
+///
+/// Lets the coordinator find misbehaving participants without attempting
+/// aggregation.
+///
+/// # Arguments
+/// * `shares_json` - JSON array of SignatureShare objects
+/// * `commitments_json` - JSON array of Commitment objects
+/// * `message_hex` - Message that was signed (hex-encoded)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+///
+/// # Returns
+/// ShareVerificationResult or FrostError
+pub fn verify_signature_shares(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<ShareVerificationResult, FrostError> {
+    signing::verify_signature_shares::<Ed25519Sha512>(
+        shares_json,
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    )
+}
+
+// =============================================================================
+// Verification
+// =============================================================================
+
+/// Verify a signature.
+///
+/// # Arguments
+/// * `signature_hex` - The aggregate signature (hex-encoded)
+/// * `message_hex` - The message that was signed (hex-encoded)
+/// * `group_public_key_hex` - The group public key (hex-encoded)
+///
+/// # Returns
+/// VerifyResult or FrostError
+pub fn verify_signature(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+) -> Result<VerifyResult, FrostError> {
+    signing::verify_signature::<Ed25519Sha512>(signature_hex, message_hex, group_public_key_hex)
+}
+
+// =============================================================================
+// Emergency Reconstruction
+// =============================================================================
+
+/// DANGER: reassemble the group signing key from `min_signers` key packages.
+///
+/// This defeats the point of threshold signing: whoever runs it holds the
+/// whole key. Only use it for disaster recovery or migrating off FROST, on an
+/// offline machine, and retire the shares afterwards.
+///
+/// # Arguments
+/// * `key_packages_json` - At least `min_signers` key packages (JSON array of KeyPackage JSON strings)
+/// * `public_key_package_json` - Group public key package (JSON)
+///
+/// # Returns
+/// ReconstructedKey or FrostError
+pub fn emergency_reconstruct_signing_key(
+    key_packages_json: &str,
+    public_key_package_json: &str,
+) -> Result<ReconstructedKey, FrostError> {
+    reconstruct::emergency_reconstruct_signing_key::<Ed25519Sha512>(
+        key_packages_json,
+        public_key_package_json,
+    )
+}
+
+// =============================================================================
+// Utility Functions
+// =============================================================================
+
+/// Get the public key from a key package
+///
+/// # Arguments
+/// * `key_package_json` - Key package (JSON)
+///
+/// # Returns
+/// PublicKeyResult or FrostError
+pub fn get_public_key(key_package_json: &str) -> Result<PublicKeyResult, FrostError> {
+    keys::get_public_key::<Ed25519Sha512>(key_package_json)
+}
+
+/// Get the group public key from a public key package
+///
+/// # Arguments
+/// * `public_key_package_json` - Public key package (JSON)
+///
+/// # Returns
+/// Hex-encoded group public key or FrostError
+pub fn get_group_public_key(public_key_package_json: &str) -> Result<String, FrostError> {
+    keys::get_group_public_key::<Ed25519Sha512>(public_key_package_json)
+}
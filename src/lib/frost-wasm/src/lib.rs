@@ -3,44 +3,117 @@
 //! Provides WebAssembly bindings for FROST threshold signature operations
 //! using Ed25519 curve.
 //!
-//! Note: For Zcash Orchard compatibility, a future version will migrate to
-//! frost-rerandomized with RedPallas curve.
-
-use frost_ed25519 as frost;
+//! The protocol logic lives in `frost-common`; this crate instantiates it with
+//! Ed25519 and adds Ed25519-specific key import and sealed share delivery.
+//!
+//! The crate root is the native Rust API: typed functions over the
+//! `frost_ed25519` types (re-exported as `frost`) returning
+//! `Result<T, FrostError>`. The `json` module layers the serialized wire
+//! format (JSON strings and hex) on top of it, and the `wasm` feature (on by
+//! default) exports that layer through wasm-bindgen in the `wasm` module.
+//!
+//! Note: For Zcash Orchard compatibility, see `frost-zcash-wasm`, which
+//! instantiates the same core with RedPallas and rerandomization.
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use frost_common::codec::identifier_to_u16;
+use frost_common::keystore::Keystore;
+use frost_common::{dkg, keys, nonces, pool, reconstruct, refresh, repair, reshare, signing};
+use frost_ed25519::Ed25519Sha512;
+use hkdf::Hkdf;
 use rand::rngs::OsRng;
+use rand::RngCore;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512};
+use std::cell::RefCell;
 use std::collections::BTreeMap;
-use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
 
-// Initialize panic hook for better error messages in WASM
-#[wasm_bindgen(start)]
-pub fn init() {
-    #[cfg(feature = "console_error_panic_hook")]
-    console_error_panic_hook::set_once();
-}
+pub use frost_ed25519 as frost;
+
+pub mod json;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // =============================================================================
 // Types
 // =============================================================================
 
-/// A participant's key share
-#[derive(Serialize, Deserialize)]
-pub struct KeyShare {
+pub use frost_common::{
+    AggregateError, AggregateSignature, Commitment, CommitmentPool, CommitmentPoolResult,
+    DkgResult, DkgRound1Package, DkgRound1Result, DkgRound2Package, DkgRound2Result, FrostError,
+    KeyGenResult, KeyHandle, KeyShare, NoncePool, NonceRegistryState, PoolSelection,
+    PoolSignatureResult, PublicKeyResult, ReconstructedKey, RefreshSharesResult, RefreshingShare,
+    RepairDelta, RepairSigma, RepairStep1Result, ReshareCommitment, ReshareRound1Result,
+    ReshareSubShare, Round1HandleResult, Round1Result, SealedNonceRegistry,
+    ShareVerificationResult, SignatureShare, SigningNonces, SigningPackage, VerifyResult,
+};
+
+/// A trusted dealer's output: each participant's secret share and the group's
+/// public key package
+pub type DealtShares = keys::DealtShares<Ed25519Sha512>;
+
+/// DKG Round 1 packages, keyed by sender
+pub type Round1Packages = dkg::Round1Packages<Ed25519Sha512>;
+
+/// DKG Round 2 packages, keyed by sender (incoming) or recipient (outgoing)
+pub type Round2Packages = dkg::Round2Packages<Ed25519Sha512>;
+
+/// A resharing dealer's VSS commitment and sub-shares, keyed by new participant
+pub type DealtSubShares = reshare::DealtSubShares<Ed25519Sha512>;
+
+/// Signature shares, keyed by signer
+pub type SignatureShares = signing::SignatureShares<Ed25519Sha512>;
+
+/// Commitments, keyed by signer
+pub type SigningCommitmentsMap = BTreeMap<frost::Identifier, frost::round1::SigningCommitments>;
+
+/// A scalar of the Ed25519 group order, as exchanged during share repair
+pub type Scalar = <frost::Ed25519ScalarField as frost::Field>::Scalar;
+
+/// A participant's key package after applying a refreshing share
+pub type RefreshedKeyPackage = KeyShare;
+
+/// Data sealed to one X25519 public key (ephemeral X25519 ECDH + HKDF-SHA256 + AES-256-GCM)
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SealedBox {
+    /// Ciphertext with GCM tag (hex-encoded in JSON)
+    #[serde(with = "hex")]
+    pub ciphertext: Vec<u8>,
+    /// AES-GCM nonce (hex-encoded in JSON)
+    #[serde(with = "hex")]
+    pub nonce: [u8; 12],
+    /// Ephemeral X25519 public key (hex-encoded in JSON)
+    #[serde(with = "hex")]
+    pub ephemeral_public_key: [u8; 32],
+}
+
+/// A dealer's output with each secret share sealed to its recipient: the
+/// sealed shares, keyed by participant, and the group's public key package
+pub type SealedShares = (
+    BTreeMap<frost::Identifier, SealedBox>,
+    frost::keys::PublicKeyPackage,
+);
+
+/// A participant's key share, sealed to their X25519 public key
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SealedKeyShare {
     /// Participant identifier (1-indexed)
     pub identifier: u16,
-    /// Serialized KeyPackage (JSON)
-    pub key_package: String,
     /// Verifying share (hex-encoded public key share)
     pub verifying_share: String,
+    /// Serialized SecretShare with the dealer's VSS commitment (JSON), sealed to the participant
+    pub sealed_secret_share: SealedBox,
 }
 
-/// Result of key generation
+/// Result of key generation with sealed shares
 #[derive(Serialize, Deserialize)]
-pub struct KeyGenResult {
+pub struct SealedKeyGenResult {
     /// Group public key (hex-encoded)
     pub group_public_key: String,
-    /// Individual key shares for each participant
-    pub shares: Vec<KeyShare>,
+    /// Sealed key shares, one per recipient public key
+    pub shares: Vec<SealedKeyShare>,
     /// Threshold required for signing
     pub threshold: u16,
     /// Total number of participants
@@ -49,483 +122,924 @@ pub struct KeyGenResult {
     pub public_key_package: String,
 }
 
-/// A commitment for Round 1 of signing
-#[derive(Serialize, Deserialize, Clone)]
-pub struct Commitment {
-    /// Participant identifier
-    pub identifier: u16,
-    /// Serialized SigningCommitments (JSON)
-    pub commitment: String,
-}
+// =============================================================================
+// Key Generation
+// =============================================================================
 
-/// Nonces generated during Round 1 (must be kept secret!)
-#[derive(Serialize, Deserialize)]
-pub struct SigningNonces {
-    /// Participant identifier
-    pub identifier: u16,
-    /// Serialized SigningNonces (JSON) - KEEP SECRET
-    pub nonces: String,
+/// Generate key shares using trusted dealer key generation.
+///
+/// # Arguments
+/// * `threshold` - Minimum number of signers required (t)
+/// * `total` - Total number of participants (n)
+///
+/// # Returns
+/// Each participant's secret share and the public key package, or FrostError
+pub fn generate_key_shares(threshold: u16, total: u16) -> Result<DealtShares, FrostError> {
+    keys::deal_key_shares::<Ed25519Sha512>(threshold, total)
 }
 
-/// Result of Round 1 commitment generation
-#[derive(Serialize, Deserialize)]
-pub struct Round1Result {
-    /// Public commitment to broadcast
-    pub commitment: Commitment,
-    /// Secret nonces to keep for Round 2
-    pub nonces: SigningNonces,
+/// Split an existing Ed25519 signing key into key shares, keeping its public key.
+///
+/// # Arguments
+/// * `signing_key` - The key to split; see `signing_key_from_seed` for RFC 8032 seeds
+/// * `threshold` - Minimum number of signers required (t)
+/// * `total` - Total number of participants (n)
+///
+/// # Returns
+/// Each participant's secret share and the public key package, or FrostError
+pub fn split_key_shares(
+    signing_key: &frost::SigningKey,
+    threshold: u16,
+    total: u16,
+) -> Result<DealtShares, FrostError> {
+    keys::split_signing_key::<Ed25519Sha512>(signing_key, threshold, total)
 }
 
-/// A signature share from Round 2
-#[derive(Serialize, Deserialize)]
-pub struct SignatureShare {
-    /// Participant identifier
-    pub identifier: u16,
-    /// Serialized SignatureShare (JSON)
-    pub share: String,
+/// Expand an RFC 8032 private key seed into its signing key.
+///
+/// # Returns
+/// The signing key with the seed's public key, or FrostError
+pub fn signing_key_from_seed(seed: &[u8; 32]) -> Result<frost::SigningKey, FrostError> {
+    frost::SigningKey::deserialize(seed_to_scalar(seed).as_ref())
+        .map_err(|e| FrostError::new("KEYGEN_ERROR", format!("Invalid signing key: {:?}", e)))
 }
 
-/// Final aggregate signature
-#[derive(Serialize, Deserialize)]
-pub struct AggregateSignature {
-    /// Full signature (hex-encoded)
-    pub signature: String,
+/// Expand an RFC 8032 seed into its signing scalar (SHA-512, clamp, reduce).
+///
+/// Reducing the clamped scalar mod the group order leaves the public key unchanged.
+fn seed_to_scalar(seed: &[u8; 32]) -> Zeroizing<[u8; 32]> {
+    let mut hash = Sha512::digest(seed);
+    let mut clamped = Zeroizing::new([0u8; 32]);
+    clamped.copy_from_slice(&hash[..32]);
+    hash.as_mut_slice().zeroize();
+    clamped[0] &= 248;
+    clamped[31] &= 127;
+    clamped[31] |= 64;
+
+    let mut scalar = curve25519_dalek::Scalar::from_bytes_mod_order(*clamped);
+    let scalar_bytes = Zeroizing::new(scalar.to_bytes());
+    scalar.zeroize();
+    scalar_bytes
 }
 
-/// Error result
-#[derive(Serialize, Deserialize)]
-pub struct FrostError {
-    pub code: String,
-    pub message: String,
+/// Verify a dealer-issued secret share and turn it into a key package.
+///
+/// Checks the share against the dealer's VSS commitment, and the commitment
+/// against the group's public key package, so a participant never stores a
+/// share that is inconsistent with everyone else's.
+///
+/// # Arguments
+/// * `secret_share` - This participant's secret share from the dealer
+/// * `public_key_package` - The group's public key package from the dealer
+///
+/// # Returns
+/// KeyPackage or FrostError
+pub fn verify_and_accept_share(
+    secret_share: frost::keys::SecretShare,
+    public_key_package: &frost::keys::PublicKeyPackage,
+) -> Result<frost::keys::KeyPackage, FrostError> {
+    keys::accept_share::<Ed25519Sha512>(secret_share, public_key_package)
 }
 
 // =============================================================================
-// Key Generation
+// Sealed Share Delivery
 // =============================================================================
 
-/// Generate key shares using trusted dealer key generation.
+/// HKDF salt for sealing key shares (domain-separated from E2E messages)
+const SEAL_HKDF_SALT: &[u8] = b"frost-share-sealing-v1";
+const SEAL_HKDF_INFO: &[u8] = b"aes-gcm-256";
+
+/// Generate key shares with each secret share sealed to its recipient.
+///
+/// Participant `i` (1-indexed) receives the share sealed to the `i`-th public
+/// key, so the dealer's output can be relayed without exposing any share. The
+/// identifier and both public keys are bound into the AES-GCM associated data,
+/// so a relayed share cannot be relabelled for another participant.
 ///
 /// # Arguments
 /// * `threshold` - Minimum number of signers required (t)
-/// * `total` - Total number of participants (n)
+/// * `recipients` - Participants' X25519 public keys, at most 255
 ///
 /// # Returns
-/// JSON string containing KeyGenResult or FrostError
-#[wasm_bindgen]
-pub fn generate_key_shares(threshold: u16, total: u16) -> String {
-    match generate_key_shares_internal(threshold, total) {
-        Ok(result) => serde_json::to_string(&result).unwrap_or_else(|e| {
-            serde_json::to_string(&FrostError {
-                code: "SERIALIZATION_ERROR".into(),
-                message: e.to_string(),
-            })
-            .unwrap()
-        }),
-        Err(e) => serde_json::to_string(&FrostError {
-            code: "KEYGEN_ERROR".into(),
-            message: e,
-        })
-        .unwrap(),
-    }
+/// SealedShares or FrostError
+pub fn generate_sealed_key_shares(
+    threshold: u16,
+    recipients: &[x25519_dalek::PublicKey],
+) -> Result<SealedShares, FrostError> {
+    generate_sealed_key_shares_internal(threshold, recipients)
+        .map_err(|e| FrostError::new("KEYGEN_ERROR", e))
 }
 
-fn generate_key_shares_internal(threshold: u16, total: u16) -> Result<KeyGenResult, String> {
-    if threshold == 0 || threshold > total {
+fn generate_sealed_key_shares_internal(
+    threshold: u16,
+    recipients: &[x25519_dalek::PublicKey],
+) -> Result<SealedShares, String> {
+    if recipients.len() > 255 {
         return Err(format!(
-            "Invalid threshold: {} must be > 0 and <= {}",
-            threshold, total
+            "At most 255 recipients are supported, got {}",
+            recipients.len()
         ));
     }
-    if total > 255 {
-        return Err("Total participants must be <= 255".into());
-    }
 
-    let mut rng = OsRng;
+    let (shares, pubkey_package) =
+        keys::deal_key_shares::<Ed25519Sha512>(threshold, recipients.len() as u16)
+            .map_err(|e| e.message)?;
 
-    // Generate key shares using trusted dealer
-    let (shares, pubkey_package) = frost::keys::generate_with_dealer(
-        total,
-        threshold,
-        frost::keys::IdentifierList::Default,
-        &mut rng,
-    )
-    .map_err(|e| format!("Key generation failed: {:?}", e))?;
-
-    // Serialize the public key package
-    let pubkey_package_json = serde_json::to_string(&pubkey_package)
-        .map_err(|e| format!("Failed to serialize public key package: {:?}", e))?;
-
-    // Convert to our format
-    let mut key_shares = Vec::with_capacity(total as usize);
-    for (identifier, secret_share) in shares {
-        // Build KeyPackage for this participant
-        let key_package = frost::keys::KeyPackage::try_from(secret_share.clone())
-            .map_err(|e| format!("Failed to create key package: {:?}", e))?;
-
-        let key_package_json = serde_json::to_string(&key_package)
-            .map_err(|e| format!("Failed to serialize key package: {:?}", e))?;
-
-        // Get the verifying share for this participant
-        let verifying_share = pubkey_package
-            .verifying_shares()
-            .get(&identifier)
-            .ok_or("Missing verifying share")?;
-
-        // Get identifier as u16
-        let id_bytes = identifier.serialize();
-        let id: u16 = u16::from_le_bytes([id_bytes[0], id_bytes[1]]);
-
-        let verifying_share_bytes = verifying_share
-            .serialize()
-            .map_err(|e| format!("Failed to serialize verifying share: {:?}", e))?;
-
-        key_shares.push(KeyShare {
-            identifier: id,
-            key_package: key_package_json,
-            verifying_share: hex::encode(verifying_share_bytes),
-        });
+    let mut sealed_shares = BTreeMap::new();
+    for ((identifier, secret_share), recipient) in shares.into_iter().zip(recipients) {
+        let secret_share_json = Zeroizing::new(
+            serde_json::to_vec(&secret_share)
+                .map_err(|e| format!("Failed to serialize secret share: {:?}", e))?,
+        );
+        sealed_shares.insert(
+            identifier,
+            seal(
+                *recipient,
+                identifier_to_u16(&identifier)?,
+                &secret_share_json,
+            )?,
+        );
     }
 
-    // Get group public key
-    let group_public_key_bytes = pubkey_package
-        .verifying_key()
-        .serialize()
-        .map_err(|e| format!("Failed to serialize group public key: {:?}", e))?;
-    let group_public_key = hex::encode(group_public_key_bytes);
-
-    Ok(KeyGenResult {
-        group_public_key,
-        shares: key_shares,
-        threshold,
-        total,
-        public_key_package: pubkey_package_json,
-    })
+    Ok((sealed_shares, pubkey_package))
 }
 
-// =============================================================================
-// Round 1: Commitment Generation
-// =============================================================================
-
-/// Generate Round 1 commitment and nonces.
+/// Open a sealed key share with the participant's X25519 private key.
+///
+/// The decrypted secret share is checked against the dealer's VSS commitment
+/// and the public key package, like `verify_and_accept_share`.
 ///
 /// # Arguments
-/// * `key_package_json` - The participant's key package (JSON, from KeyGenResult)
+/// * `identifier` - This participant's identifier, as the share is labelled
+/// * `sealed_share` - The secret share sealed to this participant
+/// * `private_key` - This participant's X25519 private key
+/// * `public_key_package` - The group's public key package
 ///
 /// # Returns
-/// JSON string containing Round1Result or FrostError
-#[wasm_bindgen]
-pub fn generate_round1_commitment(key_package_json: &str) -> String {
-    match generate_round1_internal(key_package_json) {
-        Ok(result) => serde_json::to_string(&result).unwrap_or_else(|e| {
-            serde_json::to_string(&FrostError {
-                code: "SERIALIZATION_ERROR".into(),
-                message: e.to_string(),
-            })
-            .unwrap()
-        }),
-        Err(e) => serde_json::to_string(&FrostError {
-            code: "ROUND1_ERROR".into(),
-            message: e,
-        })
-        .unwrap(),
-    }
+/// KeyPackage or FrostError
+pub fn open_share(
+    identifier: frost::Identifier,
+    sealed_share: &SealedBox,
+    private_key: &x25519_dalek::StaticSecret,
+    public_key_package: &frost::keys::PublicKeyPackage,
+) -> Result<frost::keys::KeyPackage, FrostError> {
+    open_share_internal(identifier, sealed_share, private_key, public_key_package)
+        .map_err(|e| FrostError::new("DECRYPT_ERROR", e))
 }
 
-fn generate_round1_internal(key_package_json: &str) -> Result<Round1Result, String> {
-    let mut rng = OsRng;
+fn open_share_internal(
+    identifier: frost::Identifier,
+    sealed_share: &SealedBox,
+    private_key: &x25519_dalek::StaticSecret,
+    public_key_package: &frost::keys::PublicKeyPackage,
+) -> Result<frost::keys::KeyPackage, String> {
+    let plaintext = open(private_key, identifier_to_u16(&identifier)?, sealed_share)?;
+    let secret_share: frost::keys::SecretShare =
+        serde_json::from_slice(&plaintext).map_err(|e| format!("Invalid secret share: {}", e))?;
+
+    if *secret_share.identifier() != identifier {
+        return Err(format!(
+            "Sealed share is labelled for participant {} but contains participant {}",
+            identifier_to_u16(&identifier)?,
+            identifier_to_u16(secret_share.identifier())?
+        ));
+    }
 
-    // Parse key package
-    let key_package: frost::keys::KeyPackage = serde_json::from_str(key_package_json)
-        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+    keys::accept_share::<Ed25519Sha512>(secret_share, public_key_package).map_err(|e| e.message)
+}
 
-    let identifier = *key_package.identifier();
-    let id_bytes = identifier.serialize();
-    let id: u16 = u16::from_le_bytes([id_bytes[0], id_bytes[1]]);
+fn seal(
+    recipient: x25519_dalek::PublicKey,
+    identifier: u16,
+    plaintext: &[u8],
+) -> Result<SealedBox, String> {
+    let ephemeral = x25519_dalek::EphemeralSecret::random_from_rng(OsRng);
+    let ephemeral_public = x25519_dalek::PublicKey::from(&ephemeral);
+    let shared_secret = ephemeral.diffie_hellman(&recipient);
+    // A low-order recipient key yields a shared secret anyone can compute
+    if !shared_secret.was_contributory() {
+        return Err(format!(
+            "Recipient public key for participant {} is a low-order point",
+            identifier
+        ));
+    }
+    let cipher = seal_cipher(shared_secret.as_bytes())?;
+    let aad = seal_aad(identifier, &ephemeral_public, &recipient);
+
+    let mut nonce = [0u8; 12];
+    OsRng.fill_bytes(&mut nonce);
+
+    let ciphertext = cipher
+        .encrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: plaintext,
+                aad: &aad,
+            },
+        )
+        .map_err(|e| format!("Encryption failed: {:?}", e))?;
+
+    Ok(SealedBox {
+        ciphertext,
+        nonce,
+        ephemeral_public_key: ephemeral_public.to_bytes(),
+    })
+}
 
-    // Generate nonces and commitment
-    let (nonces, commitments) = frost::round1::commit(key_package.signing_share(), &mut rng);
+fn open(
+    secret: &x25519_dalek::StaticSecret,
+    identifier: u16,
+    sealed: &SealedBox,
+) -> Result<Zeroizing<Vec<u8>>, String> {
+    let ephemeral_public = x25519_dalek::PublicKey::from(sealed.ephemeral_public_key);
+    let shared_secret = secret.diffie_hellman(&ephemeral_public);
+    if !shared_secret.was_contributory() {
+        return Err("Ephemeral public key is a low-order point".into());
+    }
+    let cipher = seal_cipher(shared_secret.as_bytes())?;
+    let aad = seal_aad(
+        identifier,
+        &ephemeral_public,
+        &x25519_dalek::PublicKey::from(secret),
+    );
+
+    cipher
+        .decrypt(
+            Nonce::from_slice(&sealed.nonce),
+            Payload {
+                msg: &sealed.ciphertext,
+                aad: &aad,
+            },
+        )
+        .map(Zeroizing::new)
+        .map_err(|_| "Decryption failed: wrong key or tampered share".into())
+}
 
-    // Serialize
-    let nonces_json = serde_json::to_string(&nonces)
-        .map_err(|e| format!("Failed to serialize nonces: {:?}", e))?;
-    let commitments_json = serde_json::to_string(&commitments)
-        .map_err(|e| format!("Failed to serialize commitments: {:?}", e))?;
+/// Associated data binding a sealed share to its participant and both X25519 keys
+fn seal_aad(
+    identifier: u16,
+    ephemeral_public: &x25519_dalek::PublicKey,
+    recipient: &x25519_dalek::PublicKey,
+) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(2 + 32 + 32);
+    aad.extend_from_slice(&identifier.to_be_bytes());
+    aad.extend_from_slice(ephemeral_public.as_bytes());
+    aad.extend_from_slice(recipient.as_bytes());
+    aad
+}
 
-    Ok(Round1Result {
-        commitment: Commitment {
-            identifier: id,
-            commitment: commitments_json,
-        },
-        nonces: SigningNonces {
-            identifier: id,
-            nonces: nonces_json,
-        },
-    })
+fn seal_cipher(shared_secret: &[u8]) -> Result<Aes256Gcm, String> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Hkdf::<Sha256>::new(Some(SEAL_HKDF_SALT), shared_secret)
+        .expand(SEAL_HKDF_INFO, key.as_mut())
+        .map_err(|e| format!("Key derivation failed: {:?}", e))?;
+    Aes256Gcm::new_from_slice(key.as_ref()).map_err(|e| format!("Invalid key: {:?}", e))
 }
 
 // =============================================================================
-// Round 2: Signature Share Generation
+// Distributed Key Generation
 // =============================================================================
 
-/// Generate Round 2 signature share.
+/// DKG part 1: generate this participant's secret polynomial and the
+/// Round 1 package to broadcast.
 ///
 /// # Arguments
-/// * `key_package_json` - The participant's key package (JSON)
-/// * `nonces_json` - The participant's SigningNonces (JSON from Round1)
-/// * `commitments_json` - JSON array of all participants' Commitment objects
-/// * `message_hex` - Message to sign (hex-encoded)
+/// * `identifier` - This participant's identifier
+/// * `max_signers` - Total number of participants (n)
+/// * `min_signers` - Minimum number of signers required (t)
 ///
 /// # Returns
-/// JSON string containing SignatureShare or FrostError
-#[wasm_bindgen]
-pub fn generate_round2_signature(
-    key_package_json: &str,
-    nonces_json: &str,
-    commitments_json: &str,
-    message_hex: &str,
-) -> String {
-    match generate_round2_internal(key_package_json, nonces_json, commitments_json, message_hex) {
-        Ok(result) => serde_json::to_string(&result).unwrap_or_else(|e| {
-            serde_json::to_string(&FrostError {
-                code: "SERIALIZATION_ERROR".into(),
-                message: e.to_string(),
-            })
-            .unwrap()
-        }),
-        Err(e) => serde_json::to_string(&FrostError {
-            code: "ROUND2_ERROR".into(),
-            message: e,
-        })
-        .unwrap(),
-    }
+/// The Round 1 secret package to keep and the package to broadcast, or FrostError
+pub fn dkg_part1(
+    identifier: frost::Identifier,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<
+    (
+        frost::keys::dkg::round1::SecretPackage,
+        frost::keys::dkg::round1::Package,
+    ),
+    FrostError,
+> {
+    dkg::part1::<Ed25519Sha512>(identifier, max_signers, min_signers)
 }
 
-fn generate_round2_internal(
-    key_package_json: &str,
-    nonces_json: &str,
-    commitments_json: &str,
-    message_hex: &str,
-) -> Result<SignatureShare, String> {
-    // Parse key package
-    let key_package: frost::keys::KeyPackage = serde_json::from_str(key_package_json)
-        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
-
-    let identifier = *key_package.identifier();
-    let id_bytes = identifier.serialize();
-    let id: u16 = u16::from_le_bytes([id_bytes[0], id_bytes[1]]);
+/// DKG part 2: verify the other participants' Round 1 packages and produce
+/// one Round 2 package per recipient.
+///
+/// # Arguments
+/// * `secret_package` - Secret package from `dkg_part1`
+/// * `round1_packages` - The other participants' Round 1 packages
+///
+/// # Returns
+/// The Round 2 secret package to keep and the packages to send, or FrostError
+pub fn dkg_part2(
+    secret_package: frost::keys::dkg::round1::SecretPackage,
+    round1_packages: &Round1Packages,
+) -> Result<(frost::keys::dkg::round2::SecretPackage, Round2Packages), FrostError> {
+    dkg::part2::<Ed25519Sha512>(secret_package, round1_packages)
+}
 
-    // Parse nonces (our wrapper type)
-    let my_nonces_wrapper: SigningNonces = serde_json::from_str(nonces_json)
-        .map_err(|e| format!("Invalid nonces wrapper JSON: {}", e))?;
-    let nonces: frost::round1::SigningNonces = serde_json::from_str(&my_nonces_wrapper.nonces)
-        .map_err(|e| format!("Invalid nonces JSON: {}", e))?;
+/// DKG part 3: verify the Round 2 packages addressed to this participant and
+/// derive the final key package and public key package.
+///
+/// # Arguments
+/// * `secret_package` - Secret package from `dkg_part2`
+/// * `round1_packages` - The other participants' Round 1 packages
+/// * `round2_packages` - The Round 2 packages addressed to this participant
+///
+/// # Returns
+/// KeyPackage and PublicKeyPackage, or FrostError
+pub fn dkg_part3(
+    secret_package: &frost::keys::dkg::round2::SecretPackage,
+    round1_packages: &Round1Packages,
+    round2_packages: &Round2Packages,
+) -> Result<(frost::keys::KeyPackage, frost::keys::PublicKeyPackage), FrostError> {
+    dkg::part3::<Ed25519Sha512>(secret_package, round1_packages, round2_packages)
+}
 
-    // Parse commitments (our wrapper type)
-    let commitments_list: Vec<Commitment> = serde_json::from_str(commitments_json)
-        .map_err(|e| format!("Invalid commitments JSON: {}", e))?;
+// =============================================================================
+// Share Refresh
+// =============================================================================
 
-    // Parse message
-    let message = hex::decode(message_hex).map_err(|e| format!("Invalid message hex: {}", e))?;
+/// Dealer-based refresh: deal zero-secret shares that rotate every
+/// participant's key share without changing the group public key.
+///
+/// Every participant must apply their refreshing share with `refresh_share`;
+/// old and refreshed shares cannot be combined afterwards.
+///
+/// # Arguments
+/// * `public_key_package` - Current PublicKeyPackage
+/// * `identifiers` - The participants' identifiers
+/// * `min_signers` - Minimum number of signers required (t), unchanged from keygen
+///
+/// # Returns
+/// The refreshing shares, in `identifiers` order, and the refreshed
+/// PublicKeyPackage, or FrostError
+pub fn compute_refreshing_shares(
+    public_key_package: frost::keys::PublicKeyPackage,
+    identifiers: &[frost::Identifier],
+    min_signers: u16,
+) -> Result<(Vec<frost::keys::SecretShare>, frost::keys::PublicKeyPackage), FrostError> {
+    refresh::deal_refreshing_shares::<Ed25519Sha512>(public_key_package, identifiers, min_signers)
+}
 
-    // Build signing commitments map
-    let mut signing_commitments: BTreeMap<frost::Identifier, frost::round1::SigningCommitments> =
-        BTreeMap::new();
+/// Apply a refreshing share from `compute_refreshing_shares` to a key package.
+///
+/// # Arguments
+/// * `refreshing_share` - This participant's refreshing share
+/// * `key_package` - The participant's current key package
+///
+/// # Returns
+/// The refreshed KeyPackage or FrostError
+pub fn refresh_share(
+    refreshing_share: frost::keys::SecretShare,
+    key_package: &frost::keys::KeyPackage,
+) -> Result<frost::keys::KeyPackage, FrostError> {
+    refresh::apply_refreshing_share::<Ed25519Sha512>(refreshing_share, key_package)
+}
 
-    for c in &commitments_list {
-        let cid = frost::Identifier::try_from(c.identifier)
-            .map_err(|e| format!("Invalid commitment identifier: {:?}", e))?;
+/// Refresh DKG part 1: like `dkg_part1`, but the secret polynomial has a
+/// zero constant term so the group key is unchanged.
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier
+/// * `max_signers` - Total number of participants (n)
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// The Round 1 secret package to keep and the package to broadcast, or FrostError
+pub fn refresh_dkg_part1(
+    identifier: frost::Identifier,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<
+    (
+        frost::keys::dkg::round1::SecretPackage,
+        frost::keys::dkg::round1::Package,
+    ),
+    FrostError,
+> {
+    refresh::refresh_part1::<Ed25519Sha512>(identifier, max_signers, min_signers)
+}
 
-        let commitment: frost::round1::SigningCommitments = serde_json::from_str(&c.commitment)
-            .map_err(|e| format!("Invalid commitment JSON: {}", e))?;
+/// Refresh DKG part 2: same as `dkg_part2` over the zero-secret polynomials.
+///
+/// # Arguments
+/// * `secret_package` - Secret package from `refresh_dkg_part1`
+/// * `round1_packages` - The other participants' Round 1 packages
+///
+/// # Returns
+/// The Round 2 secret package to keep and the packages to send, or FrostError
+pub fn refresh_dkg_part2(
+    secret_package: frost::keys::dkg::round1::SecretPackage,
+    round1_packages: &Round1Packages,
+) -> Result<(frost::keys::dkg::round2::SecretPackage, Round2Packages), FrostError> {
+    refresh::refresh_part2::<Ed25519Sha512>(secret_package, round1_packages)
+}
 
-        signing_commitments.insert(cid, commitment);
-    }
+/// Refresh DKG part 3: add the zero-secret shares to this participant's
+/// current key package and derive the updated public key package.
+///
+/// # Arguments
+/// * `secret_package` - Secret package from `refresh_dkg_part2`
+/// * `round1_packages` - The other participants' Round 1 packages
+/// * `round2_packages` - The Round 2 packages addressed to this participant
+/// * `public_key_package` - Current PublicKeyPackage
+/// * `key_package` - This participant's current key package
+///
+/// # Returns
+/// The refreshed KeyPackage and PublicKeyPackage, or FrostError
+pub fn refresh_dkg_part3(
+    secret_package: &frost::keys::dkg::round2::SecretPackage,
+    round1_packages: &Round1Packages,
+    round2_packages: &Round2Packages,
+    public_key_package: frost::keys::PublicKeyPackage,
+    key_package: frost::keys::KeyPackage,
+) -> Result<(frost::keys::KeyPackage, frost::keys::PublicKeyPackage), FrostError> {
+    refresh::refresh_part3::<Ed25519Sha512>(
+        secret_package,
+        round1_packages,
+        round2_packages,
+        public_key_package,
+        key_package,
+    )
+}
 
-    // Create signing package
-    let signing_package = frost::SigningPackage::new(signing_commitments, &message);
+// =============================================================================
+// Share Repair
+// =============================================================================
 
-    // Generate signature share
-    let signature_share = frost::round2::sign(&signing_package, &nonces, &key_package)
-        .map_err(|e| format!("Signing failed: {:?}", e))?;
+/// Share repair step 1 (helper): split this helper's contribution to the
+/// lost share into one delta per helper.
+///
+/// Any `min_signers` participants can act as helpers. Each delta must be sent
+/// privately to its recipient; helpers never learn each other's shares.
+///
+/// # Arguments
+/// * `helpers` - Helper identifiers including this one
+/// * `key_package` - This helper's key package
+/// * `participant` - Identifier of the participant whose share is being repaired
+///
+/// # Returns
+/// The deltas, keyed by the helper each goes to, or FrostError
+pub fn repair_share_step_1(
+    helpers: &[frost::Identifier],
+    key_package: &frost::keys::KeyPackage,
+    participant: frost::Identifier,
+) -> Result<BTreeMap<frost::Identifier, Scalar>, FrostError> {
+    repair::repair_step_1::<Ed25519Sha512>(helpers, key_package, participant)
+}
 
-    // Serialize
-    let share_json = serde_json::to_string(&signature_share)
-        .map_err(|e| format!("Failed to serialize signature share: {:?}", e))?;
+/// Share repair step 2 (helper): combine the deltas received from every
+/// helper into this helper's sigma.
+///
+/// # Arguments
+/// * `deltas` - Deltas addressed to this helper, one from each helper
+///
+/// # Returns
+/// This helper's sigma
+pub fn repair_share_step_2(deltas: &[Scalar]) -> Scalar {
+    repair::repair_step_2::<Ed25519Sha512>(deltas)
+}
 
-    Ok(SignatureShare {
-        identifier: id,
-        share: share_json,
-    })
+/// Share repair step 3 (participant being repaired): rebuild the lost key
+/// package from the helpers' sigmas.
+///
+/// The result is checked against the participant's verifying share in the
+/// public key package.
+///
+/// # Arguments
+/// * `sigmas` - One sigma from each helper
+/// * `identifier` - This participant's identifier
+/// * `public_key_package` - Group public key package
+/// * `min_signers` - Minimum number of signers required (t)
+///
+/// # Returns
+/// KeyPackage or FrostError
+pub fn repair_share_step_3(
+    sigmas: &[Scalar],
+    identifier: frost::Identifier,
+    public_key_package: &frost::keys::PublicKeyPackage,
+    min_signers: u16,
+) -> Result<frost::keys::KeyPackage, FrostError> {
+    repair::repair_step_3::<Ed25519Sha512>(sigmas, identifier, public_key_package, min_signers)
 }
 
 // =============================================================================
-// Signature Aggregation
+// Resharing
 // =============================================================================
 
-/// Aggregate signature shares into final signature.
+/// Reshare part 1 (current holder): deal this holder's weighted share to a
+/// new participant set with a new threshold.
+///
+/// At least `min_signers` current holders must act as dealers, and all of
+/// them must use the same dealer set. The group public key is unchanged.
+/// Old key shares stay valid under the old PublicKeyPackage, so a removed
+/// participant is only cut off once the remaining holders delete theirs.
 ///
 /// # Arguments
-/// * `shares_json` - JSON array of SignatureShare objects
-/// * `commitments_json` - JSON array of Commitment objects
-/// * `message_hex` - Message that was signed (hex-encoded)
-/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+/// * `key_package` - This dealer's current key package
+/// * `dealers` - Dealer identifiers including this one
+/// * `new_identifiers` - New participant identifiers
+/// * `new_min_signers` - New threshold (t')
 ///
 /// # Returns
-/// JSON string containing AggregateSignature or FrostError
-#[wasm_bindgen]
-pub fn aggregate_signature(
-    shares_json: &str,
-    commitments_json: &str,
-    message_hex: &str,
-    public_key_package_json: &str,
-) -> String {
-    match aggregate_internal(
-        shares_json,
-        commitments_json,
-        message_hex,
-        public_key_package_json,
-    ) {
-        Ok(result) => serde_json::to_string(&result).unwrap_or_else(|e| {
-            serde_json::to_string(&FrostError {
-                code: "SERIALIZATION_ERROR".into(),
-                message: e.to_string(),
-            })
-            .unwrap()
-        }),
-        Err(e) => serde_json::to_string(&FrostError {
-            code: "AGGREGATE_ERROR".into(),
-            message: e,
-        })
-        .unwrap(),
-    }
+/// This dealer's VSS commitment and one sub-share per new participant, or FrostError
+pub fn reshare_part1(
+    key_package: &frost::keys::KeyPackage,
+    dealers: &[frost::Identifier],
+    new_identifiers: &[frost::Identifier],
+    new_min_signers: u16,
+) -> Result<DealtSubShares, FrostError> {
+    reshare::deal_sub_shares::<Ed25519Sha512>(
+        key_package,
+        dealers,
+        new_identifiers,
+        new_min_signers,
+    )
 }
 
-fn aggregate_internal(
-    shares_json: &str,
-    commitments_json: &str,
-    message_hex: &str,
-    public_key_package_json: &str,
-) -> Result<AggregateSignature, String> {
-    // Parse inputs
-    let shares: Vec<SignatureShare> =
-        serde_json::from_str(shares_json).map_err(|e| format!("Invalid shares JSON: {}", e))?;
+/// Reshare part 2 (new participant): combine the dealers' sub-shares into a
+/// key package for the new participant set.
+///
+/// Each dealer's VSS commitment must cover exactly `new_identifiers` with
+/// threshold `new_min_signers` and match its verifying share in the old public
+/// key package; each sub-share is verified against its dealer's commitment,
+/// and the new group key must equal the old one.
+///
+/// # Arguments
+/// * `identifier` - This new participant's identifier
+/// * `sub_shares` - Sub-shares addressed to this participant, keyed by dealer
+/// * `commitments` - Every dealer's VSS commitment, keyed by dealer
+/// * `public_key_package` - The old PublicKeyPackage
+/// * `new_identifiers` - New participant identifiers, as passed to part 1
+/// * `new_min_signers` - New threshold (t'), as passed to part 1
+///
+/// # Returns
+/// The new KeyPackage and PublicKeyPackage, or FrostError
+pub fn reshare_part2(
+    identifier: frost::Identifier,
+    sub_shares: &BTreeMap<frost::Identifier, frost::keys::SecretShare>,
+    commitments: &BTreeMap<frost::Identifier, frost::keys::VerifiableSecretSharingCommitment>,
+    public_key_package: &frost::keys::PublicKeyPackage,
+    new_identifiers: &[frost::Identifier],
+    new_min_signers: u16,
+) -> Result<(frost::keys::KeyPackage, frost::keys::PublicKeyPackage), FrostError> {
+    reshare::combine_sub_shares::<Ed25519Sha512>(
+        identifier,
+        sub_shares,
+        commitments,
+        public_key_package,
+        new_identifiers,
+        new_min_signers,
+    )
+}
 
-    let commitments_list: Vec<Commitment> = serde_json::from_str(commitments_json)
-        .map_err(|e| format!("Invalid commitments JSON: {}", e))?;
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
 
-    let message = hex::decode(message_hex).map_err(|e| format!("Invalid message hex: {}", e))?;
+/// Generate Round 1 commitment and nonces.
+///
+/// # Arguments
+/// * `key_package` - The participant's key package
+///
+/// # Returns
+/// The nonces to keep secret and the commitments to publish
+pub fn generate_round1_commitment(
+    key_package: &frost::keys::KeyPackage,
+) -> (
+    frost::round1::SigningNonces,
+    frost::round1::SigningCommitments,
+) {
+    signing::round1_commit::<Ed25519Sha512>(key_package)
+}
 
-    // Parse public key package
-    let pubkey_package: frost::keys::PublicKeyPackage =
-        serde_json::from_str(public_key_package_json)
-            .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+// =============================================================================
+// Signing Package Creation
+// =============================================================================
 
-    // Build signing commitments map
-    let mut signing_commitments: BTreeMap<frost::Identifier, frost::round1::SigningCommitments> =
-        BTreeMap::new();
+/// Create the signing package for one signing session.
+///
+/// The coordinator calls this once after collecting the commitments and sends
+/// the result to every signer, so all signers sign the same package.
+///
+/// # Arguments
+/// * `commitments` - All participants' commitments
+/// * `message` - Message to sign
+///
+/// # Returns
+/// SigningPackage
+pub fn create_signing_package(
+    commitments: SigningCommitmentsMap,
+    message: &[u8],
+) -> frost::SigningPackage {
+    frost::SigningPackage::new(commitments, message)
+}
 
-    for c in &commitments_list {
-        let id = frost::Identifier::try_from(c.identifier)
-            .map_err(|e| format!("Invalid commitment identifier: {:?}", e))?;
+// =============================================================================
+// Round 2: Signature Share Generation
+// =============================================================================
 
-        let commitment: frost::round1::SigningCommitments = serde_json::from_str(&c.commitment)
-            .map_err(|e| format!("Invalid commitment JSON: {}", e))?;
+/// Generate Round 2 signature share.
+///
+/// Fails with NONCE_REUSED if the nonces already produced a signature share.
+///
+/// # Arguments
+/// * `signing_package` - The coordinator's signing package
+/// * `nonces` - The participant's nonces from Round 1
+/// * `key_package` - The participant's key package
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature(
+    signing_package: &frost::SigningPackage,
+    nonces: &frost::round1::SigningNonces,
+    key_package: &frost::keys::KeyPackage,
+) -> Result<frost::round2::SignatureShare, FrostError> {
+    signing::round2_sign::<Ed25519Sha512>(signing_package, nonces, key_package)
+}
 
-        signing_commitments.insert(id, commitment);
-    }
+// =============================================================================
+// Commitment Pools (Preprocessing)
+// =============================================================================
 
-    // Create signing package
-    let signing_package = frost::SigningPackage::new(signing_commitments, &message);
+/// Preprocess a batch of Round 1 nonces.
+///
+/// The signer publishes the commitments (`SigningNonces::commitments`) ahead
+/// of time, so signing later needs only a single round.
+///
+/// # Arguments
+/// * `key_package` - The participant's key package
+/// * `count` - Number of nonces to generate
+///
+/// # Returns
+/// The nonce pool or FrostError
+pub fn generate_commitment_pool(
+    key_package: &frost::keys::KeyPackage,
+    count: u32,
+) -> Result<Vec<frost::round1::SigningNonces>, FrostError> {
+    pool::preprocess::<Ed25519Sha512>(key_package, count)
+}
 
-    // Build signature shares map
-    let mut frost_shares: BTreeMap<frost::Identifier, frost::round2::SignatureShare> =
-        BTreeMap::new();
+/// Take the oldest unused commitment from each signer's pool (coordinator).
+///
+/// Every signer's pool is checked before any is taken from, so a failure
+/// leaves the pools untouched.
+///
+/// # Arguments
+/// * `pools` - The signers' published commitment pools, keyed by signer
+/// * `signers` - Participant identifiers taking part
+///
+/// # Returns
+/// The selected commitments or FrostError
+pub fn select_pool_commitments(
+    pools: &mut BTreeMap<frost::Identifier, Vec<frost::round1::SigningCommitments>>,
+    signers: &[frost::Identifier],
+) -> Result<SigningCommitmentsMap, FrostError> {
+    pool::select_commitments::<Ed25519Sha512>(pools, signers)
+}
 
-    for s in &shares {
-        let id = frost::Identifier::try_from(s.identifier)
-            .map_err(|e| format!("Invalid share identifier: {:?}", e))?;
+/// Generate a Round 2 signature share using the pooled nonces the
+/// coordinator selected, removing them from the pool even if signing fails.
+///
+/// # Arguments
+/// * `nonce_pool` - The participant's nonce pool
+/// * `signing_package` - The coordinator's signing package
+/// * `key_package` - The participant's key package
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_from_pool(
+    nonce_pool: &mut Vec<frost::round1::SigningNonces>,
+    signing_package: &frost::SigningPackage,
+    key_package: &frost::keys::KeyPackage,
+) -> Result<frost::round2::SignatureShare, FrostError> {
+    pool::sign_from_pool::<Ed25519Sha512>(nonce_pool, signing_package, key_package)
+}
 
-        let share: frost::round2::SignatureShare = serde_json::from_str(&s.share)
-            .map_err(|e| format!("Invalid signature share JSON: {}", e))?;
+// =============================================================================
+// Nonce Registry
+// =============================================================================
 
-        frost_shares.insert(id, share);
-    }
+/// Export the nonce registry so it can be persisted across page reloads.
+///
+/// The export is not authenticated, so whoever stores it can drop entries;
+/// `export_sealed_nonce_registry` prevents that.
+///
+/// # Returns
+/// NonceRegistryState
+pub fn export_nonce_registry() -> NonceRegistryState {
+    nonces::export_nonce_registry()
+}
 
-    // Aggregate signature
-    let signature = frost::aggregate(&signing_package, &frost_shares, &pubkey_package)
-        .map_err(|e| format!("Aggregation failed: {:?}", e))?;
+/// Merge a previously exported registry into the current one.
+///
+/// Entries are only ever added, so importing stale state cannot make used
+/// nonces usable again.
+///
+/// # Arguments
+/// * `state` - NonceRegistryState from `export_nonce_registry`
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_nonce_registry(state: NonceRegistryState) -> Result<NonceRegistryState, FrostError> {
+    nonces::merge_nonce_registry(state)
+}
 
-    let signature_bytes = signature
-        .serialize()
-        .map_err(|e| format!("Failed to serialize signature: {:?}", e))?;
+/// Export the nonce registry sealed under a key package in the keystore.
+///
+/// The entries are encrypted under a key derived from the signing share, so
+/// they cannot be dropped without it. Each export carries a higher counter
+/// than the last one exported or imported for the handle, so after a reload
+/// import the stored export before exporting again.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+///
+/// # Returns
+/// SealedNonceRegistry or FrostError
+pub fn export_sealed_nonce_registry(key_handle: u32) -> Result<SealedNonceRegistry, FrostError> {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().export_nonce_registry(key_handle))
+}
 
-    Ok(AggregateSignature {
-        signature: hex::encode(signature_bytes),
+/// Merge a sealed registry into the current one after decrypting it and
+/// checking it is not older than `min_counter`.
+///
+/// Counters are forgotten on reload, so store the `counter` of each export
+/// where the export itself cannot roll it back and pass it here; otherwise a
+/// stale export is accepted after a reload.
+///
+/// # Arguments
+/// * `key_handle` - Handle to the key package that sealed it
+/// * `sealed` - SealedNonceRegistry from `export_sealed_nonce_registry`
+/// * `min_counter` - Counter of the latest export stored, or 0
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_sealed_nonce_registry(
+    key_handle: u32,
+    sealed: SealedNonceRegistry,
+    min_counter: u64,
+) -> Result<NonceRegistryState, FrostError> {
+    KEYSTORE.with(|keystore| {
+        keystore
+            .borrow_mut()
+            .import_nonce_registry(key_handle, sealed, min_counter)
     })
 }
 
 // =============================================================================
-// Verification
+// Keystore: Opaque Key and Nonce Handles
 // =============================================================================
 
-/// Verify a signature.
+thread_local! {
+    pub(crate) static KEYSTORE: RefCell<Keystore<Ed25519Sha512>> = RefCell::new(Keystore::default());
+}
+
+/// Load a key package into the keystore and return an opaque handle.
 ///
 /// # Arguments
-/// * `signature_hex` - The aggregate signature (hex-encoded)
-/// * `message_hex` - The message that was signed (hex-encoded)
-/// * `group_public_key_hex` - The group public key (hex-encoded)
+/// * `key_package` - The participant's key package
 ///
 /// # Returns
-/// JSON string containing { "valid": bool } or FrostError
-#[wasm_bindgen]
-pub fn verify_signature(
-    signature_hex: &str,
-    message_hex: &str,
-    group_public_key_hex: &str,
-) -> String {
-    match verify_internal(signature_hex, message_hex, group_public_key_hex) {
-        Ok(valid) => serde_json::to_string(&serde_json::json!({ "valid": valid })).unwrap(),
-        Err(e) => serde_json::to_string(&FrostError {
-            code: "VERIFY_ERROR".into(),
-            message: e,
-        })
-        .unwrap(),
-    }
+/// The key handle
+pub fn load_key_package(key_package: frost::keys::KeyPackage) -> u32 {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().load_key_package(key_package))
 }
 
-fn verify_internal(
-    signature_hex: &str,
-    message_hex: &str,
-    group_public_key_hex: &str,
-) -> Result<bool, String> {
-    let sig_bytes = hex::decode(signature_hex).map_err(|e| format!("Invalid signature hex: {}", e))?;
+/// Generate a Round 1 commitment, keeping the nonces in the keystore.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+///
+/// # Returns
+/// The nonce handle and the commitments to publish, or FrostError
+pub fn generate_round1_commitment_with_handle(
+    key_handle: u32,
+) -> Result<(u32, frost::round1::SigningCommitments), FrostError> {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().round1_commit(key_handle))
+}
 
-    let message = hex::decode(message_hex).map_err(|e| format!("Invalid message hex: {}", e))?;
+/// Generate a Round 2 signature share from keystore handles.
+///
+/// The nonce handle is consumed and its nonces zeroized, so it cannot be
+/// used for a second signature.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+/// * `nonce_handle` - Handle from `generate_round1_commitment_with_handle`
+/// * `signing_package` - The coordinator's signing package
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    signing_package: &frost::SigningPackage,
+) -> Result<frost::round2::SignatureShare, FrostError> {
+    KEYSTORE.with(|keystore| {
+        keystore
+            .borrow_mut()
+            .sign(key_handle, nonce_handle, signing_package)
+    })
+}
 
-    let group_key_bytes =
-        hex::decode(group_public_key_hex).map_err(|e| format!("Invalid group public key hex: {}", e))?;
+/// Destroy a key or nonce handle, zeroizing the secret it refers to.
+///
+/// # Returns
+/// `true` if the handle existed
+pub fn destroy(handle: u32) -> bool {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().destroy(handle))
+}
 
-    // Parse signature - frost-ed25519 signatures are 64 bytes
-    let sig_array: [u8; 64] = sig_bytes
-        .try_into()
-        .map_err(|_| "Signature must be 64 bytes")?;
+// =============================================================================
+// Signature Aggregation
+// =============================================================================
 
-    let signature = frost::Signature::deserialize(&sig_array)
-        .map_err(|e| format!("Invalid signature: {:?}", e))?;
+/// Aggregate signature shares into final signature.
+///
+/// If aggregation fails because of invalid signature shares, the error is an
+/// AggregateError whose `culprits` lists the participants who sent them.
+///
+/// # Arguments
+/// * `signing_package` - The coordinator's signing package
+/// * `shares` - Signature shares, keyed by signer
+/// * `public_key_package` - The group's public key package
+///
+/// # Returns
+/// Signature or AggregateError
+pub fn aggregate_signature(
+    signing_package: &frost::SigningPackage,
+    shares: &SignatureShares,
+    public_key_package: &frost::keys::PublicKeyPackage,
+) -> Result<frost::Signature, AggregateError> {
+    signing::aggregate::<Ed25519Sha512>(signing_package, shares, public_key_package)
+}
 
-    // Parse verifying key - Ed25519 public keys are 32 bytes
-    let key_array: [u8; 32] = group_key_bytes
-        .try_into()
-        .map_err(|_| "Public key must be 32 bytes")?;
+/// Verify each signature share against the signer's verifying share.
+///
+/// Lets the coordinator find misbehaving participants without attempting
+/// aggregation.
+///
+/// # Arguments
+/// * `signing_package` - The coordinator's signing package
+/// * `shares` - Signature shares, keyed by signer
+/// * `public_key_package` - The group's public key package
+///
+/// # Returns
+/// ShareVerificationResult or FrostError
+pub fn verify_signature_shares(
+    signing_package: &frost::SigningPackage,
+    shares: &SignatureShares,
+    public_key_package: &frost::keys::PublicKeyPackage,
+) -> Result<ShareVerificationResult, FrostError> {
+    signing::verify_shares::<Ed25519Sha512>(signing_package, shares, public_key_package)
+}
 
-    let verifying_key = frost::VerifyingKey::deserialize(&key_array)
-        .map_err(|e| format!("Invalid group public key: {:?}", e))?;
+// =============================================================================
+// Verification
+// =============================================================================
 
-    // Verify
-    match verifying_key.verify(&message, &signature) {
-        Ok(()) => Ok(true),
-        Err(_) => Ok(false),
-    }
+/// Verify a signature.
+///
+/// # Arguments
+/// * `signature` - The aggregate signature
+/// * `message` - The message that was signed
+/// * `group_public_key` - The group public key
+///
+/// # Returns
+/// `true` if the signature is valid
+pub fn verify_signature(
+    signature: &frost::Signature,
+    message: &[u8],
+    group_public_key: &frost::VerifyingKey,
+) -> bool {
+    group_public_key.verify(message, signature).is_ok()
+}
+
+// =============================================================================
+// Emergency Reconstruction
+// =============================================================================
+
+/// DANGER: reassemble the group signing key from `min_signers` key packages.
+///
+/// This defeats the point of threshold signing: whoever runs it holds the
+/// whole key. Only use it for disaster recovery or migrating off FROST, on an
+/// offline machine, and retire the shares afterwards.
+///
+/// # Arguments
+/// * `key_packages` - At least `min_signers` key packages
+/// * `public_key_package` - Group public key package
+///
+/// # Returns
+/// The group SigningKey or FrostError
+pub fn emergency_reconstruct_signing_key(
+    key_packages: &[frost::keys::KeyPackage],
+    public_key_package: &frost::keys::PublicKeyPackage,
+) -> Result<frost::SigningKey, FrostError> {
+    reconstruct::reconstruct_signing_key::<Ed25519Sha512>(key_packages, public_key_package)
 }
 
 // =============================================================================
@@ -537,82 +1051,56 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_keygen() {
-        let result = generate_key_shares(2, 3);
-        let parsed: Result<KeyGenResult, _> = serde_json::from_str(&result);
-        assert!(parsed.is_ok(), "Key generation should succeed: {}", result);
-
-        let keygen = parsed.unwrap();
-        assert_eq!(keygen.threshold, 2);
-        assert_eq!(keygen.total, 3);
-        assert_eq!(keygen.shares.len(), 3);
-        assert!(!keygen.group_public_key.is_empty());
-        assert!(!keygen.public_key_package.is_empty());
-    }
-
-    #[test]
-    fn test_full_signing_flow() {
-        // Generate keys
-        let keygen_result = generate_key_shares(2, 3);
-        let keygen: KeyGenResult =
-            serde_json::from_str(&keygen_result).expect("Key generation failed");
-
-        // Round 1: Generate commitments for first 2 participants
-        let round1_1 = generate_round1_commitment(&keygen.shares[0].key_package);
-        let r1_1: Round1Result =
-            serde_json::from_str(&round1_1).expect("Round 1 participant 1 failed");
-
-        let round1_2 = generate_round1_commitment(&keygen.shares[1].key_package);
-        let r1_2: Round1Result =
-            serde_json::from_str(&round1_2).expect("Round 1 participant 2 failed");
-
-        // Collect commitments
-        let commitments = vec![r1_1.commitment.clone(), r1_2.commitment.clone()];
-        let commitments_json = serde_json::to_string(&commitments).unwrap();
-
-        // Message to sign
-        let message = "48656c6c6f20576f726c64"; // "Hello World" in hex
-
-        // Round 2: Generate signature shares
-        let nonces_1 = serde_json::to_string(&r1_1.nonces).unwrap();
-        let sig_share_1 = generate_round2_signature(
-            &keygen.shares[0].key_package,
-            &nonces_1,
-            &commitments_json,
-            message,
-        );
-        let share_1: SignatureShare =
-            serde_json::from_str(&sig_share_1).expect("Round 2 participant 1 failed");
-
-        let nonces_2 = serde_json::to_string(&r1_2.nonces).unwrap();
-        let sig_share_2 = generate_round2_signature(
-            &keygen.shares[1].key_package,
-            &nonces_2,
-            &commitments_json,
-            message,
-        );
-        let share_2: SignatureShare =
-            serde_json::from_str(&sig_share_2).expect("Round 2 participant 2 failed");
-
-        // Aggregate
-        let shares = vec![share_1, share_2];
-        let shares_json = serde_json::to_string(&shares).unwrap();
+    fn test_typed_signing_flow() {
+        let (shares, public_key_package) =
+            generate_key_shares(2, 3).expect("Key generation failed");
+        let key_packages: Vec<frost::keys::KeyPackage> = shares
+            .into_values()
+            .take(2)
+            .map(|share| verify_and_accept_share(share, &public_key_package).unwrap())
+            .collect();
+        let message = b"Hello World";
+
+        let round1: Vec<_> = key_packages
+            .iter()
+            .map(generate_round1_commitment)
+            .collect();
+        let commitments = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(k, (_, c))| (*k.identifier(), *c))
+            .collect();
+        let signing_package = create_signing_package(commitments, message);
+
+        let shares: SignatureShares = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(k, (nonces, _))| {
+                let share =
+                    generate_round2_signature(&signing_package, nonces, k).expect("Round 2 failed");
+                (*k.identifier(), share)
+            })
+            .collect();
 
-        let agg_result = aggregate_signature(
-            &shares_json,
-            &commitments_json,
+        let signature = aggregate_signature(&signing_package, &shares, &public_key_package)
+            .expect("Aggregation failed");
+        assert!(verify_signature(
+            &signature,
             message,
-            &keygen.public_key_package,
-        );
-        let agg: AggregateSignature =
-            serde_json::from_str(&agg_result).expect("Aggregation failed");
-
-        assert!(!agg.signature.is_empty());
+            public_key_package.verifying_key()
+        ));
 
-        // Verify
-        let verify_result = verify_signature(&agg.signature, message, &keygen.group_public_key);
-        let verify: serde_json::Value =
-            serde_json::from_str(&verify_result).expect("Verification parsing failed");
-        assert_eq!(verify["valid"], true, "Signature should be valid");
+        // The nonces cannot sign a second time
+        let Err(error) =
+            generate_round2_signature(&signing_package, &round1[0].0, &key_packages[0])
+        else {
+            panic!("Reused nonces should fail");
+        };
+        assert_eq!(error.code, "NONCE_REUSED");
+
+        let Err(error) = generate_key_shares(4, 3) else {
+            panic!("Threshold above the total should fail");
+        };
+        assert_eq!(error.code, "KEYGEN_ERROR");
     }
 }
@@ -0,0 +1,1306 @@
+//! wasm-bindgen exports
+//!
+//! Each export takes the same arguments as the function of the same name in
+//! the `json` module and returns its result as a JSON string: the value on
+//! success, the FrostError (or AggregateError) otherwise.
+
+use frost_common::to_json;
+use wasm_bindgen::prelude::*;
+
+use crate::FrostError;
+
+// Initialize panic hook for better error messages in WASM
+#[wasm_bindgen(start)]
+pub fn init() {
+    #[cfg(feature = "console_error_panic_hook")]
+    console_error_panic_hook::set_once();
+}
+
+// =============================================================================
+// Key Generation
+// =============================================================================
+
+/// Generate key shares using trusted dealer key generation.
+///
+/// # Returns
+/// JSON string containing KeyGenResult or FrostError
+#[wasm_bindgen]
+pub fn generate_key_shares(threshold: u16, total: u16) -> String {
+    to_json(crate::json::generate_key_shares(threshold, total))
+}
+
+/// Split an existing Ed25519 private key into key shares, keeping its public key.
+///
+/// # Returns
+/// JSON string containing KeyGenResult or FrostError
+#[wasm_bindgen]
+pub fn split_key_shares(secret_hex: &str, key_format: &str, threshold: u16, total: u16) -> String {
+    to_json(crate::json::split_key_shares(
+        secret_hex, key_format, threshold, total,
+    ))
+}
+
+/// Verify a dealer-issued secret share and turn it into a key package.
+///
+/// # Returns
+/// JSON string containing KeyShare or FrostError
+#[wasm_bindgen]
+pub fn verify_and_accept_share(secret_share_json: &str, public_key_package_json: &str) -> String {
+    to_json(crate::json::verify_and_accept_share(
+        secret_share_json,
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Sealed Share Delivery
+// =============================================================================
+
+/// Generate key shares with each secret share sealed to its recipient.
+///
+/// # Returns
+/// JSON string containing SealedKeyGenResult or FrostError
+#[wasm_bindgen]
+pub fn generate_sealed_key_shares(threshold: u16, recipient_public_keys_json: &str) -> String {
+    to_json(crate::json::generate_sealed_key_shares(
+        threshold,
+        recipient_public_keys_json,
+    ))
+}
+
+/// Open a sealed key share with the participant's X25519 private key and
+/// verify it against the public key package.
+///
+/// # Returns
+/// JSON string containing KeyShare or FrostError
+#[wasm_bindgen]
+pub fn open_share(
+    sealed_share_json: &str,
+    private_key_hex: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::open_share(
+        sealed_share_json,
+        private_key_hex,
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Distributed Key Generation
+// =============================================================================
+
+/// DKG part 1: generate this participant's secret polynomial and the
+/// Round 1 package to broadcast.
+///
+/// # Returns
+/// JSON string containing DkgRound1Result or FrostError
+#[wasm_bindgen]
+pub fn dkg_part1(identifier: u16, max_signers: u16, min_signers: u16) -> String {
+    to_json(crate::json::dkg_part1(identifier, max_signers, min_signers))
+}
+
+/// DKG part 2: verify the other participants' Round 1 packages and produce
+/// one Round 2 package per recipient.
+///
+/// # Returns
+/// JSON string containing DkgRound2Result or FrostError
+#[wasm_bindgen]
+pub fn dkg_part2(secret_package_json: &str, round1_packages_json: &str) -> String {
+    to_json(crate::json::dkg_part2(
+        secret_package_json,
+        round1_packages_json,
+    ))
+}
+
+/// DKG part 3: verify the Round 2 packages addressed to this participant and
+/// derive the final key package and public key package.
+///
+/// # Returns
+/// JSON string containing DkgResult or FrostError
+#[wasm_bindgen]
+pub fn dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+) -> String {
+    to_json(crate::json::dkg_part3(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+    ))
+}
+
+// =============================================================================
+// Share Refresh
+// =============================================================================
+
+/// Dealer-based refresh: deal zero-secret shares that rotate every
+/// participant's key share without changing the group public key.
+///
+/// # Returns
+/// JSON string containing RefreshSharesResult or FrostError
+#[wasm_bindgen]
+pub fn compute_refreshing_shares(
+    public_key_package_json: &str,
+    identifiers_json: &str,
+    min_signers: u16,
+) -> String {
+    to_json(crate::json::compute_refreshing_shares(
+        public_key_package_json,
+        identifiers_json,
+        min_signers,
+    ))
+}
+
+/// Apply a refreshing share from `compute_refreshing_shares` to a key package.
+///
+/// # Returns
+/// JSON string containing RefreshedKeyPackage or FrostError
+#[wasm_bindgen]
+pub fn refresh_share(refreshing_share_json: &str, key_package_json: &str) -> String {
+    to_json(crate::json::refresh_share(
+        refreshing_share_json,
+        key_package_json,
+    ))
+}
+
+/// Refresh DKG part 1: like `dkg_part1`, but the secret polynomial has a
+/// zero constant term so the group key is unchanged.
+///
+/// # Returns
+/// JSON string containing DkgRound1Result or FrostError
+#[wasm_bindgen]
+pub fn refresh_dkg_part1(identifier: u16, max_signers: u16, min_signers: u16) -> String {
+    to_json(crate::json::refresh_dkg_part1(
+        identifier,
+        max_signers,
+        min_signers,
+    ))
+}
+
+/// Refresh DKG part 2: same wire format as `dkg_part2`.
+///
+/// # Returns
+/// JSON string containing DkgRound2Result or FrostError
+#[wasm_bindgen]
+pub fn refresh_dkg_part2(secret_package_json: &str, round1_packages_json: &str) -> String {
+    to_json(crate::json::refresh_dkg_part2(
+        secret_package_json,
+        round1_packages_json,
+    ))
+}
+
+/// Refresh DKG part 3: add the zero-secret shares to this participant's
+/// current key package and derive the updated public key package.
+///
+/// # Returns
+/// JSON string containing DkgResult or FrostError
+#[wasm_bindgen]
+pub fn refresh_dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+    public_key_package_json: &str,
+    key_package_json: &str,
+) -> String {
+    to_json(crate::json::refresh_dkg_part3(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+        public_key_package_json,
+        key_package_json,
+    ))
+}
+
+// =============================================================================
+// Share Repair
+// =============================================================================
+
+/// Share repair step 1 (helper): split this helper's contribution to the
+/// lost share into one delta per helper.
+///
+/// # Returns
+/// JSON string containing RepairStep1Result or FrostError
+#[wasm_bindgen]
+pub fn repair_share_step_1(helpers_json: &str, key_package_json: &str, participant: u16) -> String {
+    to_json(crate::json::repair_share_step_1(
+        helpers_json,
+        key_package_json,
+        participant,
+    ))
+}
+
+/// Share repair step 2 (helper): combine the deltas received from every
+/// helper into this helper's sigma.
+///
+/// # Returns
+/// JSON string containing RepairSigma or FrostError
+#[wasm_bindgen]
+pub fn repair_share_step_2(deltas_json: &str) -> String {
+    to_json(crate::json::repair_share_step_2(deltas_json))
+}
+
+/// Share repair step 3 (participant being repaired): rebuild the lost key
+/// package from the helpers' sigmas.
+///
+/// # Returns
+/// JSON string containing KeyShare or FrostError
+#[wasm_bindgen]
+pub fn repair_share_step_3(
+    sigmas_json: &str,
+    identifier: u16,
+    public_key_package_json: &str,
+    min_signers: u16,
+) -> String {
+    to_json(crate::json::repair_share_step_3(
+        sigmas_json,
+        identifier,
+        public_key_package_json,
+        min_signers,
+    ))
+}
+
+// =============================================================================
+// Resharing
+// =============================================================================
+
+/// Reshare part 1 (current holder): deal this holder's weighted share to a
+/// new participant set with a new threshold.
+///
+/// # Returns
+/// JSON string containing ReshareRound1Result or FrostError
+#[wasm_bindgen]
+pub fn reshare_part1(
+    key_package_json: &str,
+    dealers_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> String {
+    to_json(crate::json::reshare_part1(
+        key_package_json,
+        dealers_json,
+        new_identifiers_json,
+        new_min_signers,
+    ))
+}
+
+/// Reshare part 2 (new participant): combine the dealers' sub-shares into a
+/// key package for the new participant set.
+///
+/// # Returns
+/// JSON string containing DkgResult or FrostError
+#[wasm_bindgen]
+pub fn reshare_part2(
+    identifier: u16,
+    sub_shares_json: &str,
+    commitments_json: &str,
+    public_key_package_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> String {
+    to_json(crate::json::reshare_part2(
+        identifier,
+        sub_shares_json,
+        commitments_json,
+        public_key_package_json,
+        new_identifiers_json,
+        new_min_signers,
+    ))
+}
+
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
+
+/// Generate Round 1 commitment and nonces.
+///
+/// # Returns
+/// JSON string containing Round1Result or FrostError
+#[wasm_bindgen]
+pub fn generate_round1_commitment(key_package_json: &str) -> String {
+    to_json(crate::json::generate_round1_commitment(key_package_json))
+}
+
+// =============================================================================
+// Signing Package Creation
+// =============================================================================
+
+/// Create the signing package for one signing session.
+///
+/// # Returns
+/// JSON string containing SigningPackage or FrostError
+#[wasm_bindgen]
+pub fn create_signing_package(commitments_json: &str, message_hex: &str) -> String {
+    to_json(crate::json::create_signing_package(
+        commitments_json,
+        message_hex,
+    ))
+}
+
+// =============================================================================
+// Round 2: Signature Share Generation
+// =============================================================================
+
+/// Generate Round 2 signature share.
+///
+/// # Returns
+/// JSON string containing SignatureShare or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature(
+    key_package_json: &str,
+    nonces_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature(
+        key_package_json,
+        nonces_json,
+        commitments_json,
+        message_hex,
+    ))
+}
+
+/// Generate Round 2 signature share over the coordinator's signing package.
+///
+/// # Returns
+/// JSON string containing SignatureShare or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature_with_package(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature_with_package(
+        key_package_json,
+        nonces_json,
+        signing_package_json,
+    ))
+}
+
+// =============================================================================
+// Commitment Pools (Preprocessing)
+// =============================================================================
+
+/// Preprocess a batch of Round 1 commitments.
+///
+/// # Returns
+/// JSON string containing CommitmentPoolResult or FrostError
+#[wasm_bindgen]
+pub fn generate_commitment_pool(key_package_json: &str, count: u32) -> String {
+    to_json(crate::json::generate_commitment_pool(
+        key_package_json,
+        count,
+    ))
+}
+
+/// Take the oldest unused commitment from each signer's pool (coordinator).
+///
+/// # Returns
+/// JSON string containing PoolSelection or FrostError
+#[wasm_bindgen]
+pub fn select_pool_commitments(pools_json: &str, signers_json: &str) -> String {
+    to_json(crate::json::select_pool_commitments(
+        pools_json,
+        signers_json,
+    ))
+}
+
+/// Generate a Round 2 signature share using the pooled nonces the
+/// coordinator selected.
+///
+/// # Returns
+/// JSON string containing PoolSignatureResult or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature_from_pool(
+    key_package_json: &str,
+    nonce_pool_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature_from_pool(
+        key_package_json,
+        nonce_pool_json,
+        commitments_json,
+        message_hex,
+    ))
+}
+
+// =============================================================================
+// Nonce Registry
+// =============================================================================
+
+/// Export the nonce registry so it can be persisted across page reloads.
+///
+/// # Returns
+/// JSON string containing NonceRegistryState
+#[wasm_bindgen]
+pub fn export_nonce_registry() -> String {
+    to_json::<_, FrostError>(Ok(crate::export_nonce_registry()))
+}
+
+/// Merge a previously exported registry into the current one.
+///
+/// # Returns
+/// JSON string containing the merged NonceRegistryState or FrostError
+#[wasm_bindgen]
+pub fn import_nonce_registry(state_json: &str) -> String {
+    to_json(crate::json::import_nonce_registry(state_json))
+}
+
+/// Export the nonce registry sealed under a key package in the keystore.
+///
+/// # Returns
+/// JSON string containing SealedNonceRegistry or FrostError
+#[wasm_bindgen]
+pub fn export_sealed_nonce_registry(key_handle: u32) -> String {
+    to_json(crate::export_sealed_nonce_registry(key_handle))
+}
+
+/// Merge a sealed registry into the current one after decrypting it and
+/// checking it is not older than `min_counter` (a BigInt in JavaScript: the
+/// `counter` of the latest export stored, or 0).
+///
+/// # Returns
+/// JSON string containing the merged NonceRegistryState or FrostError
+#[wasm_bindgen]
+pub fn import_sealed_nonce_registry(
+    key_handle: u32,
+    sealed_json: &str,
+    min_counter: u64,
+) -> String {
+    to_json(crate::json::import_sealed_nonce_registry(
+        key_handle,
+        sealed_json,
+        min_counter,
+    ))
+}
+
+// =============================================================================
+// Keystore: Opaque Key and Nonce Handles
+// =============================================================================
+
+/// Load a key package into the keystore and return an opaque handle.
+///
+/// # Returns
+/// JSON string containing KeyHandle or FrostError
+#[wasm_bindgen]
+pub fn load_key_package(key_package_json: &str) -> String {
+    to_json(crate::json::load_key_package(key_package_json))
+}
+
+/// Generate a Round 1 commitment, keeping the nonces in the keystore.
+///
+/// # Returns
+/// JSON string containing Round1HandleResult or FrostError
+#[wasm_bindgen]
+pub fn generate_round1_commitment_with_handle(key_handle: u32) -> String {
+    to_json(crate::json::generate_round1_commitment_with_handle(
+        key_handle,
+    ))
+}
+
+/// Generate a Round 2 signature share from keystore handles.
+///
+/// # Returns
+/// JSON string containing SignatureShare or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    commitments_json: &str,
+    message_hex: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature_with_handles(
+        key_handle,
+        nonce_handle,
+        commitments_json,
+        message_hex,
+    ))
+}
+
+/// Destroy a key or nonce handle, zeroizing the secret it refers to.
+///
+/// # Returns
+/// `true` if the handle existed
+#[wasm_bindgen]
+pub fn destroy(handle: u32) -> bool {
+    crate::destroy(handle)
+}
+
+// =============================================================================
+// Signature Aggregation
+// =============================================================================
+
+/// Aggregate signature shares into final signature.
+///
+/// # Returns
+/// JSON string containing AggregateSignature or AggregateError
+#[wasm_bindgen]
+pub fn aggregate_signature(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::aggregate_signature(
+        shares_json,
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    ))
+}
+
+/// Aggregate signature shares over the coordinator's signing package.
+///
+/// # Returns
+/// JSON string containing AggregateSignature or AggregateError
+#[wasm_bindgen]
+pub fn aggregate_signature_with_package(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::aggregate_signature_with_package(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+    ))
+}
+
+/// Verify each signature share against the signer's verifying share.
+///
+/// # Returns
+/// JSON string containing ShareVerificationResult or FrostError
+#[wasm_bindgen]
+pub fn verify_signature_shares(
+    shares_json: &str,
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::verify_signature_shares(
+        shares_json,
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Verification
+// =============================================================================
+
+/// Verify a signature.
+///
+/// # Returns
+/// JSON string containing { "valid": bool } or FrostError
+#[wasm_bindgen]
+pub fn verify_signature(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+) -> String {
+    to_json(crate::json::verify_signature(
+        signature_hex,
+        message_hex,
+        group_public_key_hex,
+    ))
+}
+
+// =============================================================================
+// Emergency Reconstruction
+// =============================================================================
+
+/// DANGER: reassemble the group signing key from `min_signers` key packages.
+///
+/// # Returns
+/// JSON string containing ReconstructedKey or FrostError
+#[wasm_bindgen]
+pub fn emergency_reconstruct_signing_key(
+    key_packages_json: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::emergency_reconstruct_signing_key(
+        key_packages_json,
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Utility Functions
+// =============================================================================
+
+/// Get the public key from a key package
+///
+/// # Returns
+/// JSON string with public key (hex) or FrostError
+#[wasm_bindgen]
+pub fn get_public_key(key_package_json: &str) -> String {
+    to_json(crate::json::get_public_key(key_package_json))
+}
+
+/// Get the group public key from a public key package
+///
+/// # Returns
+/// Hex-encoded group public key or error
+#[wasm_bindgen]
+pub fn get_group_public_key(public_key_package_json: &str) -> String {
+    crate::json::get_group_public_key(public_key_package_json)
+        .unwrap_or_else(|e| to_json::<(), _>(Err(e)))
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        seed_to_scalar, AggregateError, AggregateSignature, Commitment, CommitmentPoolResult,
+        DkgResult, DkgRound1Package, DkgRound1Result, DkgRound2Package, DkgRound2Result,
+        KeyGenResult, KeyHandle, KeyShare, NonceRegistryState, PublicKeyResult, ReconstructedKey,
+        RefreshSharesResult, RefreshedKeyPackage, RepairStep1Result, ReshareRound1Result,
+        Round1HandleResult, Round1Result, SealedKeyGenResult, SealedNonceRegistry,
+        ShareVerificationResult, SignatureShare, SigningPackage, VerifyResult,
+    };
+    use rand::rngs::OsRng;
+
+    #[test]
+    fn test_keygen() {
+        let result = generate_key_shares(2, 3);
+        let parsed: Result<KeyGenResult, _> = serde_json::from_str(&result);
+        assert!(parsed.is_ok(), "Key generation should succeed: {}", result);
+
+        let keygen = parsed.unwrap();
+        assert_eq!(keygen.threshold, 2);
+        assert_eq!(keygen.total, 3);
+        assert_eq!(keygen.shares.len(), 3);
+        assert!(!keygen.group_public_key.is_empty());
+        assert!(!keygen.public_key_package.is_empty());
+    }
+
+    #[test]
+    fn test_full_signing_flow() {
+        // Generate keys
+        let keygen_result = generate_key_shares(2, 3);
+        let keygen: KeyGenResult =
+            serde_json::from_str(&keygen_result).expect("Key generation failed");
+
+        // Round 1: Generate commitments for first 2 participants
+        let round1_1 = generate_round1_commitment(&keygen.shares[0].key_package);
+        let r1_1: Round1Result =
+            serde_json::from_str(&round1_1).expect("Round 1 participant 1 failed");
+
+        let round1_2 = generate_round1_commitment(&keygen.shares[1].key_package);
+        let r1_2: Round1Result =
+            serde_json::from_str(&round1_2).expect("Round 1 participant 2 failed");
+
+        // Collect commitments
+        let commitments = vec![r1_1.commitment.clone(), r1_2.commitment.clone()];
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        // Message to sign
+        let message = "48656c6c6f20576f726c64"; // "Hello World" in hex
+
+        // Round 2: Generate signature shares
+        let nonces_1 = serde_json::to_string(&r1_1.nonces).unwrap();
+        let sig_share_1 = generate_round2_signature(
+            &keygen.shares[0].key_package,
+            &nonces_1,
+            &commitments_json,
+            message,
+        );
+        let share_1: SignatureShare =
+            serde_json::from_str(&sig_share_1).expect("Round 2 participant 1 failed");
+
+        let nonces_2 = serde_json::to_string(&r1_2.nonces).unwrap();
+        let sig_share_2 = generate_round2_signature(
+            &keygen.shares[1].key_package,
+            &nonces_2,
+            &commitments_json,
+            message,
+        );
+        let share_2: SignatureShare =
+            serde_json::from_str(&sig_share_2).expect("Round 2 participant 2 failed");
+
+        // Aggregate
+        let shares = vec![share_1, share_2];
+        let shares_json = serde_json::to_string(&shares).unwrap();
+
+        let agg_result = aggregate_signature(
+            &shares_json,
+            &commitments_json,
+            message,
+            &keygen.public_key_package,
+        );
+        let agg: AggregateSignature =
+            serde_json::from_str(&agg_result).expect("Aggregation failed");
+
+        assert!(!agg.signature.is_empty());
+
+        // Verify
+        let verify_result = verify_signature(&agg.signature, message, &keygen.group_public_key);
+        let verify: serde_json::Value =
+            serde_json::from_str(&verify_result).expect("Verification parsing failed");
+        assert_eq!(verify["valid"], true, "Signature should be valid");
+    }
+
+    #[test]
+    fn test_dkg_signing_flow() {
+        let (min_signers, max_signers) = (2u16, 3u16);
+
+        // Part 1: every participant broadcasts a Round 1 package
+        let round1: Vec<DkgRound1Result> = (1..=max_signers)
+            .map(|id| {
+                let result = dkg_part1(id, max_signers, min_signers);
+                serde_json::from_str(&result).expect("DKG part 1 failed")
+            })
+            .collect();
+
+        let others_round1 = |id: u16| {
+            let packages: Vec<DkgRound1Package> = round1
+                .iter()
+                .filter(|r| r.package.identifier != id)
+                .map(|r| r.package.clone())
+                .collect();
+            serde_json::to_string(&packages).unwrap()
+        };
+
+        // Part 2: every participant sends one Round 2 package to each other participant
+        let round2: Vec<DkgRound2Result> = round1
+            .iter()
+            .map(|r| {
+                let result = dkg_part2(&r.secret_package, &others_round1(r.package.identifier));
+                serde_json::from_str(&result).expect("DKG part 2 failed")
+            })
+            .collect();
+
+        // Part 3: every participant derives its key package
+        let results: Vec<DkgResult> = (1..=max_signers)
+            .map(|id| {
+                let inbox: Vec<DkgRound2Package> = round2
+                    .iter()
+                    .flat_map(|r| r.packages.iter())
+                    .filter(|p| p.recipient == id)
+                    .cloned()
+                    .collect();
+                let result = dkg_part3(
+                    &round2[(id - 1) as usize].secret_package,
+                    &others_round1(id),
+                    &serde_json::to_string(&inbox).unwrap(),
+                );
+                serde_json::from_str(&result).expect("DKG part 3 failed")
+            })
+            .collect();
+
+        // Everyone agrees on the group public key
+        let group_public_key = &results[0].group_public_key;
+        assert!(results
+            .iter()
+            .all(|r| &r.group_public_key == group_public_key));
+
+        // Sign with participants 1 and 3
+        let message = "48656c6c6f20576f726c64";
+        let signers = [&results[0], &results[2]];
+
+        let round1_results: Vec<Round1Result> = signers
+            .iter()
+            .map(|s| {
+                serde_json::from_str(&generate_round1_commitment(&s.key_package))
+                    .expect("Round 1 failed")
+            })
+            .collect();
+        let commitments: Vec<Commitment> = round1_results
+            .iter()
+            .map(|r| r.commitment.clone())
+            .collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let shares: Vec<SignatureShare> = signers
+            .iter()
+            .zip(&round1_results)
+            .map(|(s, r)| {
+                let result = generate_round2_signature(
+                    &s.key_package,
+                    &serde_json::to_string(&r.nonces).unwrap(),
+                    &commitments_json,
+                    message,
+                );
+                serde_json::from_str(&result).expect("Round 2 failed")
+            })
+            .collect();
+
+        let agg_result = aggregate_signature(
+            &serde_json::to_string(&shares).unwrap(),
+            &commitments_json,
+            message,
+            &results[1].public_key_package,
+        );
+        let agg: AggregateSignature =
+            serde_json::from_str(&agg_result).expect("Aggregation failed");
+
+        let verify_result = verify_signature(&agg.signature, message, group_public_key);
+        let verify: serde_json::Value = serde_json::from_str(&verify_result).unwrap();
+        assert_eq!(verify["valid"], true, "DKG signature should be valid");
+    }
+
+    /// Run both signing rounds with the given key packages, returning the raw aggregate output
+    fn sign_with(key_packages: &[&str], public_key_package: &str, message: &str) -> String {
+        let round1: Vec<Round1Result> = key_packages
+            .iter()
+            .map(|kp| serde_json::from_str(&generate_round1_commitment(kp)).unwrap())
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let shares: Vec<SignatureShare> = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(kp, r)| {
+                let nonces = serde_json::to_string(&r.nonces).unwrap();
+                serde_json::from_str(&generate_round2_signature(
+                    kp,
+                    &nonces,
+                    &commitments_json,
+                    message,
+                ))
+                .unwrap()
+            })
+            .collect();
+
+        aggregate_signature(
+            &serde_json::to_string(&shares).unwrap(),
+            &commitments_json,
+            message,
+            public_key_package,
+        )
+    }
+
+    /// Key maintenance and the nonce registry are tested generically in
+    /// frost-common; check the exports round-trip through JSON
+    #[test]
+    fn test_maintenance_exports_round_trip() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let key_package = &keygen.shares[0].key_package;
+
+        let refresh: RefreshSharesResult = serde_json::from_str(&compute_refreshing_shares(
+            &keygen.public_key_package,
+            "[1, 2, 3]",
+            2,
+        ))
+        .expect("Refresh should succeed");
+        let _: RefreshedKeyPackage = serde_json::from_str(&refresh_share(
+            &serde_json::to_string(&refresh.shares[0]).unwrap(),
+            key_package,
+        ))
+        .expect("Applying refresh should succeed");
+
+        let _: RepairStep1Result =
+            serde_json::from_str(&repair_share_step_1("[1, 2]", key_package, 3))
+                .expect("Repair step 1 should succeed");
+
+        let _: ReshareRound1Result =
+            serde_json::from_str(&reshare_part1(key_package, "[1, 3]", "[1, 3, 4, 5]", 3))
+                .expect("Reshare part 1 should succeed");
+        let error: FrostError =
+            serde_json::from_str(&reshare_part1(key_package, "[1]", "[1, 3, 4, 5]", 3))
+                .expect("Too few dealers");
+        assert_eq!(error.code, "RESHARE_ERROR");
+
+        let pool: CommitmentPoolResult =
+            serde_json::from_str(&generate_commitment_pool(key_package, 2)).unwrap();
+        assert_eq!(pool.commitments.commitments.len(), 2);
+
+        let state: NonceRegistryState = serde_json::from_str(&export_nonce_registry()).unwrap();
+        let _: NonceRegistryState = serde_json::from_str(&import_nonce_registry(
+            &serde_json::to_string(&state).unwrap(),
+        ))
+        .unwrap();
+        let error: FrostError =
+            serde_json::from_str(&import_nonce_registry(r#"{"used_nonces":["zz"]}"#)).unwrap();
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+
+        let key_handle: KeyHandle = serde_json::from_str(&load_key_package(key_package)).unwrap();
+        let sealed_json = export_sealed_nonce_registry(key_handle.handle);
+        let mut sealed: SealedNonceRegistry = serde_json::from_str(&sealed_json).unwrap();
+        let _: NonceRegistryState = serde_json::from_str(&import_sealed_nonce_registry(
+            key_handle.handle,
+            &sealed_json,
+            sealed.counter,
+        ))
+        .expect("Sealed registry should open");
+        sealed.counter += 1;
+        let error: FrostError = serde_json::from_str(&import_sealed_nonce_registry(
+            key_handle.handle,
+            &serde_json::to_string(&sealed).unwrap(),
+            0,
+        ))
+        .unwrap();
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+    }
+
+    #[test]
+    fn test_split_existing_key() {
+        // RFC 8032 test vector 1
+        let seed = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+        let public_key = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+        let keygen: KeyGenResult = serde_json::from_str(&split_key_shares(seed, "seed", 2, 3))
+            .expect("Splitting a seed should succeed");
+        assert_eq!(keygen.group_public_key, public_key);
+
+        let message = "48656c6c6f20576f726c64";
+        let agg: AggregateSignature = serde_json::from_str(&sign_with(
+            &[&keygen.shares[0].key_package, &keygen.shares[2].key_package],
+            &keygen.public_key_package,
+            message,
+        ))
+        .expect("Split shares should sign");
+        let verify: serde_json::Value =
+            serde_json::from_str(&verify_signature(&agg.signature, message, public_key)).unwrap();
+        assert_eq!(verify["valid"], true);
+
+        let result = split_key_shares(seed, "pem", 2, 3);
+        let error: FrostError = serde_json::from_str(&result).expect("Unknown format");
+        assert_eq!(error.code, "KEYGEN_ERROR");
+    }
+
+    #[test]
+    fn test_emergency_reconstruct() {
+        let seed = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+        let keygen: KeyGenResult =
+            serde_json::from_str(&split_key_shares(seed, "seed", 2, 3)).unwrap();
+
+        let two =
+            serde_json::to_string(&[&keygen.shares[0].key_package, &keygen.shares[2].key_package])
+                .unwrap();
+        let key: ReconstructedKey = serde_json::from_str(&emergency_reconstruct_signing_key(
+            &two,
+            &keygen.public_key_package,
+        ))
+        .expect("Reconstruction should succeed");
+        assert_eq!(
+            key.signing_key,
+            hex::encode(seed_to_scalar(
+                &hex::decode(seed).unwrap().try_into().unwrap()
+            ))
+        );
+
+        // One share is below the threshold
+        let one = serde_json::to_string(&[&keygen.shares[0].key_package]).unwrap();
+        let result = emergency_reconstruct_signing_key(&one, &keygen.public_key_package);
+        let error: FrostError = serde_json::from_str(&result).expect("Too few shares");
+        assert_eq!(error.code, "RECONSTRUCT_ERROR");
+    }
+
+    #[test]
+    fn test_identifies_cheating_signer() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let signers = [&keygen.shares[0], &keygen.shares[1]];
+
+        let round1: Vec<Round1Result> = signers
+            .iter()
+            .map(|s| serde_json::from_str(&generate_round1_commitment(&s.key_package)).unwrap())
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        // Participant 2 signs a different message
+        let message = "48656c6c6f20576f726c64";
+        let shares: Vec<SignatureShare> = signers
+            .iter()
+            .zip(&round1)
+            .zip([message, "6261642062616420626164"])
+            .map(|((s, r), m)| {
+                let nonces = serde_json::to_string(&r.nonces).unwrap();
+                serde_json::from_str(&generate_round2_signature(
+                    &s.key_package,
+                    &nonces,
+                    &commitments_json,
+                    m,
+                ))
+                .unwrap()
+            })
+            .collect();
+        let shares_json = serde_json::to_string(&shares).unwrap();
+
+        let check: ShareVerificationResult = serde_json::from_str(&verify_signature_shares(
+            &shares_json,
+            &commitments_json,
+            message,
+            &keygen.public_key_package,
+        ))
+        .expect("Share verification should run");
+        assert!(!check.valid);
+        assert_eq!(check.culprits, vec![2]);
+
+        let error: AggregateError = serde_json::from_str(&aggregate_signature(
+            &shares_json,
+            &commitments_json,
+            message,
+            &keygen.public_key_package,
+        ))
+        .expect("Aggregation should fail");
+        assert_eq!(error.code, "AGGREGATE_ERROR");
+        assert_eq!(error.culprits, vec![2]);
+    }
+
+    #[test]
+    fn test_verify_and_accept_share() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let share = &keygen.shares[1];
+
+        let accepted: KeyShare = serde_json::from_str(&verify_and_accept_share(
+            share.secret_share.as_ref().unwrap(),
+            &keygen.public_key_package,
+        ))
+        .expect("Honest share should be accepted");
+        assert_eq!(accepted.key_package, share.key_package);
+
+        // A share from a different dealing is rejected
+        let other: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let result = verify_and_accept_share(
+            other.shares[1].secret_share.as_ref().unwrap(),
+            &keygen.public_key_package,
+        );
+        let error: FrostError = serde_json::from_str(&result).expect("Should be rejected");
+        assert_eq!(error.code, "VERIFY_ERROR");
+    }
+
+    #[test]
+    fn test_sealed_share_delivery() {
+        let secrets: Vec<x25519_dalek::StaticSecret> = (0..3)
+            .map(|_| x25519_dalek::StaticSecret::random_from_rng(OsRng))
+            .collect();
+        let public_keys: Vec<String> = secrets
+            .iter()
+            .map(|s| hex::encode(x25519_dalek::PublicKey::from(s).as_bytes()))
+            .collect();
+
+        let keygen: SealedKeyGenResult = serde_json::from_str(&generate_sealed_key_shares(
+            2,
+            &serde_json::to_string(&public_keys).unwrap(),
+        ))
+        .expect("Sealed keygen should succeed");
+        assert_eq!(keygen.shares.len(), 3);
+
+        let opened: Vec<KeyShare> = keygen
+            .shares
+            .iter()
+            .zip(&secrets)
+            .map(|(share, secret)| {
+                let result = open_share(
+                    &serde_json::to_string(share).unwrap(),
+                    &hex::encode(secret.to_bytes()),
+                    &keygen.public_key_package,
+                );
+                serde_json::from_str(&result).expect("Opening should succeed")
+            })
+            .collect();
+
+        let _: AggregateSignature = serde_json::from_str(&sign_with(
+            &[&opened[0].key_package, &opened[1].key_package],
+            &keygen.public_key_package,
+            "48656c6c6f20576f726c64",
+        ))
+        .expect("Opened shares should sign");
+
+        // Another participant's key cannot open the share
+        let result = open_share(
+            &serde_json::to_string(&keygen.shares[0]).unwrap(),
+            &hex::encode(secrets[1].to_bytes()),
+            &keygen.public_key_package,
+        );
+        let error: FrostError = serde_json::from_str(&result).expect("Wrong key should fail");
+        assert_eq!(error.code, "DECRYPT_ERROR");
+
+        // A share relabelled for another participant fails authentication
+        let mut relabelled = keygen.shares[0].clone();
+        relabelled.identifier = 2;
+        let result = open_share(
+            &serde_json::to_string(&relabelled).unwrap(),
+            &hex::encode(secrets[0].to_bytes()),
+            &keygen.public_key_package,
+        );
+        let error: FrostError = serde_json::from_str(&result).expect("Relabelled share");
+        assert_eq!(error.code, "DECRYPT_ERROR");
+
+        // A share from a different dealer does not match the public key package
+        let other: SealedKeyGenResult = serde_json::from_str(&generate_sealed_key_shares(
+            2,
+            &serde_json::to_string(&public_keys).unwrap(),
+        ))
+        .unwrap();
+        let result = open_share(
+            &serde_json::to_string(&other.shares[0]).unwrap(),
+            &hex::encode(secrets[0].to_bytes()),
+            &keygen.public_key_package,
+        );
+        let error: FrostError = serde_json::from_str(&result).expect("Wrong public key package");
+        assert_eq!(error.code, "DECRYPT_ERROR");
+
+        // More than 255 recipients are rejected before identifiers are assigned
+        let too_many = vec![public_keys[0].clone(); 256];
+        let result = generate_sealed_key_shares(2, &serde_json::to_string(&too_many).unwrap());
+        let error: FrostError = serde_json::from_str(&result).expect("Too many recipients");
+        assert_eq!(error.code, "KEYGEN_ERROR");
+    }
+
+    #[test]
+    fn test_sealing_rejects_low_order_keys() {
+        let secret = x25519_dalek::StaticSecret::random_from_rng(OsRng);
+        let public_key = hex::encode(x25519_dalek::PublicKey::from(&secret).as_bytes());
+        // The identity point: every shared secret with it is all zeros
+        let low_order = hex::encode([0u8; 32]);
+
+        let result = generate_sealed_key_shares(
+            2,
+            &serde_json::to_string(&[&public_key, &low_order, &public_key]).unwrap(),
+        );
+        let error: FrostError = serde_json::from_str(&result).expect("Low-order recipient");
+        assert_eq!(error.code, "KEYGEN_ERROR");
+
+        // A share whose ephemeral key is low order is refused when opening
+        let keygen: SealedKeyGenResult = serde_json::from_str(&generate_sealed_key_shares(
+            2,
+            &serde_json::to_string(&[&public_key, &public_key]).unwrap(),
+        ))
+        .unwrap();
+        let mut share = keygen.shares[0].clone();
+        share.sealed_secret_share.ephemeral_public_key = [0u8; 32];
+        let result = open_share(
+            &serde_json::to_string(&share).unwrap(),
+            &hex::encode(secret.to_bytes()),
+            &keygen.public_key_package,
+        );
+        let error: FrostError = serde_json::from_str(&result).expect("Low-order ephemeral key");
+        assert_eq!(error.code, "DECRYPT_ERROR");
+        assert!(error.message.contains("low-order"));
+    }
+
+    #[test]
+    fn test_sign_with_handles() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let message = "48656c6c6f20576f726c64";
+
+        let key_handles: Vec<KeyHandle> = keygen.shares[..2]
+            .iter()
+            .map(|share| serde_json::from_str(&load_key_package(&share.key_package)).unwrap())
+            .collect();
+        let round1: Vec<Round1HandleResult> = key_handles
+            .iter()
+            .map(|k| {
+                serde_json::from_str(&generate_round1_commitment_with_handle(k.handle)).unwrap()
+            })
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let shares: Vec<SignatureShare> = key_handles
+            .iter()
+            .zip(&round1)
+            .map(|(k, r)| {
+                serde_json::from_str(&generate_round2_signature_with_handles(
+                    k.handle,
+                    r.nonce_handle,
+                    &commitments_json,
+                    message,
+                ))
+                .expect("Round 2 with handles should succeed")
+            })
+            .collect();
+
+        let result = aggregate_signature(
+            &serde_json::to_string(&shares).unwrap(),
+            &commitments_json,
+            message,
+            &keygen.public_key_package,
+        );
+        let _: AggregateSignature = serde_json::from_str(&result).expect("Should aggregate");
+
+        // Nonce handles are consumed by Round 2
+        let result = generate_round2_signature_with_handles(
+            key_handles[0].handle,
+            round1[0].nonce_handle,
+            &commitments_json,
+            message,
+        );
+        let error: FrostError = serde_json::from_str(&result).expect("Reused nonces should fail");
+        assert_eq!(error.code, "ROUND2_ERROR");
+
+        assert!(destroy(key_handles[0].handle));
+        assert!(!destroy(key_handles[0].handle));
+        let result = generate_round1_commitment_with_handle(key_handles[0].handle);
+        let error: FrostError = serde_json::from_str(&result).expect("Destroyed key should fail");
+        assert_eq!(error.code, "ROUND1_ERROR");
+    }
+
+    #[test]
+    fn test_sign_coordinator_signing_package() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let signers = &keygen.shares[..2];
+        let message = "48656c6c6f20576f726c64";
+
+        let round1: Vec<Round1Result> = signers
+            .iter()
+            .map(|s| serde_json::from_str(&generate_round1_commitment(&s.key_package)).unwrap())
+            .collect();
+        let commitments: Vec<Commitment> = round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let package: SigningPackage =
+            serde_json::from_str(&create_signing_package(&commitments_json, message))
+                .expect("Signing package should build");
+
+        let shares: Vec<SignatureShare> = signers
+            .iter()
+            .zip(&round1)
+            .map(|(s, r)| {
+                serde_json::from_str(&generate_round2_signature_with_package(
+                    &s.key_package,
+                    &serde_json::to_string(&r.nonces).unwrap(),
+                    &package.signing_package,
+                ))
+                .expect("Round 2 should succeed")
+            })
+            .collect();
+
+        let agg: AggregateSignature = serde_json::from_str(&aggregate_signature(
+            &serde_json::to_string(&shares).unwrap(),
+            &commitments_json,
+            message,
+            &keygen.public_key_package,
+        ))
+        .expect("Should aggregate");
+
+        let agg_with_package: AggregateSignature =
+            serde_json::from_str(&aggregate_signature_with_package(
+                &serde_json::to_string(&shares).unwrap(),
+                &package.signing_package,
+                &keygen.public_key_package,
+            ))
+            .expect("Should aggregate over the signing package");
+        assert_eq!(agg_with_package.signature, agg.signature);
+
+        let group_public_key = get_group_public_key(&keygen.public_key_package);
+        assert_eq!(group_public_key, keygen.group_public_key);
+        let verify: VerifyResult = serde_json::from_str(&verify_signature(
+            &agg.signature,
+            message,
+            &group_public_key,
+        ))
+        .unwrap();
+        assert!(verify.valid);
+
+        let public_key: PublicKeyResult =
+            serde_json::from_str(&get_public_key(&signers[0].key_package)).unwrap();
+        assert_eq!(public_key.identifier, signers[0].identifier);
+        assert_eq!(public_key.public_key, signers[0].verifying_share);
+
+        let error: FrostError =
+            serde_json::from_str(&create_signing_package("not json", message)).unwrap();
+        assert_eq!(error.code, "SIGNING_PACKAGE_ERROR");
+    }
+}
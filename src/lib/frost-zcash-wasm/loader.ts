@@ -15,6 +15,23 @@
 export interface FrostError {
   code: string;
   message: string;
+  /** Identifiers whose signature share is invalid (aggregation errors only) */
+  culprits?: number[];
+}
+
+/**
+ * Error thrown by the wrappers, keeping the code and culprits of the FrostError
+ */
+export class FrostWasmError extends Error {
+  readonly code: string;
+  readonly culprits: number[];
+
+  constructor(error: FrostError) {
+    super(`${error.code}: ${error.message}`);
+    this.name = 'FrostWasmError';
+    this.code = error.code;
+    this.culprits = error.culprits ?? [];
+  }
 }
 
 /**
@@ -25,6 +42,10 @@ export interface KeyShareInfo {
   identifier: number;
   /** Serialized KeyPackage (JSON) - keep secret! */
   key_package: string;
+  /** Verifying share (hex) */
+  verifying_share: string;
+  /** Dealer's SecretShare with its VSS commitment (JSON) - keep secret! */
+  secret_share?: string;
 }
 
 /**
@@ -79,7 +100,7 @@ export interface Round1Result {
 export interface SigningPackageResult {
   /** Serialized SigningPackage (JSON) */
   signing_package: string;
-  /** Serialized randomizer (JSON) - needed for signing and verification */
+  /** Serialized randomizer seed, or the randomizer itself when chosen by the transaction builder (JSON) - needed for signing */
   randomizer: string;
 }
 
@@ -99,7 +120,7 @@ export interface SignatureShareInfo {
 export interface AggregateResult {
   /** Final aggregate signature (hex) */
   signature: string;
-  /** Randomizer used (JSON) */
+  /** Randomizer used (JSON) - needed for verification */
   randomizer: string;
 }
 
@@ -125,6 +146,7 @@ export interface PublicKeyResult {
 interface FrostZcashWasm {
   init(): void;
   generate_key_shares(threshold: number, total: number): string;
+  verify_and_accept_share(secret_share_json: string, public_key_package_json: string): string;
   generate_round1_commitment(key_package_json: string): string;
   create_signing_package(
     commitments_json: string,
@@ -151,6 +173,31 @@ interface FrostZcashWasm {
   ): string;
   get_public_key(key_package_json: string): string;
   get_group_public_key(public_key_package_json: string): string;
+  sapling_generate_key_shares(threshold: number, total: number): string;
+  sapling_generate_round1_commitment(key_package_json: string): string;
+  sapling_create_signing_package(
+    commitments_json: string,
+    message_hex: string,
+    public_key_package_json: string
+  ): string;
+  sapling_generate_round2_signature(
+    key_package_json: string,
+    nonces_json: string,
+    signing_package_json: string,
+    randomizer_json: string
+  ): string;
+  sapling_aggregate_signature(
+    shares_json: string,
+    signing_package_json: string,
+    public_key_package_json: string,
+    randomizer_json: string
+  ): string;
+  sapling_verify_signature(
+    signature_hex: string,
+    message_hex: string,
+    group_public_key_hex: string,
+    randomizer_json: string
+  ): string;
 }
 
 // WASM module singleton
@@ -194,7 +241,7 @@ function getWasm(): FrostZcashWasm {
 function parseResult<T>(json: string): T {
   const result = JSON.parse(json);
   if (result.code && result.message) {
-    throw new Error(`${result.code}: ${result.message}`);
+    throw new FrostWasmError(result as FrostError);
   }
   return result as T;
 }
@@ -215,6 +262,23 @@ export function generateKeyShares(threshold: number, total: number): KeyGenResul
   return parseResult<KeyGenResult>(wasm.generate_key_shares(threshold, total));
 }
 
+/**
+ * Verify a dealer-issued secret share and turn it into a key package
+ *
+ * @param secretShareJson The participant's `secret_share` from KeyGenResult.shares
+ * @param publicKeyPackageJson The group's public key package from KeyGenResult
+ * @returns The participant's key share
+ */
+export function verifyAndAcceptShare(
+  secretShareJson: string,
+  publicKeyPackageJson: string
+): KeyShareInfo {
+  const wasm = getWasm();
+  return parseResult<KeyShareInfo>(
+    wasm.verify_and_accept_share(secretShareJson, publicKeyPackageJson)
+  );
+}
+
 /**
  * Generate Round 1 commitment
  *
@@ -328,12 +392,104 @@ export function getGroupPublicKey(publicKeyPackageJson: string): string {
   const result = wasm.get_group_public_key(publicKeyPackageJson);
   // Check for error
   if (result.startsWith('{') && result.includes('"code"')) {
-    const error = JSON.parse(result) as FrostError;
-    throw new Error(`${error.code}: ${error.message}`);
+    throw new FrostWasmError(JSON.parse(result) as FrostError);
   }
   return result;
 }
 
+// =============================================================================
+// Sapling (RedJubjub) API
+// =============================================================================
+
+/**
+ * Generate RedJubjub key shares using trusted dealer (Sapling)
+ *
+ * @param threshold Minimum signers required (t)
+ * @param total Total number of signers (n)
+ * @returns Key generation result with shares
+ */
+export function saplingGenerateKeyShares(threshold: number, total: number): KeyGenResult {
+  const wasm = getWasm();
+  return parseResult<KeyGenResult>(wasm.sapling_generate_key_shares(threshold, total));
+}
+
+/**
+ * Generate Round 1 commitment for a RedJubjub key package
+ */
+export function saplingGenerateRound1Commitment(keyPackageJson: string): Round1Result {
+  const wasm = getWasm();
+  return parseResult<Round1Result>(wasm.sapling_generate_round1_commitment(keyPackageJson));
+}
+
+/**
+ * Create a RedJubjub signing package with randomizer
+ */
+export function saplingCreateSigningPackage(
+  commitmentsJson: string,
+  messageHex: string,
+  publicKeyPackageJson: string
+): SigningPackageResult {
+  const wasm = getWasm();
+  return parseResult<SigningPackageResult>(
+    wasm.sapling_create_signing_package(commitmentsJson, messageHex, publicKeyPackageJson)
+  );
+}
+
+/**
+ * Generate Round 2 RedJubjub signature share
+ */
+export function saplingGenerateRound2Signature(
+  keyPackageJson: string,
+  noncesJson: string,
+  signingPackageJson: string,
+  randomizerJson: string
+): SignatureShareInfo {
+  const wasm = getWasm();
+  return parseResult<SignatureShareInfo>(
+    wasm.sapling_generate_round2_signature(
+      keyPackageJson,
+      noncesJson,
+      signingPackageJson,
+      randomizerJson
+    )
+  );
+}
+
+/**
+ * Aggregate RedJubjub signature shares into final signature
+ */
+export function saplingAggregateSignature(
+  sharesJson: string,
+  signingPackageJson: string,
+  publicKeyPackageJson: string,
+  randomizerJson: string
+): AggregateResult {
+  const wasm = getWasm();
+  return parseResult<AggregateResult>(
+    wasm.sapling_aggregate_signature(
+      sharesJson,
+      signingPackageJson,
+      publicKeyPackageJson,
+      randomizerJson
+    )
+  );
+}
+
+/**
+ * Verify a rerandomized RedJubjub signature
+ */
+export function saplingVerifySignature(
+  signatureHex: string,
+  messageHex: string,
+  groupPublicKeyHex: string,
+  randomizerJson: string
+): VerifyResult {
+  const wasm = getWasm();
+  return parseResult<VerifyResult>(
+    wasm.sapling_verify_signature(signatureHex, messageHex, groupPublicKeyHex, randomizerJson)
+  );
+}
+
 // =============================================================================
 // Convenience Types for Protocol Messages
 // =============================================================================
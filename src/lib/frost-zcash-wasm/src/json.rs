@@ -0,0 +1,1570 @@
+//! JSON API
+//!
+//! Each function takes and returns the serialized forms used on the wire
+//! (JSON strings and hex), parses its arguments and calls the typed function
+//! of the same name in the crate root, `sapling` or `pczt_signing`. The
+//! wasm-bindgen exports in the `wasm` module are thin wrappers over these
+//! functions.
+
+use std::collections::BTreeMap;
+
+use pczt::Pczt;
+use zcash_address::unified::{Address as UnifiedAddress, Encoding, Fvk, Receiver, Ufvk};
+use zcash_protocol::consensus::NetworkType;
+
+use frost_common::codec::{identifier_from_u16, identifier_to_u16};
+use frost_common::keystore;
+use frost_common::{
+    dkg, keys, nonces, pool, reconstruct, refresh, repair, rerandomized, reshare, signing,
+};
+use reddsa::frost::redjubjub::JubjubBlake2b512;
+use reddsa::frost::redpallas::PallasBlake2b512;
+
+use crate::pczt_signing::{
+    OrchardSigningRequest, OrchardSpendAuthSig, PcztResult, PcztSigningRequests,
+};
+use crate::redpallas::{self, keys::KeyPackage, keys::PublicKeyPackage};
+use crate::{
+    AggregateError, AggregateResult, BatchAction, BatchActionInfo, BatchAggregateResult,
+    BatchCommitmentInfo, BatchNoncesInfo, BatchRound1HandleResult, BatchRound1Result,
+    BatchSignatureShareInfo, BatchSigningPackageResult, CommitmentPoolResult, DkgResult,
+    DkgRound1Result, DkgRound2Result, FrostError, KeyGenResult, KeyHandleInfo, KeyShareInfo,
+    NonceRegistryState, OrchardViewingKeyResult, PoolSelectionResult, PoolSignatureResult,
+    PublicKeyResult, RandomizedSigningPackage, ReconstructResult, RefreshSharesResult,
+    RepairSigmaInfo, RepairStep1Result, ReshareRound1Result, Round1HandleResult, Round1Result,
+    ShareVerificationResult, SignatureShareInfo, SigningPackageResult, VerifyResult,
+};
+
+// =============================================================================
+// Key Generation
+// =============================================================================
+
+/// Generate FROST key shares using trusted dealer
+///
+/// # Arguments
+/// * `threshold` - Minimum signers required (t)
+/// * `total` - Total number of signers (n)
+///
+/// # Returns
+/// KeyGenResult or FrostError
+pub fn generate_key_shares(threshold: u16, total: u16) -> Result<KeyGenResult, FrostError> {
+    generate_key_shares_internal(threshold, total).map_err(|e| FrostError::new("KEYGEN_ERROR", e))
+}
+
+fn generate_key_shares_internal(threshold: u16, total: u16) -> Result<KeyGenResult, String> {
+    let (shares, pubkey_package) =
+        crate::generate_key_shares(threshold, total).map_err(|e| e.message)?;
+
+    keys::key_gen_result(shares, &pubkey_package, threshold, total)
+}
+
+/// Verify a dealer-issued secret share and turn it into a key package
+///
+/// Checks the share against the dealer's VSS commitment, and the commitment
+/// against the group's public key package, so a participant never stores a
+/// share that is inconsistent with everyone else's.
+///
+/// # Arguments
+/// * `secret_share_json` - This participant's `secret_share` from KeyGenResult (JSON)
+/// * `public_key_package_json` - Serialized PublicKeyPackage (JSON, from KeyGenResult)
+///
+/// # Returns
+/// KeyShareInfo or FrostError
+pub fn verify_and_accept_share(
+    secret_share_json: &str,
+    public_key_package_json: &str,
+) -> Result<KeyShareInfo, FrostError> {
+    keys::verify_and_accept_share::<PallasBlake2b512>(secret_share_json, public_key_package_json)
+}
+
+// =============================================================================
+// Distributed Key Generation
+// =============================================================================
+
+/// DKG part 1: generate this participant's Round 1 package
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier (1-indexed)
+/// * `max_signers` - Total number of signers (n)
+/// * `min_signers` - Minimum signers required (t)
+///
+/// # Returns
+/// DkgRound1Result or FrostError
+pub fn dkg_part1(
+    identifier: u16,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DkgRound1Result, FrostError> {
+    dkg::dkg_part1::<PallasBlake2b512>(identifier, max_signers, min_signers)
+}
+
+/// DKG part 2: process the other participants' Round 1 packages
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from dkg_part1 (JSON)
+/// * `round1_packages_json` - Other participants' Round 1 packages (JSON array)
+///
+/// # Returns
+/// DkgRound2Result or FrostError
+pub fn dkg_part2(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+) -> Result<DkgRound2Result, FrostError> {
+    dkg::dkg_part2::<PallasBlake2b512>(secret_package_json, round1_packages_json)
+}
+
+/// DKG part 3: derive this participant's key package and the group public key package
+///
+/// The resulting public key package can be passed directly to
+/// `create_signing_package` and `aggregate_signature`.
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from dkg_part2 (JSON)
+/// * `round1_packages_json` - Other participants' Round 1 packages (JSON array)
+/// * `round2_packages_json` - Round 2 packages addressed to this participant (JSON array)
+///
+/// # Returns
+/// DkgResult or FrostError
+pub fn dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+) -> Result<DkgResult, FrostError> {
+    dkg_part3_internal(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+    )
+    .map_err(|e| FrostError::new("DKG_ERROR", e))
+}
+
+fn dkg_part3_internal(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+) -> Result<DkgResult, String> {
+    let secret_package: redpallas::keys::dkg::round2::SecretPackage =
+        serde_json::from_str(secret_package_json)
+            .map_err(|e| format!("Invalid secret package JSON: {}", e))?;
+
+    let round1_packages = dkg::parse_dkg_round1_packages(round1_packages_json)?;
+    let round2_packages =
+        dkg::parse_dkg_round2_packages(round2_packages_json, secret_package.identifier())?;
+
+    let (key_package, pubkey_package) =
+        crate::dkg_part3(&secret_package, &round1_packages, &round2_packages)
+            .map_err(|e| e.message)?;
+
+    dkg::dkg_result(&key_package, &pubkey_package)
+}
+
+// =============================================================================
+// Share Refresh
+// =============================================================================
+
+/// Dealer-based refresh: deal zero-secret shares that rotate every key share
+///
+/// The group key (and so the Orchard address) is unchanged. Every participant
+/// must apply their share with `refresh_share`; old and refreshed shares
+/// cannot be combined afterwards.
+///
+/// # Arguments
+/// * `public_key_package_json` - Current public key package (JSON)
+/// * `identifiers_json` - All participants' identifiers (JSON array, e.g. `[1, 2, 3]`)
+/// * `min_signers` - Minimum signers required (t), unchanged from keygen
+///
+/// # Returns
+/// RefreshSharesResult or FrostError
+pub fn compute_refreshing_shares(
+    public_key_package_json: &str,
+    identifiers_json: &str,
+    min_signers: u16,
+) -> Result<RefreshSharesResult, FrostError> {
+    refresh::compute_refreshing_shares::<PallasBlake2b512>(
+        public_key_package_json,
+        identifiers_json,
+        min_signers,
+    )
+}
+
+/// Apply a refreshing share to this participant's key package
+///
+/// # Arguments
+/// * `refreshing_share_json` - This participant's RefreshingShareInfo (JSON)
+/// * `key_package_json` - Current key package (JSON)
+///
+/// # Returns
+/// KeyShareInfo with the refreshed key package or FrostError
+pub fn refresh_share(
+    refreshing_share_json: &str,
+    key_package_json: &str,
+) -> Result<KeyShareInfo, FrostError> {
+    refresh::refresh_share::<PallasBlake2b512>(refreshing_share_json, key_package_json)
+}
+
+/// Refresh DKG part 1: like `dkg_part1`, with a zero constant term so the group key is kept
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier
+/// * `max_signers` - Total number of signers (n)
+/// * `min_signers` - Minimum signers required (t)
+///
+/// # Returns
+/// DkgRound1Result or FrostError
+pub fn refresh_dkg_part1(
+    identifier: u16,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<DkgRound1Result, FrostError> {
+    refresh::refresh_dkg_part1::<PallasBlake2b512>(identifier, max_signers, min_signers)
+}
+
+/// Refresh DKG part 2: same wire format as `dkg_part2`
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from refresh_dkg_part1 (JSON)
+/// * `round1_packages_json` - Other participants' Round 1 packages (JSON array)
+///
+/// # Returns
+/// DkgRound2Result or FrostError
+pub fn refresh_dkg_part2(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+) -> Result<DkgRound2Result, FrostError> {
+    refresh::refresh_dkg_part2::<PallasBlake2b512>(secret_package_json, round1_packages_json)
+}
+
+/// Refresh DKG part 3: derive the refreshed key package and public key package
+///
+/// # Arguments
+/// * `secret_package_json` - Secret package from refresh_dkg_part2 (JSON)
+/// * `round1_packages_json` - Other participants' Round 1 packages (JSON array)
+/// * `round2_packages_json` - Round 2 packages addressed to this participant (JSON array)
+/// * `public_key_package_json` - Current public key package (JSON)
+/// * `key_package_json` - This participant's current key package (JSON)
+///
+/// # Returns
+/// DkgResult or FrostError
+pub fn refresh_dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+    public_key_package_json: &str,
+    key_package_json: &str,
+) -> Result<DkgResult, FrostError> {
+    refresh::refresh_dkg_part3::<PallasBlake2b512>(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+        public_key_package_json,
+        key_package_json,
+    )
+}
+
+// =============================================================================
+// Share Repair
+// =============================================================================
+
+/// Repair step 1 (helper): split this helper's contribution to a lost share into deltas
+///
+/// Any `min_signers` participants can act as helpers. Each delta goes privately
+/// to its recipient; helpers never learn each other's shares.
+///
+/// # Arguments
+/// * `helpers_json` - Helper identifiers including this one (JSON array, e.g. `[1, 2]`)
+/// * `key_package_json` - This helper's key package (JSON)
+/// * `participant` - Identifier of the participant whose share is being repaired
+///
+/// # Returns
+/// RepairStep1Result or FrostError
+pub fn repair_share_step_1(
+    helpers_json: &str,
+    key_package_json: &str,
+    participant: u16,
+) -> Result<RepairStep1Result, FrostError> {
+    repair::repair_share_step_1::<PallasBlake2b512>(helpers_json, key_package_json, participant)
+}
+
+/// Repair step 2 (helper): combine the deltas received from every helper into a sigma
+///
+/// # Arguments
+/// * `deltas_json` - Deltas addressed to this helper, one from each helper (JSON array)
+///
+/// # Returns
+/// RepairSigmaInfo or FrostError
+pub fn repair_share_step_2(deltas_json: &str) -> Result<RepairSigmaInfo, FrostError> {
+    repair::repair_share_step_2::<PallasBlake2b512>(deltas_json)
+}
+
+/// Repair step 3 (repaired participant): rebuild the lost key package from the helpers' sigmas
+///
+/// The result is checked against the participant's verifying share in the
+/// public key package, so the repaired key keeps the Orchard-compatible group key.
+///
+/// # Arguments
+/// * `sigmas_json` - One RepairSigmaInfo from each helper (JSON array)
+/// * `identifier` - This participant's identifier
+/// * `public_key_package_json` - Group public key package (JSON)
+/// * `min_signers` - Minimum signers required (t)
+///
+/// # Returns
+/// KeyShareInfo or FrostError
+pub fn repair_share_step_3(
+    sigmas_json: &str,
+    identifier: u16,
+    public_key_package_json: &str,
+    min_signers: u16,
+) -> Result<KeyShareInfo, FrostError> {
+    repair::repair_share_step_3::<PallasBlake2b512>(
+        sigmas_json,
+        identifier,
+        public_key_package_json,
+        min_signers,
+    )
+}
+
+// =============================================================================
+// Resharing
+// =============================================================================
+
+/// Reshare part 1 (current holder): deal this holder's weighted share to a new participant set
+///
+/// At least `min_signers` current holders act as dealers, all using the same
+/// dealer set. The group key, and so the Orchard address, is unchanged.
+/// Old key shares stay valid under the old PublicKeyPackage, so a removed
+/// participant is only cut off once the remaining holders delete theirs.
+///
+/// # Arguments
+/// * `key_package_json` - This dealer's current key package (JSON)
+/// * `dealers_json` - Dealer identifiers including this one (JSON array, e.g. `[1, 2]`)
+/// * `new_identifiers_json` - New participant identifiers (JSON array, e.g. `[1, 2, 4, 5, 6]`)
+/// * `new_min_signers` - New threshold (t')
+///
+/// # Returns
+/// ReshareRound1Result or FrostError
+pub fn reshare_part1(
+    key_package_json: &str,
+    dealers_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> Result<ReshareRound1Result, FrostError> {
+    reshare::reshare_part1::<PallasBlake2b512>(
+        key_package_json,
+        dealers_json,
+        new_identifiers_json,
+        new_min_signers,
+    )
+}
+
+/// Reshare part 2 (new participant): combine the dealers' sub-shares into a new key package
+///
+/// Each dealer's VSS commitment must cover exactly `new_identifiers` with
+/// threshold `new_min_signers` and match its verifying share in the old public
+/// key package; each sub-share is verified against its dealer's commitment,
+/// and the new group key must equal the old one.
+///
+/// # Arguments
+/// * `identifier` - This new participant's identifier
+/// * `sub_shares_json` - Sub-shares addressed to this participant, one per dealer (JSON array)
+/// * `commitments_json` - Every dealer's ReshareCommitmentInfo (JSON array)
+/// * `public_key_package_json` - The old public key package (JSON)
+/// * `new_identifiers_json` - New participant identifiers, as passed to part 1 (JSON array)
+/// * `new_min_signers` - New threshold (t'), as passed to part 1
+///
+/// # Returns
+/// DkgResult or FrostError
+pub fn reshare_part2(
+    identifier: u16,
+    sub_shares_json: &str,
+    commitments_json: &str,
+    public_key_package_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> Result<DkgResult, FrostError> {
+    reshare::reshare_part2::<PallasBlake2b512>(
+        identifier,
+        sub_shares_json,
+        commitments_json,
+        public_key_package_json,
+        new_identifiers_json,
+        new_min_signers,
+    )
+}
+
+// =============================================================================
+// Orchard Key Compatibility
+// =============================================================================
+
+/// Validate that a public key package can act as an Orchard spend authority
+///
+/// Orchard requires the spend validating key `ak` to be encoded with ỹ = 0
+/// (sign bit of the last byte cleared). Keys produced by `generate_key_shares`
+/// and `dkg_part3` always satisfy this.
+///
+/// # Arguments
+/// * `public_key_package_json` - Public key package (JSON)
+///
+/// # Returns
+/// VerifyResult or FrostError
+pub fn validate_orchard_public_key_package(
+    public_key_package_json: &str,
+) -> Result<VerifyResult, FrostError> {
+    let pubkey_package = parse_public_key_package(public_key_package_json)
+        .map_err(|e| FrostError::new("ORCHARD_KEY_ERROR", e))?;
+
+    crate::validate_orchard_public_key_package(&pubkey_package)?;
+
+    Ok(VerifyResult { valid: true })
+}
+
+// =============================================================================
+// Orchard Viewing Keys and Addresses
+// =============================================================================
+
+/// Derive an Orchard full viewing key and unified address for a FROST group
+///
+/// The group verifying key becomes the spend validating key `ak`; `nk` and
+/// `rivk` are generated unless supplied. Every participant that needs to view
+/// incoming funds must use the same `nk`/`rivk`, so the coordinator should
+/// generate them once and distribute the result.
+///
+/// # Arguments
+/// * `public_key_package_json` - Public key package (JSON), must be Orchard compatible
+/// * `nk_hex` - Nullifier deriving key (hex, 32 bytes) or empty to generate
+/// * `rivk_hex` - Commit-ivk randomness (hex, 32 bytes) or empty to generate
+/// * `network` - "main", "test" or "regtest"
+/// * `diversifier_index` - Diversifier index of the address to derive
+///
+/// # Returns
+/// OrchardViewingKeyResult or FrostError
+pub fn derive_orchard_viewing_key(
+    public_key_package_json: &str,
+    nk_hex: &str,
+    rivk_hex: &str,
+    network: &str,
+    diversifier_index: u32,
+) -> Result<OrchardViewingKeyResult, FrostError> {
+    derive_orchard_viewing_key_internal(
+        public_key_package_json,
+        nk_hex,
+        rivk_hex,
+        network,
+        diversifier_index,
+    )
+    .map_err(|e| FrostError::new("ORCHARD_KEY_ERROR", e))
+}
+
+fn derive_orchard_viewing_key_internal(
+    public_key_package_json: &str,
+    nk_hex: &str,
+    rivk_hex: &str,
+    network: &str,
+    diversifier_index: u32,
+) -> Result<OrchardViewingKeyResult, String> {
+    let network = parse_network(network)?;
+
+    let pubkey_package = parse_public_key_package(public_key_package_json)?;
+    let nk = parse_optional_32_bytes(nk_hex, "nk")?;
+    let rivk = parse_optional_32_bytes(rivk_hex, "rivk")?;
+
+    let fvk = crate::derive_orchard_viewing_key(&pubkey_package, nk.as_ref(), rivk.as_ref())
+        .map_err(|e| e.message)?;
+
+    let ufvk = Ufvk::try_from_items(vec![Fvk::Orchard(fvk.to_bytes())])
+        .map_err(|e| format!("Failed to build unified full viewing key: {}", e))?;
+
+    let address = fvk.address_at(diversifier_index, orchard::keys::Scope::External);
+    let unified_address =
+        UnifiedAddress::try_from_items(vec![Receiver::Orchard(address.to_raw_address_bytes())])
+            .map_err(|e| format!("Failed to build unified address: {}", e))?;
+
+    // FullViewingKey encoding is ak || nk || rivk
+    let fvk_bytes = fvk.to_bytes();
+
+    Ok(OrchardViewingKeyResult {
+        ak: hex::encode(&fvk_bytes[..32]),
+        nk: hex::encode(&fvk_bytes[32..64]),
+        rivk: hex::encode(&fvk_bytes[64..]),
+        full_viewing_key: hex::encode(fvk_bytes),
+        unified_full_viewing_key: ufvk.encode(&network),
+        unified_address: unified_address.encode(&network),
+        diversifier_index,
+    })
+}
+
+/// Parse a network name into a Zcash network type
+fn parse_network(network: &str) -> Result<NetworkType, String> {
+    match network {
+        "main" | "mainnet" => Ok(NetworkType::Main),
+        "test" | "testnet" => Ok(NetworkType::Test),
+        "regtest" => Ok(NetworkType::Regtest),
+        _ => Err(format!("Unknown network: {}", network)),
+    }
+}
+
+/// Parse a 32-byte hex value, or `None` if the input is empty
+fn parse_optional_32_bytes(value_hex: &str, name: &str) -> Result<Option<[u8; 32]>, String> {
+    if value_hex.is_empty() {
+        return Ok(None);
+    }
+
+    let bytes = hex::decode(value_hex).map_err(|e| format!("Invalid {} hex: {}", name, e))?;
+    bytes
+        .try_into()
+        .map(Some)
+        .map_err(|_| format!("{} must be 32 bytes", name))
+}
+
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
+
+/// Generate Round 1 commitment for signing
+///
+/// # Arguments
+/// * `key_package_json` - Participant's key package (JSON)
+///
+/// # Returns
+/// Round1Result or FrostError
+pub fn generate_round1_commitment(key_package_json: &str) -> Result<Round1Result, FrostError> {
+    signing::generate_round1_commitment::<PallasBlake2b512>(key_package_json)
+}
+
+// =============================================================================
+// Signing Package Creation (with Randomizer)
+// =============================================================================
+
+/// Create a signing package with randomizer for rerandomized FROST
+///
+/// This should be called by the coordinator after collecting all commitments.
+/// The randomizer seed is generated from the signing package and must be
+/// distributed to all signers via a secure channel.
+///
+/// # Arguments
+/// * `commitments_json` - All participants' commitments (JSON array)
+/// * `message_hex` - Message to sign (hex-encoded)
+/// * `public_key_package_json` - Public key package (JSON)
+///
+/// # Returns
+/// SigningPackageResult or FrostError
+pub fn create_signing_package(
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<SigningPackageResult, FrostError> {
+    rerandomized::create_signing_package::<PallasBlake2b512>(
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    )
+}
+
+/// Create a signing package using a randomizer chosen by the transaction builder
+///
+/// Orchard spends commit to the randomized key `rk = ak + [alpha] G` before
+/// signing, so the coordinator must reuse that `alpha` instead of drawing a
+/// fresh one. If `rk_hex` is non-empty, the randomized group key is checked
+/// against it.
+///
+/// # Arguments
+/// * `commitments_json` - All participants' commitments (JSON array)
+/// * `message_hex` - Message to sign, e.g. the transaction sighash (hex-encoded)
+/// * `public_key_package_json` - Public key package (JSON)
+/// * `randomizer_hex` - Spend authorization randomizer alpha (hex, 32-byte scalar)
+/// * `rk_hex` - Expected randomized verifying key (hex) or empty to skip the check
+///
+/// # Returns
+/// SigningPackageResult or FrostError
+pub fn create_signing_package_with_randomizer(
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+    randomizer_hex: &str,
+    rk_hex: &str,
+) -> Result<SigningPackageResult, FrostError> {
+    rerandomized::create_signing_package_with_randomizer::<PallasBlake2b512>(
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+        randomizer_hex,
+        rk_hex,
+    )
+}
+
+// =============================================================================
+// Round 2: Signature Share Generation (Rerandomized)
+// =============================================================================
+
+/// Generate Round 2 signature share using rerandomization
+///
+/// # Arguments
+/// * `key_package_json` - Participant's key package (JSON)
+/// * `nonces_json` - Participant's nonces from Round 1 (JSON)
+/// * `signing_package_json` - Signing package from coordinator (JSON)
+/// * `randomizer_json` - Randomizer or randomizer seed from coordinator (JSON)
+///
+/// # Returns
+/// SignatureShareInfo or FrostError
+pub fn generate_round2_signature(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<SignatureShareInfo, FrostError> {
+    rerandomized::generate_round2_signature::<PallasBlake2b512>(
+        key_package_json,
+        nonces_json,
+        signing_package_json,
+        randomizer_json,
+    )
+}
+
+// =============================================================================
+// Commitment Pools (Preprocessing)
+// =============================================================================
+
+/// Preprocess a batch of Round 1 commitments
+///
+/// The signer publishes the commitment pool ahead of time, so signing later
+/// needs only a single round.
+///
+/// # Arguments
+/// * `key_package_json` - Participant's key package (JSON)
+/// * `count` - Number of commitments to generate
+///
+/// # Returns
+/// CommitmentPoolResult or FrostError
+pub fn generate_commitment_pool(
+    key_package_json: &str,
+    count: u32,
+) -> Result<CommitmentPoolResult, FrostError> {
+    pool::generate_commitment_pool::<PallasBlake2b512>(key_package_json, count)
+}
+
+/// Take the oldest unused commitment from each signer's pool (coordinator)
+///
+/// # Arguments
+/// * `pools_json` - All signers' CommitmentPoolInfo (JSON array)
+/// * `signers_json` - Participant identifiers taking part (JSON array)
+///
+/// # Returns
+/// PoolSelectionResult or FrostError
+pub fn select_pool_commitments(
+    pools_json: &str,
+    signers_json: &str,
+) -> Result<PoolSelectionResult, FrostError> {
+    pool::select_pool_commitments(pools_json, signers_json)
+}
+
+/// Generate Round 2 rerandomized signature share using the pooled nonces the
+/// coordinator selected
+///
+/// # Arguments
+/// * `key_package_json` - Participant's key package (JSON)
+/// * `nonce_pool_json` - Participant's NoncePoolInfo (JSON)
+/// * `signing_package_json` - Signing package from coordinator (JSON)
+/// * `randomizer_json` - Randomizer or randomizer seed from coordinator (JSON)
+///
+/// # Returns
+/// PoolSignatureResult or FrostError
+pub fn generate_round2_signature_from_pool(
+    key_package_json: &str,
+    nonce_pool_json: &str,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<PoolSignatureResult, FrostError> {
+    rerandomized::generate_round2_signature_from_pool::<PallasBlake2b512>(
+        key_package_json,
+        nonce_pool_json,
+        signing_package_json,
+        randomizer_json,
+    )
+}
+
+// =============================================================================
+// Nonce Registry
+// =============================================================================
+
+/// Merge a previously exported registry into the current one
+///
+/// Entries are only ever added, so importing stale state cannot make used
+/// nonces usable again.
+///
+/// # Arguments
+/// * `state_json` - NonceRegistryState from `export_nonce_registry` (JSON)
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_nonce_registry(state_json: &str) -> Result<NonceRegistryState, FrostError> {
+    nonces::import_nonce_registry(state_json)
+}
+
+/// Merge a sealed registry into the current one after decrypting it and
+/// checking it is not older than `min_counter`
+///
+/// # Arguments
+/// * `key_handle` - Handle to the key package that sealed it
+/// * `sealed_json` - SealedNonceRegistry from `export_sealed_nonce_registry` (JSON)
+/// * `min_counter` - Counter of the latest export stored, or 0
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_sealed_nonce_registry(
+    key_handle: u32,
+    sealed_json: &str,
+    min_counter: u64,
+) -> Result<NonceRegistryState, FrostError> {
+    crate::KEYSTORE.with(|keystore| {
+        keystore::import_nonce_registry(
+            &mut keystore.borrow_mut(),
+            key_handle,
+            sealed_json,
+            min_counter,
+        )
+    })
+}
+
+// =============================================================================
+// Keystore: Opaque Key and Nonce Handles
+// =============================================================================
+
+/// Load a key package into the keystore and return an opaque handle
+///
+/// # Arguments
+/// * `key_package_json` - Participant's key package (JSON)
+///
+/// # Returns
+/// KeyHandleInfo or FrostError
+pub fn load_key_package(key_package_json: &str) -> Result<KeyHandleInfo, FrostError> {
+    crate::KEYSTORE
+        .with(|keystore| keystore::load_key_package(&mut keystore.borrow_mut(), key_package_json))
+}
+
+/// Generate Round 1 commitment, keeping the nonces in the keystore
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+///
+/// # Returns
+/// Round1HandleResult or FrostError
+pub fn generate_round1_commitment_with_handle(
+    key_handle: u32,
+) -> Result<Round1HandleResult, FrostError> {
+    crate::KEYSTORE.with(|keystore| {
+        keystore::generate_round1_commitment(&mut keystore.borrow_mut(), key_handle)
+    })
+}
+
+/// Generate Round 2 rerandomized signature share from keystore handles
+///
+/// The nonce handle is consumed and its nonces zeroized, so it cannot be
+/// used for a second signature.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+/// * `nonce_handle` - Handle from `generate_round1_commitment_with_handle`
+/// * `signing_package_json` - Signing package from coordinator (JSON)
+/// * `randomizer_json` - Randomizer or randomizer seed from coordinator (JSON)
+///
+/// # Returns
+/// SignatureShareInfo or FrostError
+pub fn generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<SignatureShareInfo, FrostError> {
+    crate::KEYSTORE.with(|keystore| {
+        rerandomized::generate_round2_signature_with_handles(
+            &mut keystore.borrow_mut(),
+            key_handle,
+            nonce_handle,
+            signing_package_json,
+            randomizer_json,
+        )
+    })
+}
+
+// =============================================================================
+// Signature Aggregation (Rerandomized)
+// =============================================================================
+
+/// Aggregate signature shares into final signature
+///
+/// If a signature share is invalid, the error is an AggregateError whose
+/// `culprits` lists the participants who sent bad shares.
+///
+/// # Arguments
+/// * `shares_json` - All signature shares (JSON array)
+/// * `signing_package_json` - Signing package (JSON)
+/// * `public_key_package_json` - Public key package (JSON)
+/// * `randomizer_json` - Randomizer used for signing (JSON)
+///
+/// # Returns
+/// AggregateResult or AggregateError
+pub fn aggregate_signature(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    randomizer_json: &str,
+) -> Result<AggregateResult, AggregateError> {
+    rerandomized::aggregate_signature::<PallasBlake2b512>(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+        randomizer_json,
+    )
+}
+
+/// Verify each signature share against the signer's verifying share
+///
+/// Lets the coordinator find misbehaving participants without attempting aggregation.
+///
+/// # Arguments
+/// * `shares_json` - All signature shares (JSON array)
+/// * `signing_package_json` - Signing package (JSON)
+/// * `public_key_package_json` - Public key package (JSON)
+/// * `randomizer_json` - Randomizer used for signing (JSON)
+///
+/// # Returns
+/// ShareVerificationResult or FrostError
+pub fn verify_signature_shares(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    randomizer_json: &str,
+) -> Result<ShareVerificationResult, FrostError> {
+    rerandomized::verify_signature_shares::<PallasBlake2b512>(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+        randomizer_json,
+    )
+}
+// =============================================================================
+// Multi-Action Signing
+// =============================================================================
+
+/// Generate Round 1 commitments for several actions at once
+///
+/// A transaction spending several Orchard notes needs one spend authorization
+/// signature per action, so each signer commits once per action.
+///
+/// # Arguments
+/// * `key_package_json` - Participant's key package (JSON)
+/// * `action_count` - Number of actions to sign
+///
+/// # Returns
+/// BatchRound1Result or FrostError
+pub fn generate_round1_commitments(
+    key_package_json: &str,
+    action_count: u32,
+) -> Result<BatchRound1Result, FrostError> {
+    generate_round1_batch_internal(key_package_json, action_count)
+        .map_err(|e| FrostError::new("ROUND1_ERROR", e))
+}
+
+fn generate_round1_batch_internal(
+    key_package_json: &str,
+    action_count: u32,
+) -> Result<BatchRound1Result, String> {
+    let key_package = parse_key_package(key_package_json)?;
+
+    let round1 =
+        crate::generate_round1_commitments(&key_package, action_count).map_err(|e| e.message)?;
+
+    let mut commitments = Vec::with_capacity(round1.len());
+    let mut nonces = Vec::with_capacity(round1.len());
+    for (action_nonces, action_commitments) in &round1 {
+        commitments.push(
+            serde_json::to_string(action_commitments)
+                .map_err(|e| format!("Failed to serialize commitments: {:?}", e))?,
+        );
+        nonces.push(
+            serde_json::to_string(action_nonces)
+                .map_err(|e| format!("Failed to serialize nonces: {:?}", e))?,
+        );
+    }
+
+    let identifier = identifier_to_u16(key_package.identifier())?;
+
+    Ok(BatchRound1Result {
+        commitment: BatchCommitmentInfo {
+            identifier,
+            commitments,
+        },
+        nonces: BatchNoncesInfo { identifier, nonces },
+    })
+}
+
+/// Generate Round 1 commitments for several actions, keeping the nonces in the keystore
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+/// * `action_count` - Number of actions to sign
+///
+/// # Returns
+/// BatchRound1HandleResult or FrostError
+pub fn generate_round1_commitments_with_handle(
+    key_handle: u32,
+    action_count: u32,
+) -> Result<BatchRound1HandleResult, FrostError> {
+    let round1_error = |message: String| FrostError::new("ROUND1_ERROR", message);
+
+    let round1 = crate::generate_round1_commitments_with_handle(key_handle, action_count)?;
+
+    let mut commitments = Vec::with_capacity(round1.len());
+    let mut nonce_handles = Vec::with_capacity(round1.len());
+    for (nonce_handle, action_commitments) in &round1 {
+        commitments.push(
+            serde_json::to_string(action_commitments)
+                .map_err(|e| round1_error(format!("Failed to serialize commitments: {:?}", e)))?,
+        );
+        nonce_handles.push(*nonce_handle);
+    }
+
+    Ok(BatchRound1HandleResult {
+        commitment: BatchCommitmentInfo {
+            identifier: key_handle_identifier(key_handle).map_err(round1_error)?,
+            commitments,
+        },
+        nonce_handles,
+    })
+}
+
+/// Create one signing package per action from batched commitments
+///
+/// # Arguments
+/// * `commitments_json` - All participants' BatchCommitmentInfo (JSON array)
+/// * `actions_json` - Actions to sign (JSON array of BatchActionInfo)
+/// * `public_key_package_json` - Public key package (JSON)
+///
+/// # Returns
+/// BatchSigningPackageResult or FrostError
+pub fn create_batch_signing_package(
+    commitments_json: &str,
+    actions_json: &str,
+    public_key_package_json: &str,
+) -> Result<BatchSigningPackageResult, FrostError> {
+    create_batch_signing_package_internal(commitments_json, actions_json, public_key_package_json)
+        .map_err(|e| FrostError::new("SIGNING_PACKAGE_ERROR", e))
+}
+
+fn create_batch_signing_package_internal(
+    commitments_json: &str,
+    actions_json: &str,
+    public_key_package_json: &str,
+) -> Result<BatchSigningPackageResult, String> {
+    let batch_commitments: Vec<BatchCommitmentInfo> = serde_json::from_str(commitments_json)
+        .map_err(|e| format!("Invalid commitments JSON: {}", e))?;
+
+    let mut commitments = BTreeMap::new();
+    for c in batch_commitments {
+        let action_commitments = c
+            .commitments
+            .iter()
+            .map(|commitment| {
+                serde_json::from_str(commitment)
+                    .map_err(|e| format!("Invalid commitment JSON: {}", e))
+            })
+            .collect::<Result<Vec<redpallas::round1::SigningCommitments>, String>>()?;
+
+        commitments.insert(identifier_from_u16(c.identifier)?, action_commitments);
+    }
+
+    let actions_info: Vec<BatchActionInfo> =
+        serde_json::from_str(actions_json).map_err(|e| format!("Invalid actions JSON: {}", e))?;
+
+    let actions = actions_info
+        .iter()
+        .map(parse_batch_action)
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let pubkey_package = parse_public_key_package(public_key_package_json)?;
+
+    let packages = crate::create_batch_signing_package(&commitments, &actions, &pubkey_package)
+        .map_err(|e| e.message)?;
+
+    Ok(BatchSigningPackageResult {
+        packages: packages
+            .iter()
+            .map(|(signing_package, randomizer)| {
+                rerandomized::signing_package_result(signing_package, randomizer)
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Decode a BatchActionInfo's hex fields; empty fields become `None`
+fn parse_batch_action(action: &BatchActionInfo) -> Result<BatchAction, String> {
+    let message =
+        hex::decode(&action.message).map_err(|e| format!("Invalid message hex: {}", e))?;
+
+    let randomizer = if action.randomizer.is_empty() {
+        None
+    } else {
+        Some(rerandomized::parse_randomizer_hex::<PallasBlake2b512>(
+            &action.randomizer,
+        )?)
+    };
+
+    let rk = if action.rk.is_empty() {
+        None
+    } else {
+        Some(hex::decode(&action.rk).map_err(|e| format!("Invalid rk hex: {}", e))?)
+    };
+
+    Ok(BatchAction {
+        message,
+        randomizer,
+        rk,
+    })
+}
+
+/// Generate Round 2 signature shares for every action in a batch
+///
+/// # Arguments
+/// * `key_package_json` - Participant's key package (JSON)
+/// * `nonces_json` - Participant's BatchNoncesInfo from Round 1 (JSON)
+/// * `batch_signing_package_json` - BatchSigningPackageResult from coordinator (JSON)
+///
+/// # Returns
+/// BatchSignatureShareInfo or FrostError
+pub fn generate_round2_signatures(
+    key_package_json: &str,
+    nonces_json: &str,
+    batch_signing_package_json: &str,
+) -> Result<BatchSignatureShareInfo, FrostError> {
+    let round2_error = |message: String| FrostError::new("ROUND2_ERROR", message);
+
+    let key_package = parse_key_package(key_package_json).map_err(round2_error)?;
+
+    let batch_nonces: BatchNoncesInfo = serde_json::from_str(nonces_json)
+        .map_err(|e| round2_error(format!("Invalid nonces JSON: {}", e)))?;
+    let nonces = batch_nonces
+        .nonces
+        .iter()
+        .map(|nonces| {
+            serde_json::from_str(nonces).map_err(|e| format!("Invalid nonces JSON: {}", e))
+        })
+        .collect::<Result<Vec<redpallas::round1::SigningNonces>, String>>()
+        .map_err(round2_error)?;
+
+    let packages = parse_batch_signing_package(batch_signing_package_json).map_err(round2_error)?;
+
+    let shares = crate::generate_round2_signatures(&packages, &nonces, &key_package)?;
+
+    Ok(BatchSignatureShareInfo {
+        identifier: identifier_to_u16(key_package.identifier()).map_err(round2_error)?,
+        shares: shares
+            .iter()
+            .map(|share| {
+                serde_json::to_string(share)
+                    .map_err(|e| format!("Failed to serialize signature share: {:?}", e))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(round2_error)?,
+    })
+}
+
+/// Generate Round 2 signature shares for every action in a batch from keystore handles
+///
+/// Each nonce handle is consumed and its nonces zeroized as its action is
+/// signed.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+/// * `nonce_handles` - Handles from `generate_round1_commitments_with_handle`, in action order
+/// * `batch_signing_package_json` - BatchSigningPackageResult from coordinator (JSON)
+///
+/// # Returns
+/// BatchSignatureShareInfo or FrostError
+pub fn generate_round2_signatures_with_handles(
+    key_handle: u32,
+    nonce_handles: &[u32],
+    batch_signing_package_json: &str,
+) -> Result<BatchSignatureShareInfo, FrostError> {
+    let round2_error = |message: String| FrostError::new("ROUND2_ERROR", message);
+
+    let packages = parse_batch_signing_package(batch_signing_package_json).map_err(round2_error)?;
+
+    let shares =
+        crate::generate_round2_signatures_with_handles(key_handle, nonce_handles, &packages)?;
+
+    Ok(BatchSignatureShareInfo {
+        identifier: key_handle_identifier(key_handle).map_err(round2_error)?,
+        shares: shares
+            .iter()
+            .map(|share| {
+                serde_json::to_string(share)
+                    .map_err(|e| format!("Failed to serialize signature share: {:?}", e))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(round2_error)?,
+    })
+}
+
+/// Aggregate every action's signature shares in one call
+///
+/// # Arguments
+/// * `shares_json` - All participants' BatchSignatureShareInfo (JSON array)
+/// * `batch_signing_package_json` - BatchSigningPackageResult (JSON)
+/// * `public_key_package_json` - Public key package (JSON)
+///
+/// # Returns
+/// BatchAggregateResult or AggregateError
+pub fn aggregate_signatures(
+    shares_json: &str,
+    batch_signing_package_json: &str,
+    public_key_package_json: &str,
+) -> Result<BatchAggregateResult, AggregateError> {
+    let batch_shares: Vec<BatchSignatureShareInfo> =
+        serde_json::from_str(shares_json).map_err(|e| format!("Invalid shares JSON: {}", e))?;
+
+    let mut shares = BTreeMap::new();
+    for s in batch_shares {
+        let action_shares = s
+            .shares
+            .iter()
+            .map(|share| {
+                serde_json::from_str(share)
+                    .map_err(|e| format!("Invalid signature share JSON: {}", e))
+            })
+            .collect::<Result<Vec<redpallas::round2::SignatureShare>, String>>()?;
+
+        shares.insert(identifier_from_u16(s.identifier)?, action_shares);
+    }
+
+    let packages = parse_batch_signing_package(batch_signing_package_json)?;
+    let pubkey_package = parse_public_key_package(public_key_package_json)?;
+
+    let signatures = crate::aggregate_signatures(&packages, &shares, &pubkey_package)?;
+
+    let mut results = Vec::with_capacity(signatures.len());
+    for (signature, (signing_package, randomizer)) in signatures.iter().zip(&packages) {
+        let randomizer_json = serde_json::to_string(&randomizer.randomizer(signing_package)?)
+            .map_err(|e| format!("Failed to serialize randomizer: {:?}", e))?;
+
+        results.push(AggregateResult {
+            signature: signing::signature_hex(signature)?,
+            randomizer: randomizer_json,
+        });
+    }
+
+    Ok(BatchAggregateResult {
+        signatures: results,
+    })
+}
+
+/// Parse every action's signing package and randomizer, in action order
+fn parse_batch_signing_package(
+    batch_signing_package_json: &str,
+) -> Result<Vec<RandomizedSigningPackage>, String> {
+    let batch_package: BatchSigningPackageResult = serde_json::from_str(batch_signing_package_json)
+        .map_err(|e| format!("Invalid batch signing package JSON: {}", e))?;
+
+    batch_package
+        .packages
+        .iter()
+        .map(|package| {
+            rerandomized::parse_randomized_signing_package::<PallasBlake2b512>(
+                &package.signing_package,
+                &package.randomizer,
+            )
+        })
+        .collect()
+}
+
+// =============================================================================
+// Signature Verification
+// =============================================================================
+
+/// Verify a rerandomized signature
+///
+/// # Arguments
+/// * `signature_hex` - Signature to verify (hex-encoded)
+/// * `message_hex` - Message that was signed (hex-encoded)
+/// * `group_public_key_hex` - Group verifying key (hex-encoded)
+/// * `randomizer_json` - Randomizer used for signing (JSON)
+///
+/// # Returns
+/// VerifyResult or FrostError
+pub fn verify_signature(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+    randomizer_json: &str,
+) -> Result<VerifyResult, FrostError> {
+    rerandomized::verify_signature::<PallasBlake2b512>(
+        signature_hex,
+        message_hex,
+        group_public_key_hex,
+        randomizer_json,
+    )
+}
+
+// =============================================================================
+// Emergency Reconstruction
+// =============================================================================
+
+/// DANGER: reassemble the group signing key (the Orchard `ask`) from `min_signers` key packages
+///
+/// This defeats the point of threshold signing: whoever runs it can spend the
+/// funds alone. Only use it for disaster recovery or migrating off FROST, on an
+/// offline machine, and retire the shares afterwards.
+///
+/// # Arguments
+/// * `key_packages_json` - At least `min_signers` key packages (JSON array of KeyPackage JSON strings)
+/// * `public_key_package_json` - Group public key package (JSON)
+///
+/// # Returns
+/// ReconstructResult or FrostError
+pub fn emergency_reconstruct_signing_key(
+    key_packages_json: &str,
+    public_key_package_json: &str,
+) -> Result<ReconstructResult, FrostError> {
+    reconstruct::emergency_reconstruct_signing_key::<PallasBlake2b512>(
+        key_packages_json,
+        public_key_package_json,
+    )
+}
+
+// =============================================================================
+// Utility Functions
+// =============================================================================
+
+/// Get the public key from a key package
+///
+/// # Arguments
+/// * `key_package_json` - Key package (JSON)
+///
+/// # Returns
+/// PublicKeyResult or FrostError
+pub fn get_public_key(key_package_json: &str) -> Result<PublicKeyResult, FrostError> {
+    keys::get_public_key::<PallasBlake2b512>(key_package_json)
+}
+
+/// Get the group public key from a public key package
+///
+/// # Arguments
+/// * `public_key_package_json` - Public key package (JSON)
+///
+/// # Returns
+/// Hex-encoded group public key or FrostError
+pub fn get_group_public_key(public_key_package_json: &str) -> Result<String, FrostError> {
+    keys::get_group_public_key::<PallasBlake2b512>(public_key_package_json)
+}
+
+// =============================================================================
+// Sapling (RedJubjub)
+// =============================================================================
+
+/// Generate RedJubjub key shares using trusted dealer
+///
+/// # Arguments
+/// * `threshold` - Minimum signers required (t)
+/// * `total` - Total number of signers (n)
+///
+/// # Returns
+/// KeyGenResult or FrostError
+pub fn sapling_generate_key_shares(threshold: u16, total: u16) -> Result<KeyGenResult, FrostError> {
+    keys::generate_key_shares::<JubjubBlake2b512>(threshold, total)
+}
+
+/// Generate Round 1 commitment for RedJubjub signing
+///
+/// # Arguments
+/// * `key_package_json` - Participant's key package (JSON)
+///
+/// # Returns
+/// Round1Result or FrostError
+pub fn sapling_generate_round1_commitment(
+    key_package_json: &str,
+) -> Result<Round1Result, FrostError> {
+    signing::generate_round1_commitment::<JubjubBlake2b512>(key_package_json)
+}
+
+/// Create a RedJubjub signing package with a fresh randomizer
+///
+/// # Arguments
+/// * `commitments_json` - All participants' commitments (JSON array)
+/// * `message_hex` - Message to sign (hex-encoded)
+/// * `public_key_package_json` - Public key package (JSON)
+///
+/// # Returns
+/// SigningPackageResult or FrostError
+pub fn sapling_create_signing_package(
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> Result<SigningPackageResult, FrostError> {
+    rerandomized::create_signing_package::<JubjubBlake2b512>(
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    )
+}
+
+/// Generate Round 2 RedJubjub signature share using rerandomization
+///
+/// # Arguments
+/// * `key_package_json` - Participant's key package (JSON)
+/// * `nonces_json` - Participant's nonces from Round 1 (JSON)
+/// * `signing_package_json` - Signing package from coordinator (JSON)
+/// * `randomizer_json` - Randomizer or randomizer seed from coordinator (JSON)
+///
+/// # Returns
+/// SignatureShareInfo or FrostError
+pub fn sapling_generate_round2_signature(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<SignatureShareInfo, FrostError> {
+    rerandomized::generate_round2_signature::<JubjubBlake2b512>(
+        key_package_json,
+        nonces_json,
+        signing_package_json,
+        randomizer_json,
+    )
+}
+
+/// Load a RedJubjub key package into the keystore and return an opaque handle
+///
+/// # Arguments
+/// * `key_package_json` - Participant's key package (JSON)
+///
+/// # Returns
+/// KeyHandleInfo or FrostError
+pub fn sapling_load_key_package(key_package_json: &str) -> Result<KeyHandleInfo, FrostError> {
+    crate::sapling::SAPLING_KEYSTORE
+        .with(|keystore| keystore::load_key_package(&mut keystore.borrow_mut(), key_package_json))
+}
+
+/// Generate Round 1 RedJubjub commitment, keeping the nonces in the keystore
+///
+/// # Arguments
+/// * `key_handle` - Handle from `sapling_load_key_package`
+///
+/// # Returns
+/// Round1HandleResult or FrostError
+pub fn sapling_generate_round1_commitment_with_handle(
+    key_handle: u32,
+) -> Result<Round1HandleResult, FrostError> {
+    crate::sapling::SAPLING_KEYSTORE.with(|keystore| {
+        keystore::generate_round1_commitment(&mut keystore.borrow_mut(), key_handle)
+    })
+}
+
+/// Generate Round 2 RedJubjub signature share from keystore handles
+///
+/// The nonce handle is consumed and its nonces zeroized, so it cannot be
+/// used for a second signature.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `sapling_load_key_package`
+/// * `nonce_handle` - Handle from `sapling_generate_round1_commitment_with_handle`
+/// * `signing_package_json` - Signing package from coordinator (JSON)
+/// * `randomizer_json` - Randomizer or randomizer seed from coordinator (JSON)
+///
+/// # Returns
+/// SignatureShareInfo or FrostError
+pub fn sapling_generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> Result<SignatureShareInfo, FrostError> {
+    crate::sapling::SAPLING_KEYSTORE.with(|keystore| {
+        rerandomized::generate_round2_signature_with_handles(
+            &mut keystore.borrow_mut(),
+            key_handle,
+            nonce_handle,
+            signing_package_json,
+            randomizer_json,
+        )
+    })
+}
+
+/// Aggregate RedJubjub signature shares into final signature
+///
+/// # Arguments
+/// * `shares_json` - All signature shares (JSON array)
+/// * `signing_package_json` - Signing package (JSON)
+/// * `public_key_package_json` - Public key package (JSON)
+/// * `randomizer_json` - Randomizer used for signing (JSON)
+///
+/// # Returns
+/// AggregateResult or AggregateError (with `culprits`)
+pub fn sapling_aggregate_signature(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    randomizer_json: &str,
+) -> Result<AggregateResult, AggregateError> {
+    rerandomized::aggregate_signature::<JubjubBlake2b512>(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+        randomizer_json,
+    )
+}
+
+/// Verify a rerandomized RedJubjub signature
+///
+/// Checked against the randomized key, as a Sapling spend is against `rk`.
+///
+/// # Arguments
+/// * `signature_hex` - Signature to verify (hex-encoded)
+/// * `message_hex` - Message that was signed (hex-encoded)
+/// * `group_public_key_hex` - Group verifying key (hex-encoded)
+/// * `randomizer_json` - Randomizer used for signing (JSON)
+///
+/// # Returns
+/// VerifyResult or FrostError
+pub fn sapling_verify_signature(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+    randomizer_json: &str,
+) -> Result<VerifyResult, FrostError> {
+    rerandomized::verify_signature::<JubjubBlake2b512>(
+        signature_hex,
+        message_hex,
+        group_public_key_hex,
+        randomizer_json,
+    )
+}
+
+/// Get the public key from a RedJubjub key package
+///
+/// # Arguments
+/// * `key_package_json` - Key package (JSON)
+///
+/// # Returns
+/// PublicKeyResult or FrostError
+pub fn sapling_get_public_key(key_package_json: &str) -> Result<PublicKeyResult, FrostError> {
+    keys::get_public_key::<JubjubBlake2b512>(key_package_json)
+}
+
+/// Get the group public key from a RedJubjub public key package
+///
+/// # Arguments
+/// * `public_key_package_json` - Public key package (JSON)
+///
+/// # Returns
+/// Hex-encoded group public key or FrostError
+pub fn sapling_get_group_public_key(public_key_package_json: &str) -> Result<String, FrostError> {
+    keys::get_group_public_key::<JubjubBlake2b512>(public_key_package_json)
+}
+
+// =============================================================================
+// PCZT Signing
+// =============================================================================
+
+/// List the Orchard actions of a PCZT that need a spend authorization signature
+///
+/// Actions that already carry a signature (including dummy actions signed by
+/// the IO finalizer) are skipped.
+///
+/// # Arguments
+/// * `pczt_hex` - Serialized PCZT (hex)
+///
+/// # Returns
+/// PcztSigningRequests or FrostError
+pub fn pczt_orchard_signing_requests(pczt_hex: &str) -> Result<PcztSigningRequests, FrostError> {
+    pczt_orchard_signing_requests_internal(pczt_hex).map_err(|e| FrostError::new("PCZT_ERROR", e))
+}
+
+fn pczt_orchard_signing_requests_internal(pczt_hex: &str) -> Result<PcztSigningRequests, String> {
+    let pczt = parse_pczt(pczt_hex)?;
+
+    let (sighash, requests) =
+        crate::pczt_signing::pczt_orchard_signing_requests(pczt).map_err(|e| e.message)?;
+
+    Ok(PcztSigningRequests {
+        sighash: hex::encode(sighash),
+        actions: requests
+            .into_iter()
+            .map(|request| OrchardSigningRequest {
+                index: request.index,
+                randomizer: hex::encode(request.randomizer.serialize()),
+                rk: hex::encode(request.rk),
+                value: request.value,
+            })
+            .collect(),
+    })
+}
+
+/// Write aggregated spend authorization signatures into a PCZT
+///
+/// Each signature is verified against the action's rk and the sighash before
+/// it is stored.
+///
+/// # Arguments
+/// * `pczt_hex` - Serialized PCZT (hex)
+/// * `signatures_json` - JSON array of OrchardSpendAuthSig objects
+///
+/// # Returns
+/// PcztResult or FrostError
+pub fn pczt_apply_orchard_signatures(
+    pczt_hex: &str,
+    signatures_json: &str,
+) -> Result<PcztResult, FrostError> {
+    pczt_apply_orchard_signatures_internal(pczt_hex, signatures_json)
+        .map_err(|e| FrostError::new("PCZT_ERROR", e))
+}
+
+fn pczt_apply_orchard_signatures_internal(
+    pczt_hex: &str,
+    signatures_json: &str,
+) -> Result<PcztResult, String> {
+    let pczt = parse_pczt(pczt_hex)?;
+
+    let signatures_info: Vec<OrchardSpendAuthSig> = serde_json::from_str(signatures_json)
+        .map_err(|e| format!("Invalid signatures JSON: {}", e))?;
+
+    let mut signatures = Vec::with_capacity(signatures_info.len());
+    for sig in &signatures_info {
+        let signature = signing::parse_signature::<PallasBlake2b512>(&sig.signature)?;
+        signatures.push((sig.index, signature));
+    }
+
+    let pczt = crate::pczt_signing::pczt_apply_orchard_signatures(pczt, &signatures)
+        .map_err(|e| e.message)?;
+
+    let pczt_bytes = pczt
+        .serialize()
+        .map_err(|e| format!("Failed to serialize PCZT: {:?}", e))?;
+
+    Ok(PcztResult {
+        pczt: hex::encode(pczt_bytes),
+    })
+}
+
+/// Decode a hex-encoded PCZT
+fn parse_pczt(pczt_hex: &str) -> Result<Pczt, String> {
+    let pczt_bytes = hex::decode(pczt_hex).map_err(|e| format!("Invalid PCZT hex: {}", e))?;
+    Pczt::parse(&pczt_bytes).map_err(|e| format!("Invalid PCZT: {:?}", e))
+}
+
+// =============================================================================
+// Parsing Helpers
+// =============================================================================
+
+fn parse_key_package(key_package_json: &str) -> Result<KeyPackage, String> {
+    serde_json::from_str(key_package_json).map_err(|e| format!("Invalid key package JSON: {}", e))
+}
+
+/// Wire identifier of the key package behind a key handle
+fn key_handle_identifier(key_handle: u32) -> Result<u16, String> {
+    crate::KEYSTORE
+        .with(|keystore| keystore.borrow().identifier(key_handle))
+        .ok_or_else(|| format!("Unknown key handle {}", key_handle))
+        .and_then(|identifier| identifier_to_u16(&identifier))
+}
+
+fn parse_public_key_package(public_key_package_json: &str) -> Result<PublicKeyPackage, String> {
+    serde_json::from_str(public_key_package_json)
+        .map_err(|e| format!("Invalid public key package JSON: {}", e))
+}
@@ -3,164 +3,310 @@
 //! This module provides WASM bindings for FROST rerandomized threshold signatures
 //! using the RedPallas curve, suitable for Zcash Orchard transactions.
 //!
-//! Key design principles:
+//! Key design principles of the wasm exports:
 //! - Narrow waist API: all types cross JS↔WASM boundary as opaque bytes
 //! - Internal Rust types never exposed directly
 //! - Serde JSON for serialization
 //! - Full rerandomization support per ZIP-312
+//!
+//! The protocol logic lives in `frost-common`; this crate instantiates it with
+//! RedPallas and adds what is specific to Orchard (key encoding fix-ups,
+//! viewing keys, multi-action signing and PCZTs).
+//!
+//! The crate root (with `sapling` and `pczt_signing`) is the native Rust API:
+//! typed functions over the `reddsa` FROST types (re-exported as `redpallas`
+//! and `redjubjub`) returning `Result<T, FrostError>`. The `json` module
+//! layers the serialized wire format (JSON strings and hex) on top of it, and
+//! the `wasm` feature (on by default) exports that layer through wasm-bindgen
+//! in the `wasm` module.
 
 use rand::rngs::OsRng;
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::collections::BTreeMap;
-use wasm_bindgen::prelude::*;
-
-// Import RedPallas FROST types from reddsa
-use reddsa::frost::redpallas::{
-    self,
-    keys::{self, IdentifierList, KeyPackage, PublicKeyPackage},
-    round1::{self, SigningCommitments, SigningNonces},
-    round2::{self, SignatureShare},
-    Identifier, RandomizedParams, Signature, SigningPackage,
+
+use pasta_curves::group::ff::PrimeField;
+use pasta_curves::pallas;
+
+use orchard::keys::FullViewingKey;
+
+use frost_common::keystore::Keystore;
+use frost_common::{
+    codec, dkg, keys, nonces, pool, reconstruct, refresh, repair, rerandomized, reshare, signing,
 };
 
+pub use reddsa::frost::{redjubjub, redpallas};
+
+pub mod json;
+pub mod pczt_signing;
+pub mod sapling;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+use redpallas::keys::{KeyPackage, PublicKeyPackage};
+use redpallas::PallasBlake2b512;
+
 // =============================================================================
 // Error Handling
 // =============================================================================
 
-/// Error response structure
-#[derive(Serialize, Deserialize)]
-pub struct FrostError {
-    pub code: String,
-    pub message: String,
-}
-
-/// Success/Error result wrapper
-#[derive(Serialize)]
-#[serde(untagged)]
-pub enum FrostResult<T> {
-    Ok(T),
-    Err(FrostError),
-}
-
-impl<T: Serialize> FrostResult<T> {
-    fn to_json(&self) -> String {
-        serde_json::to_string(self).unwrap_or_else(|e| {
-            serde_json::to_string(&FrostError {
-                code: "SERIALIZATION_ERROR".into(),
-                message: e.to_string(),
-            })
-            .unwrap()
-        })
-    }
-}
+pub use frost_common::{AggregateError, FrostError};
 
 // =============================================================================
 // Key Generation Types
 // =============================================================================
 
-/// Result of key generation with trusted dealer
-#[derive(Serialize)]
-pub struct KeyGenResult {
-    /// Group verifying key (hex)
-    pub group_public_key: String,
-    /// Individual key shares for each participant
-    pub shares: Vec<KeyShareInfo>,
-    /// Threshold required for signing
-    pub threshold: u16,
-    /// Total number of participants
-    pub total: u16,
-    /// Serialized PublicKeyPackage (JSON) - needed for aggregation
-    pub public_key_package: String,
-}
+pub use frost_common::KeyGenResult;
 
 /// Individual key share info
-#[derive(Serialize)]
-pub struct KeyShareInfo {
-    /// Participant identifier (1-indexed)
-    pub identifier: u16,
-    /// Serialized KeyPackage (JSON) - keep secret!
-    pub key_package: String,
-}
+pub type KeyShareInfo = frost_common::KeyShare;
 
 // =============================================================================
-// Round 1 Types
+// Distributed Key Generation Types
+// =============================================================================
+
+/// DKG Round 1 package with sender identifier (broadcast to all participants)
+pub type DkgRound1PackageInfo = frost_common::DkgRound1Package;
+
+/// DKG Round 2 package addressed to a single recipient (send privately)
+pub type DkgRound2PackageInfo = frost_common::DkgRound2Package;
+
+pub use frost_common::{DkgResult, DkgRound1Result, DkgRound2Result};
+
+// =============================================================================
+// Share Refresh Types
 // =============================================================================
 
-/// Result of Round 1 commitment generation
-#[derive(Serialize)]
-pub struct Round1Result {
-    /// Public commitment to broadcast
-    pub commitment: CommitmentInfo,
-    /// Secret nonces - MUST NOT be reused! (JSON)
-    pub nonces: NoncesInfo,
+/// Zero-secret refreshing share for one participant (send privately)
+pub type RefreshingShareInfo = frost_common::RefreshingShare;
+
+pub use frost_common::RefreshSharesResult;
+
+// =============================================================================
+// Share Repair Types
+// =============================================================================
+
+/// Repair delta sent privately from one helper to another
+pub type RepairDeltaInfo = frost_common::RepairDelta;
+
+/// Helper's sigma, sent privately to the participant being repaired
+pub type RepairSigmaInfo = frost_common::RepairSigma;
+
+pub use frost_common::RepairStep1Result;
+
+// =============================================================================
+// Resharing Types
+// =============================================================================
+
+/// Resharing dealer's public output, broadcast to every new participant
+pub type ReshareCommitmentInfo = frost_common::ReshareCommitment;
+
+/// Sub-share sent privately from a resharing dealer to one new participant
+pub type ReshareSubShareInfo = frost_common::ReshareSubShare;
+
+pub use frost_common::ReshareRound1Result;
+
+// =============================================================================
+// Orchard Viewing Key Types
+// =============================================================================
+
+/// Orchard full viewing key and address derived from a FROST group
+#[derive(Serialize, Deserialize)]
+pub struct OrchardViewingKeyResult {
+    /// Spend validating key ak (hex) - the group verifying key
+    pub ak: String,
+    /// Nullifier deriving key nk (hex)
+    pub nk: String,
+    /// Commit-ivk randomness rivk (hex) - keep private, grants viewing capability
+    pub rivk: String,
+    /// Raw Orchard FullViewingKey ak || nk || rivk (hex)
+    pub full_viewing_key: String,
+    /// ZIP-316 unified full viewing key (Orchard only)
+    pub unified_full_viewing_key: String,
+    /// ZIP-316 unified address (Orchard receiver only)
+    pub unified_address: String,
+    /// Diversifier index of the address
+    pub diversifier_index: u32,
 }
 
+// =============================================================================
+// Round 1 Types
+// =============================================================================
+
 /// Commitment info with identifier
+pub type CommitmentInfo = frost_common::Commitment;
+
+/// Nonces info with identifier (keep secret!)
+pub type NoncesInfo = frost_common::SigningNonces;
+
+/// Opaque handle to a key package held in WASM memory
+pub type KeyHandleInfo = frost_common::KeyHandle;
+
+pub use frost_common::{Round1HandleResult, Round1Result};
+
+// =============================================================================
+// Round 2 Types
+// =============================================================================
+
+/// Signature share from Round 2
+pub type SignatureShareInfo = frost_common::SignatureShare;
+
+pub use frost_common::{NonceRegistryState, SealedNonceRegistry};
+
+// =============================================================================
+// Signing Package and Aggregation Types
+// =============================================================================
+
+pub use frost_common::{
+    AggregateResult, ShareVerificationResult, SigningPackageResult, VerifyResult,
+};
+
+// =============================================================================
+// Multi-Action Signing Types
+// =============================================================================
+
+/// One commitment per action, with identifier
 #[derive(Serialize, Deserialize, Clone)]
-pub struct CommitmentInfo {
+pub struct BatchCommitmentInfo {
     /// Participant identifier
     pub identifier: u16,
-    /// Serialized SigningCommitments (JSON)
-    pub commitment: String,
+    /// Serialized SigningCommitments (JSON), one per action
+    pub commitments: Vec<String>,
 }
 
-/// Nonces info with identifier (keep secret!)
+/// One set of nonces per action, with identifier (keep secret!)
 #[derive(Serialize, Deserialize)]
-pub struct NoncesInfo {
+pub struct BatchNoncesInfo {
     /// Participant identifier
     pub identifier: u16,
-    /// Serialized SigningNonces (JSON) - KEEP SECRET
-    pub nonces: String,
+    /// Serialized SigningNonces (JSON), one per action - KEEP SECRET
+    pub nonces: Vec<String>,
 }
 
-// =============================================================================
-// Round 2 Types
-// =============================================================================
+/// Result of batched Round 1 commitment generation
+#[derive(Serialize, Deserialize)]
+pub struct BatchRound1Result {
+    /// Public commitments to broadcast
+    pub commitment: BatchCommitmentInfo,
+    /// Secret nonces - MUST NOT be reused!
+    pub nonces: BatchNoncesInfo,
+}
 
-/// Signature share from Round 2
+/// Result of batched Round 1 with the nonces kept in the keystore
 #[derive(Serialize, Deserialize)]
-pub struct SignatureShareInfo {
+pub struct BatchRound1HandleResult {
+    /// Public commitments to broadcast
+    pub commitment: BatchCommitmentInfo,
+    /// Handles to the nonces, one per action, for `generate_round2_signatures_with_handles`
+    pub nonce_handles: Vec<u32>,
+}
+
+/// A single action to sign in a batch
+#[derive(Serialize, Deserialize, Clone)]
+pub struct BatchActionInfo {
+    /// Message to sign (hex), e.g. the transaction sighash
+    pub message: String,
+    /// Randomizer alpha (hex) or empty to draw a fresh one
+    #[serde(default)]
+    pub randomizer: String,
+    /// Expected randomized verifying key rk (hex) or empty to skip the check
+    #[serde(default)]
+    pub rk: String,
+}
+
+/// Signing packages and randomizers for every action, in action order
+#[derive(Serialize, Deserialize)]
+pub struct BatchSigningPackageResult {
+    /// One signing package and randomizer per action
+    pub packages: Vec<SigningPackageResult>,
+}
+
+/// Signature shares for every action, with identifier
+#[derive(Serialize, Deserialize)]
+pub struct BatchSignatureShareInfo {
     /// Participant identifier
     pub identifier: u16,
-    /// Serialized SignatureShare (JSON)
-    pub share: String,
+    /// Serialized SignatureShare (JSON), one per action
+    pub shares: Vec<String>,
+}
+
+/// Aggregate signatures for every action, in action order
+#[derive(Serialize, Deserialize)]
+pub struct BatchAggregateResult {
+    /// One aggregate signature and randomizer per action
+    pub signatures: Vec<AggregateResult>,
 }
 
 // =============================================================================
-// Signing Package Types
+// Commitment Pool Types
 // =============================================================================
 
-/// Result of creating a signing package with randomizer
-#[derive(Serialize)]
-pub struct SigningPackageResult {
-    /// Serialized SigningPackage (JSON)
-    pub signing_package: String,
-    /// Serialized randomizer (JSON) - needed for signing and verification
-    pub randomizer: String,
-}
+/// A signer's preprocessed commitments, published to the coordinator
+pub type CommitmentPoolInfo = frost_common::CommitmentPool;
+
+/// Nonces matching a CommitmentPoolInfo (keep secret!)
+pub type NoncePoolInfo = frost_common::NoncePool;
+
+/// Commitments chosen by the coordinator for one signing session
+pub type PoolSelectionResult = frost_common::PoolSelection;
+
+pub use frost_common::{CommitmentPoolResult, PoolSignatureResult};
 
 // =============================================================================
-// Aggregation Types
+// Emergency Reconstruction Types
 // =============================================================================
 
-/// Result of signature aggregation
-#[derive(Serialize)]
-pub struct AggregateResult {
-    /// Final aggregate signature (hex)
-    pub signature: String,
-    /// Randomizer used (JSON) - for verification
-    pub randomizer: String,
-}
+/// Group signing key reassembled by `emergency_reconstruct_signing_key`
+pub type ReconstructResult = frost_common::ReconstructedKey;
+
+// =============================================================================
+// Utility Types
+// =============================================================================
+
+pub use frost_common::PublicKeyResult;
 
 // =============================================================================
-// WASM Initialization
+// Typed API Types
 // =============================================================================
 
-/// Initialize panic hook for better error messages in WASM
-#[wasm_bindgen(start)]
-pub fn init() {
-    #[cfg(feature = "console_error_panic_hook")]
-    console_error_panic_hook::set_once();
+/// Each participant's secret share and the group's public key package, from
+/// the dealer
+pub type DealtShares = keys::DealtShares<PallasBlake2b512>;
+
+/// DKG Round 1 packages, keyed by sender
+pub type Round1Packages = dkg::Round1Packages<PallasBlake2b512>;
+
+/// DKG Round 2 packages, keyed by sender (incoming) or recipient (outgoing)
+pub type Round2Packages = dkg::Round2Packages<PallasBlake2b512>;
+
+/// A resharing dealer's VSS commitment and sub-shares, keyed by new participant
+pub type DealtSubShares = reshare::DealtSubShares<PallasBlake2b512>;
+
+/// Signature shares, keyed by signer
+pub type SignatureShares = signing::SignatureShares<PallasBlake2b512>;
+
+/// Commitments, keyed by signer
+pub type SigningCommitmentsMap =
+    BTreeMap<redpallas::Identifier, redpallas::round1::SigningCommitments>;
+
+/// A scalar of the Pallas group order, as exchanged during share repair
+pub type Scalar = pallas::Scalar;
+
+/// Randomizer of a signing package: a seed the signers derive it from, or
+/// alpha when the transaction builder fixed it
+pub type SigningRandomizer = rerandomized::SigningRandomizer<PallasBlake2b512>;
+
+/// A signing package and the randomizer the coordinator chose for it
+pub type RandomizedSigningPackage = (redpallas::SigningPackage, SigningRandomizer);
+
+/// A single action to sign in a batch
+pub struct BatchAction {
+    /// Message to sign, e.g. the transaction sighash
+    pub message: Vec<u8>,
+    /// Randomizer alpha, or `None` to draw a fresh one
+    pub randomizer: Option<redpallas::rerandomized::Randomizer>,
+    /// Expected randomized verifying key rk, or `None` to skip the check
+    pub rk: Option<Vec<u8>>,
 }
 
 // =============================================================================
@@ -174,221 +320,540 @@ pub fn init() {
 /// * `total` - Total number of signers (n)
 ///
 /// # Returns
-/// JSON string containing KeyGenResult or FrostError
-#[wasm_bindgen]
-pub fn generate_key_shares(threshold: u16, total: u16) -> String {
-    match generate_key_shares_internal(threshold, total) {
-        Ok(result) => FrostResult::Ok(result).to_json(),
-        Err(e) => FrostResult::<KeyGenResult>::Err(FrostError {
-            code: "KEYGEN_ERROR".into(),
-            message: e,
-        })
-        .to_json(),
-    }
+/// Each participant's secret share and the public key package, or FrostError
+pub fn generate_key_shares(threshold: u16, total: u16) -> Result<DealtShares, FrostError> {
+    generate_key_shares_internal(threshold, total).map_err(|e| FrostError::new("KEYGEN_ERROR", e))
 }
 
-fn generate_key_shares_internal(threshold: u16, total: u16) -> Result<KeyGenResult, String> {
-    if threshold == 0 || threshold > total {
-        return Err(format!(
-            "Invalid threshold: {} must be > 0 and <= {}",
-            threshold, total
-        ));
-    }
-    if total > 255 {
-        return Err("Total participants must be <= 255".into());
-    }
+fn generate_key_shares_internal(threshold: u16, total: u16) -> Result<DealtShares, String> {
+    let (shares, pubkey_package) =
+        keys::deal_key_shares::<PallasBlake2b512>(threshold, total).map_err(|e| e.message)?;
 
-    let mut rng = OsRng;
+    // reddsa already gives ak ỹ = 0 when dealing; check it rather than trust it
+    validate_orchard_public_key_package_internal(&pubkey_package)?;
 
-    // Generate key shares using trusted dealer with default identifiers
-    let (shares, pubkey_package) =
-        keys::generate_with_dealer(total, threshold, IdentifierList::Default, &mut rng)
-            .map_err(|e| format!("Key generation failed: {:?}", e))?;
+    Ok((shares, pubkey_package))
+}
 
-    // Extract group public key
-    let group_pubkey = pubkey_package.verifying_key();
-    let group_pubkey_bytes = group_pubkey
-        .serialize()
-        .map_err(|e| format!("Failed to serialize group public key: {:?}", e))?;
-    let group_pubkey_hex = hex::encode(group_pubkey_bytes);
-
-    // Serialize public key package for later use in aggregation
-    let pubkey_package_json =
-        serde_json::to_string(&pubkey_package).map_err(|e| format!("Serialize error: {}", e))?;
-
-    // Convert shares to key packages and serialize
-    let mut key_shares = Vec::new();
-    for (id, secret_share) in shares.iter() {
-        // Convert SecretShare to KeyPackage
-        let key_package: KeyPackage = secret_share
-            .clone()
-            .try_into()
-            .map_err(|e| format!("Failed to convert share to key package: {:?}", e))?;
-
-        let key_package_json =
-            serde_json::to_string(&key_package).map_err(|e| format!("Serialize error: {}", e))?;
-
-        let id_bytes = id.serialize();
-        let id_num = u16::from_le_bytes([id_bytes[0], id_bytes[1]]);
-
-        key_shares.push(KeyShareInfo {
-            identifier: id_num,
-            key_package: key_package_json,
-        });
-    }
+/// Verify a dealer-issued secret share and turn it into a key package
+///
+/// Checks the share against the dealer's VSS commitment, and the commitment
+/// against the group's public key package, so a participant never stores a
+/// share that is inconsistent with everyone else's.
+///
+/// # Arguments
+/// * `secret_share` - This participant's secret share from the dealer
+/// * `public_key_package` - The group's public key package from the dealer
+///
+/// # Returns
+/// KeyPackage or FrostError
+pub fn verify_and_accept_share(
+    secret_share: redpallas::keys::SecretShare,
+    public_key_package: &PublicKeyPackage,
+) -> Result<KeyPackage, FrostError> {
+    keys::accept_share::<PallasBlake2b512>(secret_share, public_key_package)
+}
 
-    // Sort by identifier for consistency
-    key_shares.sort_by_key(|s| s.identifier);
+// =============================================================================
+// Distributed Key Generation
+// =============================================================================
 
-    Ok(KeyGenResult {
-        group_public_key: group_pubkey_hex,
-        shares: key_shares,
-        threshold,
-        total,
-        public_key_package: pubkey_package_json,
-    })
+/// DKG part 1: generate this participant's Round 1 package
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier
+/// * `max_signers` - Total number of signers (n)
+/// * `min_signers` - Minimum signers required (t)
+///
+/// # Returns
+/// The Round 1 secret package to keep and the package to broadcast, or FrostError
+pub fn dkg_part1(
+    identifier: redpallas::Identifier,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<
+    (
+        redpallas::keys::dkg::round1::SecretPackage,
+        redpallas::keys::dkg::round1::Package,
+    ),
+    FrostError,
+> {
+    dkg::part1::<PallasBlake2b512>(identifier, max_signers, min_signers)
+}
+
+/// DKG part 2: process the other participants' Round 1 packages
+///
+/// # Arguments
+/// * `secret_package` - Secret package from dkg_part1
+/// * `round1_packages` - Other participants' Round 1 packages
+///
+/// # Returns
+/// The Round 2 secret package to keep and the packages to send, or FrostError
+pub fn dkg_part2(
+    secret_package: redpallas::keys::dkg::round1::SecretPackage,
+    round1_packages: &Round1Packages,
+) -> Result<(redpallas::keys::dkg::round2::SecretPackage, Round2Packages), FrostError> {
+    dkg::part2::<PallasBlake2b512>(secret_package, round1_packages)
+}
+
+/// DKG part 3: derive this participant's key package and the group public key package
+///
+/// Both are Orchard compatible, so the public key package can be passed
+/// directly to `create_signing_package` and `aggregate_signature`.
+///
+/// # Arguments
+/// * `secret_package` - Secret package from dkg_part2
+/// * `round1_packages` - Other participants' Round 1 packages
+/// * `round2_packages` - Round 2 packages addressed to this participant
+///
+/// # Returns
+/// KeyPackage and PublicKeyPackage, or FrostError
+pub fn dkg_part3(
+    secret_package: &redpallas::keys::dkg::round2::SecretPackage,
+    round1_packages: &Round1Packages,
+    round2_packages: &Round2Packages,
+) -> Result<(KeyPackage, PublicKeyPackage), FrostError> {
+    dkg_part3_internal(secret_package, round1_packages, round2_packages)
+        .map_err(|e| FrostError::new("DKG_ERROR", e))
+}
+
+fn dkg_part3_internal(
+    secret_package: &redpallas::keys::dkg::round2::SecretPackage,
+    round1_packages: &Round1Packages,
+    round2_packages: &Round2Packages,
+) -> Result<(KeyPackage, PublicKeyPackage), String> {
+    let (key_package, pubkey_package) =
+        dkg::part3::<PallasBlake2b512>(secret_package, round1_packages, round2_packages)
+            .map_err(|e| e.message)?;
+
+    // reddsa already gives ak ỹ = 0 at the end of the DKG; check it rather than trust it
+    validate_orchard_public_key_package_internal(&pubkey_package)?;
+
+    Ok((key_package, pubkey_package))
 }
 
 // =============================================================================
-// Round 1: Commitment Generation
+// Share Refresh
 // =============================================================================
 
-/// Generate Round 1 commitment for signing
+/// Dealer-based refresh: deal zero-secret shares that rotate every key share
+///
+/// The group key (and so the Orchard address) is unchanged. Every participant
+/// must apply their share with `refresh_share`; old and refreshed shares
+/// cannot be combined afterwards.
 ///
 /// # Arguments
-/// * `key_package_json` - Participant's key package (JSON)
+/// * `public_key_package` - Current public key package
+/// * `identifiers` - All participants' identifiers
+/// * `min_signers` - Minimum signers required (t), unchanged from keygen
 ///
 /// # Returns
-/// JSON string containing Round1Result or FrostError
-#[wasm_bindgen]
-pub fn generate_round1_commitment(key_package_json: &str) -> String {
-    match generate_round1_internal(key_package_json) {
-        Ok(result) => FrostResult::Ok(result).to_json(),
-        Err(e) => FrostResult::<Round1Result>::Err(FrostError {
-            code: "ROUND1_ERROR".into(),
-            message: e,
-        })
-        .to_json(),
-    }
+/// The refreshing shares, in `identifiers` order, and the refreshed
+/// PublicKeyPackage, or FrostError
+pub fn compute_refreshing_shares(
+    public_key_package: PublicKeyPackage,
+    identifiers: &[redpallas::Identifier],
+    min_signers: u16,
+) -> Result<(Vec<redpallas::keys::SecretShare>, PublicKeyPackage), FrostError> {
+    refresh::deal_refreshing_shares::<PallasBlake2b512>(
+        public_key_package,
+        identifiers,
+        min_signers,
+    )
 }
 
-fn generate_round1_internal(key_package_json: &str) -> Result<Round1Result, String> {
-    let mut rng = OsRng;
+/// Apply a refreshing share to this participant's key package
+///
+/// # Arguments
+/// * `refreshing_share` - This participant's refreshing share
+/// * `key_package` - Current key package
+///
+/// # Returns
+/// The refreshed KeyPackage or FrostError
+pub fn refresh_share(
+    refreshing_share: redpallas::keys::SecretShare,
+    key_package: &KeyPackage,
+) -> Result<KeyPackage, FrostError> {
+    refresh::apply_refreshing_share::<PallasBlake2b512>(refreshing_share, key_package)
+}
 
-    // Parse key package
-    let key_package: KeyPackage = serde_json::from_str(key_package_json)
-        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+/// Refresh DKG part 1: like `dkg_part1`, with a zero constant term so the group key is kept
+///
+/// # Arguments
+/// * `identifier` - This participant's identifier
+/// * `max_signers` - Total number of signers (n)
+/// * `min_signers` - Minimum signers required (t)
+///
+/// # Returns
+/// The Round 1 secret package to keep and the package to broadcast, or FrostError
+pub fn refresh_dkg_part1(
+    identifier: redpallas::Identifier,
+    max_signers: u16,
+    min_signers: u16,
+) -> Result<
+    (
+        redpallas::keys::dkg::round1::SecretPackage,
+        redpallas::keys::dkg::round1::Package,
+    ),
+    FrostError,
+> {
+    refresh::refresh_part1::<PallasBlake2b512>(identifier, max_signers, min_signers)
+}
 
-    // Get identifier
-    let identifier = *key_package.identifier();
-    let id_bytes = identifier.serialize();
-    let id_num = u16::from_le_bytes([id_bytes[0], id_bytes[1]]);
+/// Refresh DKG part 2: same as `dkg_part2` over the zero-secret polynomials
+///
+/// # Arguments
+/// * `secret_package` - Secret package from refresh_dkg_part1
+/// * `round1_packages` - Other participants' Round 1 packages
+///
+/// # Returns
+/// The Round 2 secret package to keep and the packages to send, or FrostError
+pub fn refresh_dkg_part2(
+    secret_package: redpallas::keys::dkg::round1::SecretPackage,
+    round1_packages: &Round1Packages,
+) -> Result<(redpallas::keys::dkg::round2::SecretPackage, Round2Packages), FrostError> {
+    refresh::refresh_part2::<PallasBlake2b512>(secret_package, round1_packages)
+}
 
-    // Generate nonces and commitments
-    let (nonces, commitments) = round1::commit(key_package.signing_share(), &mut rng);
+/// Refresh DKG part 3: derive the refreshed key package and public key package
+///
+/// # Arguments
+/// * `secret_package` - Secret package from refresh_dkg_part2
+/// * `round1_packages` - Other participants' Round 1 packages
+/// * `round2_packages` - Round 2 packages addressed to this participant
+/// * `public_key_package` - Current public key package
+/// * `key_package` - This participant's current key package
+///
+/// # Returns
+/// The refreshed KeyPackage and PublicKeyPackage, or FrostError
+pub fn refresh_dkg_part3(
+    secret_package: &redpallas::keys::dkg::round2::SecretPackage,
+    round1_packages: &Round1Packages,
+    round2_packages: &Round2Packages,
+    public_key_package: PublicKeyPackage,
+    key_package: KeyPackage,
+) -> Result<(KeyPackage, PublicKeyPackage), FrostError> {
+    refresh::refresh_part3::<PallasBlake2b512>(
+        secret_package,
+        round1_packages,
+        round2_packages,
+        public_key_package,
+        key_package,
+    )
+}
 
-    // Serialize nonces (keep secret!)
-    let nonces_json =
-        serde_json::to_string(&nonces).map_err(|e| format!("Serialize nonces error: {}", e))?;
+// =============================================================================
+// Share Repair
+// =============================================================================
 
-    // Serialize commitments
-    let commitments_json = serde_json::to_string(&commitments)
-        .map_err(|e| format!("Serialize commitments error: {}", e))?;
+/// Repair step 1 (helper): split this helper's contribution to a lost share into deltas
+///
+/// Any `min_signers` participants can act as helpers. Each delta goes privately
+/// to its recipient; helpers never learn each other's shares.
+///
+/// # Arguments
+/// * `helpers` - Helper identifiers including this one
+/// * `key_package` - This helper's key package
+/// * `participant` - Identifier of the participant whose share is being repaired
+///
+/// # Returns
+/// The deltas, keyed by the helper each goes to, or FrostError
+pub fn repair_share_step_1(
+    helpers: &[redpallas::Identifier],
+    key_package: &KeyPackage,
+    participant: redpallas::Identifier,
+) -> Result<BTreeMap<redpallas::Identifier, Scalar>, FrostError> {
+    repair::repair_step_1::<PallasBlake2b512>(helpers, key_package, participant)
+}
 
-    Ok(Round1Result {
-        commitment: CommitmentInfo {
-            identifier: id_num,
-            commitment: commitments_json,
-        },
-        nonces: NoncesInfo {
-            identifier: id_num,
-            nonces: nonces_json,
-        },
-    })
+/// Repair step 2 (helper): combine the deltas received from every helper into a sigma
+///
+/// # Arguments
+/// * `deltas` - Deltas addressed to this helper, one from each helper
+///
+/// # Returns
+/// This helper's sigma
+pub fn repair_share_step_2(deltas: &[Scalar]) -> Scalar {
+    repair::repair_step_2::<PallasBlake2b512>(deltas)
+}
+
+/// Repair step 3 (repaired participant): rebuild the lost key package from the helpers' sigmas
+///
+/// The result is checked against the participant's verifying share in the
+/// public key package, so the repaired key keeps the Orchard-compatible group key.
+///
+/// # Arguments
+/// * `sigmas` - One sigma from each helper
+/// * `identifier` - This participant's identifier
+/// * `public_key_package` - Group public key package
+/// * `min_signers` - Minimum signers required (t)
+///
+/// # Returns
+/// KeyPackage or FrostError
+pub fn repair_share_step_3(
+    sigmas: &[Scalar],
+    identifier: redpallas::Identifier,
+    public_key_package: &PublicKeyPackage,
+    min_signers: u16,
+) -> Result<KeyPackage, FrostError> {
+    repair::repair_step_3::<PallasBlake2b512>(sigmas, identifier, public_key_package, min_signers)
 }
 
 // =============================================================================
-// Signing Package Creation (with Randomizer)
+// Resharing
 // =============================================================================
 
-/// Create a signing package with randomizer for rerandomized FROST
+/// Reshare part 1 (current holder): deal this holder's weighted share to a new participant set
 ///
-/// This should be called by the coordinator after collecting all commitments.
-/// The randomizer is generated from the signing package and must be distributed
-/// to all signers via a secure channel.
+/// At least `min_signers` current holders act as dealers, all using the same
+/// dealer set. The group key, and so the Orchard address, is unchanged.
+/// Old key shares stay valid under the old PublicKeyPackage, so a removed
+/// participant is only cut off once the remaining holders delete theirs.
 ///
 /// # Arguments
-/// * `commitments_json` - All participants' commitments (JSON array)
-/// * `message_hex` - Message to sign (hex-encoded)
-/// * `public_key_package_json` - Public key package (JSON)
+/// * `key_package` - This dealer's current key package
+/// * `dealers` - Dealer identifiers including this one
+/// * `new_identifiers` - New participant identifiers
+/// * `new_min_signers` - New threshold (t')
 ///
 /// # Returns
-/// JSON string containing SigningPackageResult or FrostError
-#[wasm_bindgen]
-pub fn create_signing_package(
-    commitments_json: &str,
-    message_hex: &str,
-    public_key_package_json: &str,
-) -> String {
-    match create_signing_package_internal(commitments_json, message_hex, public_key_package_json) {
-        Ok(result) => FrostResult::Ok(result).to_json(),
-        Err(e) => FrostResult::<SigningPackageResult>::Err(FrostError {
-            code: "SIGNING_PACKAGE_ERROR".into(),
-            message: e,
-        })
-        .to_json(),
+/// This dealer's VSS commitment and one sub-share per new participant, or FrostError
+pub fn reshare_part1(
+    key_package: &KeyPackage,
+    dealers: &[redpallas::Identifier],
+    new_identifiers: &[redpallas::Identifier],
+    new_min_signers: u16,
+) -> Result<DealtSubShares, FrostError> {
+    reshare::deal_sub_shares::<PallasBlake2b512>(
+        key_package,
+        dealers,
+        new_identifiers,
+        new_min_signers,
+    )
+}
+
+/// Reshare part 2 (new participant): combine the dealers' sub-shares into a new key package
+///
+/// Each dealer's VSS commitment must cover exactly `new_identifiers` with
+/// threshold `new_min_signers` and match its verifying share in the old public
+/// key package; each sub-share is verified against its dealer's commitment,
+/// and the new group key must equal the old one.
+///
+/// # Arguments
+/// * `identifier` - This new participant's identifier
+/// * `sub_shares` - Sub-shares addressed to this participant, keyed by dealer
+/// * `commitments` - Every dealer's VSS commitment, keyed by dealer
+/// * `public_key_package` - The old public key package
+/// * `new_identifiers` - New participant identifiers, as passed to part 1
+/// * `new_min_signers` - New threshold (t'), as passed to part 1
+///
+/// # Returns
+/// The new KeyPackage and PublicKeyPackage, or FrostError
+pub fn reshare_part2(
+    identifier: redpallas::Identifier,
+    sub_shares: &BTreeMap<redpallas::Identifier, redpallas::keys::SecretShare>,
+    commitments: &BTreeMap<
+        redpallas::Identifier,
+        redpallas::keys::VerifiableSecretSharingCommitment,
+    >,
+    public_key_package: &PublicKeyPackage,
+    new_identifiers: &[redpallas::Identifier],
+    new_min_signers: u16,
+) -> Result<(KeyPackage, PublicKeyPackage), FrostError> {
+    reshare::combine_sub_shares::<PallasBlake2b512>(
+        identifier,
+        sub_shares,
+        commitments,
+        public_key_package,
+        new_identifiers,
+        new_min_signers,
+    )
+}
+
+// =============================================================================
+// Orchard Key Compatibility
+// =============================================================================
+
+/// Validate that a public key package can act as an Orchard spend authority
+///
+/// Orchard requires the spend validating key `ak` to be encoded with ỹ = 0
+/// (sign bit of the last byte cleared). Keys produced by `generate_key_shares`
+/// and `dkg_part3` always satisfy this.
+///
+/// # Arguments
+/// * `public_key_package` - Public key package
+///
+/// # Returns
+/// `()` or FrostError
+pub fn validate_orchard_public_key_package(
+    public_key_package: &PublicKeyPackage,
+) -> Result<(), FrostError> {
+    validate_orchard_public_key_package_internal(public_key_package)
+        .map_err(|e| FrostError::new("ORCHARD_KEY_ERROR", e))
+}
+
+fn validate_orchard_public_key_package_internal(
+    pubkey_package: &PublicKeyPackage,
+) -> Result<(), String> {
+    if !is_orchard_compatible(pubkey_package)? {
+        return Err("Group public key is not a valid Orchard ak (ỹ must be 0)".into());
     }
+
+    Ok(())
+}
+
+/// Check the sign bit of the group verifying key encoding
+fn is_orchard_compatible(pubkey_package: &PublicKeyPackage) -> Result<bool, String> {
+    let bytes = pubkey_package
+        .verifying_key()
+        .serialize()
+        .map_err(|e| format!("Failed to serialize group public key: {:?}", e))?;
+    Ok(bytes[31] & 0x80 == 0)
 }
 
-fn create_signing_package_internal(
-    commitments_json: &str,
-    message_hex: &str,
-    public_key_package_json: &str,
-) -> Result<SigningPackageResult, String> {
-    let mut rng = OsRng;
-
-    // Parse commitments
-    let commitments_list: Vec<CommitmentInfo> = serde_json::from_str(commitments_json)
-        .map_err(|e| format!("Invalid commitments JSON: {}", e))?;
-
-    // Parse message
-    let message = hex::decode(message_hex).map_err(|e| format!("Invalid message hex: {}", e))?;
-
-    // Parse public key package
-    let pubkey_package: PublicKeyPackage = serde_json::from_str(public_key_package_json)
-        .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
-
-    // Build commitments map
-    let mut commitments_map: BTreeMap<Identifier, SigningCommitments> = BTreeMap::new();
-    for c in commitments_list {
-        let id = Identifier::try_from(c.identifier)
-            .map_err(|_| format!("Invalid identifier: {}", c.identifier))?;
-        let commitment: SigningCommitments = serde_json::from_str(&c.commitment)
-            .map_err(|e| format!("Invalid commitment JSON: {}", e))?;
-        commitments_map.insert(id, commitment);
+// =============================================================================
+// Orchard Viewing Keys and Addresses
+// =============================================================================
+
+/// Use a 32-byte value, or generate one if none is given
+fn parse_or_generate<F: PrimeField<Repr = [u8; 32]>>(
+    value: Option<&[u8; 32]>,
+    name: &str,
+) -> Result<F, String> {
+    match value {
+        None => Ok(F::random(&mut OsRng)),
+        Some(bytes) => Option::<F>::from(F::from_repr(*bytes))
+            .ok_or_else(|| format!("{} is not canonical", name)),
     }
+}
 
-    // Create signing package
-    let signing_package = SigningPackage::new(commitments_map, &message);
+/// Derive an Orchard full viewing key for a FROST group
+///
+/// The group verifying key becomes the spend validating key `ak`; `nk` and
+/// `rivk` are generated unless supplied. Every participant that needs to view
+/// incoming funds must use the same `nk`/`rivk`, so the coordinator should
+/// generate them once and distribute the result.
+///
+/// # Arguments
+/// * `public_key_package` - Public key package, must be Orchard compatible
+/// * `nk` - Nullifier deriving key, or `None` to generate
+/// * `rivk` - Commit-ivk randomness, or `None` to generate
+///
+/// # Returns
+/// FullViewingKey or FrostError
+pub fn derive_orchard_viewing_key(
+    public_key_package: &PublicKeyPackage,
+    nk: Option<&[u8; 32]>,
+    rivk: Option<&[u8; 32]>,
+) -> Result<FullViewingKey, FrostError> {
+    derive_orchard_viewing_key_internal(public_key_package, nk, rivk)
+        .map_err(|e| FrostError::new("ORCHARD_KEY_ERROR", e))
+}
 
-    // Generate randomized params (includes randomizer)
-    let randomized_params =
-        RandomizedParams::new(pubkey_package.verifying_key(), &signing_package, &mut rng)
-            .map_err(|e| format!("Failed to create randomized params: {:?}", e))?;
+fn derive_orchard_viewing_key_internal(
+    pubkey_package: &PublicKeyPackage,
+    nk: Option<&[u8; 32]>,
+    rivk: Option<&[u8; 32]>,
+) -> Result<FullViewingKey, String> {
+    validate_orchard_public_key_package_internal(pubkey_package)?;
 
-    // Serialize signing package
-    let signing_package_json = serde_json::to_string(&signing_package)
-        .map_err(|e| format!("Serialize signing package error: {}", e))?;
+    let ak = pubkey_package
+        .verifying_key()
+        .serialize()
+        .map_err(|e| format!("Failed to serialize group public key: {:?}", e))?;
+    let nk: pallas::Base = parse_or_generate(nk, "nk")?;
+    let rivk: pallas::Scalar = parse_or_generate(rivk, "rivk")?;
 
-    // Serialize randomizer
-    let randomizer = randomized_params.randomizer();
-    let randomizer_json = serde_json::to_string(randomizer)
-        .map_err(|e| format!("Serialize randomizer error: {}", e))?;
+    // FullViewingKey encoding is ak || nk || rivk
+    let mut fvk_bytes = [0u8; 96];
+    fvk_bytes[..32].copy_from_slice(&ak);
+    fvk_bytes[32..64].copy_from_slice(&nk.to_repr());
+    fvk_bytes[64..].copy_from_slice(&rivk.to_repr());
 
-    Ok(SigningPackageResult {
-        signing_package: signing_package_json,
-        randomizer: randomizer_json,
-    })
+    FullViewingKey::from_bytes(&fvk_bytes)
+        .ok_or_else(|| "Invalid Orchard full viewing key components".into())
+}
+
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
+
+/// Generate Round 1 commitment for signing
+///
+/// # Arguments
+/// * `key_package` - Participant's key package
+///
+/// # Returns
+/// The nonces to keep secret and the commitments to publish
+pub fn generate_round1_commitment(
+    key_package: &KeyPackage,
+) -> (
+    redpallas::round1::SigningNonces,
+    redpallas::round1::SigningCommitments,
+) {
+    signing::round1_commit::<PallasBlake2b512>(key_package)
+}
+
+// =============================================================================
+// Signing Package Creation (with Randomizer)
+// =============================================================================
+
+/// Create a signing package with randomizer for rerandomized FROST
+///
+/// This should be called by the coordinator after collecting all commitments.
+/// The randomizer seed is generated from the signing package and must be
+/// distributed to all signers via a secure channel; each signer derives the
+/// randomizer from it.
+///
+/// # Arguments
+/// * `commitments` - All participants' commitments
+/// * `message` - Message to sign
+/// * `public_key_package` - Public key package
+///
+/// # Returns
+/// SigningPackage and randomizer seed, or FrostError
+pub fn create_signing_package(
+    commitments: SigningCommitmentsMap,
+    message: &[u8],
+    public_key_package: &PublicKeyPackage,
+) -> Result<RandomizedSigningPackage, FrostError> {
+    let signing_package = redpallas::SigningPackage::new(commitments, message);
+    let randomizer =
+        rerandomized::randomizer::<PallasBlake2b512>(&signing_package, public_key_package)?;
+
+    Ok((signing_package, randomizer))
+}
+
+/// Create a signing package using a randomizer chosen by the transaction builder
+///
+/// Orchard spends commit to the randomized key `rk = ak + [alpha] G` before
+/// signing, so the coordinator must reuse that `alpha` instead of drawing a
+/// fresh one. If `rk` is given, the randomized group key is checked against it.
+///
+/// # Arguments
+/// * `commitments` - All participants' commitments
+/// * `message` - Message to sign, e.g. the transaction sighash
+/// * `public_key_package` - Public key package
+/// * `randomizer` - Spend authorization randomizer alpha
+/// * `rk` - Expected randomized verifying key, or `None` to skip the check
+///
+/// # Returns
+/// SigningPackage and Randomizer, or FrostError
+pub fn create_signing_package_with_randomizer(
+    commitments: SigningCommitmentsMap,
+    message: &[u8],
+    public_key_package: &PublicKeyPackage,
+    randomizer: redpallas::rerandomized::Randomizer,
+    rk: Option<&[u8]>,
+) -> Result<RandomizedSigningPackage, FrostError> {
+    if let Some(rk) = rk {
+        rerandomized::check_rk::<PallasBlake2b512>(public_key_package, randomizer, rk)?;
+    }
+
+    Ok((
+        redpallas::SigningPackage::new(commitments, message),
+        SigningRandomizer::Randomizer(randomizer),
+    ))
 }
 
 // =============================================================================
@@ -397,322 +862,623 @@ fn create_signing_package_internal(
 
 /// Generate Round 2 signature share using rerandomization
 ///
+/// Fails with NONCE_REUSED if the nonces already produced a signature share.
+///
 /// # Arguments
-/// * `key_package_json` - Participant's key package (JSON)
-/// * `nonces_json` - Participant's nonces from Round 1 (JSON)
-/// * `signing_package_json` - Signing package from coordinator (JSON)
-/// * `randomizer_json` - Randomizer from coordinator (JSON)
+/// * `signing_package` - Signing package from coordinator
+/// * `nonces` - Participant's nonces from Round 1
+/// * `key_package` - Participant's key package
+/// * `randomizer` - Randomizer or randomizer seed from coordinator
 ///
 /// # Returns
-/// JSON string containing SignatureShareInfo or FrostError
-#[wasm_bindgen]
+/// SignatureShare or FrostError
 pub fn generate_round2_signature(
-    key_package_json: &str,
-    nonces_json: &str,
-    signing_package_json: &str,
-    randomizer_json: &str,
-) -> String {
-    match generate_round2_internal(
-        key_package_json,
-        nonces_json,
-        signing_package_json,
-        randomizer_json,
-    ) {
-        Ok(result) => FrostResult::Ok(result).to_json(),
-        Err(e) => FrostResult::<SignatureShareInfo>::Err(FrostError {
-            code: "ROUND2_ERROR".into(),
-            message: e,
-        })
-        .to_json(),
-    }
+    signing_package: &redpallas::SigningPackage,
+    nonces: &redpallas::round1::SigningNonces,
+    key_package: &KeyPackage,
+    randomizer: &SigningRandomizer,
+) -> Result<redpallas::round2::SignatureShare, FrostError> {
+    rerandomized::round2_sign::<PallasBlake2b512>(signing_package, nonces, key_package, randomizer)
 }
 
-fn generate_round2_internal(
-    key_package_json: &str,
-    nonces_json: &str,
-    signing_package_json: &str,
-    randomizer_json: &str,
-) -> Result<SignatureShareInfo, String> {
-    // Parse inputs
-    let key_package: KeyPackage = serde_json::from_str(key_package_json)
-        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+// =============================================================================
+// Commitment Pools (Preprocessing)
+// =============================================================================
 
-    let nonces_info: NoncesInfo =
-        serde_json::from_str(nonces_json).map_err(|e| format!("Invalid nonces JSON: {}", e))?;
+/// Preprocess a batch of Round 1 nonces
+///
+/// The signer publishes the commitments ahead of time, so signing later
+/// needs only a single round.
+///
+/// # Arguments
+/// * `key_package` - Participant's key package
+/// * `count` - Number of nonces to generate
+///
+/// # Returns
+/// The nonce pool or FrostError
+pub fn generate_commitment_pool(
+    key_package: &KeyPackage,
+    count: u32,
+) -> Result<Vec<redpallas::round1::SigningNonces>, FrostError> {
+    pool::preprocess::<PallasBlake2b512>(key_package, count)
+}
 
-    let nonces: SigningNonces = serde_json::from_str(&nonces_info.nonces)
-        .map_err(|e| format!("Invalid inner nonces JSON: {}", e))?;
+/// Take the oldest unused commitment from each signer's pool (coordinator)
+///
+/// # Arguments
+/// * `pools` - All signers' published commitment pools, keyed by signer
+/// * `signers` - Participant identifiers taking part
+///
+/// # Returns
+/// The selected commitments or FrostError
+pub fn select_pool_commitments(
+    pools: &mut BTreeMap<redpallas::Identifier, Vec<redpallas::round1::SigningCommitments>>,
+    signers: &[redpallas::Identifier],
+) -> Result<SigningCommitmentsMap, FrostError> {
+    pool::select_commitments::<PallasBlake2b512>(pools, signers)
+}
 
-    let signing_package: SigningPackage = serde_json::from_str(signing_package_json)
-        .map_err(|e| format!("Invalid signing package JSON: {}", e))?;
+/// Generate Round 2 rerandomized signature share using the pooled nonces the
+/// coordinator selected, removing them from the pool even if signing fails
+///
+/// # Arguments
+/// * `nonce_pool` - Participant's nonce pool
+/// * `signing_package` - Signing package from coordinator
+/// * `key_package` - Participant's key package
+/// * `randomizer` - Randomizer or randomizer seed from coordinator
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_from_pool(
+    nonce_pool: &mut Vec<redpallas::round1::SigningNonces>,
+    signing_package: &redpallas::SigningPackage,
+    key_package: &KeyPackage,
+    randomizer: &SigningRandomizer,
+) -> Result<redpallas::round2::SignatureShare, FrostError> {
+    rerandomized::sign_from_pool::<PallasBlake2b512>(
+        nonce_pool,
+        signing_package,
+        key_package,
+        randomizer,
+    )
+}
 
-    let randomizer: reddsa::frost::redpallas::Randomizer = serde_json::from_str(randomizer_json)
-        .map_err(|e| format!("Invalid randomizer JSON: {}", e))?;
+// =============================================================================
+// Nonce Registry
+// =============================================================================
 
-    // Generate signature share with rerandomization
-    let signature_share = round2::sign(&signing_package, &nonces, &key_package, randomizer)
-        .map_err(|e| format!("Signing failed: {:?}", e))?;
+/// Export the nonce registry so it can be persisted across page reloads
+///
+/// The export is not authenticated, so whoever stores it can drop entries;
+/// `export_sealed_nonce_registry` prevents that.
+///
+/// # Returns
+/// NonceRegistryState
+pub fn export_nonce_registry() -> NonceRegistryState {
+    nonces::export_nonce_registry()
+}
 
-    // Serialize signature share
-    let share_json = serde_json::to_string(&signature_share)
-        .map_err(|e| format!("Serialize share error: {}", e))?;
+/// Merge a previously exported registry into the current one
+///
+/// Entries are only ever added, so importing stale state cannot make used
+/// nonces usable again.
+///
+/// # Arguments
+/// * `state` - NonceRegistryState from `export_nonce_registry`
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_nonce_registry(state: NonceRegistryState) -> Result<NonceRegistryState, FrostError> {
+    nonces::merge_nonce_registry(state)
+}
 
-    // Get identifier
-    let id = *key_package.identifier();
-    let id_bytes = id.serialize();
-    let id_num = u16::from_le_bytes([id_bytes[0], id_bytes[1]]);
+/// Export the nonce registry sealed under a key package in the keystore
+///
+/// The entries are encrypted under a key derived from the signing share, so
+/// they cannot be dropped without it. Each export carries a higher counter
+/// than the last one exported or imported for the handle, so after a reload
+/// import the stored export before exporting again.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+///
+/// # Returns
+/// SealedNonceRegistry or FrostError
+pub fn export_sealed_nonce_registry(key_handle: u32) -> Result<SealedNonceRegistry, FrostError> {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().export_nonce_registry(key_handle))
+}
 
-    Ok(SignatureShareInfo {
-        identifier: id_num,
-        share: share_json,
+/// Merge a sealed registry into the current one after decrypting it and
+/// checking it is not older than `min_counter`
+///
+/// Counters are forgotten on reload, so store the `counter` of each export
+/// where the export itself cannot roll it back and pass it here; otherwise a
+/// stale export is accepted after a reload.
+///
+/// # Arguments
+/// * `key_handle` - Handle to the key package that sealed it
+/// * `sealed` - SealedNonceRegistry from `export_sealed_nonce_registry`
+/// * `min_counter` - Counter of the latest export stored, or 0
+///
+/// # Returns
+/// The merged NonceRegistryState or FrostError
+pub fn import_sealed_nonce_registry(
+    key_handle: u32,
+    sealed: SealedNonceRegistry,
+    min_counter: u64,
+) -> Result<NonceRegistryState, FrostError> {
+    KEYSTORE.with(|keystore| {
+        keystore
+            .borrow_mut()
+            .import_nonce_registry(key_handle, sealed, min_counter)
     })
 }
 
 // =============================================================================
-// Signature Aggregation (Rerandomized)
+// Keystore: Opaque Key and Nonce Handles
 // =============================================================================
 
-/// Aggregate signature shares into final signature
+thread_local! {
+    pub(crate) static KEYSTORE: RefCell<Keystore<PallasBlake2b512>> = RefCell::new(Keystore::default());
+}
+
+/// Load a key package into the keystore and return an opaque handle
 ///
 /// # Arguments
-/// * `shares_json` - All signature shares (JSON array)
-/// * `signing_package_json` - Signing package (JSON)
-/// * `public_key_package_json` - Public key package (JSON)
-/// * `randomizer_json` - Randomizer used for signing (JSON)
+/// * `key_package` - Participant's key package
 ///
 /// # Returns
-/// JSON string containing AggregateResult or FrostError
-#[wasm_bindgen]
-pub fn aggregate_signature(
-    shares_json: &str,
-    signing_package_json: &str,
-    public_key_package_json: &str,
-    randomizer_json: &str,
-) -> String {
-    match aggregate_internal(
-        shares_json,
-        signing_package_json,
-        public_key_package_json,
-        randomizer_json,
-    ) {
-        Ok(result) => FrostResult::Ok(result).to_json(),
-        Err(e) => FrostResult::<AggregateResult>::Err(FrostError {
-            code: "AGGREGATE_ERROR".into(),
-            message: e,
-        })
-        .to_json(),
-    }
+/// The key handle
+pub fn load_key_package(key_package: KeyPackage) -> u32 {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().load_key_package(key_package))
 }
 
-fn aggregate_internal(
-    shares_json: &str,
-    signing_package_json: &str,
-    public_key_package_json: &str,
-    randomizer_json: &str,
-) -> Result<AggregateResult, String> {
-    // Parse inputs
-    let shares_list: Vec<SignatureShareInfo> =
-        serde_json::from_str(shares_json).map_err(|e| format!("Invalid shares JSON: {}", e))?;
-
-    let signing_package: SigningPackage = serde_json::from_str(signing_package_json)
-        .map_err(|e| format!("Invalid signing package JSON: {}", e))?;
-
-    let pubkey_package: PublicKeyPackage = serde_json::from_str(public_key_package_json)
-        .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
-
-    let randomizer: reddsa::frost::redpallas::Randomizer = serde_json::from_str(randomizer_json)
-        .map_err(|e| format!("Invalid randomizer JSON: {}", e))?;
-
-    // Build signature shares map
-    let mut shares_map: BTreeMap<Identifier, SignatureShare> = BTreeMap::new();
-    for s in shares_list {
-        let id = Identifier::try_from(s.identifier)
-            .map_err(|_| format!("Invalid identifier: {}", s.identifier))?;
-        let share: SignatureShare =
-            serde_json::from_str(&s.share).map_err(|e| format!("Invalid share JSON: {}", e))?;
-        shares_map.insert(id, share);
-    }
+/// Generate Round 1 commitment, keeping the nonces in the keystore
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+///
+/// # Returns
+/// The nonce handle and the commitments to publish, or FrostError
+pub fn generate_round1_commitment_with_handle(
+    key_handle: u32,
+) -> Result<(u32, redpallas::round1::SigningCommitments), FrostError> {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().round1_commit(key_handle))
+}
 
-    // Create randomized params for aggregation
-    let randomized_params =
-        RandomizedParams::from_randomizer(pubkey_package.verifying_key(), randomizer);
+/// Generate Round 2 rerandomized signature share from keystore handles
+///
+/// The nonce handle is consumed and its nonces zeroized, so it cannot be
+/// used for a second signature.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+/// * `nonce_handle` - Handle from `generate_round1_commitment_with_handle`
+/// * `signing_package` - Signing package from coordinator
+/// * `randomizer` - Randomizer or randomizer seed from coordinator
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    signing_package: &redpallas::SigningPackage,
+    randomizer: &SigningRandomizer,
+) -> Result<redpallas::round2::SignatureShare, FrostError> {
+    KEYSTORE.with(|keystore| {
+        rerandomized::sign_with_handles(
+            &mut keystore.borrow_mut(),
+            key_handle,
+            nonce_handle,
+            signing_package,
+            randomizer,
+        )
+    })
+}
 
-    // Aggregate signature
-    let signature =
-        redpallas::aggregate(&signing_package, &shares_map, &pubkey_package, &randomized_params)
-            .map_err(|e| format!("Aggregation failed: {:?}", e))?;
+/// Destroy a key or nonce handle, zeroizing the secret it refers to
+///
+/// # Returns
+/// `true` if the handle existed
+pub fn destroy(handle: u32) -> bool {
+    KEYSTORE.with(|keystore| keystore.borrow_mut().destroy(handle))
+}
 
-    // Serialize signature
-    let sig_bytes = signature
-        .serialize()
-        .map_err(|e| format!("Failed to serialize signature: {:?}", e))?;
-    let sig_hex = hex::encode(sig_bytes);
+// =============================================================================
+// Signature Aggregation (Rerandomized)
+// =============================================================================
 
-    // Return the randomizer for verification
-    let randomizer_json =
-        serde_json::to_string(&randomizer).map_err(|e| format!("Serialize error: {}", e))?;
+/// Aggregate signature shares into final signature
+///
+/// If a signature share is invalid, the error is an AggregateError whose
+/// `culprits` lists the participants who sent bad shares.
+///
+/// # Arguments
+/// * `signing_package` - Signing package
+/// * `shares` - Signature shares, keyed by signer
+/// * `public_key_package` - Public key package
+/// * `randomizer` - Randomizer used for signing
+///
+/// # Returns
+/// Signature or AggregateError
+pub fn aggregate_signature(
+    signing_package: &redpallas::SigningPackage,
+    shares: &SignatureShares,
+    public_key_package: &PublicKeyPackage,
+    randomizer: &SigningRandomizer,
+) -> Result<redpallas::Signature, AggregateError> {
+    rerandomized::aggregate::<PallasBlake2b512>(
+        signing_package,
+        shares,
+        public_key_package,
+        randomizer,
+    )
+}
 
-    Ok(AggregateResult {
-        signature: sig_hex,
-        randomizer: randomizer_json,
-    })
+/// Verify each signature share against the signer's verifying share
+///
+/// Lets the coordinator find misbehaving participants without attempting aggregation.
+///
+/// # Arguments
+/// * `signing_package` - Signing package
+/// * `shares` - Signature shares, keyed by signer
+/// * `public_key_package` - Public key package
+/// * `randomizer` - Randomizer used for signing
+///
+/// # Returns
+/// ShareVerificationResult or FrostError
+pub fn verify_signature_shares(
+    signing_package: &redpallas::SigningPackage,
+    shares: &SignatureShares,
+    public_key_package: &PublicKeyPackage,
+    randomizer: &SigningRandomizer,
+) -> Result<ShareVerificationResult, FrostError> {
+    rerandomized::verify_shares::<PallasBlake2b512>(
+        signing_package,
+        shares,
+        public_key_package,
+        randomizer,
+    )
 }
 
 // =============================================================================
-// Signature Verification
+// Multi-Action Signing
 // =============================================================================
 
-/// Verify a rerandomized signature
+/// Maximum actions a signer can commit to in one batch
+pub const MAX_ACTION_COUNT: u32 = 1000;
+
+/// Generate Round 1 commitments for several actions at once
+///
+/// A transaction spending several Orchard notes needs one spend authorization
+/// signature per action, so each signer commits once per action.
 ///
 /// # Arguments
-/// * `signature_hex` - Signature to verify (hex-encoded)
-/// * `message_hex` - Message that was signed (hex-encoded)
-/// * `group_public_key_hex` - Group verifying key (hex-encoded)
-/// * `randomizer_json` - Randomizer used for signing (JSON)
+/// * `key_package` - Participant's key package
+/// * `action_count` - Number of actions to sign
 ///
 /// # Returns
-/// JSON string containing verification result or FrostError
-#[wasm_bindgen]
-pub fn verify_signature(
-    signature_hex: &str,
-    message_hex: &str,
-    group_public_key_hex: &str,
-    randomizer_json: &str,
-) -> String {
-    match verify_internal(signature_hex, message_hex, group_public_key_hex, randomizer_json) {
-        Ok(valid) => serde_json::to_string(&VerifyResult { valid }).unwrap(),
-        Err(e) => FrostResult::<VerifyResult>::Err(FrostError {
-            code: "VERIFY_ERROR".into(),
-            message: e,
-        })
-        .to_json(),
-    }
+/// One set of nonces and commitments per action, or FrostError
+pub fn generate_round1_commitments(
+    key_package: &KeyPackage,
+    action_count: u32,
+) -> Result<
+    Vec<(
+        redpallas::round1::SigningNonces,
+        redpallas::round1::SigningCommitments,
+    )>,
+    FrostError,
+> {
+    generate_round1_batch_internal(key_package, action_count)
+        .map_err(|e| FrostError::new("ROUND1_ERROR", e))
 }
 
-#[derive(Serialize)]
-struct VerifyResult {
-    valid: bool,
-}
-
-fn verify_internal(
-    signature_hex: &str,
-    message_hex: &str,
-    group_public_key_hex: &str,
-    randomizer_json: &str,
-) -> Result<bool, String> {
-    // Parse signature
-    let sig_bytes =
-        hex::decode(signature_hex).map_err(|e| format!("Invalid signature hex: {}", e))?;
-    let sig_array: [u8; 64] = sig_bytes
-        .try_into()
-        .map_err(|_| "Signature must be 64 bytes")?;
-    let signature = Signature::deserialize(&sig_array)
-        .map_err(|e| format!("Invalid signature: {:?}", e))?;
-
-    // Parse message
-    let message = hex::decode(message_hex).map_err(|e| format!("Invalid message hex: {}", e))?;
-
-    // Parse group public key
-    let pubkey_bytes =
-        hex::decode(group_public_key_hex).map_err(|e| format!("Invalid public key hex: {}", e))?;
-    let pubkey_array: [u8; 32] = pubkey_bytes
-        .try_into()
-        .map_err(|_| "Public key must be 32 bytes")?;
-    let verifying_key = redpallas::VerifyingKey::deserialize(&pubkey_array)
-        .map_err(|e| format!("Invalid verifying key: {:?}", e))?;
-
-    // Parse randomizer
-    let randomizer: reddsa::frost::redpallas::Randomizer = serde_json::from_str(randomizer_json)
-        .map_err(|e| format!("Invalid randomizer JSON: {}", e))?;
-
-    // Create randomized params and get randomized public key
-    let randomized_params = RandomizedParams::from_randomizer(&verifying_key, randomizer);
-    let randomized_verifying_key = randomized_params.randomized_verifying_key();
-
-    // Verify signature against randomized public key
-    match randomized_verifying_key.verify(&message, &signature) {
-        Ok(()) => Ok(true),
-        Err(_) => Ok(false),
+fn generate_round1_batch_internal(
+    key_package: &KeyPackage,
+    action_count: u32,
+) -> Result<
+    Vec<(
+        redpallas::round1::SigningNonces,
+        redpallas::round1::SigningCommitments,
+    )>,
+    String,
+> {
+    if action_count == 0 || action_count > MAX_ACTION_COUNT {
+        return Err(format!(
+            "Action count must be between 1 and {}",
+            MAX_ACTION_COUNT
+        ));
     }
-}
 
-// =============================================================================
-// Utility Functions
-// =============================================================================
+    Ok((0..action_count)
+        .map(|_| generate_round1_commitment(key_package))
+        .collect())
+}
 
-/// Get the public key from a key package
+/// Generate Round 1 commitments for several actions, keeping the nonces in the keystore
 ///
 /// # Arguments
-/// * `key_package_json` - Key package (JSON)
+/// * `key_handle` - Handle from `load_key_package`
+/// * `action_count` - Number of actions to sign
 ///
 /// # Returns
-/// JSON string with public key (hex) or FrostError
-#[wasm_bindgen]
-pub fn get_public_key(key_package_json: &str) -> String {
-    match get_public_key_internal(key_package_json) {
-        Ok(result) => serde_json::to_string(&result).unwrap(),
-        Err(e) => FrostResult::<PublicKeyResult>::Err(FrostError {
-            code: "PUBKEY_ERROR".into(),
-            message: e,
-        })
-        .to_json(),
+/// One nonce handle and commitments per action, or FrostError
+pub fn generate_round1_commitments_with_handle(
+    key_handle: u32,
+    action_count: u32,
+) -> Result<Vec<(u32, redpallas::round1::SigningCommitments)>, FrostError> {
+    if action_count == 0 || action_count > MAX_ACTION_COUNT {
+        return Err(FrostError::new(
+            "ROUND1_ERROR",
+            format!("Action count must be between 1 and {}", MAX_ACTION_COUNT),
+        ));
     }
+
+    KEYSTORE.with(|keystore| {
+        let mut keystore = keystore.borrow_mut();
+        let mut round1 = Vec::with_capacity(action_count as usize);
+        for _ in 0..action_count {
+            match keystore.round1_commit(key_handle) {
+                Ok(commitment) => round1.push(commitment),
+                Err(e) => {
+                    // Don't leave the nonces of the earlier actions behind
+                    for (nonce_handle, _) in round1 {
+                        keystore.destroy(nonce_handle);
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        Ok(round1)
+    })
 }
 
-#[derive(Serialize)]
-struct PublicKeyResult {
-    public_key: String,
-    identifier: u16,
+/// Create one signing package per action from batched commitments
+///
+/// # Arguments
+/// * `commitments` - Every participant's commitments, one per action, keyed by signer
+/// * `actions` - Actions to sign
+/// * `public_key_package` - Public key package
+///
+/// # Returns
+/// One SigningPackage and Randomizer per action, or FrostError
+pub fn create_batch_signing_package(
+    commitments: &BTreeMap<redpallas::Identifier, Vec<redpallas::round1::SigningCommitments>>,
+    actions: &[BatchAction],
+    public_key_package: &PublicKeyPackage,
+) -> Result<Vec<RandomizedSigningPackage>, FrostError> {
+    create_batch_signing_package_internal(commitments, actions, public_key_package)
+        .map_err(|e| FrostError::new("SIGNING_PACKAGE_ERROR", e))
 }
 
-fn get_public_key_internal(key_package_json: &str) -> Result<PublicKeyResult, String> {
-    let key_package: KeyPackage = serde_json::from_str(key_package_json)
-        .map_err(|e| format!("Invalid key package JSON: {}", e))?;
+fn create_batch_signing_package_internal(
+    commitments: &BTreeMap<redpallas::Identifier, Vec<redpallas::round1::SigningCommitments>>,
+    actions: &[BatchAction],
+    pubkey_package: &PublicKeyPackage,
+) -> Result<Vec<RandomizedSigningPackage>, String> {
+    if actions.is_empty() {
+        return Err("At least one action is required".into());
+    }
 
-    let verifying_share = key_package.verifying_share();
-    let pubkey_bytes = verifying_share
-        .serialize()
-        .map_err(|e| format!("Failed to serialize verifying share: {:?}", e))?;
-    let pubkey_hex = hex::encode(pubkey_bytes);
+    for (identifier, c) in commitments {
+        if c.len() != actions.len() {
+            return Err(format!(
+                "Participant {} sent {} commitments for {} actions",
+                codec::identifier_to_u16(identifier)?,
+                c.len(),
+                actions.len()
+            ));
+        }
+    }
 
-    let id = *key_package.identifier();
-    let id_bytes = id.serialize();
-    let id_num = u16::from_le_bytes([id_bytes[0], id_bytes[1]]);
+    let mut packages = Vec::with_capacity(actions.len());
+    for (i, action) in actions.iter().enumerate() {
+        // Pick out every participant's commitment for this action
+        let action_commitments: SigningCommitmentsMap = commitments
+            .iter()
+            .map(|(identifier, c)| (*identifier, c[i]))
+            .collect();
+
+        let package = match action.randomizer {
+            None => create_signing_package(action_commitments, &action.message, pubkey_package),
+            Some(randomizer) => create_signing_package_with_randomizer(
+                action_commitments,
+                &action.message,
+                pubkey_package,
+                randomizer,
+                action.rk.as_deref(),
+            ),
+        }
+        .map_err(|e| format!("Action {}: {}", i, e.message))?;
+
+        packages.push(package);
+    }
 
-    Ok(PublicKeyResult {
-        public_key: pubkey_hex,
-        identifier: id_num,
-    })
+    Ok(packages)
 }
 
-/// Get the group public key from a public key package
+/// Generate Round 2 signature shares for every action in a batch
 ///
 /// # Arguments
-/// * `public_key_package_json` - Public key package (JSON)
+/// * `packages` - The coordinator's signing packages and randomizers, in action order
+/// * `nonces` - Participant's nonces from Round 1, in action order
+/// * `key_package` - Participant's key package
 ///
 /// # Returns
-/// Hex-encoded group public key or error
-#[wasm_bindgen]
-pub fn get_group_public_key(public_key_package_json: &str) -> String {
-    match get_group_public_key_internal(public_key_package_json) {
-        Ok(hex) => hex,
-        Err(e) => FrostResult::<String>::Err(FrostError {
-            code: "PUBKEY_ERROR".into(),
-            message: e,
-        })
-        .to_json(),
+/// One SignatureShare per action, or FrostError
+pub fn generate_round2_signatures(
+    packages: &[RandomizedSigningPackage],
+    nonces: &[redpallas::round1::SigningNonces],
+    key_package: &KeyPackage,
+) -> Result<Vec<redpallas::round2::SignatureShare>, FrostError> {
+    if nonces.len() != packages.len() {
+        return Err(FrostError::new(
+            "ROUND2_ERROR",
+            format!(
+                "Have {} nonces for {} actions",
+                nonces.len(),
+                packages.len()
+            ),
+        ));
     }
+
+    let mut shares = Vec::with_capacity(packages.len());
+    for (i, (nonces, (signing_package, randomizer))) in nonces.iter().zip(packages).enumerate() {
+        let share = generate_round2_signature(signing_package, nonces, key_package, randomizer)
+            .map_err(|e| FrostError {
+                message: format!("Action {}: {}", i, e.message),
+                ..e
+            })?;
+
+        shares.push(share);
+    }
+
+    Ok(shares)
 }
 
-fn get_group_public_key_internal(public_key_package_json: &str) -> Result<String, String> {
-    let pubkey_package: PublicKeyPackage = serde_json::from_str(public_key_package_json)
-        .map_err(|e| format!("Invalid public key package JSON: {}", e))?;
+/// Generate Round 2 signature shares for every action in a batch from keystore handles
+///
+/// Each nonce handle is consumed and its nonces zeroized as its action is
+/// signed.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `load_key_package`
+/// * `nonce_handles` - Handles from `generate_round1_commitments_with_handle`, in action order
+/// * `packages` - The coordinator's signing packages and randomizers, in action order
+///
+/// # Returns
+/// One SignatureShare per action, or FrostError
+pub fn generate_round2_signatures_with_handles(
+    key_handle: u32,
+    nonce_handles: &[u32],
+    packages: &[RandomizedSigningPackage],
+) -> Result<Vec<redpallas::round2::SignatureShare>, FrostError> {
+    if nonce_handles.len() != packages.len() {
+        return Err(FrostError::new(
+            "ROUND2_ERROR",
+            format!(
+                "Have {} nonce handles for {} actions",
+                nonce_handles.len(),
+                packages.len()
+            ),
+        ));
+    }
 
-    let verifying_key = pubkey_package.verifying_key();
-    let pubkey_bytes = verifying_key
-        .serialize()
-        .map_err(|e| format!("Failed to serialize verifying key: {:?}", e))?;
-    Ok(hex::encode(pubkey_bytes))
+    let mut shares = Vec::with_capacity(packages.len());
+    for (i, (nonce_handle, (signing_package, randomizer))) in
+        nonce_handles.iter().zip(packages).enumerate()
+    {
+        let share = generate_round2_signature_with_handles(
+            key_handle,
+            *nonce_handle,
+            signing_package,
+            randomizer,
+        )
+        .map_err(|e| FrostError {
+            message: format!("Action {}: {}", i, e.message),
+            ..e
+        })?;
+
+        shares.push(share);
+    }
+
+    Ok(shares)
+}
+
+/// Aggregate every action's signature shares in one call
+///
+/// # Arguments
+/// * `packages` - The signing packages and randomizers, in action order
+/// * `shares` - Every participant's signature shares, one per action, keyed by signer
+/// * `public_key_package` - Public key package
+///
+/// # Returns
+/// One Signature per action, or AggregateError
+pub fn aggregate_signatures(
+    packages: &[RandomizedSigningPackage],
+    shares: &BTreeMap<redpallas::Identifier, Vec<redpallas::round2::SignatureShare>>,
+    public_key_package: &PublicKeyPackage,
+) -> Result<Vec<redpallas::Signature>, AggregateError> {
+    for (identifier, s) in shares {
+        if s.len() != packages.len() {
+            return Err(format!(
+                "Participant {} sent {} shares for {} actions",
+                codec::identifier_to_u16(identifier)?,
+                s.len(),
+                packages.len()
+            )
+            .into());
+        }
+    }
+
+    let mut signatures = Vec::with_capacity(packages.len());
+    for (i, (signing_package, randomizer)) in packages.iter().enumerate() {
+        let action_shares: SignatureShares = shares
+            .iter()
+            .map(|(identifier, s)| (*identifier, s[i]))
+            .collect();
+
+        let signature = aggregate_signature(
+            signing_package,
+            &action_shares,
+            public_key_package,
+            randomizer,
+        )
+        .map_err(|e| AggregateError {
+            message: format!("Action {}: {}", i, e.message),
+            ..e
+        })?;
+
+        signatures.push(signature);
+    }
+
+    Ok(signatures)
+}
+
+// =============================================================================
+// Signature Verification
+// =============================================================================
+
+/// Verify a rerandomized signature
+///
+/// # Arguments
+/// * `signature` - Signature to verify
+/// * `message` - Message that was signed
+/// * `group_public_key` - Group verifying key
+/// * `randomizer` - Randomizer used for signing
+///
+/// # Returns
+/// `true` if the signature is valid under the randomized group key
+pub fn verify_signature(
+    signature: &redpallas::Signature,
+    message: &[u8],
+    group_public_key: &redpallas::VerifyingKey,
+    randomizer: redpallas::rerandomized::Randomizer,
+) -> bool {
+    rerandomized::verify::<PallasBlake2b512>(signature, message, group_public_key, randomizer)
+}
+
+// =============================================================================
+// Emergency Reconstruction
+// =============================================================================
+
+/// DANGER: reassemble the group signing key (the Orchard `ask`) from `min_signers` key packages
+///
+/// This defeats the point of threshold signing: whoever runs it can spend the
+/// funds alone. Only use it for disaster recovery or migrating off FROST, on an
+/// offline machine, and retire the shares afterwards.
+///
+/// # Arguments
+/// * `key_packages` - At least `min_signers` key packages
+/// * `public_key_package` - Group public key package
+///
+/// # Returns
+/// The group SigningKey or FrostError
+pub fn emergency_reconstruct_signing_key(
+    key_packages: &[KeyPackage],
+    public_key_package: &PublicKeyPackage,
+) -> Result<redpallas::SigningKey, FrostError> {
+    reconstruct::reconstruct_signing_key::<PallasBlake2b512>(key_packages, public_key_package)
 }
 
 // =============================================================================
@@ -724,92 +1490,61 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_key_generation() {
-        let result = generate_key_shares(2, 3);
-        let parsed: KeyGenResult = serde_json::from_str(&result).expect("Should parse result");
-
-        assert_eq!(parsed.threshold, 2);
-        assert_eq!(parsed.total, 3);
-        assert_eq!(parsed.shares.len(), 3);
-        assert!(!parsed.group_public_key.is_empty());
-        assert!(!parsed.public_key_package.is_empty());
-    }
+    fn test_typed_signing_ceremony() {
+        let (shares, public_key_package) =
+            generate_key_shares(2, 3).expect("Key generation failed");
+        validate_orchard_public_key_package(&public_key_package).unwrap();
+        let key_packages: Vec<KeyPackage> = shares
+            .into_values()
+            .map(|share| verify_and_accept_share(share, &public_key_package).unwrap())
+            .collect();
+        let signers: Vec<&KeyPackage> = key_packages.iter().take(2).collect();
+        let message = b"native signing";
+
+        let round1: Vec<_> = signers
+            .iter()
+            .map(|k| generate_round1_commitment(k))
+            .collect();
+        let commitments = signers
+            .iter()
+            .zip(&round1)
+            .map(|(k, (_, c))| (*k.identifier(), *c))
+            .collect();
+
+        let (signing_package, randomizer) =
+            create_signing_package(commitments, message, &public_key_package)
+                .expect("Signing package failed");
+
+        let shares: SignatureShares = signers
+            .iter()
+            .zip(&round1)
+            .map(|(k, (nonces, _))| {
+                let share = generate_round2_signature(&signing_package, nonces, k, &randomizer)
+                    .expect("Round 2 failed");
+                (*k.identifier(), share)
+            })
+            .collect();
 
-    #[test]
-    fn test_full_signing_ceremony() {
-        // Generate keys
-        let keygen_result = generate_key_shares(2, 3);
-        let keygen: KeyGenResult =
-            serde_json::from_str(&keygen_result).expect("Key generation should succeed");
-
-        // Round 1: Generate commitments for first 2 participants
-        let round1_1 = generate_round1_commitment(&keygen.shares[0].key_package);
-        let r1_1: Round1Result =
-            serde_json::from_str(&round1_1).expect("Round 1 participant 1 should succeed");
-
-        let round1_2 = generate_round1_commitment(&keygen.shares[1].key_package);
-        let r1_2: Round1Result =
-            serde_json::from_str(&round1_2).expect("Round 1 participant 2 should succeed");
-
-        // Collect commitments
-        let commitments = vec![r1_1.commitment.clone(), r1_2.commitment.clone()];
-        let commitments_json = serde_json::to_string(&commitments).unwrap();
-
-        // Message to sign
-        let message = "48656c6c6f20576f726c64"; // "Hello World" in hex
-
-        // Create signing package with randomizer
-        let signing_pkg_result =
-            create_signing_package(&commitments_json, message, &keygen.public_key_package);
-        let signing_pkg: SigningPackageResult = serde_json::from_str(&signing_pkg_result)
-            .expect("Signing package creation should succeed");
-
-        // Round 2: Generate signature shares with randomizer
-        let nonces_1 = serde_json::to_string(&r1_1.nonces).unwrap();
-        let sig_share_1 = generate_round2_signature(
-            &keygen.shares[0].key_package,
-            &nonces_1,
-            &signing_pkg.signing_package,
-            &signing_pkg.randomizer,
-        );
-        let share_1: SignatureShareInfo =
-            serde_json::from_str(&sig_share_1).expect("Round 2 participant 1 should succeed");
-
-        let nonces_2 = serde_json::to_string(&r1_2.nonces).unwrap();
-        let sig_share_2 = generate_round2_signature(
-            &keygen.shares[1].key_package,
-            &nonces_2,
-            &signing_pkg.signing_package,
-            &signing_pkg.randomizer,
-        );
-        let share_2: SignatureShareInfo =
-            serde_json::from_str(&sig_share_2).expect("Round 2 participant 2 should succeed");
-
-        // Aggregate
-        let shares = vec![share_1, share_2];
-        let shares_json = serde_json::to_string(&shares).unwrap();
-
-        let agg_result = aggregate_signature(
-            &shares_json,
-            &signing_pkg.signing_package,
-            &keygen.public_key_package,
-            &signing_pkg.randomizer,
-        );
-        let agg: AggregateResult =
-            serde_json::from_str(&agg_result).expect("Aggregation should succeed");
-
-        assert!(!agg.signature.is_empty());
-
-        // Verify
-        let verify_result = verify_signature(
-            &agg.signature,
+        let signature =
+            aggregate_signature(&signing_package, &shares, &public_key_package, &randomizer)
+                .expect("Aggregation failed");
+        assert!(verify_signature(
+            &signature,
             message,
-            &keygen.group_public_key,
-            &signing_pkg.randomizer,
-        );
-        let verify: VerifyResult =
-            serde_json::from_str(&verify_result).expect("Verification should succeed");
+            public_key_package.verifying_key(),
+            randomizer.randomizer(&signing_package).unwrap()
+        ));
 
-        assert!(verify.valid, "Signature should be valid");
+        let Err(error) =
+            generate_round2_signature(&signing_package, &round1[0].0, signers[0], &randomizer)
+        else {
+            panic!("Reused nonces should fail");
+        };
+        assert_eq!(error.code, "NONCE_REUSED");
+
+        let Err(error) = generate_key_shares(4, 3) else {
+            panic!("Threshold above the total should fail");
+        };
+        assert_eq!(error.code, "KEYGEN_ERROR");
     }
 }
@@ -0,0 +1,415 @@
+//! PCZT (partially created Zcash transaction) signing
+//!
+//! Lets a FROST group act as the Signer role for the Orchard actions of a
+//! PCZT: the coordinator lists the actions that still need a spend
+//! authorization signature, runs one FROST round per action with
+//! `create_signing_package_with_randomizer` (message = sighash,
+//! randomizer = alpha, rk = the action's rk), and writes the aggregated
+//! signatures back into the PCZT. The JSON forms, over hex-encoded PCZTs, are
+//! in the `json` module.
+
+use serde::{Deserialize, Serialize};
+
+use orchard::primitives::redpallas::{Signature as OrchardSignature, SpendAuth};
+use pasta_curves::group::ff::PrimeField;
+use pczt::roles::low_level_signer::{OrchardParseError, Signer as LowLevelSigner};
+use pczt::roles::signer::Signer;
+use pczt::Pczt;
+
+use crate::redpallas;
+use frost_common::FrostError;
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// An Orchard action that still needs a spend authorization signature
+pub struct OrchardSpendRequest {
+    /// Index of the action in the Orchard bundle
+    pub index: usize,
+    /// Spend authorization randomizer alpha
+    pub randomizer: redpallas::rerandomized::Randomizer,
+    /// Randomized verifying key rk
+    pub rk: [u8; 32],
+    /// Value of the spent note in zatoshis, if known
+    pub value: Option<u64>,
+}
+
+/// An Orchard action that still needs a spend authorization signature (wire form)
+#[derive(Serialize, Deserialize)]
+pub struct OrchardSigningRequest {
+    /// Index of the action in the Orchard bundle
+    pub index: usize,
+    /// Spend authorization randomizer alpha (hex) - pass as `randomizer_hex`
+    pub randomizer: String,
+    /// Randomized verifying key rk (hex) - pass as `rk_hex`
+    pub rk: String,
+    /// Value of the spent note in zatoshis, if known
+    pub value: Option<u64>,
+}
+
+/// Orchard signing requests extracted from a PCZT
+#[derive(Serialize, Deserialize)]
+pub struct PcztSigningRequests {
+    /// ZIP-244 shielded sighash (hex) - the message every action signs
+    pub sighash: String,
+    /// Actions that need a spend authorization signature
+    pub actions: Vec<OrchardSigningRequest>,
+}
+
+/// An aggregated spend authorization signature for one action
+#[derive(Serialize, Deserialize)]
+pub struct OrchardSpendAuthSig {
+    /// Index of the action in the Orchard bundle
+    pub index: usize,
+    /// Aggregate signature (hex), from `aggregate_signature`
+    pub signature: String,
+}
+
+/// Result of applying signatures to a PCZT
+#[derive(Serialize, Deserialize)]
+pub struct PcztResult {
+    /// Updated PCZT (hex)
+    pub pczt: String,
+}
+
+// =============================================================================
+// Extracting Signing Requests
+// =============================================================================
+
+/// List the Orchard actions of a PCZT that need a spend authorization signature
+///
+/// Actions that already carry a signature (including dummy actions signed by
+/// the IO finalizer) are skipped.
+///
+/// # Arguments
+/// * `pczt` - The PCZT, ready for the Signer role
+///
+/// # Returns
+/// The ZIP-244 shielded sighash every action signs and the actions to sign,
+/// or FrostError
+pub fn pczt_orchard_signing_requests(
+    pczt: Pczt,
+) -> Result<([u8; 32], Vec<OrchardSpendRequest>), FrostError> {
+    pczt_orchard_signing_requests_internal(pczt).map_err(|e| FrostError::new("PCZT_ERROR", e))
+}
+
+fn pczt_orchard_signing_requests_internal(
+    pczt: Pczt,
+) -> Result<([u8; 32], Vec<OrchardSpendRequest>), String> {
+    // alpha and the note value are only exposed on the parsed Orchard bundle,
+    // which the low-level signer hands to a closure without changing anything
+    let mut actions = Vec::new();
+    let pczt = LowLevelSigner::new(pczt)
+        .sign_orchard_with(|_, bundle, _| -> Result<(), BundleError> {
+            for (index, action) in bundle.actions().iter().enumerate() {
+                let spend = action.spend();
+
+                // Dummy actions are signed by the IO finalizer, so they already carry a signature
+                if spend.spend_auth_sig().is_some() {
+                    continue;
+                }
+
+                let alpha = spend.alpha().as_ref().ok_or_else(|| {
+                    BundleError(format!(
+                        "Action {} is missing its randomizer (alpha)",
+                        index
+                    ))
+                })?;
+                let randomizer = redpallas::rerandomized::Randomizer::deserialize(&alpha.to_repr())
+                    .map_err(|e| BundleError(format!("Invalid alpha: {:?}", e)))?;
+
+                actions.push(OrchardSpendRequest {
+                    index,
+                    randomizer,
+                    rk: <[u8; 32]>::from(spend.rk()),
+                    value: spend.value().map(|value| value.inner()),
+                });
+            }
+            Ok(())
+        })
+        .map_err(|e| e.0)?
+        .finish();
+
+    // Computing the sighash requires a PCZT that is ready for the Signer role
+    let signer =
+        Signer::new(pczt).map_err(|e| format!("PCZT is not ready for signing: {:?}", e))?;
+
+    Ok((signer.shielded_sighash(), actions))
+}
+
+/// Error raised while reading the parsed Orchard bundle
+struct BundleError(String);
+
+impl From<OrchardParseError> for BundleError {
+    fn from(e: OrchardParseError) -> Self {
+        BundleError(format!("Invalid Orchard bundle: {:?}", e))
+    }
+}
+
+// =============================================================================
+// Applying Signatures
+// =============================================================================
+
+/// Write aggregated spend authorization signatures into a PCZT
+///
+/// Each signature is verified against the action's rk and the sighash before
+/// it is stored.
+///
+/// # Arguments
+/// * `pczt` - The PCZT, ready for the Signer role
+/// * `signatures` - Aggregate signatures, with the index of the action each is for
+///
+/// # Returns
+/// The updated PCZT or FrostError
+pub fn pczt_apply_orchard_signatures(
+    pczt: Pczt,
+    signatures: &[(usize, redpallas::Signature)],
+) -> Result<Pczt, FrostError> {
+    pczt_apply_orchard_signatures_internal(pczt, signatures)
+        .map_err(|e| FrostError::new("PCZT_ERROR", e))
+}
+
+fn pczt_apply_orchard_signatures_internal(
+    pczt: Pczt,
+    signatures: &[(usize, redpallas::Signature)],
+) -> Result<Pczt, String> {
+    let mut signer =
+        Signer::new(pczt).map_err(|e| format!("PCZT is not ready for signing: {:?}", e))?;
+
+    for (index, signature) in signatures {
+        let sig_array: [u8; 64] = signature
+            .serialize()
+            .map_err(|e| format!("Failed to serialize signature: {:?}", e))?
+            .try_into()
+            .map_err(|_| "Signature must be 64 bytes")?;
+
+        signer
+            .apply_orchard_signature(*index, OrchardSignature::<SpendAuth>::from(sig_array))
+            .map_err(|e| format!("Failed to apply signature to action {}: {:?}", index, e))?;
+    }
+
+    Ok(signer.finish())
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{
+        aggregate_signature, create_signing_package_with_randomizer, derive_orchard_viewing_key,
+        generate_key_shares, generate_round1_commitment, generate_round2_signature,
+        pczt_apply_orchard_signatures, pczt_orchard_signing_requests,
+    };
+    use orchard::keys::{FullViewingKey, Scope};
+    use orchard::note::{ExtractedNoteCommitment, RandomSeed, Rho};
+    use orchard::primitives::redpallas::VerificationKey;
+    use orchard::tree::{MerkleHashOrchard, MerklePath};
+    use orchard::value::NoteValue;
+    use orchard::{Note, NoteVersion};
+    use pczt::roles::creator::Creator;
+    use pczt::roles::io_finalizer::IoFinalizer;
+    use rand::rngs::OsRng;
+    use rand::RngCore;
+    use zcash_primitives::transaction::builder::{BuildConfig, Builder, BundlePadding, PcztResult};
+    use zcash_primitives::transaction::fees::zip317;
+    use zcash_protocol::consensus::{BlockHeight, MAIN_NETWORK};
+    use zcash_protocol::memo::MemoBytes;
+    use zcash_protocol::value::Zatoshis;
+
+    /// Build a PCZT spending one note owned by `fvk`, ready for the Signer role
+    fn build_pczt(fvk: &FullViewingKey) -> (Pczt, usize) {
+        let recipient = fvk.address_at(0u32, Scope::External);
+
+        // A received note, found at an arbitrary position in the commitment tree
+        let rho = Rho::from_bytes(&[7; 32]).unwrap();
+        let rseed = loop {
+            let mut bytes = [0u8; 32];
+            OsRng.fill_bytes(&mut bytes);
+            if let Some(rseed) = RandomSeed::from_bytes(bytes, &rho).into() {
+                break rseed;
+            }
+        };
+        let note = Note::from_parts(
+            recipient,
+            NoteValue::from_raw(1_000_000),
+            rho,
+            rseed,
+            NoteVersion::V2,
+        )
+        .unwrap();
+        let cmx = ExtractedNoteCommitment::from(note.commitment());
+        let merkle_path = MerklePath::from_parts(0, [MerkleHashOrchard::from_cmx(&cmx); 32]);
+        let anchor = merkle_path.root(cmx);
+
+        let mut builder = Builder::new(
+            MAIN_NETWORK,
+            BlockHeight::from_u32(3_000_000),
+            BuildConfig::Standard {
+                sapling_anchor: None,
+                orchard_anchor: Some(anchor),
+                ironwood_anchor: None,
+                orchard_padding: BundlePadding::DEFAULT,
+                ironwood_padding: BundlePadding::DEFAULT,
+            },
+        );
+        builder
+            .add_orchard_spend::<zip317::FeeRule>(fvk.clone(), note, merkle_path)
+            .unwrap();
+        builder
+            .add_orchard_output::<zip317::FeeRule>(
+                None,
+                recipient,
+                Zatoshis::const_from_u64(100_000),
+                MemoBytes::empty(),
+            )
+            .unwrap();
+        builder
+            .add_orchard_output::<zip317::FeeRule>(
+                Some(fvk.to_ovk(Scope::Internal)),
+                fvk.address_at(0u32, Scope::Internal),
+                Zatoshis::const_from_u64(890_000),
+                MemoBytes::empty(),
+            )
+            .unwrap();
+        let PcztResult {
+            pczt_parts,
+            orchard_meta,
+            ..
+        } = builder
+            .build_for_pczt(OsRng, &zip317::FeeRule::standard())
+            .unwrap();
+
+        let pczt = Creator::build_from_parts(pczt_parts).unwrap();
+        let pczt = IoFinalizer::new(pczt).finalize_io().unwrap();
+        (pczt, orchard_meta.spend_action_index(0).unwrap())
+    }
+
+    /// Run one rerandomized FROST signing round for an Orchard action
+    fn sign_action(
+        key_packages: &[&str],
+        public_key_package: &str,
+        sighash: &str,
+        action: &OrchardSigningRequest,
+    ) -> String {
+        let round1: Vec<_> = key_packages
+            .iter()
+            .map(|kp| generate_round1_commitment(kp).unwrap())
+            .collect();
+        let commitments: Vec<_> = round1.iter().map(|r| r.commitment.clone()).collect();
+
+        let signing_package = create_signing_package_with_randomizer(
+            &serde_json::to_string(&commitments).unwrap(),
+            sighash,
+            public_key_package,
+            &action.randomizer,
+            &action.rk,
+        )
+        .expect("The PCZT's alpha should randomize the group key to its rk");
+
+        let shares: Vec<_> = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(kp, r)| {
+                generate_round2_signature(
+                    kp,
+                    &serde_json::to_string(&r.nonces).unwrap(),
+                    &signing_package.signing_package,
+                    &signing_package.randomizer,
+                )
+                .unwrap()
+            })
+            .collect();
+
+        aggregate_signature(
+            &serde_json::to_string(&shares).unwrap(),
+            &signing_package.signing_package,
+            public_key_package,
+            &signing_package.randomizer,
+        )
+        .unwrap_or_else(|_| panic!("Aggregation should succeed"))
+        .signature
+    }
+
+    #[test]
+    fn test_sign_orchard_pczt() {
+        let keygen = generate_key_shares(2, 3).unwrap();
+        let viewing_key =
+            derive_orchard_viewing_key(&keygen.public_key_package, "", "", "main", 0).unwrap();
+        let fvk_bytes: [u8; 96] = hex::decode(&viewing_key.full_viewing_key)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let fvk = FullViewingKey::from_bytes(&fvk_bytes).unwrap();
+
+        let (pczt, spend_index) = build_pczt(&fvk);
+        let pczt_hex = hex::encode(pczt.serialize().unwrap());
+
+        // Only the real spend needs a signature; the dummy spend is already signed
+        let requests = pczt_orchard_signing_requests(&pczt_hex).unwrap();
+        assert_eq!(requests.actions.len(), 1);
+        let action = &requests.actions[0];
+        assert_eq!(action.index, spend_index);
+        assert_eq!(action.value, Some(1_000_000));
+
+        let sighash: [u8; 32] = hex::decode(&requests.sighash).unwrap().try_into().unwrap();
+        let alpha = <pasta_curves::pallas::Scalar as PrimeField>::from_repr(
+            hex::decode(&action.randomizer).unwrap().try_into().unwrap(),
+        )
+        .unwrap();
+        let ak: [u8; 32] = hex::decode(&viewing_key.ak).unwrap().try_into().unwrap();
+        let ak = VerificationKey::<SpendAuth>::try_from(ak).unwrap();
+        let expected_rk: [u8; 32] = (&ak.randomize(&alpha)).into();
+        assert_eq!(action.rk, hex::encode(expected_rk));
+
+        let signature = sign_action(
+            &[&keygen.shares[0].key_package, &keygen.shares[2].key_package],
+            &keygen.public_key_package,
+            &requests.sighash,
+            action,
+        );
+        let signatures = vec![OrchardSpendAuthSig {
+            index: action.index,
+            signature,
+        }];
+        let signed =
+            pczt_apply_orchard_signatures(&pczt_hex, &serde_json::to_string(&signatures).unwrap())
+                .unwrap();
+
+        // Every action, including the dummy signed by the IO finalizer, now
+        // carries a spend authorization signature valid under its rk
+        assert!(pczt_orchard_signing_requests(&signed.pczt)
+            .unwrap()
+            .actions
+            .is_empty());
+        LowLevelSigner::new(Pczt::parse(&hex::decode(&signed.pczt).unwrap()).unwrap())
+            .sign_orchard_with(|_, bundle, _| -> Result<(), BundleError> {
+                assert_eq!(bundle.actions().len(), 2);
+                for pczt_action in bundle.actions() {
+                    let spend = pczt_action.spend();
+                    let sig = spend.spend_auth_sig().as_ref().expect("Action is signed");
+                    let rk = VerificationKey::<SpendAuth>::try_from(<[u8; 32]>::from(spend.rk()))
+                        .unwrap();
+                    assert!(rk.verify(&sighash, sig).is_ok());
+                }
+                Ok(())
+            })
+            .unwrap_or_else(|e| panic!("{}", e.0));
+    }
+
+    #[test]
+    fn test_rejects_malformed_pczt() {
+        let Err(error) = pczt_orchard_signing_requests("00") else {
+            panic!("Should be an error");
+        };
+        assert_eq!(error.code, "PCZT_ERROR");
+
+        let Err(error) = pczt_apply_orchard_signatures("not hex", "[]") else {
+            panic!("Should be an error");
+        };
+        assert_eq!(error.code, "PCZT_ERROR");
+    }
+}
@@ -0,0 +1,389 @@
+//! FROST Threshold Signatures for Zcash Sapling (RedJubjub)
+//!
+//! Same surface as the RedPallas functions in the crate root, but over the
+//! Jubjub curve so the group key can act as a Sapling spend validating key
+//! `ak`. Functions carry a `sapling_` prefix because wasm-bindgen exports
+//! share one namespace; the JSON forms are in the `json` module.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use frost_common::keystore::Keystore;
+use frost_common::{keys, rerandomized, signing};
+use reddsa::frost::redjubjub::{self, JubjubBlake2b512};
+
+use crate::{AggregateError, FrostError};
+
+/// A trusted dealer's output: each participant's secret share and the group's
+/// public key package
+pub type SaplingDealtShares = keys::DealtShares<JubjubBlake2b512>;
+
+/// Signature shares, keyed by signer
+pub type SaplingSignatureShares = signing::SignatureShares<JubjubBlake2b512>;
+
+/// Randomizer of a Sapling signing package: a seed the signers derive it
+/// from, or the randomizer itself
+pub type SaplingSigningRandomizer = rerandomized::SigningRandomizer<JubjubBlake2b512>;
+
+// =============================================================================
+// Key Generation
+// =============================================================================
+
+/// Generate RedJubjub key shares using trusted dealer
+///
+/// # Arguments
+/// * `threshold` - Minimum signers required (t)
+/// * `total` - Total number of signers (n)
+///
+/// # Returns
+/// Each participant's secret share and the PublicKeyPackage, or FrostError
+pub fn sapling_generate_key_shares(
+    threshold: u16,
+    total: u16,
+) -> Result<SaplingDealtShares, FrostError> {
+    keys::deal_key_shares::<JubjubBlake2b512>(threshold, total)
+}
+
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
+
+/// Generate Round 1 commitment for RedJubjub signing
+///
+/// # Arguments
+/// * `key_package` - Participant's key package
+///
+/// # Returns
+/// The nonces to keep secret and the commitments to publish
+pub fn sapling_generate_round1_commitment(
+    key_package: &redjubjub::keys::KeyPackage,
+) -> (
+    redjubjub::round1::SigningNonces,
+    redjubjub::round1::SigningCommitments,
+) {
+    signing::round1_commit::<JubjubBlake2b512>(key_package)
+}
+
+// =============================================================================
+// Signing Package Creation (with Randomizer)
+// =============================================================================
+
+/// Create a RedJubjub signing package with a fresh randomizer
+///
+/// # Arguments
+/// * `commitments` - All participants' commitments
+/// * `message` - Message to sign
+/// * `public_key_package` - Public key package
+///
+/// # Returns
+/// SigningPackage and randomizer seed, or FrostError
+pub fn sapling_create_signing_package(
+    commitments: BTreeMap<redjubjub::Identifier, redjubjub::round1::SigningCommitments>,
+    message: &[u8],
+    public_key_package: &redjubjub::keys::PublicKeyPackage,
+) -> Result<(redjubjub::SigningPackage, SaplingSigningRandomizer), FrostError> {
+    let signing_package = redjubjub::SigningPackage::new(commitments, message);
+    let randomizer =
+        rerandomized::randomizer::<JubjubBlake2b512>(&signing_package, public_key_package)?;
+
+    Ok((signing_package, randomizer))
+}
+
+// =============================================================================
+// Round 2: Signature Share Generation (Rerandomized)
+// =============================================================================
+
+/// Generate Round 2 RedJubjub signature share using rerandomization
+///
+/// # Arguments
+/// * `signing_package` - Signing package from coordinator
+/// * `nonces` - Participant's nonces from Round 1
+/// * `key_package` - Participant's key package
+/// * `randomizer` - Randomizer or randomizer seed from coordinator
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn sapling_generate_round2_signature(
+    signing_package: &redjubjub::SigningPackage,
+    nonces: &redjubjub::round1::SigningNonces,
+    key_package: &redjubjub::keys::KeyPackage,
+    randomizer: &SaplingSigningRandomizer,
+) -> Result<redjubjub::round2::SignatureShare, FrostError> {
+    rerandomized::round2_sign::<JubjubBlake2b512>(signing_package, nonces, key_package, randomizer)
+}
+
+// =============================================================================
+// Keystore: Opaque Key and Nonce Handles
+// =============================================================================
+
+thread_local! {
+    pub(crate) static SAPLING_KEYSTORE: RefCell<Keystore<JubjubBlake2b512>> = RefCell::new(Keystore::default());
+}
+
+/// Load a RedJubjub key package into the keystore and return an opaque handle
+///
+/// Sapling handles live in their own keystore, apart from the RedPallas
+/// handles of `load_key_package`.
+///
+/// # Arguments
+/// * `key_package` - Participant's key package
+///
+/// # Returns
+/// The key handle
+pub fn sapling_load_key_package(key_package: redjubjub::keys::KeyPackage) -> u32 {
+    SAPLING_KEYSTORE.with(|keystore| keystore.borrow_mut().load_key_package(key_package))
+}
+
+/// Generate Round 1 RedJubjub commitment, keeping the nonces in the keystore
+///
+/// # Arguments
+/// * `key_handle` - Handle from `sapling_load_key_package`
+///
+/// # Returns
+/// The nonce handle and the commitments to publish, or FrostError
+pub fn sapling_generate_round1_commitment_with_handle(
+    key_handle: u32,
+) -> Result<(u32, redjubjub::round1::SigningCommitments), FrostError> {
+    SAPLING_KEYSTORE.with(|keystore| keystore.borrow_mut().round1_commit(key_handle))
+}
+
+/// Generate Round 2 RedJubjub signature share from keystore handles
+///
+/// The nonce handle is consumed and its nonces zeroized, so it cannot be
+/// used for a second signature.
+///
+/// # Arguments
+/// * `key_handle` - Handle from `sapling_load_key_package`
+/// * `nonce_handle` - Handle from `sapling_generate_round1_commitment_with_handle`
+/// * `signing_package` - Signing package from coordinator
+/// * `randomizer` - Randomizer or randomizer seed from coordinator
+///
+/// # Returns
+/// SignatureShare or FrostError
+pub fn sapling_generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    signing_package: &redjubjub::SigningPackage,
+    randomizer: &SaplingSigningRandomizer,
+) -> Result<redjubjub::round2::SignatureShare, FrostError> {
+    SAPLING_KEYSTORE.with(|keystore| {
+        rerandomized::sign_with_handles(
+            &mut keystore.borrow_mut(),
+            key_handle,
+            nonce_handle,
+            signing_package,
+            randomizer,
+        )
+    })
+}
+
+/// Destroy a Sapling key or nonce handle, zeroizing the secret it refers to
+///
+/// # Returns
+/// `true` if the handle existed
+pub fn sapling_destroy(handle: u32) -> bool {
+    SAPLING_KEYSTORE.with(|keystore| keystore.borrow_mut().destroy(handle))
+}
+
+// =============================================================================
+// Signature Aggregation (Rerandomized)
+// =============================================================================
+
+/// Aggregate RedJubjub signature shares into final signature
+///
+/// # Arguments
+/// * `signing_package` - Signing package
+/// * `shares` - Signature shares, keyed by signer
+/// * `public_key_package` - Public key package
+/// * `randomizer` - Randomizer used for signing
+///
+/// # Returns
+/// Signature or AggregateError (with `culprits`)
+pub fn sapling_aggregate_signature(
+    signing_package: &redjubjub::SigningPackage,
+    shares: &SaplingSignatureShares,
+    public_key_package: &redjubjub::keys::PublicKeyPackage,
+    randomizer: &SaplingSigningRandomizer,
+) -> Result<redjubjub::Signature, AggregateError> {
+    rerandomized::aggregate::<JubjubBlake2b512>(
+        signing_package,
+        shares,
+        public_key_package,
+        randomizer,
+    )
+}
+
+// =============================================================================
+// Signature Verification
+// =============================================================================
+
+/// Verify a rerandomized RedJubjub signature
+///
+/// Checked against the randomized key, as a Sapling spend is against `rk`.
+///
+/// # Arguments
+/// * `signature` - Signature to verify
+/// * `message` - Message that was signed
+/// * `group_public_key` - Group verifying key
+/// * `randomizer` - Randomizer used for signing
+///
+/// # Returns
+/// `true` if the signature is valid under the randomized group key
+pub fn sapling_verify_signature(
+    signature: &redjubjub::Signature,
+    message: &[u8],
+    group_public_key: &redjubjub::VerifyingKey,
+    randomizer: redjubjub::rerandomized::Randomizer,
+) -> bool {
+    rerandomized::verify::<JubjubBlake2b512>(signature, message, group_public_key, randomizer)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sapling_signing_ceremony() {
+        let (shares, public_key_package) = sapling_generate_key_shares(2, 3).unwrap();
+        assert_eq!(shares.len(), 3);
+
+        let message = b"sapling spend";
+        let key_packages: Vec<redjubjub::keys::KeyPackage> = shares
+            .into_values()
+            .take(2)
+            .map(|share| share.try_into().unwrap())
+            .collect();
+
+        let round1: Vec<_> = key_packages
+            .iter()
+            .map(sapling_generate_round1_commitment)
+            .collect();
+        let commitments = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(k, (_, c))| (*k.identifier(), *c))
+            .collect();
+
+        let (signing_package, randomizer) =
+            sapling_create_signing_package(commitments, message, &public_key_package)
+                .expect("Signing package should succeed");
+
+        let shares: SaplingSignatureShares = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(k, (nonces, _))| {
+                let share =
+                    sapling_generate_round2_signature(&signing_package, nonces, k, &randomizer)
+                        .expect("Round 2 should succeed");
+                (*k.identifier(), share)
+            })
+            .collect();
+
+        let signature = sapling_aggregate_signature(
+            &signing_package,
+            &shares,
+            &public_key_package,
+            &randomizer,
+        )
+        .expect("Aggregation should succeed");
+
+        assert!(
+            sapling_verify_signature(
+                &signature,
+                message,
+                public_key_package.verifying_key(),
+                randomizer.randomizer(&signing_package).unwrap()
+            ),
+            "Signature should verify"
+        );
+    }
+
+    #[test]
+    fn test_sapling_sign_with_handles() {
+        let (shares, public_key_package) = sapling_generate_key_shares(2, 3).unwrap();
+        let message = b"sapling spend";
+
+        let key_handles: Vec<(redjubjub::Identifier, u32)> = shares
+            .into_values()
+            .skip(1)
+            .map(|share| {
+                let key_package: redjubjub::keys::KeyPackage = share.try_into().unwrap();
+                (
+                    *key_package.identifier(),
+                    sapling_load_key_package(key_package),
+                )
+            })
+            .collect();
+        let round1: Vec<_> = key_handles
+            .iter()
+            .map(|(_, k)| sapling_generate_round1_commitment_with_handle(*k).unwrap())
+            .collect();
+        let commitments = key_handles
+            .iter()
+            .zip(&round1)
+            .map(|((identifier, _), (_, c))| (*identifier, *c))
+            .collect();
+
+        let (signing_package, randomizer) =
+            sapling_create_signing_package(commitments, message, &public_key_package).unwrap();
+
+        let shares: SaplingSignatureShares = key_handles
+            .iter()
+            .zip(&round1)
+            .map(|((identifier, k), (n, _))| {
+                let share = sapling_generate_round2_signature_with_handles(
+                    *k,
+                    *n,
+                    &signing_package,
+                    &randomizer,
+                )
+                .expect("Round 2 with handles should succeed");
+                (*identifier, share)
+            })
+            .collect();
+
+        let signature = sapling_aggregate_signature(
+            &signing_package,
+            &shares,
+            &public_key_package,
+            &randomizer,
+        )
+        .expect("Aggregation should succeed");
+        assert!(sapling_verify_signature(
+            &signature,
+            message,
+            public_key_package.verifying_key(),
+            randomizer.randomizer(&signing_package).unwrap()
+        ));
+
+        // Nonce handles are consumed by Round 2
+        let (_, key_handle) = key_handles[0];
+        let error = sapling_generate_round2_signature_with_handles(
+            key_handle,
+            round1[0].0,
+            &signing_package,
+            &randomizer,
+        )
+        .expect_err("Reused nonces should fail");
+        assert_eq!(error.code, "ROUND2_ERROR");
+
+        assert!(sapling_destroy(key_handle));
+        assert!(!sapling_destroy(key_handle));
+    }
+
+    #[test]
+    fn test_sapling_rejects_pallas_key_package() {
+        // A RedPallas key package must not be usable with the Jubjub backend
+        let keygen = crate::json::generate_key_shares(2, 3).unwrap();
+        let Err(error) =
+            crate::json::sapling_generate_round1_commitment(&keygen.shares[0].key_package)
+        else {
+            panic!("Should be an error");
+        };
+        assert_eq!(error.code, "ROUND1_ERROR");
+    }
+}
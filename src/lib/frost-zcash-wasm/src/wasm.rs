@@ -0,0 +1,1898 @@
+//! wasm-bindgen exports
+//!
+//! Each export takes the same arguments as the function of the same name in
+//! the `json` module and returns its result as a JSON string: the value on
+//! success, the FrostError (or AggregateError) otherwise.
+
+use frost_common::to_json;
+use wasm_bindgen::prelude::*;
+
+use crate::FrostError;
+
+// Initialize panic hook for better error messages in WASM
+#[wasm_bindgen(start)]
+pub fn init() {
+    #[cfg(feature = "console_error_panic_hook")]
+    console_error_panic_hook::set_once();
+}
+
+// =============================================================================
+// Key Generation
+// =============================================================================
+
+/// Generate FROST key shares using trusted dealer
+///
+/// # Returns
+/// JSON string containing KeyGenResult or FrostError
+#[wasm_bindgen]
+pub fn generate_key_shares(threshold: u16, total: u16) -> String {
+    to_json(crate::json::generate_key_shares(threshold, total))
+}
+
+/// Verify a dealer-issued secret share and turn it into a key package
+///
+/// # Returns
+/// JSON string containing KeyShareInfo or FrostError
+#[wasm_bindgen]
+pub fn verify_and_accept_share(secret_share_json: &str, public_key_package_json: &str) -> String {
+    to_json(crate::json::verify_and_accept_share(
+        secret_share_json,
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Distributed Key Generation
+// =============================================================================
+
+/// DKG part 1: generate this participant's Round 1 package
+///
+/// # Returns
+/// JSON string containing DkgRound1Result or FrostError
+#[wasm_bindgen]
+pub fn dkg_part1(identifier: u16, max_signers: u16, min_signers: u16) -> String {
+    to_json(crate::json::dkg_part1(identifier, max_signers, min_signers))
+}
+
+/// DKG part 2: process the other participants' Round 1 packages
+///
+/// # Returns
+/// JSON string containing DkgRound2Result or FrostError
+#[wasm_bindgen]
+pub fn dkg_part2(secret_package_json: &str, round1_packages_json: &str) -> String {
+    to_json(crate::json::dkg_part2(
+        secret_package_json,
+        round1_packages_json,
+    ))
+}
+
+/// DKG part 3: derive this participant's key package and the group public key package
+///
+/// # Returns
+/// JSON string containing DkgResult or FrostError
+#[wasm_bindgen]
+pub fn dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+) -> String {
+    to_json(crate::json::dkg_part3(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+    ))
+}
+
+// =============================================================================
+// Share Refresh
+// =============================================================================
+
+/// Dealer-based refresh: deal zero-secret shares that rotate every key share
+///
+/// # Returns
+/// JSON string containing RefreshSharesResult or FrostError
+#[wasm_bindgen]
+pub fn compute_refreshing_shares(
+    public_key_package_json: &str,
+    identifiers_json: &str,
+    min_signers: u16,
+) -> String {
+    to_json(crate::json::compute_refreshing_shares(
+        public_key_package_json,
+        identifiers_json,
+        min_signers,
+    ))
+}
+
+/// Apply a refreshing share to this participant's key package
+///
+/// # Returns
+/// JSON string containing KeyShareInfo with the refreshed key package or FrostError
+#[wasm_bindgen]
+pub fn refresh_share(refreshing_share_json: &str, key_package_json: &str) -> String {
+    to_json(crate::json::refresh_share(
+        refreshing_share_json,
+        key_package_json,
+    ))
+}
+
+/// Refresh DKG part 1: like `dkg_part1`, with a zero constant term so the group key is kept
+///
+/// # Returns
+/// JSON string containing DkgRound1Result or FrostError
+#[wasm_bindgen]
+pub fn refresh_dkg_part1(identifier: u16, max_signers: u16, min_signers: u16) -> String {
+    to_json(crate::json::refresh_dkg_part1(
+        identifier,
+        max_signers,
+        min_signers,
+    ))
+}
+
+/// Refresh DKG part 2: same wire format as `dkg_part2`
+///
+/// # Returns
+/// JSON string containing DkgRound2Result or FrostError
+#[wasm_bindgen]
+pub fn refresh_dkg_part2(secret_package_json: &str, round1_packages_json: &str) -> String {
+    to_json(crate::json::refresh_dkg_part2(
+        secret_package_json,
+        round1_packages_json,
+    ))
+}
+
+/// Refresh DKG part 3: derive the refreshed key package and public key package
+///
+/// # Returns
+/// JSON string containing DkgResult or FrostError
+#[wasm_bindgen]
+pub fn refresh_dkg_part3(
+    secret_package_json: &str,
+    round1_packages_json: &str,
+    round2_packages_json: &str,
+    public_key_package_json: &str,
+    key_package_json: &str,
+) -> String {
+    to_json(crate::json::refresh_dkg_part3(
+        secret_package_json,
+        round1_packages_json,
+        round2_packages_json,
+        public_key_package_json,
+        key_package_json,
+    ))
+}
+
+// =============================================================================
+// Share Repair
+// =============================================================================
+
+/// Repair step 1 (helper): split this helper's contribution to a lost share into deltas
+///
+/// # Returns
+/// JSON string containing RepairStep1Result or FrostError
+#[wasm_bindgen]
+pub fn repair_share_step_1(helpers_json: &str, key_package_json: &str, participant: u16) -> String {
+    to_json(crate::json::repair_share_step_1(
+        helpers_json,
+        key_package_json,
+        participant,
+    ))
+}
+
+/// Repair step 2 (helper): combine the deltas received from every helper into a sigma
+///
+/// # Returns
+/// JSON string containing RepairSigmaInfo or FrostError
+#[wasm_bindgen]
+pub fn repair_share_step_2(deltas_json: &str) -> String {
+    to_json(crate::json::repair_share_step_2(deltas_json))
+}
+
+/// Repair step 3 (repaired participant): rebuild the lost key package from the helpers' sigmas
+///
+/// # Returns
+/// JSON string containing KeyShareInfo or FrostError
+#[wasm_bindgen]
+pub fn repair_share_step_3(
+    sigmas_json: &str,
+    identifier: u16,
+    public_key_package_json: &str,
+    min_signers: u16,
+) -> String {
+    to_json(crate::json::repair_share_step_3(
+        sigmas_json,
+        identifier,
+        public_key_package_json,
+        min_signers,
+    ))
+}
+
+// =============================================================================
+// Resharing
+// =============================================================================
+
+/// Reshare part 1 (current holder): deal this holder's weighted share to a new participant set
+///
+/// # Returns
+/// JSON string containing ReshareRound1Result or FrostError
+#[wasm_bindgen]
+pub fn reshare_part1(
+    key_package_json: &str,
+    dealers_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> String {
+    to_json(crate::json::reshare_part1(
+        key_package_json,
+        dealers_json,
+        new_identifiers_json,
+        new_min_signers,
+    ))
+}
+
+/// Reshare part 2 (new participant): combine the dealers' sub-shares into a new key package
+///
+/// # Returns
+/// JSON string containing DkgResult or FrostError
+#[wasm_bindgen]
+pub fn reshare_part2(
+    identifier: u16,
+    sub_shares_json: &str,
+    commitments_json: &str,
+    public_key_package_json: &str,
+    new_identifiers_json: &str,
+    new_min_signers: u16,
+) -> String {
+    to_json(crate::json::reshare_part2(
+        identifier,
+        sub_shares_json,
+        commitments_json,
+        public_key_package_json,
+        new_identifiers_json,
+        new_min_signers,
+    ))
+}
+
+// =============================================================================
+// Orchard Key Compatibility
+// =============================================================================
+
+/// Validate that a public key package can act as an Orchard spend authority
+///
+/// # Returns
+/// JSON string containing { "valid": true } or FrostError
+#[wasm_bindgen]
+pub fn validate_orchard_public_key_package(public_key_package_json: &str) -> String {
+    to_json(crate::json::validate_orchard_public_key_package(
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Orchard Viewing Keys and Addresses
+// =============================================================================
+
+/// Derive an Orchard full viewing key and unified address for a FROST group
+///
+/// # Returns
+/// JSON string containing OrchardViewingKeyResult or FrostError
+#[wasm_bindgen]
+pub fn derive_orchard_viewing_key(
+    public_key_package_json: &str,
+    nk_hex: &str,
+    rivk_hex: &str,
+    network: &str,
+    diversifier_index: u32,
+) -> String {
+    to_json(crate::json::derive_orchard_viewing_key(
+        public_key_package_json,
+        nk_hex,
+        rivk_hex,
+        network,
+        diversifier_index,
+    ))
+}
+
+// =============================================================================
+// Round 1: Commitment Generation
+// =============================================================================
+
+/// Generate Round 1 commitment for signing
+///
+/// # Returns
+/// JSON string containing Round1Result or FrostError
+#[wasm_bindgen]
+pub fn generate_round1_commitment(key_package_json: &str) -> String {
+    to_json(crate::json::generate_round1_commitment(key_package_json))
+}
+
+// =============================================================================
+// Signing Package Creation (with Randomizer)
+// =============================================================================
+
+/// Create a signing package with randomizer for rerandomized FROST
+///
+/// # Returns
+/// JSON string containing SigningPackageResult or FrostError
+#[wasm_bindgen]
+pub fn create_signing_package(
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::create_signing_package(
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    ))
+}
+
+/// Create a signing package using a randomizer chosen by the transaction builder
+///
+/// # Returns
+/// JSON string containing SigningPackageResult or FrostError
+#[wasm_bindgen]
+pub fn create_signing_package_with_randomizer(
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+    randomizer_hex: &str,
+    rk_hex: &str,
+) -> String {
+    to_json(crate::json::create_signing_package_with_randomizer(
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+        randomizer_hex,
+        rk_hex,
+    ))
+}
+
+// =============================================================================
+// Round 2: Signature Share Generation (Rerandomized)
+// =============================================================================
+
+/// Generate Round 2 signature share using rerandomization
+///
+/// # Returns
+/// JSON string containing SignatureShareInfo or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature(
+        key_package_json,
+        nonces_json,
+        signing_package_json,
+        randomizer_json,
+    ))
+}
+
+// =============================================================================
+// Commitment Pools (Preprocessing)
+// =============================================================================
+
+/// Preprocess a batch of Round 1 commitments
+///
+/// # Returns
+/// JSON string containing CommitmentPoolResult or FrostError
+#[wasm_bindgen]
+pub fn generate_commitment_pool(key_package_json: &str, count: u32) -> String {
+    to_json(crate::json::generate_commitment_pool(
+        key_package_json,
+        count,
+    ))
+}
+
+/// Take the oldest unused commitment from each signer's pool (coordinator)
+///
+/// # Returns
+/// JSON string containing PoolSelectionResult or FrostError
+#[wasm_bindgen]
+pub fn select_pool_commitments(pools_json: &str, signers_json: &str) -> String {
+    to_json(crate::json::select_pool_commitments(
+        pools_json,
+        signers_json,
+    ))
+}
+
+/// Generate Round 2 rerandomized signature share using the pooled nonces the
+/// coordinator selected
+///
+/// # Returns
+/// JSON string containing PoolSignatureResult or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature_from_pool(
+    key_package_json: &str,
+    nonce_pool_json: &str,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature_from_pool(
+        key_package_json,
+        nonce_pool_json,
+        signing_package_json,
+        randomizer_json,
+    ))
+}
+
+// =============================================================================
+// Nonce Registry
+// =============================================================================
+
+/// Export the nonce registry so it can be persisted across page reloads
+///
+/// # Returns
+/// JSON string containing NonceRegistryState
+#[wasm_bindgen]
+pub fn export_nonce_registry() -> String {
+    to_json::<_, FrostError>(Ok(crate::export_nonce_registry()))
+}
+
+/// Merge a previously exported registry into the current one
+///
+/// # Returns
+/// JSON string containing the merged NonceRegistryState or FrostError
+#[wasm_bindgen]
+pub fn import_nonce_registry(state_json: &str) -> String {
+    to_json(crate::json::import_nonce_registry(state_json))
+}
+
+/// Export the nonce registry sealed under a key package in the keystore
+///
+/// # Returns
+/// JSON string containing SealedNonceRegistry or FrostError
+#[wasm_bindgen]
+pub fn export_sealed_nonce_registry(key_handle: u32) -> String {
+    to_json(crate::export_sealed_nonce_registry(key_handle))
+}
+
+/// Merge a sealed registry into the current one after decrypting it and
+/// checking it is not older than `min_counter` (a BigInt in JavaScript: the
+/// `counter` of the latest export stored, or 0).
+///
+/// # Returns
+/// JSON string containing the merged NonceRegistryState or FrostError
+#[wasm_bindgen]
+pub fn import_sealed_nonce_registry(
+    key_handle: u32,
+    sealed_json: &str,
+    min_counter: u64,
+) -> String {
+    to_json(crate::json::import_sealed_nonce_registry(
+        key_handle,
+        sealed_json,
+        min_counter,
+    ))
+}
+
+// =============================================================================
+// Keystore: Opaque Key and Nonce Handles
+// =============================================================================
+
+/// Load a key package into the keystore and return an opaque handle
+///
+/// # Returns
+/// JSON string containing KeyHandleInfo or FrostError
+#[wasm_bindgen]
+pub fn load_key_package(key_package_json: &str) -> String {
+    to_json(crate::json::load_key_package(key_package_json))
+}
+
+/// Generate Round 1 commitment, keeping the nonces in the keystore
+///
+/// # Returns
+/// JSON string containing Round1HandleResult or FrostError
+#[wasm_bindgen]
+pub fn generate_round1_commitment_with_handle(key_handle: u32) -> String {
+    to_json(crate::json::generate_round1_commitment_with_handle(
+        key_handle,
+    ))
+}
+
+/// Generate Round 2 rerandomized signature share from keystore handles
+///
+/// # Returns
+/// JSON string containing SignatureShareInfo or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signature_with_handles(
+        key_handle,
+        nonce_handle,
+        signing_package_json,
+        randomizer_json,
+    ))
+}
+
+/// Destroy a key or nonce handle, zeroizing the secret it refers to
+///
+/// # Returns
+/// `true` if the handle existed
+#[wasm_bindgen]
+pub fn destroy(handle: u32) -> bool {
+    crate::destroy(handle)
+}
+
+// =============================================================================
+// Signature Aggregation (Rerandomized)
+// =============================================================================
+
+/// Aggregate signature shares into final signature
+///
+/// # Returns
+/// JSON string containing AggregateResult or AggregateError
+#[wasm_bindgen]
+pub fn aggregate_signature(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    randomizer_json: &str,
+) -> String {
+    to_json(crate::json::aggregate_signature(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+        randomizer_json,
+    ))
+}
+
+/// Verify each signature share against the signer's verifying share
+///
+/// # Returns
+/// JSON string containing ShareVerificationResult or FrostError
+#[wasm_bindgen]
+pub fn verify_signature_shares(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    randomizer_json: &str,
+) -> String {
+    to_json(crate::json::verify_signature_shares(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+        randomizer_json,
+    ))
+}
+
+// =============================================================================
+// Multi-Action Signing
+// =============================================================================
+
+/// Generate Round 1 commitments for several actions at once
+///
+/// # Returns
+/// JSON string containing BatchRound1Result or FrostError
+#[wasm_bindgen]
+pub fn generate_round1_commitments(key_package_json: &str, action_count: u32) -> String {
+    to_json(crate::json::generate_round1_commitments(
+        key_package_json,
+        action_count,
+    ))
+}
+
+/// Generate Round 1 commitments for several actions, keeping the nonces in the keystore
+///
+/// # Returns
+/// JSON string containing BatchRound1HandleResult or FrostError
+#[wasm_bindgen]
+pub fn generate_round1_commitments_with_handle(key_handle: u32, action_count: u32) -> String {
+    to_json(crate::json::generate_round1_commitments_with_handle(
+        key_handle,
+        action_count,
+    ))
+}
+
+/// Create one signing package per action from batched commitments
+///
+/// # Returns
+/// JSON string containing BatchSigningPackageResult or FrostError
+#[wasm_bindgen]
+pub fn create_batch_signing_package(
+    commitments_json: &str,
+    actions_json: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::create_batch_signing_package(
+        commitments_json,
+        actions_json,
+        public_key_package_json,
+    ))
+}
+
+/// Generate Round 2 signature shares for every action in a batch
+///
+/// # Returns
+/// JSON string containing BatchSignatureShareInfo or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signatures(
+    key_package_json: &str,
+    nonces_json: &str,
+    batch_signing_package_json: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signatures(
+        key_package_json,
+        nonces_json,
+        batch_signing_package_json,
+    ))
+}
+
+/// Generate Round 2 signature shares for every action in a batch from keystore handles
+///
+/// # Returns
+/// JSON string containing BatchSignatureShareInfo or FrostError
+#[wasm_bindgen]
+pub fn generate_round2_signatures_with_handles(
+    key_handle: u32,
+    nonce_handles: &[u32],
+    batch_signing_package_json: &str,
+) -> String {
+    to_json(crate::json::generate_round2_signatures_with_handles(
+        key_handle,
+        nonce_handles,
+        batch_signing_package_json,
+    ))
+}
+
+/// Aggregate every action's signature shares in one call
+///
+/// # Returns
+/// JSON string containing BatchAggregateResult or AggregateError
+#[wasm_bindgen]
+pub fn aggregate_signatures(
+    shares_json: &str,
+    batch_signing_package_json: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::aggregate_signatures(
+        shares_json,
+        batch_signing_package_json,
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Signature Verification
+// =============================================================================
+
+/// Verify a rerandomized signature
+///
+/// # Returns
+/// JSON string containing verification result or FrostError
+#[wasm_bindgen]
+pub fn verify_signature(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+    randomizer_json: &str,
+) -> String {
+    to_json(crate::json::verify_signature(
+        signature_hex,
+        message_hex,
+        group_public_key_hex,
+        randomizer_json,
+    ))
+}
+
+// =============================================================================
+// Emergency Reconstruction
+// =============================================================================
+
+/// DANGER: reassemble the group signing key (the Orchard `ask`) from `min_signers` key packages
+///
+/// # Returns
+/// JSON string containing ReconstructResult or FrostError
+#[wasm_bindgen]
+pub fn emergency_reconstruct_signing_key(
+    key_packages_json: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::emergency_reconstruct_signing_key(
+        key_packages_json,
+        public_key_package_json,
+    ))
+}
+
+// =============================================================================
+// Utility Functions
+// =============================================================================
+
+/// Get the public key from a key package
+///
+/// # Returns
+/// JSON string with public key (hex) or FrostError
+#[wasm_bindgen]
+pub fn get_public_key(key_package_json: &str) -> String {
+    to_json(crate::json::get_public_key(key_package_json))
+}
+
+/// Get the group public key from a public key package
+///
+/// # Returns
+/// Hex-encoded group public key or error
+#[wasm_bindgen]
+pub fn get_group_public_key(public_key_package_json: &str) -> String {
+    crate::json::get_group_public_key(public_key_package_json)
+        .unwrap_or_else(|e| to_json::<(), _>(Err(e)))
+}
+
+// =============================================================================
+// Sapling (RedJubjub)
+// =============================================================================
+
+/// Generate RedJubjub key shares using trusted dealer
+///
+/// # Returns
+/// JSON string containing KeyGenResult or FrostError
+#[wasm_bindgen]
+pub fn sapling_generate_key_shares(threshold: u16, total: u16) -> String {
+    to_json(crate::json::sapling_generate_key_shares(threshold, total))
+}
+
+/// Generate Round 1 commitment for RedJubjub signing
+///
+/// # Returns
+/// JSON string containing Round1Result or FrostError
+#[wasm_bindgen]
+pub fn sapling_generate_round1_commitment(key_package_json: &str) -> String {
+    to_json(crate::json::sapling_generate_round1_commitment(
+        key_package_json,
+    ))
+}
+
+/// Create a RedJubjub signing package with a fresh randomizer
+///
+/// # Returns
+/// JSON string containing SigningPackageResult or FrostError
+#[wasm_bindgen]
+pub fn sapling_create_signing_package(
+    commitments_json: &str,
+    message_hex: &str,
+    public_key_package_json: &str,
+) -> String {
+    to_json(crate::json::sapling_create_signing_package(
+        commitments_json,
+        message_hex,
+        public_key_package_json,
+    ))
+}
+
+/// Generate Round 2 RedJubjub signature share using rerandomization
+///
+/// # Returns
+/// JSON string containing SignatureShareInfo or FrostError
+#[wasm_bindgen]
+pub fn sapling_generate_round2_signature(
+    key_package_json: &str,
+    nonces_json: &str,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> String {
+    to_json(crate::json::sapling_generate_round2_signature(
+        key_package_json,
+        nonces_json,
+        signing_package_json,
+        randomizer_json,
+    ))
+}
+
+/// Load a RedJubjub key package into the keystore and return an opaque handle
+///
+/// # Returns
+/// JSON string containing KeyHandleInfo or FrostError
+#[wasm_bindgen]
+pub fn sapling_load_key_package(key_package_json: &str) -> String {
+    to_json(crate::json::sapling_load_key_package(key_package_json))
+}
+
+/// Generate Round 1 RedJubjub commitment, keeping the nonces in the keystore
+///
+/// # Returns
+/// JSON string containing Round1HandleResult or FrostError
+#[wasm_bindgen]
+pub fn sapling_generate_round1_commitment_with_handle(key_handle: u32) -> String {
+    to_json(crate::json::sapling_generate_round1_commitment_with_handle(
+        key_handle,
+    ))
+}
+
+/// Generate Round 2 RedJubjub signature share from keystore handles
+///
+/// # Returns
+/// JSON string containing SignatureShareInfo or FrostError
+#[wasm_bindgen]
+pub fn sapling_generate_round2_signature_with_handles(
+    key_handle: u32,
+    nonce_handle: u32,
+    signing_package_json: &str,
+    randomizer_json: &str,
+) -> String {
+    to_json(crate::json::sapling_generate_round2_signature_with_handles(
+        key_handle,
+        nonce_handle,
+        signing_package_json,
+        randomizer_json,
+    ))
+}
+
+/// Destroy a Sapling key or nonce handle, zeroizing the secret it refers to
+///
+/// # Returns
+/// `true` if the handle existed
+#[wasm_bindgen]
+pub fn sapling_destroy(handle: u32) -> bool {
+    crate::sapling::sapling_destroy(handle)
+}
+
+/// Aggregate RedJubjub signature shares into final signature
+///
+/// # Returns
+/// JSON string containing AggregateResult or AggregateError (with `culprits`)
+#[wasm_bindgen]
+pub fn sapling_aggregate_signature(
+    shares_json: &str,
+    signing_package_json: &str,
+    public_key_package_json: &str,
+    randomizer_json: &str,
+) -> String {
+    to_json(crate::json::sapling_aggregate_signature(
+        shares_json,
+        signing_package_json,
+        public_key_package_json,
+        randomizer_json,
+    ))
+}
+
+/// Verify a rerandomized RedJubjub signature
+///
+/// # Returns
+/// JSON string containing verification result or FrostError
+#[wasm_bindgen]
+pub fn sapling_verify_signature(
+    signature_hex: &str,
+    message_hex: &str,
+    group_public_key_hex: &str,
+    randomizer_json: &str,
+) -> String {
+    to_json(crate::json::sapling_verify_signature(
+        signature_hex,
+        message_hex,
+        group_public_key_hex,
+        randomizer_json,
+    ))
+}
+
+/// Get the public key from a RedJubjub key package
+///
+/// # Returns
+/// JSON string with public key (hex) or FrostError
+#[wasm_bindgen]
+pub fn sapling_get_public_key(key_package_json: &str) -> String {
+    to_json(crate::json::sapling_get_public_key(key_package_json))
+}
+
+/// Get the group public key from a RedJubjub public key package
+///
+/// # Returns
+/// Hex-encoded group public key or error
+#[wasm_bindgen]
+pub fn sapling_get_group_public_key(public_key_package_json: &str) -> String {
+    crate::json::sapling_get_group_public_key(public_key_package_json)
+        .unwrap_or_else(|e| to_json::<(), _>(Err(e)))
+}
+
+// =============================================================================
+// PCZT Signing
+// =============================================================================
+
+/// List the Orchard actions of a PCZT that need a spend authorization signature
+///
+/// # Returns
+/// JSON string containing PcztSigningRequests or FrostError
+#[wasm_bindgen]
+pub fn pczt_orchard_signing_requests(pczt_hex: &str) -> String {
+    to_json(crate::json::pczt_orchard_signing_requests(pczt_hex))
+}
+
+/// Write aggregated spend authorization signatures into a PCZT
+///
+/// # Returns
+/// JSON string containing PcztResult or FrostError
+#[wasm_bindgen]
+pub fn pczt_apply_orchard_signatures(pczt_hex: &str, signatures_json: &str) -> String {
+    to_json(crate::json::pczt_apply_orchard_signatures(
+        pczt_hex,
+        signatures_json,
+    ))
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        is_orchard_compatible, AggregateError, AggregateResult, BatchActionInfo,
+        BatchAggregateResult, BatchCommitmentInfo, BatchRound1HandleResult, BatchRound1Result,
+        BatchSignatureShareInfo, BatchSigningPackageResult, CommitmentInfo, CommitmentPoolResult,
+        DkgResult, DkgRound1PackageInfo, DkgRound1Result, DkgRound2PackageInfo, DkgRound2Result,
+        KeyGenResult, KeyHandleInfo, KeyShareInfo, NonceRegistryState, OrchardViewingKeyResult,
+        ReconstructResult, RefreshSharesResult, RepairStep1Result, ReshareRound1Result,
+        Round1HandleResult, Round1Result, SealedNonceRegistry, ShareVerificationResult,
+        SignatureShareInfo, SigningPackageResult, VerifyResult,
+    };
+    use orchard::keys::{FullViewingKey, Scope};
+    use pasta_curves::group::ff::PrimeField;
+    use pasta_curves::pallas;
+    use rand::rngs::OsRng;
+    use reddsa::frost::redpallas::{
+        self,
+        keys::PublicKeyPackage,
+        rerandomized::{RandomizedParams, Randomizer},
+        Signature,
+    };
+    use std::collections::BTreeMap;
+    use zcash_address::unified::{Address as UnifiedAddress, Encoding, Fvk, Receiver, Ufvk};
+    use zcash_protocol::consensus::NetworkType;
+
+    #[test]
+    fn test_key_generation() {
+        let result = generate_key_shares(2, 3);
+        let parsed: KeyGenResult = serde_json::from_str(&result).expect("Should parse result");
+
+        assert_eq!(parsed.threshold, 2);
+        assert_eq!(parsed.total, 3);
+        assert_eq!(parsed.shares.len(), 3);
+        assert!(!parsed.group_public_key.is_empty());
+        assert!(!parsed.public_key_package.is_empty());
+    }
+
+    #[test]
+    fn test_verify_and_accept_share() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        for share in &keygen.shares {
+            let secret_share = share.secret_share.as_ref().expect("Dealer secret share");
+            let accepted: KeyShareInfo = serde_json::from_str(&verify_and_accept_share(
+                secret_share,
+                &keygen.public_key_package,
+            ))
+            .expect("Honest share should be accepted");
+            assert_eq!(accepted.key_package, share.key_package);
+        }
+
+        // A share from a different dealing is rejected
+        let other: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let result = verify_and_accept_share(
+            other.shares[1].secret_share.as_ref().unwrap(),
+            &keygen.public_key_package,
+        );
+        let error: FrostError = serde_json::from_str(&result).expect("Should be rejected");
+        assert_eq!(error.code, "VERIFY_ERROR");
+    }
+
+    #[test]
+    fn test_full_signing_ceremony() {
+        // Generate keys
+        let keygen_result = generate_key_shares(2, 3);
+        let keygen: KeyGenResult =
+            serde_json::from_str(&keygen_result).expect("Key generation should succeed");
+
+        // Round 1: Generate commitments for first 2 participants
+        let round1_1 = generate_round1_commitment(&keygen.shares[0].key_package);
+        let r1_1: Round1Result =
+            serde_json::from_str(&round1_1).expect("Round 1 participant 1 should succeed");
+
+        let round1_2 = generate_round1_commitment(&keygen.shares[1].key_package);
+        let r1_2: Round1Result =
+            serde_json::from_str(&round1_2).expect("Round 1 participant 2 should succeed");
+
+        // Collect commitments
+        let commitments = vec![r1_1.commitment.clone(), r1_2.commitment.clone()];
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        // Message to sign
+        let message = "48656c6c6f20576f726c64"; // "Hello World" in hex
+
+        // Create signing package with randomizer
+        let signing_pkg_result =
+            create_signing_package(&commitments_json, message, &keygen.public_key_package);
+        let signing_pkg: SigningPackageResult = serde_json::from_str(&signing_pkg_result)
+            .expect("Signing package creation should succeed");
+
+        // Round 2: Generate signature shares with randomizer
+        let nonces_1 = serde_json::to_string(&r1_1.nonces).unwrap();
+        let sig_share_1 = generate_round2_signature(
+            &keygen.shares[0].key_package,
+            &nonces_1,
+            &signing_pkg.signing_package,
+            &signing_pkg.randomizer,
+        );
+        let share_1: SignatureShareInfo =
+            serde_json::from_str(&sig_share_1).expect("Round 2 participant 1 should succeed");
+
+        let nonces_2 = serde_json::to_string(&r1_2.nonces).unwrap();
+        let sig_share_2 = generate_round2_signature(
+            &keygen.shares[1].key_package,
+            &nonces_2,
+            &signing_pkg.signing_package,
+            &signing_pkg.randomizer,
+        );
+        let share_2: SignatureShareInfo =
+            serde_json::from_str(&sig_share_2).expect("Round 2 participant 2 should succeed");
+
+        // Aggregate
+        let shares = vec![share_1, share_2];
+        let shares_json = serde_json::to_string(&shares).unwrap();
+
+        let agg_result = aggregate_signature(
+            &shares_json,
+            &signing_pkg.signing_package,
+            &keygen.public_key_package,
+            &signing_pkg.randomizer,
+        );
+        let agg: AggregateResult =
+            serde_json::from_str(&agg_result).expect("Aggregation should succeed");
+
+        assert!(!agg.signature.is_empty());
+
+        // Verify
+        let verify_result = verify_signature(
+            &agg.signature,
+            message,
+            &keygen.group_public_key,
+            &agg.randomizer,
+        );
+        let verify: VerifyResult =
+            serde_json::from_str(&verify_result).expect("Verification should succeed");
+
+        assert!(verify.valid, "Signature should be valid");
+    }
+
+    /// Run a full DKG ceremony and return every participant's result
+    fn run_dkg(min_signers: u16, max_signers: u16) -> Vec<DkgResult> {
+        let round1: Vec<DkgRound1Result> = (1..=max_signers)
+            .map(|id| {
+                serde_json::from_str(&dkg_part1(id, max_signers, min_signers))
+                    .expect("DKG part 1 should succeed")
+            })
+            .collect();
+
+        let others_round1 = |id: u16| {
+            let packages: Vec<DkgRound1PackageInfo> = round1
+                .iter()
+                .filter(|r| r.package.identifier != id)
+                .map(|r| r.package.clone())
+                .collect();
+            serde_json::to_string(&packages).unwrap()
+        };
+
+        let round2: Vec<DkgRound2Result> = round1
+            .iter()
+            .map(|r| {
+                let result = dkg_part2(&r.secret_package, &others_round1(r.package.identifier));
+                serde_json::from_str(&result).expect("DKG part 2 should succeed")
+            })
+            .collect();
+
+        (1..=max_signers)
+            .map(|id| {
+                let inbox: Vec<DkgRound2PackageInfo> = round2
+                    .iter()
+                    .flat_map(|r| r.packages.iter())
+                    .filter(|p| p.recipient == id)
+                    .cloned()
+                    .collect();
+                let result = dkg_part3(
+                    &round2[(id - 1) as usize].secret_package,
+                    &others_round1(id),
+                    &serde_json::to_string(&inbox).unwrap(),
+                );
+                serde_json::from_str(&result).expect("DKG part 3 should succeed")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dkg_signing_ceremony() {
+        let results = run_dkg(2, 3);
+
+        // All participants agree on the group key
+        let group_public_key = &results[0].group_public_key;
+        assert!(results
+            .iter()
+            .all(|r| &r.group_public_key == group_public_key));
+
+        // Sign with participants 2 and 3
+        let message = "48656c6c6f20576f726c64";
+        let r1_2: Round1Result =
+            serde_json::from_str(&generate_round1_commitment(&results[1].key_package)).unwrap();
+        let r1_3: Round1Result =
+            serde_json::from_str(&generate_round1_commitment(&results[2].key_package)).unwrap();
+
+        let commitments = vec![r1_2.commitment.clone(), r1_3.commitment.clone()];
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let signing_pkg: SigningPackageResult = serde_json::from_str(&create_signing_package(
+            &commitments_json,
+            message,
+            &results[0].public_key_package,
+        ))
+        .expect("Signing package creation should succeed");
+
+        let share_2: SignatureShareInfo = serde_json::from_str(&generate_round2_signature(
+            &results[1].key_package,
+            &serde_json::to_string(&r1_2.nonces).unwrap(),
+            &signing_pkg.signing_package,
+            &signing_pkg.randomizer,
+        ))
+        .expect("Round 2 participant 2 should succeed");
+        let share_3: SignatureShareInfo = serde_json::from_str(&generate_round2_signature(
+            &results[2].key_package,
+            &serde_json::to_string(&r1_3.nonces).unwrap(),
+            &signing_pkg.signing_package,
+            &signing_pkg.randomizer,
+        ))
+        .expect("Round 2 participant 3 should succeed");
+
+        let agg: AggregateResult = serde_json::from_str(&aggregate_signature(
+            &serde_json::to_string(&vec![share_2, share_3]).unwrap(),
+            &signing_pkg.signing_package,
+            &results[0].public_key_package,
+            &signing_pkg.randomizer,
+        ))
+        .expect("Aggregation should succeed");
+
+        let verify: VerifyResult = serde_json::from_str(&verify_signature(
+            &agg.signature,
+            message,
+            group_public_key,
+            &agg.randomizer,
+        ))
+        .expect("Verification should succeed");
+
+        assert!(verify.valid, "DKG signature should be valid");
+    }
+
+    #[test]
+    fn test_orchard_compatible_keygen() {
+        // Every group key must come out with ỹ = 0; check a handful of dealings
+        for _ in 0..8 {
+            let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+            let validation: VerifyResult = serde_json::from_str(
+                &validate_orchard_public_key_package(&keygen.public_key_package),
+            )
+            .expect("Dealer key should be Orchard compatible");
+            assert!(validation.valid);
+
+            let key_bytes = hex::decode(&keygen.group_public_key).unwrap();
+            assert_eq!(key_bytes[31] & 0x80, 0);
+
+            // The dealt shares must combine into valid signatures
+            let r1_1: Round1Result =
+                serde_json::from_str(&generate_round1_commitment(&keygen.shares[0].key_package))
+                    .unwrap();
+            let r1_3: Round1Result =
+                serde_json::from_str(&generate_round1_commitment(&keygen.shares[2].key_package))
+                    .unwrap();
+            let commitments_json =
+                serde_json::to_string(&vec![r1_1.commitment.clone(), r1_3.commitment.clone()])
+                    .unwrap();
+            let message = "00";
+            let signing_pkg: SigningPackageResult = serde_json::from_str(&create_signing_package(
+                &commitments_json,
+                message,
+                &keygen.public_key_package,
+            ))
+            .unwrap();
+
+            let shares: Vec<SignatureShareInfo> = [(0, &r1_1), (2, &r1_3)]
+                .iter()
+                .map(|(i, r1)| {
+                    serde_json::from_str(&generate_round2_signature(
+                        &keygen.shares[*i].key_package,
+                        &serde_json::to_string(&r1.nonces).unwrap(),
+                        &signing_pkg.signing_package,
+                        &signing_pkg.randomizer,
+                    ))
+                    .unwrap()
+                })
+                .collect();
+
+            let agg: AggregateResult = serde_json::from_str(&aggregate_signature(
+                &serde_json::to_string(&shares).unwrap(),
+                &signing_pkg.signing_package,
+                &keygen.public_key_package,
+                &signing_pkg.randomizer,
+            ))
+            .expect("Aggregation should succeed");
+
+            let verify: VerifyResult = serde_json::from_str(&verify_signature(
+                &agg.signature,
+                message,
+                &keygen.group_public_key,
+                &agg.randomizer,
+            ))
+            .unwrap();
+            assert!(verify.valid);
+        }
+
+        for _ in 0..4 {
+            let results = run_dkg(2, 3);
+            for r in &results {
+                let key_bytes = hex::decode(&r.group_public_key).unwrap();
+                assert_eq!(
+                    key_bytes[31] & 0x80,
+                    0,
+                    "DKG key should be Orchard compatible"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_orchard_rejects_odd_y() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let pubkey_package: PublicKeyPackage =
+            serde_json::from_str(&keygen.public_key_package).unwrap();
+
+        // Setting the sign bit encodes -ak, a valid point with ỹ = 1
+        let mut key_bytes = pubkey_package.verifying_key().serialize().unwrap();
+        key_bytes[31] |= 0x80;
+        let negated = PublicKeyPackage::new(
+            pubkey_package.verifying_shares().clone(),
+            redpallas::VerifyingKey::deserialize(&key_bytes).unwrap(),
+            pubkey_package.min_signers(),
+        );
+        let negated_json = serde_json::to_string(&negated).unwrap();
+
+        let result = validate_orchard_public_key_package(&negated_json);
+        let error: FrostError =
+            serde_json::from_str(&result).expect("Validation should fail for ỹ = 1");
+        assert_eq!(error.code, "ORCHARD_KEY_ERROR");
+    }
+
+    #[test]
+    fn test_orchard_viewing_key_matches_orchard_derivation() {
+        use orchard::keys::SpendingKey;
+
+        // Derive reference components from a fixed spending key with the orchard crate
+        let sk_bytes: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let sk = Option::<SpendingKey>::from(SpendingKey::from_bytes(sk_bytes)).unwrap();
+        let expected_fvk = FullViewingKey::from(&sk);
+        let expected_bytes = expected_fvk.to_bytes();
+
+        // A public key package whose group key is that spending key's ak
+        let verifying_key = redpallas::VerifyingKey::deserialize(&expected_bytes[..32]).unwrap();
+        let pubkey_package = PublicKeyPackage::new(BTreeMap::new(), verifying_key, None);
+        let pubkey_package_json = serde_json::to_string(&pubkey_package).unwrap();
+
+        let result: OrchardViewingKeyResult = serde_json::from_str(&derive_orchard_viewing_key(
+            &pubkey_package_json,
+            &hex::encode(&expected_bytes[32..64]),
+            &hex::encode(&expected_bytes[64..]),
+            "main",
+            0,
+        ))
+        .expect("Viewing key derivation should succeed");
+
+        assert_eq!(result.full_viewing_key, hex::encode(expected_bytes));
+
+        let expected_ufvk = Ufvk::try_from_items(vec![Fvk::Orchard(expected_bytes)])
+            .unwrap()
+            .encode(&NetworkType::Main);
+        assert_eq!(result.unified_full_viewing_key, expected_ufvk);
+        assert!(result.unified_full_viewing_key.starts_with("uview1"));
+
+        let expected_address = expected_fvk.address_at(0u32, Scope::External);
+        let expected_ua = UnifiedAddress::try_from_items(vec![Receiver::Orchard(
+            expected_address.to_raw_address_bytes(),
+        )])
+        .unwrap()
+        .encode(&NetworkType::Main);
+        assert_eq!(result.unified_address, expected_ua);
+        assert!(result.unified_address.starts_with("u1"));
+    }
+
+    #[test]
+    fn test_orchard_viewing_key_known_answer() {
+        // First vector of zcash-test-vectors orchard/key_components.py
+        let ak = "740bbe5d0580b2cad430180d02cc128b9a140d5e07c151721dc16d25d4e20f15";
+        let nk = "9f2f826738945ad01f47f70db0c367c246c20c61ff5583948c39dea968fefd1b";
+        let rivk = "021ccf89604f5f7cc6e034b32d338908b819fbe325fee6458b56b4ca71a7e43d";
+
+        let verifying_key =
+            redpallas::VerifyingKey::deserialize(&hex::decode(ak).unwrap()).unwrap();
+        let pubkey_package = PublicKeyPackage::new(BTreeMap::new(), verifying_key, None);
+
+        let result: OrchardViewingKeyResult = serde_json::from_str(&derive_orchard_viewing_key(
+            &serde_json::to_string(&pubkey_package).unwrap(),
+            nk,
+            rivk,
+            "main",
+            0,
+        ))
+        .expect("Viewing key derivation should succeed");
+
+        assert_eq!(result.full_viewing_key, format!("{}{}{}", ak, nk, rivk));
+        assert_eq!(
+            result.unified_full_viewing_key,
+            "uview1hp2f9pawrvznv7hmrxd4a6w8swanpmafq5md59ecwxg66v6uqj7khl7uq506rqhj58d5gaw9yt0l870ryfxy6wyl48r8ytwaxpwdycv6fhhptlz8dnrwgy4ggtel5waqpzaaf2h0a5h49dhevxjy99k5t2rvgvlyrhk4av856uwkzzhlhmclqpgrp0qea"
+        );
+        // Encodes the vector's default_d || default_pk_d
+        assert_eq!(
+            result.unified_address,
+            "u1qylzskzykhk5l5vk6zlyqqruvskzv74hk20lmrllzy3vdz6pvny5t9zwlrm86ukw77y5pu8uep2m33s7sc7gn6aq0jm9neg5tsektyn9"
+        );
+    }
+
+    #[test]
+    fn test_orchard_viewing_key_from_frost_group() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+
+        // Generated nk/rivk
+        let generated: OrchardViewingKeyResult = serde_json::from_str(&derive_orchard_viewing_key(
+            &keygen.public_key_package,
+            "",
+            "",
+            "test",
+            0,
+        ))
+        .expect("Viewing key derivation should succeed");
+        assert_eq!(generated.ak, keygen.group_public_key);
+        assert!(generated.unified_full_viewing_key.starts_with("uviewtest1"));
+        assert!(generated.unified_address.starts_with("utest1"));
+
+        // Supplying the same nk/rivk reproduces the same keys; other indices give new addresses
+        let supplied: OrchardViewingKeyResult = serde_json::from_str(&derive_orchard_viewing_key(
+            &keygen.public_key_package,
+            &generated.nk,
+            &generated.rivk,
+            "test",
+            1,
+        ))
+        .unwrap();
+        assert_eq!(
+            supplied.unified_full_viewing_key,
+            generated.unified_full_viewing_key
+        );
+        assert_ne!(supplied.unified_address, generated.unified_address);
+    }
+
+    #[test]
+    fn test_signing_with_external_randomizer() {
+        use pasta_curves::group::ff::Field;
+
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let pubkey_package: PublicKeyPackage =
+            serde_json::from_str(&keygen.public_key_package).unwrap();
+
+        // The transaction builder picks alpha and commits to rk = ak + [alpha] G
+        let alpha = pallas::Scalar::random(&mut OsRng).to_repr();
+        let randomizer = Randomizer::deserialize(&alpha).unwrap();
+        let rk_bytes =
+            RandomizedParams::from_randomizer(pubkey_package.verifying_key(), randomizer)
+                .randomized_verifying_key()
+                .serialize()
+                .unwrap();
+
+        let r1_1: Round1Result =
+            serde_json::from_str(&generate_round1_commitment(&keygen.shares[0].key_package))
+                .unwrap();
+        let r1_2: Round1Result =
+            serde_json::from_str(&generate_round1_commitment(&keygen.shares[1].key_package))
+                .unwrap();
+        let commitments_json =
+            serde_json::to_string(&vec![r1_1.commitment.clone(), r1_2.commitment.clone()]).unwrap();
+        let sighash = "11".repeat(32);
+
+        // A mismatched rk is rejected
+        let wrong_rk = keygen.group_public_key.clone();
+        let error: FrostError = serde_json::from_str(&create_signing_package_with_randomizer(
+            &commitments_json,
+            &sighash,
+            &keygen.public_key_package,
+            &hex::encode(alpha),
+            &wrong_rk,
+        ))
+        .expect("Mismatched rk should be rejected");
+        assert_eq!(error.code, "SIGNING_PACKAGE_ERROR");
+
+        let signing_pkg: SigningPackageResult =
+            serde_json::from_str(&create_signing_package_with_randomizer(
+                &commitments_json,
+                &sighash,
+                &keygen.public_key_package,
+                &hex::encode(alpha),
+                &hex::encode(&rk_bytes),
+            ))
+            .expect("Signing package with external randomizer should succeed");
+
+        let shares: Vec<SignatureShareInfo> = [(0, &r1_1), (1, &r1_2)]
+            .iter()
+            .map(|(i, r1)| {
+                serde_json::from_str(&generate_round2_signature(
+                    &keygen.shares[*i].key_package,
+                    &serde_json::to_string(&r1.nonces).unwrap(),
+                    &signing_pkg.signing_package,
+                    &signing_pkg.randomizer,
+                ))
+                .unwrap()
+            })
+            .collect();
+
+        let agg: AggregateResult = serde_json::from_str(&aggregate_signature(
+            &serde_json::to_string(&shares).unwrap(),
+            &signing_pkg.signing_package,
+            &keygen.public_key_package,
+            &signing_pkg.randomizer,
+        ))
+        .expect("Aggregation should succeed");
+
+        // The signature verifies directly under the transaction's rk
+        let rk = redpallas::VerifyingKey::deserialize(&rk_bytes).unwrap();
+        let signature = Signature::deserialize(&hex::decode(&agg.signature).unwrap()).unwrap();
+        assert!(rk
+            .verify(&hex::decode(&sighash).unwrap(), &signature)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_multi_action_signing_ceremony() {
+        use pasta_curves::group::ff::Field;
+
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let sighash = "22".repeat(32);
+
+        // Three actions: one with a builder-chosen alpha, two with fresh randomizers
+        let alpha = pallas::Scalar::random(&mut OsRng).to_repr();
+        let actions = vec![
+            BatchActionInfo {
+                message: sighash.clone(),
+                randomizer: hex::encode(alpha),
+                rk: String::new(),
+            },
+            BatchActionInfo {
+                message: sighash.clone(),
+                randomizer: String::new(),
+                rk: String::new(),
+            },
+            BatchActionInfo {
+                message: sighash.clone(),
+                randomizer: String::new(),
+                rk: String::new(),
+            },
+        ];
+
+        // Round 1: each signer commits once per action
+        let signers = [&keygen.shares[0], &keygen.shares[2]];
+        let round1: Vec<BatchRound1Result> = signers
+            .iter()
+            .map(|s| {
+                serde_json::from_str(&generate_round1_commitments(&s.key_package, 3))
+                    .expect("Batched Round 1 should succeed")
+            })
+            .collect();
+        let commitments: Vec<BatchCommitmentInfo> =
+            round1.iter().map(|r| r.commitment.clone()).collect();
+
+        let batch_package_json = create_batch_signing_package(
+            &serde_json::to_string(&commitments).unwrap(),
+            &serde_json::to_string(&actions).unwrap(),
+            &keygen.public_key_package,
+        );
+        let batch_package: BatchSigningPackageResult = serde_json::from_str(&batch_package_json)
+            .expect("Batch signing package should succeed");
+        assert_eq!(batch_package.packages.len(), 3);
+
+        // Round 2: each signer sends a vector of shares
+        let shares: Vec<BatchSignatureShareInfo> = signers
+            .iter()
+            .zip(&round1)
+            .map(|(s, r)| {
+                serde_json::from_str(&generate_round2_signatures(
+                    &s.key_package,
+                    &serde_json::to_string(&r.nonces).unwrap(),
+                    &batch_package_json,
+                ))
+                .expect("Batched Round 2 should succeed")
+            })
+            .collect();
+
+        let agg: BatchAggregateResult = serde_json::from_str(&aggregate_signatures(
+            &serde_json::to_string(&shares).unwrap(),
+            &batch_package_json,
+            &keygen.public_key_package,
+        ))
+        .expect("Batched aggregation should succeed");
+        assert_eq!(agg.signatures.len(), 3);
+
+        for signature in &agg.signatures {
+            let verify: VerifyResult = serde_json::from_str(&verify_signature(
+                &signature.signature,
+                &sighash,
+                &keygen.group_public_key,
+                &signature.randomizer,
+            ))
+            .unwrap();
+            assert!(verify.valid, "Every action signature should be valid");
+        }
+
+        // Each action gets its own randomizer
+        assert_ne!(
+            batch_package.packages[1].randomizer,
+            batch_package.packages[2].randomizer
+        );
+
+        for action_count in [0, crate::MAX_ACTION_COUNT + 1] {
+            let error: FrostError = serde_json::from_str(&generate_round1_commitments(
+                &signers[0].key_package,
+                action_count,
+            ))
+            .expect("Out-of-range action count should fail");
+            assert_eq!(error.code, "ROUND1_ERROR");
+        }
+    }
+
+    #[test]
+    fn test_multi_action_sign_with_handles() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let sighash = "33".repeat(32);
+        let actions: Vec<BatchActionInfo> = (0..2)
+            .map(|_| BatchActionInfo {
+                message: sighash.clone(),
+                randomizer: String::new(),
+                rk: String::new(),
+            })
+            .collect();
+
+        let key_handles: Vec<KeyHandleInfo> = keygen.shares[1..]
+            .iter()
+            .map(|share| serde_json::from_str(&load_key_package(&share.key_package)).unwrap())
+            .collect();
+        let round1: Vec<BatchRound1HandleResult> = key_handles
+            .iter()
+            .map(|k| {
+                serde_json::from_str(&generate_round1_commitments_with_handle(k.handle, 2))
+                    .expect("Batched Round 1 with handles should succeed")
+            })
+            .collect();
+        let commitments: Vec<BatchCommitmentInfo> =
+            round1.iter().map(|r| r.commitment.clone()).collect();
+
+        let batch_package_json = create_batch_signing_package(
+            &serde_json::to_string(&commitments).unwrap(),
+            &serde_json::to_string(&actions).unwrap(),
+            &keygen.public_key_package,
+        );
+        serde_json::from_str::<BatchSigningPackageResult>(&batch_package_json)
+            .expect("Batch signing package should succeed");
+
+        let shares: Vec<BatchSignatureShareInfo> = key_handles
+            .iter()
+            .zip(&round1)
+            .map(|(k, r)| {
+                serde_json::from_str(&generate_round2_signatures_with_handles(
+                    k.handle,
+                    &r.nonce_handles,
+                    &batch_package_json,
+                ))
+                .expect("Batched Round 2 with handles should succeed")
+            })
+            .collect();
+
+        let agg: BatchAggregateResult = serde_json::from_str(&aggregate_signatures(
+            &serde_json::to_string(&shares).unwrap(),
+            &batch_package_json,
+            &keygen.public_key_package,
+        ))
+        .expect("Batched aggregation should succeed");
+
+        for signature in &agg.signatures {
+            let verify: VerifyResult = serde_json::from_str(&verify_signature(
+                &signature.signature,
+                &sighash,
+                &keygen.group_public_key,
+                &signature.randomizer,
+            ))
+            .unwrap();
+            assert!(verify.valid, "Every action signature should be valid");
+        }
+
+        // Every nonce handle was consumed by Round 2
+        let error: FrostError = serde_json::from_str(&generate_round2_signatures_with_handles(
+            key_handles[0].handle,
+            &round1[0].nonce_handles,
+            &batch_package_json,
+        ))
+        .expect("Reused nonces should fail");
+        assert_eq!(error.code, "ROUND2_ERROR");
+    }
+
+    /// Run one rerandomized signing round with the given key packages
+    fn sign_with(key_packages: &[&str], public_key_package: &str, message: &str) -> String {
+        let round1: Vec<Round1Result> = key_packages
+            .iter()
+            .map(|kp| serde_json::from_str(&generate_round1_commitment(kp)).unwrap())
+            .collect();
+        let commitments: Vec<CommitmentInfo> =
+            round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        let signing_pkg: SigningPackageResult = serde_json::from_str(&create_signing_package(
+            &commitments_json,
+            message,
+            public_key_package,
+        ))
+        .unwrap();
+
+        let shares: Vec<SignatureShareInfo> = key_packages
+            .iter()
+            .zip(&round1)
+            .map(|(kp, r)| {
+                let nonces = serde_json::to_string(&r.nonces).unwrap();
+                serde_json::from_str(&generate_round2_signature(
+                    kp,
+                    &nonces,
+                    &signing_pkg.signing_package,
+                    &signing_pkg.randomizer,
+                ))
+                .unwrap()
+            })
+            .collect();
+
+        aggregate_signature(
+            &serde_json::to_string(&shares).unwrap(),
+            &signing_pkg.signing_package,
+            public_key_package,
+            &signing_pkg.randomizer,
+        )
+    }
+
+    /// Refresh is tested generically in frost-common; check refreshed shares
+    /// keep the Orchard-compatible group key and sign rerandomized
+    #[test]
+    fn test_refresh_keeps_orchard_address() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+
+        let refresh: RefreshSharesResult = serde_json::from_str(&compute_refreshing_shares(
+            &keygen.public_key_package,
+            "[1, 2, 3]",
+            2,
+        ))
+        .expect("Refresh should succeed");
+
+        let refreshed: Vec<KeyShareInfo> = keygen
+            .shares
+            .iter()
+            .zip(&refresh.shares)
+            .map(|(old, share)| {
+                let result =
+                    refresh_share(&serde_json::to_string(share).unwrap(), &old.key_package);
+                serde_json::from_str(&result).expect("Applying refresh should succeed")
+            })
+            .collect();
+
+        // The group key, and so the Orchard address, is unchanged
+        let old_pkp: PublicKeyPackage = serde_json::from_str(&keygen.public_key_package).unwrap();
+        let new_pkp: PublicKeyPackage = serde_json::from_str(&refresh.public_key_package).unwrap();
+        assert_eq!(old_pkp.verifying_key(), new_pkp.verifying_key());
+        assert!(is_orchard_compatible(&new_pkp).unwrap());
+
+        let message = "48656c6c6f20576f726c64";
+        let agg: AggregateResult = serde_json::from_str(&sign_with(
+            &[&refreshed[0].key_package, &refreshed[2].key_package],
+            &refresh.public_key_package,
+            message,
+        ))
+        .expect("Refreshed shares should sign");
+        let verify: VerifyResult = serde_json::from_str(&verify_signature(
+            &agg.signature,
+            message,
+            &keygen.group_public_key,
+            &agg.randomizer,
+        ))
+        .unwrap();
+        assert!(
+            verify.valid,
+            "Signature should verify under the original group key"
+        );
+    }
+
+    /// Key maintenance and the nonce registry are tested generically in
+    /// frost-common; check the exports round-trip through JSON
+    #[test]
+    fn test_maintenance_exports_round_trip() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let key_package = &keygen.shares[0].key_package;
+
+        let _: RepairStep1Result =
+            serde_json::from_str(&repair_share_step_1("[1, 2]", key_package, 3))
+                .expect("Repair step 1 should succeed");
+
+        let _: ReshareRound1Result =
+            serde_json::from_str(&reshare_part1(key_package, "[1, 3]", "[1, 3, 4, 5]", 3))
+                .expect("Reshare part 1 should succeed");
+        let error: FrostError =
+            serde_json::from_str(&reshare_part1(key_package, "[1]", "[1, 3, 4, 5]", 3))
+                .expect("Too few dealers");
+        assert_eq!(error.code, "RESHARE_ERROR");
+
+        let two =
+            serde_json::to_string(&[&keygen.shares[1].key_package, &keygen.shares[2].key_package])
+                .unwrap();
+        let key: ReconstructResult = serde_json::from_str(&emergency_reconstruct_signing_key(
+            &two,
+            &keygen.public_key_package,
+        ))
+        .expect("Reconstruction should succeed");
+        assert_eq!(key.group_public_key, keygen.group_public_key);
+
+        let pool: CommitmentPoolResult =
+            serde_json::from_str(&generate_commitment_pool(key_package, 2)).unwrap();
+        assert_eq!(pool.commitments.commitments.len(), 2);
+
+        let state: NonceRegistryState = serde_json::from_str(&export_nonce_registry()).unwrap();
+        let _: NonceRegistryState = serde_json::from_str(&import_nonce_registry(
+            &serde_json::to_string(&state).unwrap(),
+        ))
+        .unwrap();
+        let error: FrostError =
+            serde_json::from_str(&import_nonce_registry(r#"{"used_nonces":["zz"]}"#)).unwrap();
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+
+        let key_handle: KeyHandleInfo =
+            serde_json::from_str(&load_key_package(key_package)).unwrap();
+        let sealed_json = export_sealed_nonce_registry(key_handle.handle);
+        let mut sealed: SealedNonceRegistry = serde_json::from_str(&sealed_json).unwrap();
+        let _: NonceRegistryState = serde_json::from_str(&import_sealed_nonce_registry(
+            key_handle.handle,
+            &sealed_json,
+            sealed.counter,
+        ))
+        .expect("Sealed registry should open");
+        sealed.counter += 1;
+        let error: FrostError = serde_json::from_str(&import_sealed_nonce_registry(
+            key_handle.handle,
+            &serde_json::to_string(&sealed).unwrap(),
+            0,
+        ))
+        .unwrap();
+        assert_eq!(error.code, "NONCE_REGISTRY_ERROR");
+    }
+
+    #[test]
+    fn test_identifies_cheating_signer() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let signers = [&keygen.shares[0], &keygen.shares[1]];
+
+        let round1: Vec<Round1Result> = signers
+            .iter()
+            .map(|s| serde_json::from_str(&generate_round1_commitment(&s.key_package)).unwrap())
+            .collect();
+        let commitments: Vec<CommitmentInfo> =
+            round1.iter().map(|r| r.commitment.clone()).collect();
+        let commitments_json = serde_json::to_string(&commitments).unwrap();
+
+        // Participant 2 signs a different message
+        let packages: Vec<SigningPackageResult> =
+            ["48656c6c6f20576f726c64", "6261642062616420626164"]
+                .iter()
+                .map(|m| {
+                    serde_json::from_str(&create_signing_package(
+                        &commitments_json,
+                        m,
+                        &keygen.public_key_package,
+                    ))
+                    .unwrap()
+                })
+                .collect();
+        let shares: Vec<SignatureShareInfo> = signers
+            .iter()
+            .zip(&round1)
+            .zip(&packages)
+            .map(|((s, r), p)| {
+                let nonces = serde_json::to_string(&r.nonces).unwrap();
+                serde_json::from_str(&generate_round2_signature(
+                    &s.key_package,
+                    &nonces,
+                    &p.signing_package,
+                    &p.randomizer,
+                ))
+                .unwrap()
+            })
+            .collect();
+        let shares_json = serde_json::to_string(&shares).unwrap();
+
+        let check: ShareVerificationResult = serde_json::from_str(&verify_signature_shares(
+            &shares_json,
+            &packages[0].signing_package,
+            &keygen.public_key_package,
+            &packages[0].randomizer,
+        ))
+        .expect("Share verification should run");
+        assert!(!check.valid);
+        assert_eq!(check.culprits, vec![2]);
+
+        let error: AggregateError = serde_json::from_str(&aggregate_signature(
+            &shares_json,
+            &packages[0].signing_package,
+            &keygen.public_key_package,
+            &packages[0].randomizer,
+        ))
+        .expect("Aggregation should fail");
+        assert_eq!(error.code, "AGGREGATE_ERROR");
+        assert_eq!(error.culprits, vec![2]);
+    }
+
+    #[test]
+    fn test_sign_with_handles() {
+        let keygen: KeyGenResult = serde_json::from_str(&generate_key_shares(2, 3)).unwrap();
+        let message = "48656c6c6f20576f726c64";
+
+        let key_handles: Vec<KeyHandleInfo> = keygen.shares[..2]
+            .iter()
+            .map(|share| serde_json::from_str(&load_key_package(&share.key_package)).unwrap())
+            .collect();
+        let round1: Vec<Round1HandleResult> = key_handles
+            .iter()
+            .map(|k| {
+                serde_json::from_str(&generate_round1_commitment_with_handle(k.handle)).unwrap()
+            })
+            .collect();
+        let commitments: Vec<CommitmentInfo> =
+            round1.iter().map(|r| r.commitment.clone()).collect();
+
+        let signing_pkg: SigningPackageResult = serde_json::from_str(&create_signing_package(
+            &serde_json::to_string(&commitments).unwrap(),
+            message,
+            &keygen.public_key_package,
+        ))
+        .unwrap();
+
+        let shares: Vec<SignatureShareInfo> = key_handles
+            .iter()
+            .zip(&round1)
+            .map(|(k, r)| {
+                serde_json::from_str(&generate_round2_signature_with_handles(
+                    k.handle,
+                    r.nonce_handle,
+                    &signing_pkg.signing_package,
+                    &signing_pkg.randomizer,
+                ))
+                .expect("Round 2 with handles should succeed")
+            })
+            .collect();
+
+        let result: AggregateResult = serde_json::from_str(&aggregate_signature(
+            &serde_json::to_string(&shares).unwrap(),
+            &signing_pkg.signing_package,
+            &keygen.public_key_package,
+            &signing_pkg.randomizer,
+        ))
+        .expect("Should aggregate");
+        assert!(!result.signature.is_empty());
+
+        // Nonce handles are consumed by Round 2
+        let error: FrostError = serde_json::from_str(&generate_round2_signature_with_handles(
+            key_handles[0].handle,
+            round1[0].nonce_handle,
+            &signing_pkg.signing_package,
+            &signing_pkg.randomizer,
+        ))
+        .expect("Reused nonces should fail");
+        assert_eq!(error.code, "ROUND2_ERROR");
+
+        assert!(destroy(key_handles[0].handle));
+        assert!(!destroy(key_handles[0].handle));
+    }
+}
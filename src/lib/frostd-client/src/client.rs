@@ -0,0 +1,307 @@
+//! frostd client
+//!
+//! Same endpoints as `FrostClient` in `frost-client/client.ts`:
+//!
+//! - POST /challenge - Get authentication challenge (returns UUID)
+//! - POST /login - Authenticate with signed challenge
+//! - POST /logout - Invalidate access token
+//! - POST /create_new_session - Create signing session (coordinator)
+//! - POST /list_sessions - List user's sessions
+//! - POST /get_session_info - Get session details
+//! - POST /send - Send encrypted message
+//! - POST /receive - Receive encrypted messages (polling)
+//! - POST /close_session - Close session (coordinator)
+
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::error::{ClientError, ClientErrorCode};
+use crate::types::{
+    ChallengeResponse, CloseSessionRequest, CreateSessionRequest, CreateSessionResponse,
+    GetSessionInfoRequest, GetSessionInfoResponse, ListSessionsResponse, LoginRequest,
+    LoginResponse, PublicKey, ReceiveRequest, ReceiveResponse, ReceivedMessage, SendRequest,
+};
+
+/// Raw response from frostd
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Carries requests to frostd: over HTTP (`HttpTransport`) or straight into
+/// an in-process `MockFrostd`
+pub trait Transport {
+    /// POST a JSON body to `endpoint` (e.g. "/login"), with a bearer token if given
+    fn post(
+        &self,
+        endpoint: &str,
+        body: &str,
+        access_token: Option<&str>,
+    ) -> Result<Response, ClientError>;
+}
+
+/// Client for one frostd user
+pub struct FrostdClient<T: Transport> {
+    transport: T,
+    access_token: Option<String>,
+}
+
+impl<T: Transport> FrostdClient<T> {
+    pub fn new(transport: T) -> Self {
+        FrostdClient {
+            transport,
+            access_token: None,
+        }
+    }
+
+    // =========================================================================
+    // Authentication
+    // =========================================================================
+
+    pub fn is_authenticated(&self) -> bool {
+        self.access_token.is_some()
+    }
+
+    pub fn access_token(&self) -> Option<&str> {
+        self.access_token.as_deref()
+    }
+
+    /// Use an existing access token (e.g. from storage)
+    pub fn set_access_token(&mut self, access_token: Option<String>) {
+        self.access_token = access_token;
+    }
+
+    /// POST /challenge
+    pub fn challenge(&self) -> Result<ChallengeResponse, ClientError> {
+        self.request("/challenge", &serde_json::json!({}), None)
+    }
+
+    /// POST /login with a challenge already signed by the caller
+    ///
+    /// `signature` is the XEdDSA signature over the challenge UUID's 16 bytes
+    /// (hex-encoded).
+    pub fn login(
+        &mut self,
+        challenge: &str,
+        pubkey: &str,
+        signature: &str,
+    ) -> Result<LoginResponse, ClientError> {
+        let request = LoginRequest {
+            challenge: challenge.into(),
+            pubkey: pubkey.into(),
+            signature: signature.into(),
+        };
+        let response: LoginResponse = self.request("/login", &request, None)?;
+
+        self.access_token = Some(response.access_token.clone());
+        Ok(response)
+    }
+
+    /// Fetch a challenge, sign it with the 32-byte X25519 private key and log in
+    pub fn authenticate(&mut self, private_key: &[u8]) -> Result<LoginResponse, ClientError> {
+        let challenge = self.challenge()?.challenge;
+
+        // frostd verifies the signature over the UUID's binary form, not the string
+        let uuid = Uuid::parse_str(&challenge).map_err(|e| {
+            ClientError::new(
+                ClientErrorCode::ParseError,
+                format!("Invalid challenge UUID: {}", e),
+            )
+        })?;
+
+        let invalid_key = |e: String| ClientError::new(ClientErrorCode::InvalidArgument, e);
+        let pubkey = xeddsa_wasm::get_public_key(private_key).map_err(invalid_key)?;
+        let signature = xeddsa_wasm::sign(private_key, uuid.as_bytes()).map_err(invalid_key)?;
+
+        self.login(&challenge, &hex::encode(pubkey), &hex::encode(signature))
+    }
+
+    /// POST /logout, then forget the token even if the request failed
+    pub fn logout(&mut self) {
+        if self.is_authenticated() {
+            let _ = self.post::<_, IgnoredAny>("/logout", &serde_json::json!({}));
+        }
+        self.access_token = None;
+    }
+
+    // =========================================================================
+    // Session Management
+    // =========================================================================
+
+    /// POST /create_new_session (the caller becomes the coordinator)
+    pub fn create_session(
+        &self,
+        pubkeys: &[PublicKey],
+        message_count: u8,
+    ) -> Result<CreateSessionResponse, ClientError> {
+        let request = CreateSessionRequest {
+            pubkeys: pubkeys.to_vec(),
+            message_count,
+        };
+        self.post("/create_new_session", &request)
+    }
+
+    /// POST /list_sessions
+    pub fn list_sessions(&self) -> Result<ListSessionsResponse, ClientError> {
+        self.post("/list_sessions", &serde_json::json!({}))
+    }
+
+    /// POST /get_session_info
+    pub fn get_session_info(
+        &self,
+        session_id: &str,
+    ) -> Result<GetSessionInfoResponse, ClientError> {
+        let request = GetSessionInfoRequest {
+            session_id: session_id.into(),
+        };
+        self.post("/get_session_info", &request)
+    }
+
+    /// POST /close_session (coordinator only)
+    pub fn close_session(&self, session_id: &str) -> Result<(), ClientError> {
+        let request = CloseSessionRequest {
+            session_id: session_id.into(),
+        };
+        self.post::<_, IgnoredAny>("/close_session", &request)
+            .map(|_| ())
+    }
+
+    // =========================================================================
+    // Messaging
+    // =========================================================================
+
+    /// POST /send
+    ///
+    /// `msg` is hex-encoded and MUST be end-to-end encrypted. An empty
+    /// `recipients` list sends to the coordinator.
+    pub fn send(
+        &self,
+        session_id: &str,
+        recipients: &[PublicKey],
+        msg: &str,
+    ) -> Result<(), ClientError> {
+        let request = SendRequest {
+            session_id: session_id.into(),
+            recipients: recipients.to_vec(),
+            msg: msg.into(),
+        };
+        self.post::<_, IgnoredAny>("/send", &request).map(|_| ())
+    }
+
+    /// POST /receive: messages queued for this user (or for the coordinator)
+    /// since the last call
+    pub fn receive(
+        &self,
+        session_id: &str,
+        as_coordinator: bool,
+    ) -> Result<Vec<ReceivedMessage>, ClientError> {
+        let request = ReceiveRequest {
+            session_id: session_id.into(),
+            as_coordinator,
+        };
+        let response: ReceiveResponse = self.post("/receive", &request)?;
+        Ok(response.msgs)
+    }
+
+    // =========================================================================
+    // Requests
+    // =========================================================================
+
+    fn post<Req: Serialize, Resp: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        request: &Req,
+    ) -> Result<Resp, ClientError> {
+        let access_token = self
+            .access_token
+            .as_deref()
+            .ok_or_else(|| ClientError::new(ClientErrorCode::NotAuthorized, "Not authenticated"))?;
+        self.request(endpoint, request, Some(access_token))
+    }
+
+    fn request<Req: Serialize, Resp: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        request: &Req,
+        access_token: Option<&str>,
+    ) -> Result<Resp, ClientError> {
+        let body = serde_json::to_string(request).map_err(|e| {
+            ClientError::new(
+                ClientErrorCode::ParseError,
+                format!("Failed to serialize request: {}", e),
+            )
+        })?;
+
+        let response = self.transport.post(endpoint, &body, access_token)?;
+        if !(200..300).contains(&response.status) {
+            return Err(ClientError::from_response(response.status, &response.body));
+        }
+
+        // Some endpoints return an empty body
+        let body = if response.body.trim().is_empty() {
+            "null"
+        } else {
+            &response.body
+        };
+        serde_json::from_str(body).map_err(|e| {
+            ClientError::new(
+                ClientErrorCode::ParseError,
+                format!("Failed to parse JSON response: {}", e),
+            )
+        })
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockFrostd;
+
+    #[test]
+    fn test_authentication() {
+        let server = MockFrostd::default();
+        let key = xeddsa_wasm::generate_keypair();
+        let pubkey = hex::encode(key.public_key());
+        let mut client = FrostdClient::new(server.clone());
+
+        let error = client.list_sessions().expect_err("Requests need a token");
+        assert_eq!(error.code, ClientErrorCode::NotAuthorized);
+
+        // Signing the UUID string instead of its 16 bytes is rejected
+        let challenge = client.challenge().unwrap().challenge;
+        let signature = xeddsa_wasm::sign(&key.private_key(), challenge.as_bytes()).unwrap();
+        let error = client
+            .login(&challenge, &pubkey, &hex::encode(signature))
+            .expect_err("Signature over the UUID string should fail");
+        assert_eq!(error.code, ClientErrorCode::NotAuthorized);
+
+        // Challenges are single use
+        let uuid = Uuid::parse_str(&challenge).unwrap();
+        let signature = xeddsa_wasm::sign(&key.private_key(), uuid.as_bytes()).unwrap();
+        let error = client
+            .login(&challenge, &pubkey, &hex::encode(signature))
+            .expect_err("A consumed challenge should fail");
+        assert_eq!(error.code, ClientErrorCode::NotAuthorized);
+
+        client
+            .authenticate(&key.private_key())
+            .expect("Login should succeed");
+        assert!(client.list_sessions().unwrap().session_ids.is_empty());
+
+        let access_token = client.access_token().unwrap().to_string();
+        client.logout();
+        assert!(!client.is_authenticated());
+
+        let mut revoked = FrostdClient::new(server);
+        revoked.set_access_token(Some(access_token));
+        let error = revoked
+            .list_sessions()
+            .expect_err("Logged out tokens should be revoked");
+        assert_eq!(error.code, ClientErrorCode::NotAuthorized);
+    }
+}
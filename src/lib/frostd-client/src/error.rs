@@ -0,0 +1,65 @@
+//! Client error type, with the same codes as `frost-client/errors.ts`
+
+use std::fmt;
+
+use crate::types::ErrorResponse;
+
+/// Client-side error codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientErrorCode {
+    NetworkError,
+    ParseError,
+    NotAuthorized,
+    InvalidArgument,
+    SessionNotFound,
+    NotCoordinator,
+    UnknownError,
+}
+
+/// Error from a frostd request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientError {
+    pub code: ClientErrorCode,
+    pub message: String,
+}
+
+impl ClientError {
+    pub fn new(code: ClientErrorCode, message: impl Into<String>) -> Self {
+        ClientError {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// Map a failed response: frostd's `{ code, msg }` body if present,
+    /// otherwise the status code
+    pub fn from_response(status: u16, body: &str) -> Self {
+        match serde_json::from_str::<ErrorResponse>(body) {
+            Ok(error) => {
+                let code = match error.code {
+                    ErrorResponse::INVALID_ARGUMENT => ClientErrorCode::InvalidArgument,
+                    ErrorResponse::UNAUTHORIZED => ClientErrorCode::NotAuthorized,
+                    ErrorResponse::SESSION_NOT_FOUND => ClientErrorCode::SessionNotFound,
+                    ErrorResponse::NOT_COORDINATOR => ClientErrorCode::NotCoordinator,
+                    _ => ClientErrorCode::UnknownError,
+                };
+                ClientError::new(code, error.msg)
+            }
+            Err(_) if status == 401 || status == 403 => {
+                ClientError::new(ClientErrorCode::NotAuthorized, "Unauthorized")
+            }
+            Err(_) => ClientError::new(
+                ClientErrorCode::UnknownError,
+                format!("Request failed with status {}", status),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for ClientError {}
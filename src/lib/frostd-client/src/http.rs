@@ -0,0 +1,67 @@
+//! Blocking HTTP transport for a real frostd
+
+use std::time::Duration;
+
+use crate::client::{Response, Transport};
+use crate::error::{ClientError, ClientErrorCode};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Sends requests to a frostd server over HTTP(S)
+pub struct HttpTransport {
+    base_url: String,
+    agent: ureq::Agent,
+}
+
+impl HttpTransport {
+    /// `base_url` is the server root, e.g. "https://localhost:2744"
+    pub fn new(base_url: &str) -> Self {
+        Self::with_timeout(base_url, DEFAULT_TIMEOUT)
+    }
+
+    pub fn with_timeout(base_url: &str, timeout: Duration) -> Self {
+        HttpTransport {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            agent: ureq::AgentBuilder::new().timeout(timeout).build(),
+        }
+    }
+}
+
+impl Transport for HttpTransport {
+    fn post(
+        &self,
+        endpoint: &str,
+        body: &str,
+        access_token: Option<&str>,
+    ) -> Result<Response, ClientError> {
+        let mut request = self
+            .agent
+            .post(&format!("{}{}", self.base_url, endpoint))
+            .set("Content-Type", "application/json");
+        if let Some(access_token) = access_token {
+            request = request.set("Authorization", &format!("Bearer {}", access_token));
+        }
+
+        // Error statuses still carry frostd's error body
+        let response = match request.send_string(body) {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => {
+                return Err(ClientError::new(
+                    ClientErrorCode::NetworkError,
+                    format!("Network request failed: {}", e),
+                ))
+            }
+        };
+
+        let status = response.status();
+        let body = response.into_string().map_err(|e| {
+            ClientError::new(
+                ClientErrorCode::NetworkError,
+                format!("Failed to read response: {}", e),
+            )
+        })?;
+
+        Ok(Response { status, body })
+    }
+}
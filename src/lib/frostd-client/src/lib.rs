@@ -0,0 +1,33 @@
+//! Rust client for the frostd server
+//!
+//! Implements the frostd REST protocol (challenge/login, sessions, send and
+//! receive) like `frost-client/http-client.ts`, so ceremonies can be driven
+//! from Rust services and tests. Login signs the challenge with the
+//! `xeddsa-wasm` code the web UI uses.
+//!
+//! Requests go through a `Transport`: `HttpTransport` (the `http` feature, on
+//! by default) talks to a real server, and `MockFrostd` is an in-process
+//! server for running multi-party ceremonies locally without any network.
+//!
+//! ```no_run
+//! use frostd_client::{FrostdClient, HttpTransport};
+//!
+//! let key = xeddsa_wasm::generate_keypair();
+//! let mut client = FrostdClient::new(HttpTransport::new("https://localhost:2744"));
+//! client.authenticate(&key.private_key())?;
+//! let sessions = client.list_sessions()?;
+//! # Ok::<(), frostd_client::ClientError>(())
+//! ```
+
+pub mod client;
+pub mod error;
+#[cfg(feature = "http")]
+pub mod http;
+pub mod mock;
+pub mod types;
+
+pub use client::{FrostdClient, Response, Transport};
+pub use error::{ClientError, ClientErrorCode};
+#[cfg(feature = "http")]
+pub use http::HttpTransport;
+pub use mock::MockFrostd;
@@ -0,0 +1,448 @@
+//! In-process frostd for tests
+//!
+//! `MockFrostd` implements `Transport`, so any number of clients can run a
+//! full ceremony against it without a network. It keeps frostd's rules:
+//! challenges are single use and must be signed with XEdDSA, only a
+//! session's coordinator and participants can see it, only the coordinator
+//! can close it, and messages wait in per-recipient queues until received.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::client::{Response, Transport};
+use crate::error::ClientError;
+use crate::types::{
+    ChallengeResponse, CloseSessionRequest, CreateSessionRequest, CreateSessionResponse,
+    ErrorResponse, GetSessionInfoRequest, GetSessionInfoResponse, ListSessionsResponse,
+    LoginRequest, LoginResponse, PublicKey, ReceiveRequest, ReceiveResponse, ReceivedMessage,
+    SendRequest, SessionId,
+};
+
+/// Mock frostd server; clones share the same state
+#[derive(Clone, Default)]
+pub struct MockFrostd {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    /// Challenges handed out and not yet used
+    challenges: HashSet<Uuid>,
+    /// Access token -> public key of the logged-in user
+    tokens: HashMap<String, PublicKey>,
+    sessions: BTreeMap<SessionId, Session>,
+}
+
+struct Session {
+    coordinator: PublicKey,
+    pubkeys: Vec<PublicKey>,
+    message_count: u8,
+    /// Messages sent to the coordinator
+    coordinator_queue: Vec<ReceivedMessage>,
+    /// Messages sent to each participant
+    queues: HashMap<PublicKey, Vec<ReceivedMessage>>,
+}
+
+impl Session {
+    fn is_member(&self, pubkey: &PublicKey) -> bool {
+        self.coordinator == *pubkey || self.pubkeys.contains(pubkey)
+    }
+}
+
+/// Response body, or the frostd error to return with status 500
+type Reply = Result<String, ErrorResponse>;
+
+impl Transport for MockFrostd {
+    fn post(
+        &self,
+        endpoint: &str,
+        body: &str,
+        access_token: Option<&str>,
+    ) -> Result<Response, ClientError> {
+        let mut state = self.state.lock().expect("Mock frostd state poisoned");
+
+        let reply = match endpoint {
+            "/challenge" => state.challenge(),
+            "/login" => parse(body).and_then(|request| state.login(request)),
+            _ => state
+                .authorize(access_token)
+                .and_then(|user| state.handle(endpoint, body, &user, access_token)),
+        };
+
+        Ok(match reply {
+            Ok(body) => Response { status: 200, body },
+            Err(error) => Response {
+                status: 500,
+                body: to_body(&error),
+            },
+        })
+    }
+}
+
+impl State {
+    fn handle(
+        &mut self,
+        endpoint: &str,
+        body: &str,
+        user: &PublicKey,
+        access_token: Option<&str>,
+    ) -> Reply {
+        match endpoint {
+            "/logout" => {
+                if let Some(access_token) = access_token {
+                    self.tokens.remove(access_token);
+                }
+                Ok(String::new())
+            }
+            "/create_new_session" => self.create_session(user, parse(body)?),
+            "/list_sessions" => self.list_sessions(user),
+            "/get_session_info" => self.get_session_info(user, parse(body)?),
+            "/close_session" => self.close_session(user, parse(body)?),
+            "/send" => self.send(user, parse(body)?),
+            "/receive" => self.receive(user, parse(body)?),
+            _ => Err(ErrorResponse::new(
+                ErrorResponse::INVALID_ARGUMENT,
+                format!("Unknown endpoint {}", endpoint),
+            )),
+        }
+    }
+
+    // =========================================================================
+    // Authentication
+    // =========================================================================
+
+    fn challenge(&mut self) -> Reply {
+        let challenge = Uuid::new_v4();
+        self.challenges.insert(challenge);
+        Ok(to_body(&ChallengeResponse {
+            challenge: challenge.to_string(),
+        }))
+    }
+
+    fn login(&mut self, request: LoginRequest) -> Reply {
+        let challenge = Uuid::parse_str(&request.challenge)
+            .map_err(|e| invalid_argument(format!("Invalid challenge: {}", e)))?;
+
+        // Consumed whether or not the signature checks out
+        if !self.challenges.remove(&challenge) {
+            return Err(unauthorized("Unknown or already used challenge"));
+        }
+
+        let pubkey = hex::decode(&request.pubkey)
+            .map_err(|e| invalid_argument(format!("Invalid pubkey hex: {}", e)))?;
+        let signature = hex::decode(&request.signature)
+            .map_err(|e| invalid_argument(format!("Invalid signature hex: {}", e)))?;
+
+        let valid = xeddsa_wasm::verify(&pubkey, challenge.as_bytes(), &signature)
+            .map_err(invalid_argument)?;
+        if !valid {
+            return Err(unauthorized("Invalid challenge signature"));
+        }
+
+        let access_token = Uuid::new_v4().to_string();
+        self.tokens.insert(access_token.clone(), request.pubkey);
+        Ok(to_body(&LoginResponse { access_token }))
+    }
+
+    fn authorize(&self, access_token: Option<&str>) -> Result<PublicKey, ErrorResponse> {
+        access_token
+            .and_then(|access_token| self.tokens.get(access_token))
+            .cloned()
+            .ok_or_else(|| unauthorized("Unauthorized"))
+    }
+
+    // =========================================================================
+    // Session Management
+    // =========================================================================
+
+    fn create_session(&mut self, user: &PublicKey, request: CreateSessionRequest) -> Reply {
+        if request.pubkeys.is_empty() {
+            return Err(invalid_argument("A session needs at least one participant"));
+        }
+
+        let session_id = Uuid::new_v4().to_string();
+        let session = Session {
+            coordinator: user.clone(),
+            pubkeys: request.pubkeys,
+            message_count: request.message_count,
+            coordinator_queue: Vec::new(),
+            queues: HashMap::new(),
+        };
+        self.sessions.insert(session_id.clone(), session);
+
+        Ok(to_body(&CreateSessionResponse { session_id }))
+    }
+
+    fn list_sessions(&self, user: &PublicKey) -> Reply {
+        let session_ids = self
+            .sessions
+            .iter()
+            .filter(|(_, session)| session.is_member(user))
+            .map(|(session_id, _)| session_id.clone())
+            .collect();
+        Ok(to_body(&ListSessionsResponse { session_ids }))
+    }
+
+    fn get_session_info(&self, user: &PublicKey, request: GetSessionInfoRequest) -> Reply {
+        let session = self.session(user, &request.session_id)?;
+        Ok(to_body(&GetSessionInfoResponse {
+            message_count: session.message_count,
+            pubkeys: session.pubkeys.clone(),
+            coordinator_pubkey: session.coordinator.clone(),
+        }))
+    }
+
+    fn close_session(&mut self, user: &PublicKey, request: CloseSessionRequest) -> Reply {
+        if self.session(user, &request.session_id)?.coordinator != *user {
+            return Err(not_coordinator());
+        }
+        self.sessions.remove(&request.session_id);
+        Ok(String::new())
+    }
+
+    /// Look up a session the user belongs to; other sessions don't exist as far
+    /// as the user can tell
+    fn session(&self, user: &PublicKey, session_id: &SessionId) -> Result<&Session, ErrorResponse> {
+        self.sessions
+            .get(session_id)
+            .filter(|session| session.is_member(user))
+            .ok_or_else(session_not_found)
+    }
+
+    // =========================================================================
+    // Messaging
+    // =========================================================================
+
+    fn send(&mut self, user: &PublicKey, request: SendRequest) -> Reply {
+        self.session(user, &request.session_id)?;
+        let session = self
+            .sessions
+            .get_mut(&request.session_id)
+            .ok_or_else(session_not_found)?;
+
+        if let Some(unknown) = request
+            .recipients
+            .iter()
+            .find(|recipient| !session.pubkeys.contains(recipient))
+        {
+            return Err(invalid_argument(format!(
+                "Recipient {} is not in the session",
+                unknown
+            )));
+        }
+
+        let message = ReceivedMessage {
+            sender: user.clone(),
+            msg: request.msg,
+        };
+        if request.recipients.is_empty() {
+            session.coordinator_queue.push(message);
+        } else {
+            for recipient in request.recipients {
+                session
+                    .queues
+                    .entry(recipient)
+                    .or_default()
+                    .push(message.clone());
+            }
+        }
+
+        Ok(String::new())
+    }
+
+    fn receive(&mut self, user: &PublicKey, request: ReceiveRequest) -> Reply {
+        self.session(user, &request.session_id)?;
+        let session = self
+            .sessions
+            .get_mut(&request.session_id)
+            .ok_or_else(session_not_found)?;
+
+        let msgs = if request.as_coordinator {
+            if session.coordinator != *user {
+                return Err(not_coordinator());
+            }
+            std::mem::take(&mut session.coordinator_queue)
+        } else {
+            session.queues.remove(user).unwrap_or_default()
+        };
+
+        Ok(to_body(&ReceiveResponse { msgs }))
+    }
+}
+
+fn parse<T: DeserializeOwned>(body: &str) -> Result<T, ErrorResponse> {
+    serde_json::from_str(body).map_err(|e| invalid_argument(format!("Invalid request: {}", e)))
+}
+
+fn to_body<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).expect("frostd responses serialize to JSON")
+}
+
+fn invalid_argument(msg: impl Into<String>) -> ErrorResponse {
+    ErrorResponse::new(ErrorResponse::INVALID_ARGUMENT, msg)
+}
+
+fn unauthorized(msg: &str) -> ErrorResponse {
+    ErrorResponse::new(ErrorResponse::UNAUTHORIZED, msg)
+}
+
+fn session_not_found() -> ErrorResponse {
+    ErrorResponse::new(ErrorResponse::SESSION_NOT_FOUND, "Session not found")
+}
+
+fn not_coordinator() -> ErrorResponse {
+    ErrorResponse::new(
+        ErrorResponse::NOT_COORDINATOR,
+        "Only the coordinator can do this",
+    )
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::FrostdClient;
+    use crate::error::ClientErrorCode;
+    use frost_wasm::{Commitment, SignatureShare, SigningPackage};
+
+    // The mock relays opaque bytes, so the test skips end-to-end encryption
+    // and sends hex-encoded JSON
+    fn encode<T: Serialize>(value: &T) -> String {
+        hex::encode(serde_json::to_vec(value).unwrap())
+    }
+
+    fn decode<T: DeserializeOwned>(msg: &str) -> T {
+        serde_json::from_slice(&hex::decode(msg).unwrap()).unwrap()
+    }
+
+    fn logged_in(server: &MockFrostd) -> (PublicKey, FrostdClient<MockFrostd>) {
+        let key = xeddsa_wasm::generate_keypair();
+        let mut client = FrostdClient::new(server.clone());
+        client
+            .authenticate(&key.private_key())
+            .expect("Login should succeed");
+        (hex::encode(key.public_key()), client)
+    }
+
+    #[test]
+    fn test_signing_ceremony() {
+        let server = MockFrostd::default();
+        let keygen = frost_wasm::json::generate_key_shares(2, 3).unwrap();
+        let message = "48656c6c6f20576f726c64";
+
+        let (coordinator_pubkey, coordinator) = logged_in(&server);
+        let signers: Vec<_> = keygen.shares[..2]
+            .iter()
+            .map(|share| {
+                let (pubkey, client) = logged_in(&server);
+                (share, pubkey, client)
+            })
+            .collect();
+        let pubkeys: Vec<PublicKey> = signers
+            .iter()
+            .map(|(_, pubkey, _)| pubkey.clone())
+            .collect();
+
+        let session_id = coordinator.create_session(&pubkeys, 1).unwrap().session_id;
+
+        // Round 1: every signer sends its commitment to the coordinator
+        let mut nonces = Vec::new();
+        for (share, _, client) in &signers {
+            assert_eq!(
+                client.list_sessions().unwrap().session_ids,
+                std::slice::from_ref(&session_id)
+            );
+            let info = client.get_session_info(&session_id).unwrap();
+            assert_eq!(info.coordinator_pubkey, coordinator_pubkey);
+
+            let round1 = frost_wasm::json::generate_round1_commitment(&share.key_package).unwrap();
+            client
+                .send(&session_id, &[], &encode(&round1.commitment))
+                .unwrap();
+            nonces.push(round1.nonces);
+        }
+
+        let commitments: Vec<Commitment> = coordinator
+            .receive(&session_id, true)
+            .unwrap()
+            .iter()
+            .map(|m| decode(&m.msg))
+            .collect();
+        assert_eq!(commitments.len(), 2);
+
+        let package = frost_wasm::json::create_signing_package(
+            &serde_json::to_string(&commitments).unwrap(),
+            message,
+        )
+        .unwrap();
+        coordinator
+            .send(&session_id, &pubkeys, &encode(&package))
+            .unwrap();
+
+        // Round 2: every signer signs the package it received
+        for ((share, _, client), nonces) in signers.iter().zip(&nonces) {
+            let error = client
+                .receive(&session_id, true)
+                .expect_err("Only the coordinator receives as coordinator");
+            assert_eq!(error.code, ClientErrorCode::NotCoordinator);
+
+            let msgs = client.receive(&session_id, false).unwrap();
+            assert_eq!(msgs.len(), 1);
+            assert_eq!(msgs[0].sender, coordinator_pubkey);
+            let package: SigningPackage = decode(&msgs[0].msg);
+
+            let signature_share = frost_wasm::json::generate_round2_signature_with_package(
+                &share.key_package,
+                &serde_json::to_string(nonces).unwrap(),
+                &package.signing_package,
+            )
+            .unwrap();
+            client
+                .send(&session_id, &[], &encode(&signature_share))
+                .unwrap();
+        }
+
+        let shares: Vec<SignatureShare> = coordinator
+            .receive(&session_id, true)
+            .unwrap()
+            .iter()
+            .map(|m| decode(&m.msg))
+            .collect();
+        let signature = frost_wasm::json::aggregate_signature_with_package(
+            &serde_json::to_string(&shares).unwrap(),
+            &package.signing_package,
+            &keygen.public_key_package,
+        )
+        .unwrap();
+
+        let verify = frost_wasm::json::verify_signature(
+            &signature.signature,
+            message,
+            &keygen.group_public_key,
+        )
+        .unwrap();
+        assert!(verify.valid);
+
+        let (_, outsider) = logged_in(&server);
+        let error = outsider
+            .get_session_info(&session_id)
+            .expect_err("Outsiders should not see the session");
+        assert_eq!(error.code, ClientErrorCode::SessionNotFound);
+
+        let error = signers[0]
+            .2
+            .close_session(&session_id)
+            .expect_err("Only the coordinator can close the session");
+        assert_eq!(error.code, ClientErrorCode::NotCoordinator);
+
+        coordinator.close_session(&session_id).unwrap();
+        assert!(coordinator.list_sessions().unwrap().session_ids.is_empty());
+    }
+}
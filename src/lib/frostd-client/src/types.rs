@@ -0,0 +1,156 @@
+//! frostd REST API types
+//!
+//! Request and response bodies matching the official frostd spec
+//! (https://frost.zfnd.org/zcash/server.html) and `src/types/api.ts`.
+
+use serde::{Deserialize, Serialize};
+
+/// Hex-encoded public key (the XEdDSA / X25519 key used to log in)
+pub type PublicKey = String;
+
+/// Session identifier (UUID)
+pub type SessionId = String;
+
+// =============================================================================
+// Authentication API
+// =============================================================================
+
+/// POST /challenge response
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChallengeResponse {
+    /// UUID challenge to be signed
+    pub challenge: String,
+}
+
+/// POST /login request
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoginRequest {
+    /// The UUID challenge from /challenge
+    pub challenge: String,
+    /// Client's public key (hex-encoded)
+    pub pubkey: PublicKey,
+    /// XEdDSA signature over the challenge UUID's 16 bytes (hex-encoded)
+    pub signature: String,
+}
+
+/// POST /login response
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoginResponse {
+    /// Bearer token for authenticated requests (valid for 1 hour)
+    pub access_token: String,
+}
+
+// =============================================================================
+// Session Management API
+// =============================================================================
+
+/// POST /create_new_session request (coordinator)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateSessionRequest {
+    /// Public keys of all participants
+    pub pubkeys: Vec<PublicKey>,
+    /// Number of messages to sign in this session
+    pub message_count: u8,
+}
+
+/// POST /create_new_session response
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CreateSessionResponse {
+    /// Created session identifier
+    pub session_id: SessionId,
+}
+
+/// POST /list_sessions response
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ListSessionsResponse {
+    /// Sessions the user coordinates or takes part in
+    pub session_ids: Vec<SessionId>,
+}
+
+/// POST /get_session_info request
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetSessionInfoRequest {
+    pub session_id: SessionId,
+}
+
+/// POST /get_session_info response
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GetSessionInfoResponse {
+    /// Number of messages to sign
+    pub message_count: u8,
+    /// Participant public keys
+    pub pubkeys: Vec<PublicKey>,
+    /// Coordinator's public key
+    pub coordinator_pubkey: PublicKey,
+}
+
+/// POST /close_session request (coordinator)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CloseSessionRequest {
+    pub session_id: SessionId,
+}
+
+// =============================================================================
+// Messaging API
+// =============================================================================
+
+/// POST /send request
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SendRequest {
+    /// Session context
+    pub session_id: SessionId,
+    /// Recipient public keys (empty = send to the coordinator)
+    pub recipients: Vec<PublicKey>,
+    /// Hex-encoded message - MUST be end-to-end encrypted
+    pub msg: String,
+}
+
+/// POST /receive request
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReceiveRequest {
+    /// Session context
+    pub session_id: SessionId,
+    /// True to receive the messages sent to the coordinator
+    pub as_coordinator: bool,
+}
+
+/// A message waiting for the receiver
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReceivedMessage {
+    /// Sender's public key
+    pub sender: PublicKey,
+    /// Hex-encoded message
+    pub msg: String,
+}
+
+/// POST /receive response
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReceiveResponse {
+    #[serde(default)]
+    pub msgs: Vec<ReceivedMessage>,
+}
+
+// =============================================================================
+// Errors
+// =============================================================================
+
+/// Error body returned by frostd (status 500)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ErrorResponse {
+    pub code: u32,
+    pub msg: String,
+}
+
+impl ErrorResponse {
+    pub const INVALID_ARGUMENT: u32 = 1;
+    pub const UNAUTHORIZED: u32 = 2;
+    pub const SESSION_NOT_FOUND: u32 = 3;
+    pub const NOT_COORDINATOR: u32 = 4;
+
+    pub fn new(code: u32, msg: impl Into<String>) -> Self {
+        ErrorResponse {
+            code,
+            msg: msg.into(),
+        }
+    }
+}
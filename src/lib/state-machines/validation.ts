@@ -444,7 +444,12 @@ export function validateMessageIdLinkage(
 /**
  * Valid backend IDs.
  */
-const VALID_BACKENDS: BackendId[] = ['ed25519', 'orchard-redpallas'];
+const VALID_BACKENDS: BackendId[] = ['ed25519', 'orchard-redpallas', 'sapling-redjubjub'];
+
+/**
+ * Backends that use rerandomized FROST and so require a randomizer.
+ */
+const RERANDOMIZED_BACKENDS: BackendId[] = ['orchard-redpallas', 'sapling-redjubjub'];
 
 /**
  * Validate backend ID.
@@ -690,11 +695,15 @@ export function validateCommitmentsSet(
     };
   }
 
-  // For Orchard, randomizer must not be empty
-  if (expectedBackend === 'orchard-redpallas' && p.randomizer.length === 0) {
+  // For rerandomized backends, randomizer must not be empty
+  if (
+    expectedBackend &&
+    RERANDOMIZED_BACKENDS.includes(expectedBackend) &&
+    p.randomizer.length === 0
+  ) {
     return {
       code: 'MISSING_RANDOMIZER',
-      message: 'randomizer is required for orchard-redpallas backend',
+      message: `randomizer is required for ${expectedBackend} backend`,
     };
   }
 
@@ -802,11 +811,14 @@ export function validateSignatureResult(
     };
   }
 
-  // For Orchard, randomizer should be present
-  if (p.backendId === 'orchard-redpallas' && (!p.randomizer || p.randomizer.length === 0)) {
+  // For rerandomized backends, randomizer should be present
+  if (
+    RERANDOMIZED_BACKENDS.includes(p.backendId) &&
+    (!p.randomizer || p.randomizer.length === 0)
+  ) {
     return {
       code: 'MISSING_RANDOMIZER',
-      message: 'randomizer is required for orchard-redpallas results',
+      message: `randomizer is required for ${p.backendId} results`,
     };
   }
 
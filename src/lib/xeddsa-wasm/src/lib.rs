@@ -2,28 +2,36 @@
 //!
 //! This module provides WASM bindings for XEdDSA signing and verification,
 //! using the same xeddsa crate that frostd uses for authentication.
+//!
+//! The functions here can be called from native Rust with default features
+//! off. The `wasm` feature (on by default) adds the wasm-bindgen exports in
+//! the `wasm` module.
 
+#[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 use xeddsa::xed25519::{PrivateKey as XEdPrivateKey, PublicKey as XEdPublicKey};
 use xeddsa::{Sign, Verify}; // Import traits for sign/verify methods
 use x25519_dalek::{PublicKey, StaticSecret};
 use rand::rngs::OsRng;
 
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 /// Result of keypair generation
-#[wasm_bindgen]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub struct Keypair {
     private_key: Vec<u8>,
     public_key: Vec<u8>,
 }
 
-#[wasm_bindgen]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 impl Keypair {
-    #[wasm_bindgen(getter)]
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
     pub fn private_key(&self) -> Vec<u8> {
         self.private_key.clone()
     }
 
-    #[wasm_bindgen(getter)]
+    #[cfg_attr(feature = "wasm", wasm_bindgen(getter))]
     pub fn public_key(&self) -> Vec<u8> {
         self.public_key.clone()
     }
@@ -31,7 +39,6 @@ impl Keypair {
 
 /// Generate a new X25519 keypair for XEdDSA signing.
 /// Returns a Keypair with 32-byte private_key and 32-byte public_key.
-#[wasm_bindgen]
 pub fn generate_keypair() -> Keypair {
     let secret = StaticSecret::random_from_rng(OsRng);
     let public = PublicKey::from(&secret);
@@ -49,10 +56,9 @@ pub fn generate_keypair() -> Keypair {
 ///
 /// # Returns
 /// 32-byte X25519 public key
-#[wasm_bindgen]
-pub fn get_public_key(private_key: &[u8]) -> Result<Vec<u8>, JsValue> {
+pub fn get_public_key(private_key: &[u8]) -> Result<Vec<u8>, String> {
     if private_key.len() != 32 {
-        return Err(JsValue::from_str("Private key must be 32 bytes"));
+        return Err("Private key must be 32 bytes".into());
     }
 
     let mut pk_bytes = [0u8; 32];
@@ -73,10 +79,9 @@ pub fn get_public_key(private_key: &[u8]) -> Result<Vec<u8>, JsValue> {
 ///
 /// # Returns
 /// 64-byte XEdDSA signature
-#[wasm_bindgen]
-pub fn sign(private_key: &[u8], message: &[u8]) -> Result<Vec<u8>, JsValue> {
+pub fn sign(private_key: &[u8], message: &[u8]) -> Result<Vec<u8>, String> {
     if private_key.len() != 32 {
-        return Err(JsValue::from_str("Private key must be 32 bytes"));
+        return Err("Private key must be 32 bytes".into());
     }
 
     let mut pk_bytes = [0u8; 32];
@@ -87,7 +92,7 @@ pub fn sign(private_key: &[u8], message: &[u8]) -> Result<Vec<u8>, JsValue> {
 
     // Use xeddsa crate's sign method - same as frostd uses
     // Returns [u8; 64] signature
-    let signature: [u8; 64] = xed_privkey.sign(message, &mut OsRng);
+    let signature: [u8; 64] = xed_privkey.sign(message, OsRng);
 
     Ok(signature.to_vec())
 }
@@ -102,13 +107,12 @@ pub fn sign(private_key: &[u8], message: &[u8]) -> Result<Vec<u8>, JsValue> {
 ///
 /// # Returns
 /// true if signature is valid, false otherwise
-#[wasm_bindgen]
-pub fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool, JsValue> {
+pub fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool, String> {
     if public_key.len() != 32 {
-        return Err(JsValue::from_str("Public key must be 32 bytes"));
+        return Err("Public key must be 32 bytes".into());
     }
     if signature.len() != 64 {
-        return Err(JsValue::from_str("Signature must be 64 bytes"));
+        return Err("Signature must be 64 bytes".into());
     }
 
     let mut pk_bytes = [0u8; 32];
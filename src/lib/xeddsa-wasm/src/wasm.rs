@@ -0,0 +1,34 @@
+//! wasm-bindgen exports
+//!
+//! Each export calls the Rust function of the same name in the crate root,
+//! turning its error message into a thrown JS error.
+
+use wasm_bindgen::prelude::*;
+
+use crate::Keypair;
+
+/// Generate a new X25519 keypair for XEdDSA signing.
+/// Returns a Keypair with 32-byte private_key and 32-byte public_key.
+#[wasm_bindgen]
+pub fn generate_keypair() -> Keypair {
+    crate::generate_keypair()
+}
+
+/// Get the X25519 public key from a 32-byte private key.
+#[wasm_bindgen]
+pub fn get_public_key(private_key: &[u8]) -> Result<Vec<u8>, JsValue> {
+    crate::get_public_key(private_key).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Sign a message using XEdDSA with an X25519 private key.
+/// Returns the 64-byte signature.
+#[wasm_bindgen]
+pub fn sign(private_key: &[u8], message: &[u8]) -> Result<Vec<u8>, JsValue> {
+    crate::sign(private_key, message).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Verify an XEdDSA signature using an X25519 public key.
+#[wasm_bindgen]
+pub fn verify(public_key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool, JsValue> {
+    crate::verify(public_key, message, signature).map_err(|e| JsValue::from_str(&e))
+}
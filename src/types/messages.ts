@@ -102,7 +102,7 @@ export interface WasmSignatureShare {
  * Matches test-zcash-ceremony-live.ts wire format.
  */
 export interface SigningPackagePayload {
-  /** FROST backend to use ('ed25519' | 'orchard-redpallas') */
+  /** FROST backend to use ('ed25519' | 'orchard-redpallas' | 'sapling-redjubjub') */
   backendId: BackendId;
   /** Unique identifier for this signing attempt - links all related messages */
   message_id: string;